@@ -0,0 +1,401 @@
+//! Generates the field name constants of the models in src/model, and when the protobuf
+//! feature is enabled, the message schemas used to decode the protobuf responses
+//! from IGDB's published schema in proto/igdbapi.proto.
+//! When the generated-models feature is enabled, it generates a model per message of the
+//! schema as well. IGDB_PROTO points to another copy of the schema, e.g. one freshly
+//! downloaded from https://api.igdb.com/v4/igdbapi.proto.
+
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const PROTO: &str = "proto/igdbapi.proto";
+const MODELS: &str = "src/model";
+
+struct ProtoField {
+    number: u32,
+    name: String,
+    kind: String,
+    repeated: bool,
+}
+
+struct ProtoMessage {
+    name: String,
+    fields: Vec<ProtoField>,
+}
+
+fn main() {
+    let proto_path = env::var("IGDB_PROTO").unwrap_or_else(|_| PROTO.to_owned());
+    println!("cargo:rerun-if-env-changed=IGDB_PROTO");
+    println!("cargo:rerun-if-changed={}", proto_path);
+    println!("cargo:rerun-if-changed={}", MODELS);
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+
+    let fields_dir = Path::new(&out_dir).join("fields");
+    fs::create_dir_all(&fields_dir).expect("fields directory is created");
+    for entry in fs::read_dir(MODELS).expect("src/model is readable") {
+        let path = entry.expect("src/model is readable").path();
+        let model = fs::read_to_string(&path).expect("models are readable");
+        if let Some((name, fields)) = parse_model(&model) {
+            fs::write(
+                fields_dir.join(path.file_name().unwrap()),
+                generate_fields(&name, &fields),
+            )
+            .expect("field names are written");
+        }
+    }
+
+    let protobuf = env::var_os("CARGO_FEATURE_PROTOBUF").is_some();
+    let models = env::var_os("CARGO_FEATURE_GENERATED_MODELS").is_some();
+    if !protobuf && !models {
+        return;
+    }
+
+    let proto = fs::read_to_string(&proto_path).expect("igdbapi.proto is readable");
+    let (messages, enums) = parse(&proto);
+    if protobuf {
+        fs::write(
+            Path::new(&out_dir).join("protobuf_schema.rs"),
+            generate(&messages, &enums),
+        )
+        .expect("protobuf schema is written");
+    }
+    if models {
+        fs::write(
+            Path::new(&out_dir).join("generated_models.rs"),
+            generate_models(&messages, &enums),
+        )
+        .expect("generated models are written");
+    }
+}
+
+///Returns the name and the public fields of the first struct of a model file
+fn parse_model(model: &str) -> Option<(String, Vec<String>)> {
+    let mut lines = model.lines().map(str::trim);
+    let name = lines.find_map(|line| {
+        line.strip_prefix("pub struct ")
+            .and_then(|line| line.strip_suffix(" {"))
+    })?;
+    let fields: Vec<String> = lines
+        .take_while(|line| *line != "}")
+        .filter_map(|line| line.strip_prefix("pub "))
+        .filter_map(|line| line.split(':').next())
+        .map(str::to_owned)
+        .collect();
+
+    match fields.is_empty() {
+        true => None,
+        false => Some((name.to_owned(), fields)),
+    }
+}
+
+///Generates a constant and an enum variant per field, e.g. FIRST_RELEASE_DATE and
+/// GameField::FirstReleaseDate for Game.first_release_date
+fn generate_fields(name: &str, fields: &[String]) -> String {
+    let mut code = String::new();
+    for field in fields {
+        writeln!(
+            code,
+            "pub const {}: &str = \"{}\";",
+            field.to_uppercase(),
+            field
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        code,
+        "\n///A field of {name}, accepted wherever a field name is\n\
+         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]\npub enum {name}Field {{"
+    )
+    .unwrap();
+    for field in fields {
+        writeln!(code, "    {},", camel_case(field)).unwrap();
+    }
+    writeln!(
+        code,
+        "}}\n\nimpl {name}Field {{\n    pub fn as_str(self) -> &'static str {{\n        match self {{"
+    )
+    .unwrap();
+    for field in fields {
+        writeln!(
+            code,
+            "            {}Field::{} => {},",
+            name,
+            camel_case(field),
+            field.to_uppercase()
+        )
+        .unwrap();
+    }
+    writeln!(
+        code,
+        "        }}\n    }}\n}}\n\n\
+         impl From<{name}Field> for String {{\n    fn from(field: {name}Field) -> Self {{\n        \
+         field.as_str().to_owned()\n    }}\n}}\n\n\
+         impl std::fmt::Display for {name}Field {{\n    \
+         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{\n        \
+         f.write_str(self.as_str())\n    }}\n}}"
+    )
+    .unwrap();
+    code
+}
+
+///first_release_date => FirstReleaseDate
+fn camel_case(field: &str) -> String {
+    field
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .concat()
+}
+
+///Parses the messages and the enum names of the schema, which only uses plain
+/// `[repeated] type name = number;` fields
+fn parse(proto: &str) -> (Vec<ProtoMessage>, HashSet<String>) {
+    let mut messages = Vec::new();
+    let mut enums = HashSet::new();
+    let mut current: Option<ProtoMessage> = None;
+
+    for line in proto.lines().map(str::trim) {
+        let words: Vec<&str> = line
+            .trim_end_matches(';')
+            .split_whitespace()
+            .filter(|word| *word != "=")
+            .collect();
+
+        match words.as_slice() {
+            ["message", name, "{"] => {
+                current = Some(ProtoMessage {
+                    name: name.to_string(),
+                    fields: Vec::new(),
+                })
+            }
+            ["enum", name, "{"] => {
+                enums.insert(name.to_string());
+            }
+            ["}"] => messages.extend(current.take()),
+            [kind, name, number] | ["repeated", kind, name, number] => {
+                if let Some(message) = current.as_mut() {
+                    message.fields.push(ProtoField {
+                        number: number.parse().expect("field numbers are integers"),
+                        name: name.to_string(),
+                        kind: kind.to_string(),
+                        repeated: words[0] == "repeated",
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (messages, enums)
+}
+
+fn generate(messages: &[ProtoMessage], enums: &HashSet<String>) -> String {
+    let mut schema = String::new();
+
+    // Multiquery responses are always read as json
+    let messages: Vec<&ProtoMessage> = messages
+        .iter()
+        .filter(|message| !message.name.starts_with("MultiQuery"))
+        .collect();
+
+    for message in &messages {
+        writeln!(
+            schema,
+            "static {}: Message = Message {{ fields: &[",
+            static_name(&message.name)
+        )
+        .unwrap();
+        for field in &message.fields {
+            writeln!(
+                schema,
+                "    Field {{ number: {}, name: \"{}\", kind: {}, repeated: {} }},",
+                field.number,
+                field.name,
+                kind(&field.kind, enums),
+                field.repeated
+            )
+            .unwrap();
+        }
+        writeln!(schema, "] }};\n").unwrap();
+    }
+
+    // Every endpoint answers a message wrapping the registries in its only field,
+    // named after the endpoint path without underscores, e.g. GameEngineResult.gameengines
+    writeln!(
+        schema,
+        "pub(crate) fn result_message(field: &str) -> Option<&'static Message> {{\n    match field {{"
+    )
+    .unwrap();
+    for message in messages.iter().filter(|m| m.name.ends_with("Result")) {
+        if let [field] = message.fields.as_slice() {
+            writeln!(
+                schema,
+                "        \"{}\" => Some(&{}),",
+                field.name,
+                static_name(&message.name)
+            )
+            .unwrap();
+        }
+    }
+    writeln!(schema, "        _ => None,\n    }}\n}}").unwrap();
+
+    schema
+}
+
+///Generates a module per registry message, e.g. `game_engine` for GameEngine, holding
+/// the model, its field names and its Endpoint
+fn generate_models(messages: &[ProtoMessage], enums: &HashSet<String>) -> String {
+    let mut code = String::new();
+    let identifiable: HashSet<&str> = messages
+        .iter()
+        .filter(|m| m.fields.iter().any(|f| f.name == "id"))
+        .map(|m| m.name.as_str())
+        .collect();
+    // The endpoint paths are named after the only field of the results, without underscores
+    let paths: Vec<&str> = messages
+        .iter()
+        .filter(|m| m.name.ends_with("Result") && !m.name.starts_with("MultiQuery"))
+        .filter_map(|m| match m.fields.as_slice() {
+            [field] => Some(field.name.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    for message in messages.iter().filter(|m| {
+        !m.name.ends_with("Result") && !m.name.starts_with("MultiQuery") && m.name != "Count"
+    }) {
+        let name = &message.name;
+        let module = static_name(name).to_lowercase();
+        writeln!(code, "pub mod {module} {{\n    use super::*;\n").unwrap();
+        writeln!(
+            code,
+            "    ///{name} as described by igdbapi.proto\n    \
+             #[derive(Deserialize, Debug, Clone, Default)]\n    \
+             #[serde(default)]\n    pub struct {name} {{"
+        )
+        .unwrap();
+        for field in &message.fields {
+            let mut kind = rust_type(&field.kind, enums, &identifiable);
+            if field.repeated {
+                kind = format!("Vec<{}>", kind);
+            }
+            writeln!(code, "        pub {}: {},", identifier(&field.name), kind).unwrap();
+        }
+        writeln!(code, "    }}\n").unwrap();
+
+        if identifiable.contains(name.as_str()) {
+            writeln!(
+                code,
+                "    impl Identifiable for {name} {{\n        type Id = u64;\n\n        \
+                 fn id(&self) -> u64 {{\n            self.id\n        }}\n    }}\n"
+            )
+            .unwrap();
+        }
+
+        if let Some(path) = endpoint_path(&module, &paths) {
+            writeln!(
+                code,
+                "    ///The `{path}` endpoint, e.g. for IGDBClient::query\n    \
+                 pub struct {name}Endpoint;\n\n    \
+                 impl Endpoint for {name}Endpoint {{\n        type Model = {name};\n        \
+                 const PATH: &'static str = \"{path}\";\n    }}\n"
+            )
+            .unwrap();
+        }
+
+        let fields: Vec<String> = message.fields.iter().map(|f| f.name.clone()).collect();
+        writeln!(
+            code,
+            "    ///Field names of {name}, e.g. for RequestBuilder::add_field\n    \
+             pub mod fields {{\n{}\n    }}\n}}\n",
+            generate_fields(name, &fields)
+        )
+        .unwrap();
+    }
+    code
+}
+
+///The path of the endpoint serving the registries of the module, e.g. game_engines for
+/// game_engine, found among the result field names
+fn endpoint_path(module: &str, paths: &[&str]) -> Option<String> {
+    let candidates = [
+        module.to_owned(),
+        format!("{}s", module),
+        format!("{}ies", module.trim_end_matches('y')),
+        format!("{}es", module),
+    ];
+    let path = candidates
+        .into_iter()
+        .find(|candidate| paths.contains(&candidate.replace('_', "").as_str()))?;
+    // The search endpoint is the only one named in the singular
+    match path.as_str() {
+        "searches" => Some("search".to_owned()),
+        _ => Some(path),
+    }
+}
+
+fn rust_type(kind: &str, enums: &HashSet<String>, identifiable: &HashSet<&str>) -> String {
+    match kind {
+        "bool" => "bool".to_owned(),
+        "double" => "f64".to_owned(),
+        "int32" | "int64" => "i64".to_owned(),
+        "uint32" | "uint64" => "u64".to_owned(),
+        "string" => "String".to_owned(),
+        // Timestamps are answered as seconds since the unix epoch in json
+        "google.protobuf.Timestamp" => "u64".to_owned(),
+        kind if enums.contains(kind) => "i32".to_owned(),
+        kind if identifiable.contains(kind) => {
+            format!("IdOr<Box<{}::{}>>", static_name(kind).to_lowercase(), kind)
+        }
+        kind => format!(
+            "Option<Box<{}::{}>>",
+            static_name(kind).to_lowercase(),
+            kind
+        ),
+    }
+}
+
+///Escapes the field names which are rust keywords, e.g. `type`
+fn identifier(field: &str) -> String {
+    match field {
+        "type" | "match" | "ref" | "self" | "struct" | "enum" | "mod" | "use" | "loop" => {
+            format!("r#{}", field)
+        }
+        field => field.to_owned(),
+    }
+}
+
+fn kind(kind: &str, enums: &HashSet<String>) -> String {
+    match kind {
+        "bool" => "Kind::Bool".to_owned(),
+        "double" => "Kind::Double".to_owned(),
+        "int32" | "int64" => "Kind::Int".to_owned(),
+        "uint32" | "uint64" => "Kind::Uint".to_owned(),
+        "string" => "Kind::String".to_owned(),
+        "google.protobuf.Timestamp" => "Kind::Timestamp".to_owned(),
+        kind if enums.contains(kind) => "Kind::Enum".to_owned(),
+        kind => format!("Kind::Message(&{})", static_name(kind)),
+    }
+}
+
+///GameEngineLogo => GAME_ENGINE_LOGO
+fn static_name(name: &str) -> String {
+    let mut static_name = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i != 0 {
+            static_name.push('_');
+        }
+        static_name.push(c.to_ascii_uppercase());
+    }
+    static_name
+}
@@ -13,7 +13,7 @@ fn main() {
 
         for franchise in franchises_client.get_by_name("Lego", 5).await.unwrap() {
             for game in &franchise.games {
-                let game_info = games_client.get_first_by_id(*game as usize).await.unwrap();
+                let game_info = games_client.get_first_by_id(*game).await.unwrap();
                 println!("Name: {}", game_info.name);
                 // avoid hitting the rate limits (4 request per second)
                 thread::sleep(time::Duration::from_millis(300));
@@ -22,10 +22,7 @@ fn main() {
         for age_rating in game.age_ratings {
             //Get a maximum of 3 age ratings for Modern Warfare 3
 
-            let ratings = age_rating_client
-                .get_by_id(age_rating as usize, 3)
-                .await
-                .unwrap();
+            let ratings = age_rating_client.get_by_id(age_rating, 3).await.unwrap();
 
             for rating in ratings {
                 println!(
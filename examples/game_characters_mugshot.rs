@@ -1,7 +1,6 @@
 use async_std::task;
 use igdb::client::IGDBClient;
 use igdb::media_quality::MediaQuality;
-use igdb::request_builder::Equality;
 
 fn main() {
     task::block_on(async {
@@ -16,8 +15,8 @@ fn main() {
         let mut mug_shot_request = IGDBClient::create_request();
         mug_shot_request
             .add_fields(vec!["name", "mug_shot"])
-            .add_where_in("games".to_string(), vec!["550".to_string()])
-            .add_where("mug_shot", Equality::NotEqual, "null")
+            .add_where_in("games", vec![550])
+            .add_where_not_null("mug_shot")
             .limit(10);
 
         let mugshots_client = igdb_client.character_mug_shots();
@@ -26,7 +25,7 @@ fn main() {
             println!("{:?}", ch);
             mugshots_client
                 .download_by_id(
-                    ch.mug_shot as usize,
+                    ch.mug_shot,
                     format!("{}.png", ch.name),
                     MediaQuality::Original,
                 )
@@ -16,10 +16,7 @@ fn main() {
         let engine_id = game.game_engines.first().unwrap();
 
         let engines_client = igdb_client.game_engines();
-        let engine = engines_client
-            .get_first_by_id(*engine_id as usize)
-            .await
-            .unwrap();
+        let engine = engines_client.get_first_by_id(*engine_id).await.unwrap();
 
         println!(
             "name: {}, url: {}, companies: {:?}",
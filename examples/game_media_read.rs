@@ -30,7 +30,7 @@ fn main() {
                 .await
                 .unwrap();
 
-            debug_assert!(bytes.len() > 0);
+            debug_assert!(!bytes.is_empty());
 
             let mut f = std::fs::File::create(format!("{}.jpg", screen.id)).unwrap();
             f.write_all(&bytes).unwrap();
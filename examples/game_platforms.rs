@@ -15,12 +15,7 @@ fn main() {
 
         let mut game_platforms = vec![];
         for p_id in game.platforms {
-            game_platforms.push(
-                platforms_client
-                    .get_first_by_id(p_id as usize)
-                    .await
-                    .unwrap(),
-            );
+            game_platforms.push(platforms_client.get_first_by_id(p_id.id()).await.unwrap());
         }
 
         for platform in game_platforms {
@@ -22,10 +22,7 @@ fn main() {
         let platform_logos_client = igdb_client.platform_logos();
 
         for p_id in game.platforms {
-            let platform = platforms_client
-                .get_first_by_id(p_id as usize)
-                .await
-                .unwrap();
+            let platform = platforms_client.get_first_by_id(p_id.id()).await.unwrap();
 
             platform_logos_client
                 .download_by_id(
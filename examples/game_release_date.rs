@@ -18,7 +18,7 @@ fn main() {
 
         for release in releases {
             let platform = platform_client
-                .get_first_by_id(release.platform as usize)
+                .get_first_by_id(release.platform)
                 .await
                 .unwrap();
 
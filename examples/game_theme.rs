@@ -19,7 +19,7 @@ fn main() {
         let themes_client = igdb_client.themes();
 
         for theme in game.themes {
-            let results = themes_client.get_by_id(theme as usize, 10).await.unwrap();
+            let results = themes_client.get_by_id(theme.id(), 10).await.unwrap();
             for r in results {
                 println!("{:?}", r);
             }
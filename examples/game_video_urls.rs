@@ -16,6 +16,7 @@ fn main() {
 
         for video in response {
             println!("{:?}", video);
+            println!("{}", video.youtube_url());
         }
 
         //  GameVideo { id: 5993, game: 1942, video_id: "xQGam9OHSUo" }
@@ -18,12 +18,12 @@ fn main() {
             .add_field("name")
             .add_fields(vec!["storyline", "summary"])
             .contains("name", "Ast")
-            .add_where("category", Equality::NotEqual, "0")
+            .add_where("category", Equality::NotEqual, 0)
             .sort_by("name", OrderBy::Descending)
             .limit(3);
 
         // Generated query
-        // fields name,storyline,summary; where name  ~ *"Ast"* & category != 0; sort name desc; limit 3;
+        // fields name,storyline,summary; where name ~ *"Ast"* & category != 0; sort name desc; limit 3;
 
         let game_client = igdb_client.games();
         let games = game_client.get(game_request).await.unwrap();
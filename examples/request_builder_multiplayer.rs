@@ -20,14 +20,14 @@ fn main() {
 
         let result = games_client.get(games_req).await.unwrap();
 
-        let ids: Vec<String> = result.iter().map(|g| g.id.to_string()).collect();
+        let ids: Vec<_> = result.iter().map(|g| g.id).collect();
         let names: Vec<String> = result.iter().map(|g| g.name.clone()).collect();
 
         let multiplayer_client = igdb_client.multiplayer_modes();
 
         let mut mul_request = IGDBClient::create_request();
 
-        mul_request.all_fields().add_where_in("id".to_owned(), ids);
+        mul_request.all_fields().add_where_id_in("id", ids);
 
         let results = multiplayer_client.get(mul_request).await.unwrap();
 
@@ -0,0 +1,275 @@
+use crate::error::ensure_success;
+use crate::time::{Instant, SystemTime};
+use crate::token_store::{StoredToken, TokenStore};
+use crate::transport::{default_transport, HttpTransport};
+use crate::Error;
+use async_std::sync::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+
+///Credentials used to authenticate against the IGDB v4 api:
+/// the Twitch application Client-ID and an OAuth app access token,
+/// sent as `Client-ID` and `Authorization: Bearer` headers.
+/// # Examples
+/// ```
+/// use igdb::auth::Credentials;
+/// use igdb::client::IGDBClient;
+///
+/// let credentials = Credentials::new("client_id", "access_token");
+/// let igdb = IGDBClient::with_credentials(credentials);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Credentials {
+    pub client_id: String,
+    pub access_token: String,
+}
+
+impl Credentials {
+    pub fn new<S: Into<String>>(client_id: S, access_token: S) -> Credentials {
+        Credentials {
+            client_id: client_id.into(),
+            access_token: access_token.into(),
+        }
+    }
+}
+
+const TWITCH_TOKEN_URL: &str = "https://id.twitch.tv/oauth2/token";
+///Tokens are refreshed this long before IGDB would reject them
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+///Acquires Twitch app access tokens from the client_id and client_secret
+/// of a Twitch application, caches them and refreshes them before they expire.
+/// See with_token_store to reuse them across runs or processes.
+/// # Examples
+/// ```
+/// use igdb::auth::TwitchAuthenticator;
+/// use igdb::client::IGDBClient;
+///
+/// let igdb = IGDBClient::with_authenticator(TwitchAuthenticator::new("client_id", "client_secret"));
+/// ```
+pub struct TwitchAuthenticator {
+    client_id: String,
+    client_secret: String,
+    token: Mutex<Option<AccessToken>>,
+    pub(crate) transport: Arc<dyn HttpTransport>,
+    store: Option<Arc<dyn TokenStore>>,
+}
+
+struct AccessToken {
+    value: String,
+    expires_at: Instant,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+impl AccessToken {
+    fn is_fresh(&self, now: Instant) -> bool {
+        now + REFRESH_MARGIN < self.expires_at
+    }
+
+    ///The stored token, None once it expired
+    fn from_stored(stored: StoredToken, now: Instant, system_now: SystemTime) -> Option<Self> {
+        let remaining = stored.expires_at.duration_since(system_now).ok()?;
+        Some(AccessToken {
+            value: stored.access_token,
+            expires_at: now + remaining,
+        })
+    }
+}
+
+impl TwitchAuthenticator {
+    pub fn new<S: Into<String>>(client_id: S, client_secret: S) -> TwitchAuthenticator {
+        TwitchAuthenticator {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            token: Mutex::new(None),
+            transport: default_transport(),
+            store: None,
+        }
+    }
+
+    /// Reuses the tokens of the given store while they are fresh, storing the new ones,
+    /// e.g. a FileTokenStore so that a command line tool authenticates once per token
+    pub fn with_token_store<S: TokenStore + 'static>(mut self, store: S) -> Self {
+        self.store = Some(Arc::new(store));
+        self
+    }
+
+    /// Sets the transport sending the token requests,
+    /// IGDBClient::with_transport sets it as well
+    pub fn with_transport<T: HttpTransport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Returns the credentials to use for the next request,
+    /// requesting a new app access token to Twitch if needed.
+    pub async fn credentials(&self) -> Result<Credentials, Error> {
+        let mut token = self.token.lock().await;
+
+        match &*token {
+            Some(t) if t.is_fresh(Instant::now()) => {}
+            _ => *token = Some(self.stored_or_requested_token().await?),
+        }
+
+        let value = token.as_ref().map(|t| t.value.clone()).unwrap_or_default();
+        Ok(Credentials::new(self.client_id.clone(), value))
+    }
+
+    /// Discards the cached token so the next request acquires a new one
+    pub async fn invalidate(&self) {
+        *self.token.lock().await = None;
+        if let Some(store) = &self.store {
+            store.remove(&self.client_id);
+        }
+    }
+
+    async fn stored_or_requested_token(&self) -> Result<AccessToken, Error> {
+        let store = match &self.store {
+            Some(store) => store,
+            None => return self.request_token().await,
+        };
+        let stored = store
+            .get(&self.client_id)
+            .and_then(|stored| AccessToken::from_stored(stored, Instant::now(), SystemTime::now()))
+            .filter(|token| token.is_fresh(Instant::now()));
+        if let Some(token) = stored {
+            return Ok(token);
+        }
+
+        let token = self.request_token().await?;
+        let remaining = token.expires_at.saturating_duration_since(Instant::now());
+        store.put(
+            &self.client_id,
+            &StoredToken {
+                access_token: token.value.clone(),
+                expires_at: SystemTime::now() + remaining,
+            },
+        );
+        Ok(token)
+    }
+
+    async fn request_token(&self) -> Result<AccessToken, Error> {
+        log::debug!("Requesting a new Twitch app access token");
+
+        let body = format!(
+            "client_id={}&client_secret={}&grant_type=client_credentials",
+            form_encode(&self.client_id),
+            form_encode(&self.client_secret)
+        );
+        let request = http::Request::post(TWITCH_TOKEN_URL)
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(body.into_bytes())?;
+
+        let response = ensure_success(self.transport.send(request).await?)?;
+        let response = serde_json::from_slice::<TokenResponse>(response.body())?;
+
+        Ok(AccessToken {
+            value: response.access_token,
+            expires_at: Instant::now() + Duration::from_secs(response.expires_in),
+        })
+    }
+}
+
+///Percent-encodes a form value, keeping the unreserved characters only
+pub(crate) fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+///Where the credentials of an IGDBClient come from
+pub(crate) enum Authenticator {
+    Static(Credentials),
+    Twitch(TwitchAuthenticator),
+}
+
+impl Authenticator {
+    pub(crate) async fn credentials(&self) -> Result<Credentials, Error> {
+        match self {
+            Authenticator::Static(credentials) => Ok(credentials.clone()),
+            Authenticator::Twitch(twitch) => twitch.credentials().await,
+        }
+    }
+
+    /// Discards the current credentials, returns false if they cannot be renewed
+    pub(crate) async fn invalidate(&self) -> bool {
+        match self {
+            Authenticator::Static(_) => false,
+            Authenticator::Twitch(twitch) => {
+                twitch.invalidate().await;
+                true
+            }
+        }
+    }
+}
+
+#[test]
+fn access_token_is_refreshed_before_expiry() {
+    let now = Instant::now();
+    let token = |expires_in| AccessToken {
+        value: "token".into(),
+        expires_at: now + Duration::from_secs(expires_in),
+    };
+
+    assert!(token(3600).is_fresh(now));
+    assert!(!token(30).is_fresh(now));
+    assert!(!token(0).is_fresh(now));
+}
+
+#[test]
+fn stored_tokens_are_reused_until_they_expire() {
+    use crate::mock::ScriptedTransport;
+    use crate::token_store::MemoryTokenStore;
+
+    // Answers a new token to each request
+    let answer = |n: usize| format!(r#"{{"access_token": "token{}", "expires_in": 3600}}"#, n);
+    let requested = ScriptedTransport::new()
+        .then(200, answer(1))
+        .then(200, answer(2))
+        .then(200, answer(3));
+    let store = Arc::new(MemoryTokenStore::new());
+    let twitch = || {
+        TwitchAuthenticator::new("client_id", "secret")
+            .with_transport(requested.clone())
+            .with_token_store(store.clone())
+    };
+
+    let token = |twitch: &TwitchAuthenticator| {
+        async_std::task::block_on(twitch.credentials())
+            .unwrap()
+            .access_token
+    };
+    assert_eq!("token1", token(&twitch()));
+    // Another run or worker reuses the stored token
+    let other = twitch();
+    assert_eq!("token1", token(&other));
+    assert_eq!(1, requested.sent());
+
+    async_std::task::block_on(other.invalidate());
+    assert_eq!(None, store.get("client_id"));
+    assert_eq!("token2", token(&twitch()));
+
+    let expired = StoredToken {
+        access_token: "expired".to_owned(),
+        expires_at: SystemTime::now() - Duration::from_secs(1),
+    };
+    store.put("client_id", &expired);
+    assert_eq!("token3", token(&twitch()));
+}
+
+#[test]
+fn form_values_are_percent_encoded() {
+    assert_eq!("abc-123_.~", form_encode("abc-123_.~"));
+    assert_eq!("a%20b%26c%3Dd%2B", form_encode("a b&c=d+"));
+}
@@ -0,0 +1,232 @@
+use crate::endpoints::Endpoint;
+use crate::request_builder::RequestBuilder;
+use crate::response::Lenient;
+use crate::Error;
+use serde::de::DeserializeOwned;
+use std::fmt;
+
+///A query of an IGDBClient::batch, on any endpoint
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use igdb::batch::BatchQuery;
+/// use igdb::client::{GamesClient, IGDBClient, PlatformsClient};
+/// use igdb::model::games::Game;
+///
+/// task::block_on(async {
+///     let igdb = IGDBClient::new("client_id", "token").with_concurrency(4);
+///     let mut games = IGDBClient::create_request();
+///     games.add_field("name").search("Zelda");
+///
+///     let results = igdb
+///         .batch(vec![
+///             BatchQuery::new::<GamesClient>(&games),
+///             BatchQuery::new::<PlatformsClient>(&IGDBClient::create_request()),
+///         ])
+///         .await;
+///
+///     let games: Vec<Game> = results[0].as_ref().unwrap().get().unwrap();
+/// })
+/// ```
+pub struct BatchQuery {
+    pub(crate) path: &'static str,
+    pub(crate) body: Result<String, Error>,
+}
+
+impl BatchQuery {
+    ///Queries the given endpoint, requesting every field of its model when the request has none
+    pub fn new<E: Endpoint>(request: &RequestBuilder) -> BatchQuery {
+        BatchQuery {
+            path: E::PATH,
+            body: request.build_body_for::<E::Model>(),
+        }
+    }
+
+    ///The path of the queried endpoint
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+}
+
+///The registries returned for a BatchQuery, kept as json until read into their model
+#[derive(Debug, Clone)]
+pub struct BatchResult {
+    pub registries: Vec<serde_json::Value>,
+    pub(crate) path: &'static str,
+}
+
+impl BatchResult {
+    ///Deserializes the registries into the given model
+    pub fn get<T: DeserializeOwned>(&self) -> Result<Vec<T>, Error> {
+        self.registries
+            .iter()
+            .map(|registry| Ok(T::deserialize(registry)?))
+            .collect()
+    }
+
+    ///Deserializes the registries into the given model, setting aside the ones which cannot
+    /// be read instead of failing
+    pub fn get_lenient<T: DeserializeOwned>(&self) -> Lenient<T> {
+        Lenient::read(self.path, &self.registries)
+    }
+}
+
+///How many queries of a batch succeeded, and why the other ones failed
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use igdb::batch::{BatchQuery, BatchSummary};
+/// use igdb::client::{GamesClient, IGDBClient};
+///
+/// task::block_on(async {
+///     let igdb = IGDBClient::new("client_id", "token").with_concurrency(4);
+///     let queries = (0..500)
+///         .map(|page| {
+///             let mut games = IGDBClient::create_request();
+///             games.add_field("name").limit(500).offset(page * 500);
+///             BatchQuery::new::<GamesClient>(&games)
+///         })
+///         .collect();
+///
+///     let results = igdb.batch(queries).await;
+///     let summary = BatchSummary::of(&results);
+///     if !summary.is_complete() {
+///         eprintln!("{}", summary);
+///     }
+/// })
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    pub succeeded: usize,
+    ///Index of each failed query in the batch, along with its error
+    pub failed: Vec<(usize, String)>,
+}
+
+impl BatchSummary {
+    pub fn of(results: &[Result<BatchResult, Error>]) -> BatchSummary {
+        let mut summary = BatchSummary::default();
+        for (index, result) in results.iter().enumerate() {
+            match result {
+                Ok(_) => summary.succeeded += 1,
+                Err(e) => summary.failed.push((index, e.to_string())),
+            }
+        }
+        summary
+    }
+
+    ///Whether every query succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl fmt::Display for BatchSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} of {} queries failed",
+            self.failed.len(),
+            self.succeeded + self.failed.len()
+        )?;
+        for (index, e) in &self.failed {
+            write!(f, "\n  #{}: {}", index, e)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn batch_results_are_returned_in_order() {
+    use crate::client::{GenresClient, IGDBClient, ThemesClient};
+    use crate::model::genre::Genre;
+    use crate::transport::{HttpTransport, TransportFuture};
+
+    ///Answers the registry named after the endpoint, slower for the first one
+    struct Named;
+
+    impl HttpTransport for Named {
+        fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            let genres = request.uri().path().ends_with("genres");
+            Box::pin(async move {
+                if genres {
+                    async_std::task::sleep(std::time::Duration::from_millis(20)).await;
+                }
+                let name = match genres {
+                    true => "genre",
+                    false => "theme",
+                };
+                Ok(http::Response::new(
+                    format!(r#"[{{"id":1,"name":"{}"}}]"#, name).into_bytes(),
+                ))
+            })
+        }
+    }
+
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(Named)
+        .without_rate_limit()
+        .with_concurrency(2);
+    let request = IGDBClient::create_request();
+
+    let results = async_std::task::block_on(igdb.batch(vec![
+        BatchQuery::new::<GenresClient>(&request),
+        BatchQuery::new::<ThemesClient>(&request),
+    ]));
+
+    let names: Vec<String> = results
+        .iter()
+        .map(|result| {
+            result.as_ref().unwrap().get::<Genre>().unwrap()[0]
+                .name
+                .clone()
+        })
+        .collect();
+    assert_eq!(vec!["genre", "theme"], names);
+}
+
+#[test]
+fn failed_queries_and_invalid_registries_are_set_aside() {
+    use crate::client::{GenresClient, IGDBClient, ThemesClient};
+    use crate::model::genre::Genre;
+    use crate::transport::{HttpTransport, TransportFuture};
+
+    ///Answers the genres, one of them invalid, and fails the themes
+    struct Failing;
+
+    impl HttpTransport for Failing {
+        fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            let genres = request.uri().path().ends_with("genres");
+            Box::pin(async move {
+                let response = match genres {
+                    true => http::Response::builder()
+                        .body(br#"[{"id":5,"name":"Shooter"},{"id":"twelve"}]"#.to_vec()),
+                    false => http::Response::builder()
+                        .status(400)
+                        .body(br#"[{"title":"Syntax Error"}]"#.to_vec()),
+                };
+                Ok(response?)
+            })
+        }
+    }
+
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(Failing)
+        .without_rate_limit();
+    let request = IGDBClient::create_request();
+
+    let results = async_std::task::block_on(igdb.batch(vec![
+        BatchQuery::new::<GenresClient>(&request),
+        BatchQuery::new::<ThemesClient>(&request),
+    ]));
+
+    let genres = results[0].as_ref().unwrap().get_lenient::<Genre>();
+    assert_eq!("Shooter", genres.registries[0].name);
+    assert_eq!("genres", genres.invalid[0].endpoint);
+
+    let summary = BatchSummary::of(&results);
+    assert_eq!(1, summary.succeeded);
+    assert_eq!(
+        "1 of 2 queries failed\n  #1: IGDB error 400: Syntax Error",
+        summary.to_string()
+    );
+}
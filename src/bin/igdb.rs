@@ -0,0 +1,468 @@
+//! Command line client for ad-hoc IGDB queries, with the `cli` feature.
+//!
+//! ```text
+//! igdb games --search "celeste" --fields name,rating --limit 5 --format table
+//! igdb genres --query 'fields name,slug; sort name asc; limit 50;'
+//! igdb platforms --fields name --limit 20 --format table --interactive
+//! igdb login --client-id <id> --client-secret <secret>
+//! ```
+//!
+//! Credentials are read from the IGDB_CLIENT_ID and IGDB_ACCESS_TOKEN or IGDB_CLIENT_SECRET
+//! environment variables, or else from the config file written by `igdb login`.
+
+use async_std::task;
+use igdb::auth::TwitchAuthenticator;
+use igdb::client::*;
+use igdb::page::Page;
+use igdb::request_builder::RequestBuilder;
+use igdb::token_store::FileTokenStore;
+use igdb::Error;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage:
+  igdb <endpoint> [--search <term>] [--fields <a,b>] [--exclude <a,b>] [--where <filters>]
+                  [--sort <field asc|desc>] [--limit <n>] [--offset <n>] [--format json|table]
+                  [--interactive]
+  igdb <endpoint> --query <apicalypse> [--format json|table]
+
+  --interactive pages through the results, reading n (next), p (previous) or q (quit)
+  igdb login --client-id <id> (--client-secret <secret> | --token <token>)
+  igdb endpoints
+
+Credentials come from IGDB_CLIENT_ID with IGDB_ACCESS_TOKEN or IGDB_CLIENT_SECRET,
+or else from the config file written by `igdb login`.";
+
+///Credentials stored by `igdb login`
+#[derive(serde_derive::Serialize, serde_derive::Deserialize, Default, Debug, PartialEq)]
+struct Config {
+    client_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format {
+    Json,
+    Table,
+}
+
+///A query of an endpoint as given on the command line
+#[derive(Debug)]
+struct Query {
+    endpoint: String,
+    request: RequestBuilder,
+    format: Format,
+    interactive: bool,
+}
+
+macro_rules! endpoints {
+    ($($client: ty),+ $(,)?) => {
+        const ENDPOINTS: &[&str] = &[$(<$client as igdb::endpoints::Endpoint>::PATH),+];
+
+        ///Queries the endpoint with the given path, returning its registries as json
+        async fn query_raw(
+            igdb: &IGDBClient,
+            endpoint: &str,
+            request: &RequestBuilder,
+        ) -> Result<Value, Error> {
+            $(
+                if endpoint == <$client as igdb::endpoints::Endpoint>::PATH {
+                    return igdb.query_raw::<$client>(request).await;
+                }
+            )+
+            Err(unknown_endpoint(endpoint))
+        }
+
+        ///Requests the page of the endpoint with the given path at the offset of the request
+        async fn page_raw(
+            igdb: &IGDBClient,
+            endpoint: &str,
+            request: &RequestBuilder,
+        ) -> Result<Page<Value>, Error> {
+            $(
+                if endpoint == <$client as igdb::endpoints::Endpoint>::PATH {
+                    return igdb.page_raw::<$client>(request).await;
+                }
+            )+
+            Err(unknown_endpoint(endpoint))
+        }
+    };
+}
+
+endpoints!(
+    AgeRatingsClient,
+    AgeRatingContentDescriptionsClient,
+    AlternativeNamesClient,
+    ArtworksClient,
+    CharacterMugshotsClient,
+    CharactersClient,
+    CollectionsClient,
+    CompaniesClient,
+    CoversClient,
+    EventsClient,
+    EventLogosClient,
+    EventNetworksClient,
+    ExternalGameClient,
+    GamesClient,
+    GameLocalizationsClient,
+    GameModesClient,
+    GameTimeToBeatsClient,
+    GameVersionsClient,
+    GameVersionFeaturesClient,
+    GameVersionFeatureValuesClient,
+    GameVideosClient,
+    GenresClient,
+    EnginesClient,
+    GameEngineLogosClient,
+    FranchisesClient,
+    InvolvedCompaniesClient,
+    KeywordsClient,
+    LanguagesClient,
+    LanguageSupportsClient,
+    MultiPlayerModesClient,
+    NetworkTypesClient,
+    PlatformsClient,
+    PlatformLogosClient,
+    PlatformVersionsClient,
+    PlayerPerpectivesClient,
+    PopularityPrimitivesClient,
+    PopularityTypesClient,
+    RegionsClient,
+    ReleaseDatesClient,
+    ScreenshotsClient,
+    ThemesClient,
+    WebsitesClient,
+);
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        None | Some("-h" | "--help" | "help") => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Some("endpoints") => {
+            ENDPOINTS
+                .iter()
+                .for_each(|endpoint| println!("{}", endpoint));
+            return ExitCode::SUCCESS;
+        }
+        Some("login") => login(&args[1..]),
+        Some(_) => parse_query(&args).and_then(|query| task::block_on(run(query))),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("igdb: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(query: Query) -> Result<(), Error> {
+    let igdb = client()?;
+    if query.interactive {
+        return browse(&igdb, query).await;
+    }
+    let registries = query_raw(&igdb, &query.endpoint, &query.request).await?;
+    match (query.format, registries.as_array()) {
+        (Format::Table, Some(registries)) => print(registries, Format::Table)?,
+        _ => println!("{}", serde_json::to_string_pretty(&registries)?),
+    }
+    Ok(())
+}
+
+///Prints a page at a time, moving to the next or previous one as read from stdin
+async fn browse(igdb: &IGDBClient, query: Query) -> Result<(), Error> {
+    let mut page = page_raw(igdb, &query.endpoint, &query.request).await?;
+    loop {
+        print(&page.registries, query.format)?;
+        eprint!(
+            "page {} - {}{}q: ",
+            page.number(),
+            if page.has_prev() { "p, " } else { "" },
+            if page.has_next() { "n, " } else { "" }
+        );
+
+        let mut command = String::new();
+        if std::io::stdin().read_line(&mut command)? == 0 {
+            return Ok(());
+        }
+        let moved = match command.trim() {
+            "n" | "next" | "" => page.next().await?,
+            "p" | "prev" => page.prev().await?,
+            "q" | "quit" => return Ok(()),
+            other => {
+                eprintln!("unknown command {}", other);
+                continue;
+            }
+        };
+        match moved {
+            Some(moved) => page = moved,
+            None => eprintln!("no such page"),
+        }
+    }
+}
+
+fn print(registries: &[Value], format: Format) -> Result<(), Error> {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(registries)?),
+        Format::Table => print!("{}", table(registries)),
+    }
+    Ok(())
+}
+
+///Reads `<endpoint> [options]` into a query, the options being turned into an Apicalypse
+/// query parsed like RequestBuilder::from_str
+fn parse_query(args: &[String]) -> Result<Query, Error> {
+    let endpoint = args[0].clone();
+    let mut clauses = Vec::new();
+    let mut raw = None;
+    let mut format = Format::Json;
+    let mut interactive = false;
+
+    let mut options = args[1..].iter();
+    while let Some(option) = options.next() {
+        let mut value = || {
+            options
+                .next()
+                .cloned()
+                .ok_or_else(|| invalid(format!("{} needs a value", option)))
+        };
+        match option.as_str() {
+            "--search" | "-s" => clauses.push(format!("search {};", quote(&value()?))),
+            "--fields" | "-f" => clauses.push(format!("fields {};", value()?)),
+            "--exclude" | "-x" => clauses.push(format!("exclude {};", value()?)),
+            "--where" | "-w" => clauses.push(format!("where {};", value()?)),
+            "--sort" => clauses.push(format!("sort {};", value()?)),
+            "--limit" | "-l" => clauses.push(format!("limit {};", value()?)),
+            "--offset" | "-o" => clauses.push(format!("offset {};", value()?)),
+            "--query" | "-q" => raw = Some(value()?),
+            "--interactive" | "-i" => interactive = true,
+            "--format" => {
+                format = match value()?.as_str() {
+                    "json" => Format::Json,
+                    "table" => Format::Table,
+                    other => return Err(invalid(format!("unknown format {}", other))),
+                }
+            }
+            other => return Err(invalid(format!("unknown option {}\n\n{}", other, USAGE))),
+        }
+    }
+
+    let query = match (raw, clauses.is_empty()) {
+        (Some(raw), true) => raw,
+        (Some(_), false) => return Err(invalid("--query cannot be combined with other options")),
+        (None, _) => clauses.join(" "),
+    };
+    Ok(Query {
+        endpoint,
+        request: query.parse()?,
+        format,
+        interactive,
+    })
+}
+
+fn quote(term: &str) -> String {
+    format!("\"{}\"", term.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+///Creates the client from the environment, or else from the config file
+fn client() -> Result<IGDBClient, Error> {
+    let env = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+    let config = match env("IGDB_CLIENT_ID") {
+        Some(client_id) => Config {
+            client_id,
+            client_secret: env("IGDB_CLIENT_SECRET"),
+            // IGDB_TOKEN is still read for the scripts written before ClientBuilder::from_env
+            token: env("IGDB_ACCESS_TOKEN").or_else(|| env("IGDB_TOKEN")),
+        },
+        None => {
+            let path = config_path()?;
+            let config = std::fs::read(&path).map_err(|_| {
+                invalid(format!(
+                    "no credentials, set IGDB_CLIENT_ID and IGDB_ACCESS_TOKEN or run `igdb login` ({})",
+                    path.display()
+                ))
+            })?;
+            serde_json::from_slice(&config)?
+        }
+    };
+
+    match config {
+        Config {
+            client_id,
+            token: Some(token),
+            ..
+        } => Ok(IGDBClient::new(client_id, token)),
+        Config {
+            client_id,
+            client_secret: Some(secret),
+            ..
+        } => {
+            // The tokens are reused until they expire instead of being requested on every run
+            let tokens = config_path()?.with_file_name("tokens.json");
+            let twitch = TwitchAuthenticator::new(client_id, secret)
+                .with_token_store(FileTokenStore::new(tokens));
+            Ok(IGDBClient::with_authenticator(twitch))
+        }
+        _ => Err(invalid("no token nor client secret configured")),
+    }
+}
+
+///Stores the credentials in the config file, readable by the current user only
+fn login(args: &[String]) -> Result<(), Error> {
+    let mut config = Config::default();
+    for pair in args.chunks(2) {
+        let value = pair
+            .get(1)
+            .cloned()
+            .ok_or_else(|| invalid(format!("{} needs a value", pair[0])))?;
+        match pair[0].as_str() {
+            "--client-id" => config.client_id = value,
+            "--client-secret" => config.client_secret = Some(value),
+            "--token" => config.token = Some(value),
+            other => return Err(invalid(format!("unknown option {}\n\n{}", other, USAGE))),
+        }
+    }
+    if config.client_id.is_empty() || (config.token.is_none() && config.client_secret.is_none()) {
+        return Err(invalid(
+            "login needs --client-id and --client-secret or --token",
+        ));
+    }
+
+    let path = config_path()?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(&path, serde_json::to_vec_pretty(&config)?)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    }
+    println!("credentials saved to {}", path.display());
+    Ok(())
+}
+
+///IGDB_CONFIG, or `igdb/config.json` in the user config directory
+fn config_path() -> Result<PathBuf, Error> {
+    if let Some(path) = std::env::var_os("IGDB_CONFIG") {
+        return Ok(PathBuf::from(path));
+    }
+    let dir = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .ok_or_else(|| invalid("no config directory, set IGDB_CONFIG"))?;
+    Ok(dir.join("igdb").join("config.json"))
+}
+
+///Renders the registries as a table, a column per field in the order they first appear
+fn table(registries: &[Value]) -> String {
+    let mut columns: Vec<String> = Vec::new();
+    let rows: Vec<BTreeMap<&str, String>> = registries
+        .iter()
+        .filter_map(Value::as_object)
+        .map(|registry| {
+            registry
+                .iter()
+                .map(|(field, value)| {
+                    if !columns.contains(field) {
+                        columns.push(field.clone());
+                    }
+                    let cell = match value {
+                        Value::String(string) => string.clone(),
+                        value => value.to_string(),
+                    };
+                    (field.as_str(), cell)
+                })
+                .collect()
+        })
+        .collect();
+
+    let widths: Vec<usize> = columns
+        .iter()
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column.as_str()))
+                .map(|cell| cell.chars().count())
+                .chain([column.len()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let line = |cells: Vec<&str>| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:width$}", cell, width = width))
+            .collect();
+        format!("{}\n", cells.join("  ").trim_end())
+    };
+
+    let mut table = line(columns.iter().map(String::as_str).collect());
+    for row in &rows {
+        table.push_str(&line(
+            columns
+                .iter()
+                .map(|column| row.get(column.as_str()).map_or("", String::as_str))
+                .collect(),
+        ));
+    }
+    table
+}
+
+fn unknown_endpoint(endpoint: &str) -> Error {
+    invalid(format!(
+        "unknown endpoint {}, see `igdb endpoints`",
+        endpoint
+    ))
+}
+
+fn invalid<S: Into<String>>(message: S) -> Error {
+    Error::InvalidQuery(message.into())
+}
+
+#[test]
+fn options_are_read_into_a_query() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+    let query = parse_query(&args(&[
+        "games",
+        "--search",
+        "celeste",
+        "--fields",
+        "name,rating",
+        "--limit",
+        "5",
+        "--format",
+        "table",
+        "-i",
+    ]))
+    .unwrap();
+    assert_eq!("games", query.endpoint);
+    assert_eq!(Format::Table, query.format);
+    assert!(query.interactive);
+    assert_eq!(
+        r#"fields name,rating; search "celeste"; limit 5;"#,
+        query.request.to_query()
+    );
+
+    let raw = parse_query(&args(&["genres", "-q", "fields name; sort name asc;"])).unwrap();
+    assert_eq!(
+        "fields name; sort name asc; limit 10;",
+        raw.request.to_query()
+    );
+    assert!(parse_query(&args(&["genres", "-q", "fields name;", "-l", "5"])).is_err());
+
+    let registries: Vec<Value> =
+        serde_json::from_str(r#"[{"id": 1942, "name": "The Witcher 3"}, {"id": 7}]"#).unwrap();
+    assert_eq!("id    name\n1942  The Witcher 3\n7\n", table(&registries));
+}
@@ -0,0 +1,221 @@
+//! Blocking version of the IGDB client, for tools that do not run an async runtime
+//! such as build scripts or command line importers. Requests are run to completion
+//! on the async-std runtime the async client already relies on.
+
+use crate::auth::{Credentials, TwitchAuthenticator};
+use crate::client::IGDBClient;
+use crate::endpoints::Endpoint;
+use crate::media_quality::MediaQuality;
+use crate::model::id_or::Identifiable;
+use crate::multiquery::{MultiQueryBuilder, MultiQueryResults};
+use crate::request_builder::RequestBuilder;
+use crate::response::{Lenient, Response};
+use crate::search::SearchResult;
+use crate::Error;
+use async_std::task::block_on;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+
+///Blocking IGDB client, mirroring the queries of IGDBClient.
+/// Configure an IGDBClient and convert it to share its settings.
+/// # Examples
+/// ```no_run
+/// use igdb::blocking::Client;
+/// use igdb::client::{GamesClient, IGDBClient};
+///
+/// let igdb = Client::new("client_id", "token");
+/// let mut request = IGDBClient::create_request();
+/// request.add_field("name").search("Celeste");
+///
+/// for game in igdb.query::<GamesClient>(&request).unwrap() {
+///     println!("{}", game.name);
+/// }
+/// ```
+pub struct Client {
+    inner: IGDBClient,
+}
+
+impl Client {
+    ///Creates a blocking client from a client_id and an app access token, see IGDBClient::new
+    pub fn new<S: Into<String>>(client_id: S, token: S) -> Client {
+        IGDBClient::new(client_id, token).into()
+    }
+
+    ///Creates a blocking client from the given Credentials
+    pub fn with_credentials(credentials: Credentials) -> Client {
+        IGDBClient::with_credentials(credentials).into()
+    }
+
+    ///Creates a blocking client acquiring and refreshing app access tokens
+    /// from a Twitch application client_id and client_secret
+    pub fn from_client_secret<S: Into<String>>(client_id: S, client_secret: S) -> Client {
+        IGDBClient::with_authenticator(TwitchAuthenticator::new(client_id, client_secret)).into()
+    }
+
+    ///Returns the async client sending the requests
+    pub fn async_client(&self) -> &IGDBClient {
+        &self.inner
+    }
+
+    ///Queries any endpoint with the given request, see IGDBClient::query
+    pub fn query<E: Endpoint>(&self, request: &RequestBuilder) -> Result<Vec<E::Model>, Error> {
+        block_on(self.inner.query::<E>(request))
+    }
+
+    ///Queries any endpoint reading the registries into the given struct,
+    /// see IGDBClient::query_into
+    pub fn query_into<E: Endpoint, T: DeserializeOwned>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Vec<T>, Error> {
+        block_on(self.inner.query_into::<E, T>(request))
+    }
+
+    ///Queries any endpoint returning the registries as json, see IGDBClient::query_raw
+    pub fn query_raw<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<serde_json::Value, Error> {
+        block_on(self.inner.query_raw::<E>(request))
+    }
+
+    ///Queries any endpoint returning the response body, see IGDBClient::query_bytes
+    pub fn query_bytes<E: Endpoint>(&self, request: &RequestBuilder) -> Result<Vec<u8>, Error> {
+        block_on(self.inner.query_bytes::<E>(request))
+    }
+
+    ///Queries any endpoint, setting aside the registries which cannot be read into the model,
+    /// see IGDBClient::query_lenient
+    pub fn query_lenient<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Lenient<E::Model>, Error> {
+        block_on(self.inner.query_lenient::<E>(request))
+    }
+
+    ///Queries any endpoint paging through limits above 500 registries, see IGDBClient::query_all
+    pub fn query_all<E: Endpoint>(&self, request: &RequestBuilder) -> Result<Vec<E::Model>, Error> {
+        block_on(self.inner.query_all::<E>(request))
+    }
+
+    ///Queries any endpoint and returns the response metadata as well,
+    /// see IGDBClient::query_with_meta
+    pub fn query_with_meta<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Response<E::Model>, Error> {
+        block_on(self.inner.query_with_meta::<E>(request))
+    }
+
+    ///Iterates over every registry matching the request, requesting the next page of
+    /// 500 registries when the current one is consumed, see IGDBClient::stream
+    /// # Examples
+    /// ```no_run
+    /// use igdb::blocking::Client;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    ///
+    /// let igdb = Client::new("client_id", "token");
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name");
+    ///
+    /// for game in igdb.stream::<GamesClient>(&request).take(1000) {
+    ///     println!("{}", game.unwrap().name);
+    /// }
+    /// ```
+    pub fn stream<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> impl Iterator<Item = Result<E::Model, Error>> {
+        let mut stream = Box::pin(self.inner.stream::<E>(request));
+        std::iter::from_fn(move || block_on(stream.next()))
+    }
+
+    ///Iterates over every registry matching the request, paging by id,
+    /// see IGDBClient::stream_by_id
+    pub fn stream_by_id<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> impl Iterator<Item = Result<E::Model, Error>>
+    where
+        E::Model: Identifiable,
+    {
+        let mut stream = Box::pin(self.inner.stream_by_id::<E>(request));
+        std::iter::from_fn(move || block_on(stream.next()))
+    }
+
+    ///Returns the number of registries matching the request, see IGDBClient::count
+    pub fn count<E: Endpoint>(&self, request: &RequestBuilder) -> Result<u64, Error> {
+        block_on(self.inner.count::<E>(request))
+    }
+
+    ///Sends the queries of the given MultiQueryBuilder in a single request
+    pub fn multiquery(&self, multiquery: &MultiQueryBuilder) -> Result<MultiQueryResults, Error> {
+        block_on(self.inner.multiquery(multiquery))
+    }
+
+    ///Searches the given term across games, characters, companies and more,
+    /// see IGDBClient::search_all
+    pub fn search_all<S: Into<String>>(&self, term: S) -> Result<Vec<SearchResult>, Error> {
+        block_on(self.inner.search_all(term))
+    }
+
+    ///Downloads the image with the given image_id at the given size, see IGDBClient::image_bytes
+    pub fn image_bytes<S: Into<String>>(
+        &self,
+        image_id: S,
+        quality: MediaQuality,
+    ) -> Result<Vec<u8>, Error> {
+        block_on(self.inner.image_bytes(image_id, quality))
+    }
+
+    ///Downloads the image with the given image_id at the given size to the given destination,
+    /// see IGDBClient::download_image
+    pub fn download_image<S: Into<String>, W: std::io::Write>(
+        &self,
+        image_id: S,
+        quality: MediaQuality,
+        dest: W,
+    ) -> Result<(), Error> {
+        block_on(self.inner.download_image(image_id, quality, dest))
+    }
+}
+
+impl From<IGDBClient> for Client {
+    fn from(inner: IGDBClient) -> Self {
+        Client { inner }
+    }
+}
+
+#[test]
+fn blocking_client_pages_through_the_results() {
+    use crate::client::GenresClient;
+    use crate::transport::{HttpTransport, TransportFuture};
+
+    ///Answers 500 genres to the first page and 2 to the second one
+    struct Pages;
+
+    impl HttpTransport for Pages {
+        fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            let body = String::from_utf8_lossy(request.body()).into_owned();
+            let count = match body.contains("offset 500;") {
+                true => 2,
+                false => 500,
+            };
+            let genres: Vec<String> = (0..count).map(|id| format!("{{\"id\":{}}}", id)).collect();
+            Box::pin(async move {
+                Ok(http::Response::new(
+                    format!("[{}]", genres.join(",")).into_bytes(),
+                ))
+            })
+        }
+    }
+
+    let igdb: Client = IGDBClient::new("client_id", "token")
+        .with_transport(Pages)
+        .without_rate_limit()
+        .into();
+    let request = IGDBClient::create_request();
+
+    assert_eq!(500, igdb.query::<GenresClient>(&request).unwrap().len());
+    assert_eq!(502, igdb.stream::<GenresClient>(&request).count());
+}
@@ -0,0 +1,124 @@
+//! Hard limits on the requests of a job, see IGDBClient::with_budget.
+//!
+//! Every attempt sent to IGDB counts, retries included, along with the bytes of its body and
+//! of its response. Cached responses and identical queries joined in flight are free.
+//! Once a limit is reached the following requests fail with Error::BudgetExceeded without
+//! being sent, a request started within the budget may only exceed it by its own retries.
+
+use crate::Error;
+use std::sync::Mutex;
+
+///Limits on the requests sent to IGDB, none by default
+/// # Examples
+/// ```
+/// use igdb::budget::Budget;
+///
+/// let budget = Budget::new().max_requests(10_000).max_bytes(500 * 1024 * 1024);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub(crate) max_requests: Option<u64>,
+    pub(crate) max_bytes: Option<u64>,
+}
+
+impl Budget {
+    pub fn new() -> Budget {
+        Budget::default()
+    }
+
+    ///Number of attempts sent, retries included
+    pub fn max_requests(mut self, requests: u64) -> Budget {
+        self.max_requests = Some(requests);
+        self
+    }
+
+    ///Number of bytes sent and received, request and response bodies included
+    pub fn max_bytes(mut self, bytes: u64) -> Budget {
+        self.max_bytes = Some(bytes);
+        self
+    }
+
+    fn is_exceeded_by(&self, usage: &BudgetUsage) -> bool {
+        self.max_requests
+            .is_some_and(|max_requests| usage.requests >= max_requests)
+            || self
+                .max_bytes
+                .is_some_and(|max_bytes| usage.bytes >= max_bytes)
+    }
+}
+
+///What was spent of a budget
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BudgetUsage {
+    pub requests: u64,
+    pub bytes: u64,
+}
+
+///Counts the requests sent by every endpoint client created from the same IGDBClient
+pub(crate) struct BudgetTracker {
+    budget: Budget,
+    usage: Mutex<BudgetUsage>,
+}
+
+impl BudgetTracker {
+    pub(crate) fn new(budget: Budget) -> BudgetTracker {
+        BudgetTracker {
+            budget,
+            usage: Mutex::new(BudgetUsage::default()),
+        }
+    }
+
+    ///Fails with Error::BudgetExceeded once a limit is reached
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        let usage = self.usage();
+        match self.budget.is_exceeded_by(&usage) {
+            true => Err(Error::BudgetExceeded(usage)),
+            false => Ok(()),
+        }
+    }
+
+    ///Records an attempt sent and the bytes it sent and received
+    pub(crate) fn record(&self, bytes: usize) {
+        let mut usage = self.usage.lock().unwrap();
+        usage.requests += 1;
+        usage.bytes += bytes as u64;
+    }
+
+    pub(crate) fn usage(&self) -> BudgetUsage {
+        *self.usage.lock().unwrap()
+    }
+
+    pub(crate) fn budget(&self) -> Budget {
+        self.budget
+    }
+}
+
+#[test]
+fn requests_fail_without_being_sent_once_the_budget_is_spent() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::MockTransport;
+
+    let mock = MockTransport::new().with_fixture("genres", "fields id; limit 10;", r#"[{"id":5}]"#);
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock.clone())
+        .without_rate_limit();
+    let job = igdb.with_budget(Budget::new().max_requests(2));
+    let mut request = IGDBClient::create_request();
+    request.add_field("id");
+
+    for _ in 0..2 {
+        async_std::task::block_on(job.query::<GenresClient>(&request)).unwrap();
+    }
+    let exceeded = async_std::task::block_on(job.query::<GenresClient>(&request));
+    assert!(matches!(
+        exceeded,
+        Err(Error::BudgetExceeded(BudgetUsage { requests: 2, .. }))
+    ));
+    assert_eq!(2, mock.requests().len());
+    let sent = request.to_query().len() + br#"[{"id":5}]"#.len();
+    assert_eq!(2 * sent as u64, job.budget_usage().unwrap().bytes);
+
+    // The client the job was created from is not limited
+    async_std::task::block_on(igdb.query::<GenresClient>(&request)).unwrap();
+    assert_eq!(None, igdb.budget_usage());
+}
@@ -0,0 +1,300 @@
+//! Canonical form of the queries, see RequestBuilder::canonical.
+//!
+//! Queries requesting the same registries can be written differently: fields and id lists
+//! in another order, conditions joined with `&` or `|` swapped, or other spacing once parsed.
+//! Their canonical forms are the same, so that tests can assert what a query asks for and
+//! caches can key on it, while QueryDiff tells which clauses of two queries differ.
+
+use crate::expr::Expr;
+use crate::query_parser::read_leaf;
+use crate::request_builder::{distinct, escape, RequestBuilder, Value};
+use crate::Error;
+use std::fmt;
+
+///A clause differing between two queries, None on the side not having it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClauseDiff {
+    ///Keyword of the clause, e.g. `where`, or `names` for the names resolved by the client
+    pub clause: &'static str,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+///The clauses differing between the canonical forms of two queries, see RequestBuilder::diff
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryDiff {
+    pub clauses: Vec<ClauseDiff>,
+}
+
+impl QueryDiff {
+    ///Whether the queries are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+}
+
+///Lists the clauses like a diff, `-` for the left query and `+` for the right one
+impl fmt::Display for QueryDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, diff) in self.clauses.iter().enumerate() {
+            if i != 0 {
+                f.write_str("\n")?;
+            }
+            if let Some(left) = &diff.left {
+                write!(f, "- {} {};", diff.clause, left)?;
+            }
+            if let (Some(_), Some(_)) = (&diff.left, &diff.right) {
+                f.write_str("\n")?;
+            }
+            if let Some(right) = &diff.right {
+                write!(f, "+ {} {};", diff.clause, right)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RequestBuilder {
+    ///Returns the equivalent request in canonical form: fields, exclusions, id lists and the
+    /// conditions joined with `&` or `|` sorted and without duplicates, nested `&` and `|`
+    /// flattened. The sort clause is kept in order, its first field sorting first.
+    /// # Examples
+    /// ```
+    /// use igdb::request_builder::{Equality, RequestBuilder};
+    ///
+    /// let mut request = RequestBuilder::new();
+    /// request
+    ///     .add_fields(["rating", "name"])
+    ///     .add_where("platforms", Equality::Equal, vec![48, 6])
+    ///     .add_where("rating", Equality::Greater, 80);
+    ///
+    /// assert_eq!(
+    ///     "fields name,rating; where platforms = (6,48) & rating > 80; limit 10;",
+    ///     request.canonical().to_query()
+    /// );
+    /// ```
+    pub fn canonical(&self) -> RequestBuilder {
+        let mut request = self.clone();
+        request.fields = sorted(&request.fields);
+        request.exclude = sorted(&request.exclude);
+        let expr = request.filters.expr.take().map(canonical_expr);
+        request.filters.set(expr);
+        let mut sorted_by = Vec::new();
+        request
+            .sort
+            .retain(|(field, _)| match sorted_by.contains(field) {
+                true => false,
+                false => {
+                    sorted_by.push(field.clone());
+                    true
+                }
+            });
+        for filter in &mut request.names {
+            filter.names.sort();
+            filter.names.dedup();
+        }
+        request
+            .names
+            .sort_by(|a, b| (a.endpoint, a.field).cmp(&(b.endpoint, b.field)));
+        request
+    }
+
+    ///Whether both requests ask for the same registries in the same order,
+    /// their canonical forms being the same
+    /// # Examples
+    /// ```
+    /// use igdb::request_builder::RequestBuilder;
+    ///
+    /// let request: RequestBuilder = "fields name, rating; where id = (2,1) | rating > 80;".parse().unwrap();
+    /// let expected: RequestBuilder = "fields rating,name;where rating > 80 | id = (1,2);".parse().unwrap();
+    /// assert!(request.is_equivalent(&expected));
+    /// ```
+    pub fn is_equivalent(&self, other: &RequestBuilder) -> bool {
+        self.diff(other).is_empty()
+    }
+
+    ///Returns the clauses differing between the canonical forms of both requests
+    /// # Examples
+    /// ```
+    /// use igdb::request_builder::RequestBuilder;
+    ///
+    /// let request: RequestBuilder = "fields name; where rating > 80; limit 5;".parse().unwrap();
+    /// let expected: RequestBuilder = "fields name; where rating > 85; limit 5;".parse().unwrap();
+    /// assert_eq!(
+    ///     "- where rating > 80;\n+ where rating > 85;",
+    ///     request.diff(&expected).to_string()
+    /// );
+    /// ```
+    pub fn diff(&self, other: &RequestBuilder) -> QueryDiff {
+        let clauses = self
+            .canonical()
+            .clauses()
+            .into_iter()
+            .zip(other.canonical().clauses())
+            .filter(|((_, left), (_, right))| left != right)
+            .map(|((clause, left), (_, right))| ClauseDiff {
+                clause,
+                left,
+                right,
+            })
+            .collect();
+        QueryDiff { clauses }
+    }
+
+    ///Every clause of the query as rendered, in the order of the query, None when absent
+    fn clauses(&self) -> [(&'static str, Option<String>); 8] {
+        let list = |values: &[String]| Some(distinct(values).join(",")).filter(|l| !l.is_empty());
+        let sort = self
+            .sort
+            .iter()
+            .map(|(field, order)| format!("{} {}", field, order))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let names = self
+            .names
+            .iter()
+            .map(|filter| {
+                format!(
+                    "{} = {}({})",
+                    filter.field,
+                    filter.endpoint,
+                    filter.names.join(",")
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" & ");
+        [
+            ("fields", list(&self.fields)),
+            ("exclude", list(&self.exclude)),
+            (
+                "search",
+                Some(format!("\"{}\"", escape(&self.search))).filter(|_| !self.search.is_empty()),
+            ),
+            ("where", self.filters.expr.as_ref().map(ToString::to_string)),
+            ("names", Some(names).filter(|names| !names.is_empty())),
+            ("sort", Some(sort).filter(|sort| !sort.is_empty())),
+            ("limit", Some(self.limit.to_string())),
+            (
+                "offset",
+                Some(self.offset.to_string()).filter(|_| self.offset != 0),
+            ),
+        ]
+    }
+}
+
+///Parses the query and returns it in canonical form, see RequestBuilder::canonical
+/// # Examples
+/// ```
+/// use igdb::canonical::canonical_query;
+///
+/// assert_eq!(
+///     canonical_query("fields name,id;  where  genres = (12,5);").unwrap(),
+///     canonical_query("where genres = (5,12); fields id,name;").unwrap()
+/// );
+/// ```
+pub fn canonical_query(query: &str) -> Result<String, Error> {
+    Ok(query.parse::<RequestBuilder>()?.canonical().to_query())
+}
+
+fn sorted(values: &[String]) -> Vec<String> {
+    let mut values = values.to_vec();
+    values.sort();
+    values.dedup();
+    values
+}
+
+fn canonical_expr(expr: Expr) -> Expr {
+    match expr {
+        Expr::And(exprs) => junction(exprs, Expr::And, |expr| match expr {
+            Expr::And(exprs) => Ok(exprs),
+            expr => Err(expr),
+        }),
+        Expr::Or(exprs) => junction(exprs, Expr::Or, |expr| match expr {
+            Expr::Or(exprs) => Ok(exprs),
+            expr => Err(expr),
+        }),
+        // Values kept as written by the parser, e.g. id lists with spaces
+        Expr::Cmp {
+            field,
+            op,
+            value: Value::Raw(value),
+        } => match read_leaf(&field, op, value.trim()) {
+            list @ Expr::In { .. } => canonical_expr(list),
+            _ => Expr::Cmp {
+                field,
+                op,
+                value: Value::Raw(value),
+            },
+        },
+        Expr::In {
+            field,
+            mut values,
+            negated,
+        } => {
+            // Integers in numeric order, then the other values as rendered
+            values.sort_by_key(|value| match value {
+                Value::Int(id) => (0, *id, String::new()),
+                value => (1, 0, value.to_string()),
+            });
+            values.dedup();
+            Expr::In {
+                field,
+                values,
+                negated,
+            }
+        }
+        expr => expr,
+    }
+}
+
+///Flattens the nested junctions of the same kind and sorts their canonical parts
+fn junction(
+    exprs: Vec<Expr>,
+    join: fn(Vec<Expr>) -> Expr,
+    same_kind: fn(Expr) -> Result<Vec<Expr>, Expr>,
+) -> Expr {
+    let mut parts: Vec<Expr> = Vec::with_capacity(exprs.len());
+    for expr in exprs.into_iter().map(canonical_expr) {
+        match same_kind(expr) {
+            Ok(nested) => parts.extend(nested),
+            Err(expr) => parts.push(expr),
+        }
+    }
+    let mut parts: Vec<(String, Expr)> = parts
+        .into_iter()
+        .map(|expr| (expr.to_string(), expr))
+        .collect();
+    parts.sort_by(|(a, _), (b, _)| a.cmp(b));
+    parts.dedup_by(|(a, _), (b, _)| a == b);
+    let mut parts: Vec<Expr> = parts.into_iter().map(|(_, expr)| expr).collect();
+    match parts.len() {
+        1 => parts.remove(0),
+        _ => join(parts),
+    }
+}
+
+#[test]
+fn nested_conditions_are_flattened_and_sorted() {
+    let request: RequestBuilder =
+        "fields name; where (rating > 80 & (genres = (12, 5, 12) & cover != null)) | id = 7;"
+            .parse()
+            .unwrap();
+    assert_eq!(
+        "fields name; where (cover != null & genres = (5,12) & rating > 80) | id = 7; limit 10;",
+        request.canonical().to_query()
+    );
+
+    let reordered: RequestBuilder =
+        "fields name; where id = 7 | (cover != null & rating > 80 & genres = (5,12)); limit 20;"
+            .parse()
+            .unwrap();
+    let diff = request.diff(&reordered);
+    assert_eq!(
+        vec![ClauseDiff {
+            clause: "limit",
+            left: Some("10".to_owned()),
+            right: Some("20".to_owned()),
+        }],
+        diff.clauses
+    );
+}
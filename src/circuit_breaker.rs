@@ -0,0 +1,149 @@
+//! Failing fast while IGDB is down, see IGDBClient::with_circuit_breaker.
+//!
+//! After a number of consecutive failed requests, the circuit opens: the following requests
+//! fail right away with Error::CircuitOpen instead of piling up behind timeouts. Once the
+//! cooldown elapsed, a single request is let through to probe IGDB, closing the circuit
+//! when it succeeds and opening it for another cooldown otherwise.
+
+use crate::time::Instant;
+use crate::Error;
+use std::sync::Mutex;
+use std::time::Duration;
+
+///Counts the consecutive failures of the requests sent by every endpoint client created
+/// from the same IGDBClient
+pub(crate) struct CircuitBreaker {
+    failures_to_open: u32,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    consecutive_failures: u32,
+    ///When the circuit opened, or when its last probe was let through
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(failures_to_open: u32, cooldown: Duration) -> CircuitBreaker {
+        CircuitBreaker {
+            failures_to_open: failures_to_open.max(1),
+            cooldown,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    ///Fails with Error::CircuitOpen while the circuit is open
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        self.check_at(Instant::now())
+    }
+
+    fn check_at(&self, now: Instant) -> Result<(), Error> {
+        let mut state = self.state.lock().unwrap();
+        match state.opened_at {
+            Some(opened_at) => {
+                let elapsed = now.saturating_duration_since(opened_at);
+                match self.cooldown.checked_sub(elapsed).filter(|d| !d.is_zero()) {
+                    Some(retry_after) => Err(Error::CircuitOpen { retry_after }),
+                    None => {
+                        // The other requests keep failing fast until the probe is answered
+                        state.opened_at = Some(now);
+                        Ok(())
+                    }
+                }
+            }
+            None => Ok(()),
+        }
+    }
+
+    ///Records the outcome of a request let through
+    pub(crate) fn record<T, F>(&self, result: &Result<(http::Response<T>, F), Error>) {
+        self.record_at(is_failure(result), Instant::now());
+    }
+
+    fn record_at(&self, failed: bool, now: Instant) {
+        let mut state = self.state.lock().unwrap();
+        match failed {
+            true => {
+                state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+                if state.consecutive_failures >= self.failures_to_open {
+                    if state.opened_at.is_none() {
+                        log::warn!(
+                            "{} consecutive failed requests, failing fast for {:?}",
+                            state.consecutive_failures,
+                            self.cooldown
+                        );
+                    }
+                    state.opened_at = Some(now);
+                }
+            }
+            false => *state = State::default(),
+        }
+    }
+}
+
+///Whether the request failed because of IGDB or the network: it could not be sent,
+/// timed out, or was answered with a 5xx status
+fn is_failure<T, F>(result: &Result<(http::Response<T>, F), Error>) -> bool {
+    match result {
+        Ok((response, _)) => response.status().is_server_error(),
+        Err(Error::Http(_)) => true,
+        Err(_) => false,
+    }
+}
+
+#[test]
+fn circuit_opens_after_consecutive_failures_and_probes_after_the_cooldown() {
+    let breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+    let start = Instant::now();
+
+    breaker.record_at(true, start);
+    breaker.record_at(true, start);
+    breaker.record_at(false, start);
+    breaker.record_at(true, start);
+    breaker.record_at(true, start);
+    assert!(breaker.check_at(start).is_ok());
+
+    breaker.record_at(true, start);
+    match breaker.check_at(start + Duration::from_secs(10)) {
+        Err(Error::CircuitOpen { retry_after }) => {
+            assert_eq!(Duration::from_secs(20), retry_after)
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    let probe = start + Duration::from_secs(30);
+    assert!(breaker.check_at(probe).is_ok());
+    assert!(breaker.check_at(probe).is_err());
+    breaker.record_at(true, probe);
+    assert!(breaker.check_at(probe + Duration::from_secs(29)).is_err());
+
+    let probe = probe + Duration::from_secs(30);
+    assert!(breaker.check_at(probe).is_ok());
+    breaker.record_at(false, probe);
+    assert!(breaker.check_at(probe).is_ok());
+}
+
+#[test]
+fn open_circuit_fails_fast_without_sending() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+
+    let down = ScriptedTransport::new().then(503, "");
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(down.clone())
+        .without_rate_limit()
+        .with_retries(1, Duration::from_millis(1))
+        .with_circuit_breaker(2, Duration::from_secs(60));
+    let request = IGDBClient::create_request();
+
+    for _ in 0..4 {
+        let _ = async_std::task::block_on(igdb.query::<GenresClient>(&request));
+    }
+    assert_eq!(2, down.sent());
+    assert!(matches!(
+        async_std::task::block_on(igdb.query::<GenresClient>(&request)),
+        Err(Error::CircuitOpen { .. })
+    ));
+}
@@ -1,10 +1,54 @@
 use_client_imports!();
+use crate::batch::{BatchQuery, BatchResult};
+use crate::budget::{Budget, BudgetTracker, BudgetUsage};
+use crate::circuit_breaker::CircuitBreaker;
+use crate::client_builder::ClientBuilder;
+use crate::cursor::CursorPager;
+use crate::endpoint_client::{split_in_pages, MAX_LIMIT};
+use crate::export::{ExportFormat, Exporter};
+use crate::image_url::ImageUrl;
+use crate::json_numbers::JsonNumbers;
+use crate::limits::Limits;
+use crate::loader::Loader;
+use crate::media_helpers;
+use crate::middleware::Middleware;
+use crate::model_fields::model_fields;
+use crate::multiquery::{MultiQueryBuilder, MultiQueryResults, MULTIQUERY_PATH};
+use crate::page::Page;
+use crate::pipeline::Pipeline;
+use crate::query_lint::LintMode;
+use crate::rate_limiter::{Priority, RateLimiter, ThrottleState};
+use crate::request_builder::{ResponseFormat, Value};
+use crate::request_options::{CacheMode, RequestOptions};
+use crate::response::{Lenient, Response};
+use crate::response_cache::{CacheStats, CacheStore, MemoryStore, ResponseCache};
+use crate::retry::RetryPolicy;
+use crate::schema::{SchemaMode, SchemaReport};
+use crate::search::{search_request, Search, SearchResult, SEARCH_PATH};
+use crate::signing::RequestSigner;
+use crate::single_flight::SingleFlight;
+use crate::sync::{self, Changes, Checkpoint, SyncPage, SyncState};
+use crate::time::Instant;
+use crate::title_match::{self, MatchOptions, TitleMatch};
+use crate::transport::HttpTransport;
+use crate::watch::{self, Subscription};
+use crate::webhooks::WebhooksClient;
+use futures::{stream, SinkExt, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
+use std::time::Duration;
 //create_client! macro automatically generates clients
 //for different endpoints and extends IGDBClient struct
 
 //params: (ClientName, EntityResult, IGDB client impl method)
 
 create_client!(AgeRatingsClient, AgeRating, age_ratings);
+create_client!(
+    AgeRatingContentDescriptionsClient,
+    AgeRatingContentDescription,
+    age_rating_content_descriptions
+);
+create_client!(AlternativeNamesClient, AlternativeName, alternative_names);
 create_client!(ArtworksClient, Artwork, artworks);
 create_client!(
     CharacterMugshotsClient,
@@ -12,22 +56,58 @@ create_client!(
     character_mug_shots
 );
 create_client!(CharactersClient, Character, characters);
+create_client!(CollectionsClient, Collection, collections);
 create_client!(CompaniesClient, Company, companies);
 create_client!(CoversClient, Cover, covers);
+create_client!(EventsClient, Event, events);
+create_client!(EventLogosClient, EventLogo, event_logos);
+create_client!(EventNetworksClient, EventNetwork, event_networks);
 create_client!(ExternalGameClient, ExternalGame, external_games);
 create_client!(GamesClient, Game, games);
+create_client!(
+    GameLocalizationsClient,
+    GameLocalization,
+    game_localizations
+);
 create_client!(GameModesClient, GameMode, game_modes);
+create_client!(GameTimeToBeatsClient, GameTimeToBeat, game_time_to_beats);
+create_client!(GameVersionsClient, GameVersion, game_versions);
+create_client!(
+    GameVersionFeaturesClient,
+    GameVersionFeature,
+    game_version_features
+);
+create_client!(
+    GameVersionFeatureValuesClient,
+    GameVersionFeatureValue,
+    game_version_feature_values
+);
 create_client!(GameVideosClient, GameVideo, game_videos);
-create_client!(EnginesClient, Engine, game_engines);
+create_client!(GenresClient, Genre, genres);
+create_client!(EnginesClient, GameEngine, game_engines);
+create_client!(GameEngineLogosClient, GameEngineLogo, game_engine_logos);
 create_client!(FranchisesClient, Franchise, franchises);
+create_client!(InvolvedCompaniesClient, InvolvedCompany, involved_companies);
+create_client!(KeywordsClient, Keyword, keywords);
+create_client!(LanguagesClient, Language, languages);
+create_client!(LanguageSupportsClient, LanguageSupport, language_supports);
 create_client!(MultiPlayerModesClient, MultiplayerMode, multiplayer_modes);
+create_client!(NetworkTypesClient, NetworkType, network_types);
 create_client!(PlatformsClient, Platform, platforms);
 create_client!(PlatformLogosClient, PlatformLogo, platform_logos);
+create_client!(PlatformVersionsClient, PlatformVersion, platform_versions);
 create_client!(
     PlayerPerpectivesClient,
     PlayerPerspective,
     player_perspectives
 );
+create_client!(
+    PopularityPrimitivesClient,
+    PopularityPrimitive,
+    popularity_primitives
+);
+create_client!(PopularityTypesClient, PopularityType, popularity_types);
+create_client!(RegionsClient, Region, regions);
 create_client!(ReleaseDatesClient, ReleaseDate, release_dates);
 create_client!(ScreenshotsClient, Screenshot, screenshots);
 create_client!(ThemesClient, Theme, themes);
@@ -35,22 +115,27 @@ create_client!(WebsitesClient, Website, websites);
 
 expand_media_download!(ArtworksClient);
 expand_media_download!(CoversClient);
+expand_media_download!(EventLogosClient);
 expand_media_download!(ScreenshotsClient);
 expand_media_download!(PlatformLogosClient);
+expand_media_download!(GameEngineLogosClient);
 expand_media_download!(CharacterMugshotsClient);
 
+expand_get_by_game_id!(AlternativeNamesClient, AlternativeName);
 expand_get_by_game_id!(ArtworksClient, Artwork);
 expand_get_by_game_id!(CoversClient, Cover);
 expand_get_by_game_id!(ExternalGameClient, ExternalGame);
+expand_get_by_game_id!(GameLocalizationsClient, GameLocalization);
 expand_get_by_game_id!(GameVideosClient, GameVideo);
+expand_get_by_game_id!(LanguageSupportsClient, LanguageSupport);
 expand_get_by_game_id!(MultiPlayerModesClient, MultiplayerMode);
 expand_get_by_game_id!(ReleaseDatesClient, ReleaseDate);
 expand_get_by_game_id!(ScreenshotsClient, Screenshot);
 expand_get_by_game_id!(WebsitesClient, Website);
 
 pub struct IGDBClient {
-    client_id: String,
-    token: String,
+    auth: Arc<Authenticator>,
+    config: ClientConfig,
 }
 
 // The IGDB client.
@@ -66,27 +151,2020 @@ impl IGDBClient {
     /// let igdb = IGDBClient::new("client_id", "token");
     /// ```
     pub fn new<S: Into<String>>(client_id: S, token: S) -> IGDBClient {
+        IGDBClient::with_credentials(Credentials::new(client_id, token))
+    }
+
+    /// Creates a new instance of the IGDB Client from the given Credentials
+    /// # Examples
+    /// ```
+    /// use igdb::auth::Credentials;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::with_credentials(Credentials::new("client_id", "token"));
+    /// ```
+    pub fn with_credentials(credentials: Credentials) -> IGDBClient {
+        IGDBClient::with_auth(Authenticator::Static(credentials))
+    }
+
+    /// Creates a new instance of the IGDB Client from your Twitch application client_id
+    /// and client_secret. App access tokens are acquired and refreshed automatically.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::from_client_secret("client_id", "client_secret");
+    /// ```
+    pub fn from_client_secret<S: Into<String>>(client_id: S, client_secret: S) -> IGDBClient {
+        IGDBClient::with_authenticator(TwitchAuthenticator::new(client_id, client_secret))
+    }
+
+    /// Creates a new instance of the IGDB Client from the IGDB_CLIENT_ID and IGDB_ACCESS_TOKEN
+    /// or IGDB_CLIENT_SECRET environment variables, sending the requests to IGDB_BASE_URL
+    /// when set, e.g. for command line tools and CI jobs. Fails listing the missing variables,
+    /// see ClientBuilder::from_env to configure the client further.
+    /// # Examples
+    /// ```no_run
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::from_env().unwrap();
+    /// ```
+    pub fn from_env() -> Result<IGDBClient, Error> {
+        ClientBuilder::from_env()?.build()
+    }
+
+    /// Creates a new instance of the IGDB Client using the given TwitchAuthenticator
+    /// to acquire and refresh app access tokens.
+    pub fn with_authenticator(authenticator: TwitchAuthenticator) -> IGDBClient {
+        IGDBClient::with_auth(Authenticator::Twitch(authenticator))
+    }
+
+    /// Returns a ClientBuilder to configure the client before creating it,
+    /// see ClientBuilder
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    fn with_auth(auth: Authenticator) -> IGDBClient {
+        IGDBClient::with_config(auth, ClientConfig::default())
+    }
+
+    pub(crate) fn with_config(auth: Authenticator, config: ClientConfig) -> IGDBClient {
         IGDBClient {
-            client_id: client_id.into(),
-            token: token.into(),
+            auth: Arc::new(auth),
+            config,
         }
     }
-    /// Creates a new instance of a Request builder
-    /// you can use it's methods to create custom queries
+
+    /// Gzips the body of outgoing requests longer than the given threshold (in bytes)
+    /// and sets the `Content-Encoding: gzip` header accordingly.
+    /// Useful when sending big id lists or multiqueries.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").compress_requests_above(1024);
+    /// ```
+    #[cfg(feature = "gzip")]
+    pub fn compress_requests_above(mut self, threshold: usize) -> IGDBClient {
+        self.config.compress_above = Some(threshold);
+        self
+    }
+
+    /// Sends every request to the given base url instead of https://api.igdb.com/v4,
+    /// e.g. to go through a caching proxy
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
     ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_base_url("http://localhost:8080/v4");
+    /// ```
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> IGDBClient {
+        self.config.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the http method used to send the queries, POST by default.
+    /// Only change it for proxies that do not accept POST requests.
     /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::HttpMethod;
     ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_method(HttpMethod::Get);
+    /// ```
+    pub fn with_method(mut self, method: HttpMethod) -> IGDBClient {
+        self.config.method = method;
+        self
+    }
+
+    /// Sets the format IGDB answers in, json by default.
+    /// Protobuf requires the `protobuf` feature.
+    /// # Examples
     /// ```
     /// use igdb::client::IGDBClient;
-    /// use igdb::request_builder::Equality;
+    /// use igdb::request_builder::ResponseFormat;
+    ///
+    /// # #[cfg(feature = "protobuf")]
+    /// let igdb = IGDBClient::new("client_id", "token").with_response_format(ResponseFormat::Protobuf);
+    /// ```
+    pub fn with_response_format(mut self, format: ResponseFormat) -> IGDBClient {
+        self.config.format = format;
+        self
+    }
+
+    /// Sets how the fields of the responses missing from the models are handled,
+    /// ignored by default, see the schema module
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::schema::SchemaMode;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_schema_mode(SchemaMode::Report);
+    /// // ... queries ...
+    /// for (endpoint, fields) in igdb.schema_report().unknown_fields {
+    ///     println!("{} answered unknown fields {:?}", endpoint, fields);
+    /// }
+    /// ```
+    pub fn with_schema_mode(mut self, mode: SchemaMode) -> IGDBClient {
+        self.config.schema_mode = mode;
+        self
+    }
+
+    /// Sets how the queries are checked before being sent: `fields *` on heavy endpoints,
+    /// every field of referenced registries and responses estimated too large are warned
+    /// about or rejected, unchecked by default, see RequestBuilder::lint
+    /// # Examples
+    /// ```
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::query_lint::LintMode;
+    /// use igdb::Error;
     ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_lint_mode(LintMode::Strict);
     /// let mut request = IGDBClient::create_request();
-    /// request
-    /// .all_fields()
-    /// .add_where("id", Equality::Greater, "340")
-    /// .limit(10);
+    /// request.all_fields().limit(500);
+    ///
+    /// let games = task::block_on(igdb.query::<GamesClient>(&request));
+    /// assert!(matches!(games, Err(Error::InvalidQuery(_))));
     /// ```
-    pub fn create_request() -> RequestBuilder {
-        RequestBuilder::new()
+    pub fn with_lint_mode(mut self, mode: LintMode) -> IGDBClient {
+        self.config.lint_mode = mode;
+        self
+    }
+
+    /// Sets how the numbers of the json returned without a model are written, e.g. the large
+    /// ids as strings for consumers reading every number as an f64, see the json_numbers module
+    /// # Examples
+    /// ```
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::json_numbers::JsonNumbers;
+    /// use igdb::mock::MockTransport;
+    ///
+    /// let mock = MockTransport::new().with_fixture(
+    ///     "games",
+    ///     "fields name; limit 10;",
+    ///     r#"[{"id": 9007199254740993, "name": "Celeste"}]"#,
+    /// );
+    /// let igdb = IGDBClient::new("client_id", "token")
+    ///     .with_transport(mock)
+    ///     .with_json_numbers(JsonNumbers::Safe);
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name");
+    ///
+    /// let games = task::block_on(igdb.query_raw::<GamesClient>(&request)).unwrap();
+    /// assert_eq!("9007199254740993", games[0]["id"]);
+    /// ```
+    pub fn with_json_numbers(mut self, numbers: JsonNumbers) -> IGDBClient {
+        self.config.json_numbers = numbers;
+        self
+    }
+
+    /// Returns the fields of the responses missing from the models since the client was
+    /// created, recorded unless the schema mode ignores them
+    pub fn schema_report(&self) -> SchemaReport {
+        self.config.unknown_fields.report()
+    }
+
+    /// Sets the transport sending every request, including the Twitch token requests
+    /// and media downloads, see the transport module
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::transport::ReqwestTransport;
+    ///
+    /// let http = reqwest::Client::builder().user_agent("my-app").build().unwrap();
+    /// let igdb = IGDBClient::new("client_id", "token").with_transport(ReqwestTransport::from(http));
+    /// ```
+    pub fn with_transport<T: HttpTransport + 'static>(mut self, transport: T) -> IGDBClient {
+        self.set_transport(Arc::new(transport));
+        self
+    }
+
+    pub(crate) fn set_transport(&mut self, transport: Arc<dyn HttpTransport>) {
+        // The authenticator is only shared once endpoint clients are created
+        if let Some(Authenticator::Twitch(twitch)) = Arc::get_mut(&mut self.auth) {
+            twitch.transport = transport.clone();
+        }
+        self.config.transport = transport;
+    }
+
+    /// Adds a middleware run around every request sent to IGDB and media download,
+    /// after the ones added before it, see the middleware module
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::middleware::Next;
+    /// use igdb::transport::TransportFuture;
+    ///
+    /// fn log(request: http::Request<Vec<u8>>, next: Next<'_>) -> TransportFuture<'_> {
+    ///     println!("{} {}", request.method(), request.uri());
+    ///     next.run(request)
+    /// }
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_middleware(log);
+    /// ```
+    pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> IGDBClient {
+        self.config.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Signs every request right before it is sent, after the middlewares, retries and
+    /// rate limiter, e.g. to add the HMAC headers of a gateway fronting IGDB.
+    /// See the signing module.
+    /// # Examples
+    /// ```
+    /// use http::HeaderMap;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::signing::SigningRequest;
+    /// use igdb::transport::TransportError;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_request_signer(
+    ///     |request: &SigningRequest<'_>, headers: &mut HeaderMap| -> Result<(), TransportError> {
+    ///         headers.insert("x-timestamp", request.timestamp.as_secs().into());
+    ///         Ok(())
+    ///     },
+    /// );
+    /// ```
+    pub fn with_request_signer<S: RequestSigner + 'static>(mut self, signer: S) -> IGDBClient {
+        self.config.signer = Some(Arc::new(signer));
+        self
+    }
+
+    /// Sets the timeout applied to every request
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use std::time::Duration;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_timeout(Duration::from_secs(10));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> IGDBClient {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a header sent with every request
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_header("x-gateway-key", "secret");
+    /// ```
+    pub fn with_header<N: Into<String>, V: Into<String>>(
+        mut self,
+        name: N,
+        value: V,
+    ) -> IGDBClient {
+        self.config.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets how many chunked requests (e.g. get_by_ids with more than 500 ids)
+    /// or batched queries can be sent in parallel. Defaults to 1.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_concurrency(4);
+    /// ```
+    pub fn with_concurrency(mut self, concurrency: usize) -> IGDBClient {
+        self.config.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Limits the requests sent by this client, and every endpoint client created from it,
+    /// to the given number per second. Defaults to the 4 requests per second IGDB accepts.
+    /// The rate is slowed down while IGDB answers 429 Too Many Requests, see throttle_state.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_rate_limit(2);
+    /// ```
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> IGDBClient {
+        self.config.rate_limiter = Some(Arc::new(RateLimiter::new(requests_per_second)));
+        self
+    }
+
+    /// Returns a client sharing the rate limit of this one, whose requests are served
+    /// with the given priority, e.g. Background for a crawler running along a UI.
+    /// Background requests wait while interactive ones are waiting for the rate limit.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::rate_limiter::Priority;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token");
+    /// let crawler = igdb.with_priority(Priority::Background);
+    /// ```
+    pub fn with_priority(&self, priority: Priority) -> IGDBClient {
+        self.with_options(&RequestOptions::new().priority(priority))
+    }
+
+    /// Sets the priority of the requests to the given endpoint, unless set for the whole
+    /// client, see with_priority. The requests to the other endpoints are interactive.
+    /// # Examples
+    /// ```
+    /// use igdb::client::{ExternalGameClient, IGDBClient};
+    /// use igdb::rate_limiter::Priority;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token")
+    ///     .with_endpoint_priority::<ExternalGameClient>(Priority::Background);
+    /// ```
+    pub fn with_endpoint_priority<E: Endpoint>(mut self, priority: Priority) -> IGDBClient {
+        self.config.endpoint_priorities.push((E::PATH, priority));
+        self
+    }
+
+    /// Sets how requests answered with 429 Too Many Requests or a transient 5xx status
+    /// are retried: up to max_attempts attempts in total, waiting base_delay before the first
+    /// retry and doubling it for each following one, unless IGDB sends a Retry-After header.
+    /// Defaults to 3 attempts and 500ms, 1 attempt disables retries.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use std::time::Duration;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_retries(5, Duration::from_secs(1));
+    /// ```
+    pub fn with_retries(mut self, max_attempts: u32, base_delay: Duration) -> IGDBClient {
+        self.config.retry = RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+        };
+        self
+    }
+
+    /// Caches the successful responses for the given time to live, keeping at most
+    /// max_entries of them. Identical queries sent to the same endpoint are then answered
+    /// from memory, e.g. taxonomy lookups or popular game pages.
+    /// The cache is shared by every endpoint client created from this client.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use std::time::Duration;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_cache(Duration::from_secs(600), 1000);
+    /// ```
+    pub fn with_cache(self, ttl: Duration, max_entries: usize) -> IGDBClient {
+        self.with_cache_store(ttl, MemoryStore::new(max_entries))
+    }
+
+    /// Caches the successful responses in the given store for the given time to live,
+    /// e.g. a FileStore to reuse them across runs, see the response_cache module
+    pub fn with_cache_store<S: CacheStore + 'static>(
+        mut self,
+        ttl: Duration,
+        store: S,
+    ) -> IGDBClient {
+        self.config.cache = Some(Arc::new(ResponseCache::new(ttl, store)));
+        self
+    }
+
+    /// Sends the identical queries issued while one is in flight only once, sharing its
+    /// response, e.g. for a web backend answering many users browsing the same pages.
+    /// When the query fails, one of the waiting callers sends it again.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_single_flight();
+    /// ```
+    pub fn with_single_flight(mut self) -> IGDBClient {
+        self.config.single_flight = Some(Arc::new(SingleFlight::default()));
+        self
+    }
+
+    /// Fails fast with Error::CircuitOpen for the given cooldown once the given number of
+    /// requests failed in a row, because they could not be sent, timed out or were answered
+    /// with a 5xx status, so that an IGDB outage does not pile up waiting tasks.
+    /// A single request is let through after the cooldown, closing the circuit when it succeeds.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use std::time::Duration;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token")
+    ///     .with_circuit_breaker(5, Duration::from_secs(30));
+    /// ```
+    pub fn with_circuit_breaker(mut self, failures: u32, cooldown: Duration) -> IGDBClient {
+        self.config.circuit_breaker = Some(Arc::new(CircuitBreaker::new(failures, cooldown)));
+        self
+    }
+
+    /// Returns a client sharing the configuration of this one, whose requests fail with
+    /// Error::BudgetExceeded once they spent the given budget, e.g. for a sync job against
+    /// a partner quota. Every attempt counts, retries included, cached responses do not.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::budget::Budget;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::Error;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let job = igdb.with_budget(Budget::new().max_requests(1000));
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name");
+    ///
+    ///     match job.query::<GamesClient>(&request).await {
+    ///         Err(Error::BudgetExceeded(usage)) => println!("stopped after {} requests", usage.requests),
+    ///         games => println!("{:?}", games.map(|games| games.len())),
+    ///     }
+    /// })
+    /// ```
+    pub fn with_budget(&self, budget: Budget) -> IGDBClient {
+        let mut config = self.config.clone();
+        config.budget = Some(Arc::new(BudgetTracker::new(budget)));
+        IGDBClient {
+            auth: self.auth.clone(),
+            config,
+        }
+    }
+
+    /// Returns a client sharing the configuration of this one, which sends an attempt again
+    /// once it was not answered after the given time and takes the first response, dropping
+    /// the other attempt, e.g. for the lookups of a search box. The duplicate is only sent
+    /// when the rate limit allows it right away, and never for background requests.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use std::time::Duration;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let lookups = igdb.with_hedging(Duration::from_millis(300));
+    ///     let game = lookups.games().get_first_by_name("Witcher").await.unwrap();
+    ///     println!("{}", game.name);
+    /// })
+    /// ```
+    pub fn with_hedging(&self, after: Duration) -> IGDBClient {
+        let mut config = self.config.clone();
+        config.hedge_after = Some(after);
+        IGDBClient {
+            auth: self.auth.clone(),
+            config,
+        }
+    }
+
+    /// Returns what was spent of the budget, None when requests are not limited,
+    /// see IGDBClient::with_budget
+    pub fn budget_usage(&self) -> Option<BudgetUsage> {
+        self.config.budget.as_ref().map(|budget| budget.usage())
+    }
+
+    /// Returns the hit and miss counters of the response cache, None when responses
+    /// are not cached, see IGDBClient::with_cache
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.config.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Discards every cached response
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.config.cache {
+            cache.invalidate(None, None);
+        }
+    }
+
+    /// Discards the cached responses of the given endpoint, e.g. after editing its registries
+    /// # Examples
+    /// ```
+    /// use igdb::client::{GenresClient, IGDBClient};
+    /// use std::time::Duration;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_cache(Duration::from_secs(600), 100);
+    /// igdb.invalidate_cache::<GenresClient>();
+    /// ```
+    pub fn invalidate_cache<E: Endpoint>(&self) {
+        if let Some(cache) = &self.config.cache {
+            cache.invalidate(Some(E::PATH), None);
+        }
+    }
+
+    /// Discards the cached responses older than the given age
+    pub fn invalidate_cache_older_than(&self, age: Duration) {
+        if let Some(cache) = &self.config.cache {
+            cache.invalidate(None, Some(age));
+        }
+    }
+
+    /// Returns a client sharing the credentials, cache and rate limit of this one, sending
+    /// its requests with the given overrides, e.g. to fail interactive requests fast
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::request_options::{CacheMode, RequestOptions};
+    /// use std::time::Duration;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token").with_cache(Duration::from_secs(600), 100);
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name").search("Celeste");
+    ///
+    ///     let options = RequestOptions::new()
+    ///         .timeout(Duration::from_secs(2))
+    ///         .without_retries()
+    ///         .cache(CacheMode::Bypass);
+    ///     let games = igdb.with_options(&options).query::<GamesClient>(&request).await.unwrap();
+    /// })
+    /// ```
+    pub fn with_options(&self, options: &RequestOptions) -> IGDBClient {
+        let mut config = self.config.clone();
+        options.apply(&mut config);
+        IGDBClient {
+            auth: self.auth.clone(),
+            config,
+        }
+    }
+
+    /// Returns a client sending its requests with the credentials of another Twitch
+    /// application, e.g. of a customer of a service, sharing the transport and its
+    /// connections with this one. Each tenant has its own rate limit, shared by the clients
+    /// returned for the same client_id, and its own cached responses.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::auth::Credentials;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use std::time::Duration;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token").with_cache(Duration::from_secs(600), 1000);
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name").search("Celeste");
+    ///
+    ///     let customer = igdb.as_tenant(Credentials::new("customer_client_id", "customer_token"));
+    ///     let games = customer.query::<GamesClient>(&request).await.unwrap();
+    /// })
+    /// ```
+    pub fn as_tenant(&self, credentials: Credentials) -> IGDBClient {
+        let mut config = self.config.clone();
+        config.rate_limiter = config
+            .rate_limiter
+            .as_ref()
+            .map(|limiter| config.tenant_limiters.get(&credentials.client_id, limiter));
+        config.tenant = Some(credentials.client_id.clone());
+        config.limit_headers = Arc::default();
+        IGDBClient {
+            auth: Arc::new(Authenticator::Static(credentials)),
+            config,
+        }
+    }
+
+    /// Sends the requests as soon as possible, for proxies limiting the rate on their own
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token")
+    ///     .with_base_url("http://localhost:8080/v4")
+    ///     .without_rate_limit();
+    /// ```
+    pub fn without_rate_limit(mut self) -> IGDBClient {
+        self.config.rate_limiter = None;
+        self
+    }
+
+    /// Returns how fast requests are currently sent, to show in a UI when IGDB pushes back.
+    /// None when the rate is not limited
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token");
+    /// let state = igdb.throttle_state().unwrap();
+    /// assert!(!state.is_throttled());
+    /// ```
+    pub fn throttle_state(&self) -> Option<ThrottleState> {
+        self.config
+            .rate_limiter
+            .as_ref()
+            .map(|rate_limiter| rate_limiter.state())
     }
+
+    /// Returns the limits of the requests and what is left of them: the state of the rate
+    /// limiter and of the budget, along with the rate limit headers of the last response
+    /// carrying some, see Limits. The headers of the tenants are kept apart, those of a
+    /// client returned by IGDBClient::as_tenant being its own
+    /// # Examples
+    /// ```
+    /// use igdb::budget::Budget;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token").with_budget(Budget::new().max_requests(100));
+    /// let limits = igdb.limits();
+    /// assert_eq!(Some(100), limits.remaining_requests());
+    /// assert!(!limits.is_throttled());
+    /// ```
+    pub fn limits(&self) -> Limits {
+        Limits::new(
+            self.throttle_state(),
+            self.config.limit_headers.last(),
+            self.config
+                .budget
+                .as_ref()
+                .map(|budget| (budget.budget(), budget.usage())),
+        )
+    }
+
+    /// Creates a new instance of a Request builder
+    /// you can use it's methods to create custom queries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .all_fields()
+    /// .add_where("id", Equality::Greater, 340)
+    /// .limit(10);
+    /// ```
+    pub fn create_request() -> RequestBuilder {
+        RequestBuilder::new()
+    }
+
+    /// Queries any endpoint implementing the Endpoint trait with the given request
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name").search("Celeste");
+    ///
+    ///     let games = igdb.query::<GamesClient>(&request).await.unwrap();
+    /// })
+    /// ```
+    pub async fn query<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Vec<E::Model>, Error> {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+            .get::<E::Model>(request)
+            .await
+    }
+
+    /// Queries any endpoint like IGDBClient::query, but sets aside the registries which cannot
+    /// be read into the model instead of failing, e.g. when IGDB changed the type of a field
+    /// # Examples
+    /// ```
+    /// use async_std::task;
+    /// use igdb::client::{GenresClient, IGDBClient};
+    /// use igdb::mock::MockTransport;
+    ///
+    /// let mock = MockTransport::new().with_fixture(
+    ///     "genres",
+    ///     "fields name; limit 10;",
+    ///     r#"[{"id": 5, "name": "Shooter"}, {"id": 12, "name": ["RPG"]}]"#,
+    /// );
+    /// let igdb = IGDBClient::new("client_id", "token").with_transport(mock);
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name");
+    ///
+    /// let genres = task::block_on(igdb.query_lenient::<GenresClient>(&request)).unwrap();
+    /// assert_eq!("Shooter", genres.registries[0].name);
+    /// assert_eq!("[1].name", genres.invalid[0].field);
+    /// ```
+    pub async fn query_lenient<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Lenient<E::Model>, Error> {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+            .get_lenient::<E::Model>(request)
+            .await
+    }
+
+    /// Queries any endpoint like IGDBClient::query, but reads the registries into the given
+    /// struct instead of the endpoint model, e.g. a minimal struct holding the few fields
+    /// needed. Requests naming no field request the fields of the struct.
+    /// # Examples
+    /// ```
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::mock::MockTransport;
+    /// use serde_derive::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct GameName {
+    ///     name: String,
+    /// }
+    ///
+    /// let mock = MockTransport::new().with_fixture(
+    ///     "games",
+    ///     "fields name; search \"Celeste\"; limit 10;",
+    ///     r#"[{"id": 26226, "name": "Celeste"}]"#,
+    /// );
+    /// let igdb = IGDBClient::new("client_id", "token").with_transport(mock);
+    /// let mut request = IGDBClient::create_request();
+    /// request.search("Celeste");
+    ///
+    /// let games = task::block_on(igdb.query_into::<GamesClient, GameName>(&request)).unwrap();
+    /// assert_eq!("Celeste", games[0].name);
+    /// ```
+    pub async fn query_into<E: Endpoint, T: DeserializeOwned>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Vec<T>, Error> {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+            .get::<T>(request)
+            .await
+    }
+
+    /// Queries any endpoint like IGDBClient::query, but returns the registries as json
+    /// without reading them into a model, e.g. to explore the api or forward the results.
+    /// Requests naming no field request the fields of the endpoint model.
+    /// # Examples
+    /// ```
+    /// use async_std::task;
+    /// use igdb::client::{GenresClient, IGDBClient};
+    /// use igdb::mock::MockTransport;
+    ///
+    /// let mock = MockTransport::new().with_fixture(
+    ///     "genres",
+    ///     "fields name,slug; limit 10;",
+    ///     r#"[{"id": 12, "name": "Role-playing (RPG)", "slug": "role-playing-rpg"}]"#,
+    /// );
+    /// let igdb = IGDBClient::new("client_id", "token").with_transport(mock);
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_fields(["name", "slug"]);
+    ///
+    /// let genres = task::block_on(igdb.query_raw::<GenresClient>(&request)).unwrap();
+    /// assert_eq!("role-playing-rpg", genres[0]["slug"]);
+    /// ```
+    pub async fn query_raw<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<serde_json::Value, Error> {
+        let client = EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone()).raw();
+        let request = client.resolve_names(request).await?;
+        client
+            .post::<serde_json::Value>(E::PATH, request.build_body_for::<E::Model>()?)
+            .await
+    }
+
+    /// Queries any endpoint like IGDBClient::query_raw, but returns the response body
+    /// as answered by IGDB, json unless protobuf responses were requested
+    /// (see IGDBClient::with_response_format), e.g. to store it or pipe it to another program
+    pub async fn query_bytes<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Vec<u8>, Error> {
+        let client = EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone());
+        let request = client.resolve_names(request).await?;
+        let (body, _) = client
+            .post_bytes(E::PATH, request.build_body_for::<E::Model>()?)
+            .await?;
+        Ok(body)
+    }
+
+    /// Queries any endpoint like IGDBClient::query, and also returns the status, headers
+    /// and raw body of the response, e.g. to read the total number of matching registries
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name").search("Zelda").limit(10);
+    ///
+    ///     let response = igdb.query_with_meta::<GamesClient>(&request).await.unwrap();
+    ///     println!("{} of {:?} games", response.items.len(), response.total_count());
+    /// })
+    /// ```
+    pub async fn query_with_meta<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Response<E::Model>, Error> {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+            .get_with_meta::<E::Model>(request)
+            .await
+    }
+
+    /// Queries any endpoint like IGDBClient::query, but limits above the 500 registries
+    /// IGDB returns per request are honored by sending one request per 500 registries
+    /// and concatenating their results in order. See IGDBClient::with_concurrency
+    /// to send several of them in parallel.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::request_builder::OrderBy;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request
+    ///         .add_field("name")
+    ///         .sort_by("rating_count", OrderBy::Descending)
+    ///         .limit(2000);
+    ///
+    ///     let games = igdb.query_all::<GamesClient>(&request).await.unwrap();
+    /// })
+    /// ```
+    pub async fn query_all<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Vec<E::Model>, Error> {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+            .get_all::<E::Model>(request)
+            .await
+    }
+
+    /// Queries any endpoint like IGDBClient::query_all, then cleans up the registries of the
+    /// pages with the given pipeline, e.g. to drop the registries repeated by pages shifted
+    /// while they were requested
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::pipeline::Pipeline;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token").with_concurrency(4);
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_fields(["name", "total_rating_count"]).limit(2000);
+    ///
+    ///     let pipeline = Pipeline::new()
+    ///         .dedupe_by_id()
+    ///         .sort_by_key(|game: &igdb::model::games::Game| std::cmp::Reverse(game.total_rating_count));
+    ///     let games = igdb.query_all_with::<GamesClient>(&request, &pipeline).await.unwrap();
+    /// })
+    /// ```
+    pub async fn query_all_with<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+        pipeline: &Pipeline<E::Model>,
+    ) -> Result<Vec<E::Model>, Error>
+    where
+        E::Model: 'static,
+    {
+        Ok(pipeline.apply(self.query_all::<E>(request).await?))
+    }
+
+    /// Queries any endpoint like IGDBClient::query_all, writing the registries of each page
+    /// to the writer as NDJSON or CSV as soon as it is received, see the export module.
+    /// Returns the number of registries written.
+    /// # Examples
+    /// ```
+    /// use async_std::task;
+    /// use igdb::client::{GenresClient, IGDBClient};
+    /// use igdb::export::ExportFormat;
+    /// use igdb::mock::MockTransport;
+    ///
+    /// let mock = MockTransport::new().with_fixture(
+    ///     "genres",
+    ///     "fields name,slug; limit 10;",
+    ///     r#"[{"id": 12, "name": "Role-playing (RPG)", "slug": "role-playing-rpg"}]"#,
+    /// );
+    /// let igdb = IGDBClient::new("client_id", "token").with_transport(mock);
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_fields(["name", "slug"]);
+    ///
+    /// let mut csv = Vec::new();
+    /// let written = task::block_on(igdb.export::<GenresClient, _>(&request, ExportFormat::Csv, &mut csv));
+    /// assert_eq!(1, written.unwrap());
+    /// assert_eq!("id,name,slug\n12,Role-playing (RPG),role-playing-rpg\n", String::from_utf8(csv).unwrap());
+    /// ```
+    pub async fn export<E: Endpoint, W: std::io::Write>(
+        &self,
+        request: &RequestBuilder,
+        format: ExportFormat,
+        writer: W,
+    ) -> Result<usize, Error> {
+        let mut exporter = Exporter::new(writer, format);
+        for page in split_in_pages(request) {
+            let registries = self.query_raw::<E>(&page).await?;
+            match registries.as_array() {
+                Some(registries) => exporter.write_all(registries)?,
+                None => exporter.write(&registries)?,
+            }
+        }
+        let written = exporter.written();
+        exporter.finish()?;
+        Ok(written)
+    }
+
+    /// Requests the page of any endpoint at the offset of the request, pages holding as many
+    /// registries as the request limit. The returned Page requests the pages around it,
+    /// see Page::next and Page::prev.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name").search("Zelda").limit(20);
+    ///
+    ///     let mut page = igdb.page::<GamesClient>(&request).await.unwrap();
+    ///     loop {
+    ///         println!("page {}: {} games", page.number(), page.registries.len());
+    ///         match page.next().await.unwrap() {
+    ///             Some(next) => page = next,
+    ///             None => break,
+    ///         }
+    ///     }
+    /// })
+    /// ```
+    pub async fn page<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Page<E::Model>, Error> {
+        Page::request(
+            EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone()),
+            request.clone(),
+        )
+        .await
+    }
+
+    /// Requests a page of any endpoint like IGDBClient::page, but keeps the registries as json
+    /// like IGDBClient::query_raw. Requests naming no field request the fields of the endpoint model.
+    pub async fn page_raw<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> Result<Page<serde_json::Value>, Error> {
+        let mut request = request.clone();
+        if request.fields.is_empty() {
+            request.add_fields(
+                model_fields::<E::Model>()
+                    .unwrap_or_default()
+                    .iter()
+                    .copied(),
+            );
+        }
+        Page::request(
+            EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone()).raw(),
+            request,
+        )
+        .await
+    }
+
+    /// Streams every registry of any endpoint matching the given request,
+    /// transparently paging through the results 500 registries at a time.
+    /// The request limit is ignored, the stream starts at the request offset.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use futures::TryStreamExt;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::request_builder::{Equality, OrderBy};
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request
+    ///         .add_field("name")
+    ///         .add_where("rating", Equality::Greater, 90)
+    ///         .sort_by("id", OrderBy::Ascending);
+    ///
+    ///     let mut games = Box::pin(igdb.stream::<GamesClient>(&request));
+    ///     while let Some(game) = games.try_next().await.unwrap() {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub fn stream<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> impl Stream<Item = Result<E::Model, Error>> {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+            .stream::<E::Model>(request.clone())
+    }
+
+    /// Pages through the registries of any endpoint matching the request by ascending id,
+    /// requesting the registries following the last id seen instead of using offsets.
+    /// Pages have the request limit, its sort and offset are ignored.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::request_builder::Equality;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request
+    ///         .add_field("name")
+    ///         .add_where("rating", Equality::Greater, 90)
+    ///         .limit(500);
+    ///
+    ///     let mut pager = igdb.cursor_pager::<GamesClient>(&request);
+    ///     while let Some(games) = pager.next_page().await.unwrap() {
+    ///         println!("{} games up to id {}", games.len(), pager.last_id());
+    ///     }
+    /// })
+    /// ```
+    pub fn cursor_pager<E: Endpoint>(&self, request: &RequestBuilder) -> CursorPager<E::Model>
+    where
+        E::Model: Identifiable,
+    {
+        CursorPager::new(
+            EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone()),
+            request.clone(),
+        )
+    }
+
+    /// Streams every registry of any endpoint matching the given request like
+    /// IGDBClient::stream, paging by id instead of offset, see IGDBClient::cursor_pager.
+    /// The registries are sorted by id, the request limit, sort and offset are ignored.
+    pub fn stream_by_id<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+    ) -> impl Stream<Item = Result<E::Model, Error>>
+    where
+        E::Model: Identifiable,
+    {
+        let mut request = request.clone();
+        request.limit(MAX_LIMIT);
+        self.cursor_pager::<E>(&request).into_stream()
+    }
+
+    /// Sends every registry of any endpoint matching the request through the bounded channel,
+    /// in chunks of up to `chunk_size` registries, like IGDBClient::stream. The next page is
+    /// only requested once the channel accepted the registries of the previous one, so a slow
+    /// receiver, e.g. writing to a database, throttles the crawl instead of growing memory.
+    /// Returns the number of registries sent, stopping early once the receiver is dropped.
+    /// The registries read before a failed request are sent before its error is returned.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use futures::channel::mpsc;
+    /// use futures::StreamExt;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::model::games::Game;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name");
+    ///
+    ///     let (sender, mut receiver) = mpsc::channel::<Vec<Game>>(4);
+    ///     let writer = task::spawn(async move {
+    ///         while let Some(games) = receiver.next().await {
+    ///             println!("writing {} games", games.len());
+    ///         }
+    ///     });
+    ///     let sent = igdb
+    ///         .query_to_channel::<GamesClient>(&request, sender, 100)
+    ///         .await
+    ///         .unwrap();
+    ///     writer.await;
+    ///     println!("{} games written", sent);
+    /// })
+    /// ```
+    pub async fn query_to_channel<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+        mut sender: futures::channel::mpsc::Sender<Vec<E::Model>>,
+        chunk_size: usize,
+    ) -> Result<u64, Error> {
+        if chunk_size == 0 {
+            return Err(Error::InvalidQuery(
+                "query_to_channel needs chunks of at least 1 registry".to_owned(),
+            ));
+        }
+
+        let mut chunks = Box::pin(self.stream::<E>(request).try_chunks(chunk_size));
+        let mut sent = 0;
+        while let Some(chunk) = chunks.next().await {
+            let (registries, error) = match chunk {
+                Ok(registries) => (registries, None),
+                Err(stream::TryChunksError(registries, error)) => (registries, Some(error)),
+            };
+            let count = registries.len() as u64;
+            if !registries.is_empty() && sender.send(registries).await.is_err() {
+                log::debug!(
+                    "query_to_channel receiver dropped after {} registries",
+                    sent
+                );
+                return Ok(sent);
+            }
+            sent += count;
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+        Ok(sent)
+    }
+
+    /// Walks the registries of any endpoint matching the request filters and updated
+    /// at or after the checkpoint, by pages of 500 registries sorted by id.
+    /// Storing the checkpoint of each handled page lets an interrupted sync resume,
+    /// the one of the last page walks the registries updated since this sync started.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use futures::TryStreamExt;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::sync::Checkpoint;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_fields(["name", "updated_at"]);
+    ///
+    ///     let mut pages = Box::pin(igdb.sync::<GamesClient>(&request, Checkpoint::default()));
+    ///     while let Some(page) = pages.try_next().await.unwrap() {
+    ///         println!("{} games, resume from {:?}", page.registries.len(), page.checkpoint);
+    ///     }
+    /// })
+    /// ```
+    pub fn sync<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+        checkpoint: Checkpoint,
+    ) -> impl Stream<Item = Result<SyncPage<E::Model>, Error>>
+    where
+        E::Model: Identifiable,
+    {
+        sync::sync(
+            EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone()),
+            request.clone(),
+            checkpoint,
+        )
+    }
+
+    /// Returns up to 500 registries of any endpoint matching the request filters and
+    /// updated after the given state, along with the state to request the next changes from.
+    /// Calling it until no registry is returned keeps a mirror up to date, see SyncState
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::sync::SyncState;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name");
+    ///
+    ///     let mut state = SyncState::default();
+    ///     loop {
+    ///         let changes = igdb.changes_since::<GamesClient>(&request, state).await.unwrap();
+    ///         if changes.is_empty() {
+    ///             break;
+    ///         }
+    ///         println!("{} games changed", changes.registries.len());
+    ///         state = changes.state;
+    ///     }
+    /// })
+    /// ```
+    pub async fn changes_since<E: Endpoint>(
+        &self,
+        request: &RequestBuilder,
+        state: SyncState,
+    ) -> Result<Changes<E::Model>, Error> {
+        sync::changes_since(
+            &EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone()),
+            request,
+            state,
+        )
+        .await
+    }
+
+    /// Polls the subscription request every interval and delivers the registries of any
+    /// endpoint added or updated since the previous poll, never ending. A failed poll is
+    /// delivered as an error and retried after the interval, see Subscription
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use futures::StreamExt;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::request_builder::Equality;
+    /// use igdb::watch::Subscription;
+    /// use std::time::Duration;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_field("name").add_where("platforms", Equality::Equal, 6);
+    ///
+    ///     let subscription = Subscription::new(request, Duration::from_secs(600));
+    ///     let mut changes = Box::pin(igdb.watch::<GamesClient>(subscription));
+    ///     while let Some(changes) = changes.next().await {
+    ///         for game in changes.unwrap().registries {
+    ///             println!("{} was added or updated", game.name);
+    ///         }
+    ///     }
+    /// })
+    /// ```
+    pub fn watch<E: Endpoint>(
+        &self,
+        subscription: Subscription,
+    ) -> impl Stream<Item = Result<Changes<E::Model>, Error>> {
+        watch::watch(
+            EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone()),
+            subscription,
+        )
+    }
+
+    /// Returns the number of registries of any endpoint matching the search and filters
+    /// of the given request, fields, sort and limit are ignored
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    /// use igdb::request_builder::Equality;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_where("rating", Equality::Greater, 90);
+    ///
+    ///     let total = igdb.count::<GamesClient>(&request).await.unwrap();
+    /// })
+    /// ```
+    pub async fn count<E: Endpoint>(&self, request: &RequestBuilder) -> Result<u64, Error> {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+            .count(request)
+            .await
+    }
+
+    /// Sends the queries of the given MultiQueryBuilder in a single request
+    /// and returns their results, see MultiQueryBuilder
+    pub async fn multiquery(
+        &self,
+        multiquery: &MultiQueryBuilder,
+    ) -> Result<MultiQueryResults, Error> {
+        EndpointClient::new(self.auth.clone(), MULTIQUERY_PATH, self.config.clone())
+            .raw()
+            .post::<MultiQueryResults>(MULTIQUERY_PATH, multiquery.build_body())
+            .await
+    }
+
+    /// Sends the given queries concurrently, at most as many at once as set with
+    /// with_concurrency and within the rate limit, and returns their results in the same order.
+    /// A failed query does not stop the others, see BatchQuery and BatchSummary
+    pub async fn batch(&self, queries: Vec<BatchQuery>) -> Vec<Result<BatchResult, Error>> {
+        let requests = queries.into_iter().map(|query| async move {
+            let registries =
+                EndpointClient::new(self.auth.clone(), query.path, self.config.clone())
+                    .raw()
+                    .post::<Vec<serde_json::Value>>(query.path, query.body?)
+                    .await?;
+            Ok(BatchResult {
+                registries,
+                path: query.path,
+            })
+        });
+
+        stream::iter(requests)
+            .buffered(self.config.concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Searches the given term in games, characters, collections, companies, platforms and themes
+    /// at once, returning the 50 best matches, see SearchResult
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for result in igdb.search_all("Zelda").await.unwrap() {
+    ///         println!("{}", result.name());
+    ///     }
+    /// })
+    /// ```
+    pub async fn search_all<S: Into<String>>(&self, term: S) -> Result<Vec<SearchResult>, Error> {
+        let results = EndpointClient::new(self.auth.clone(), SEARCH_PATH, self.config.clone())
+            .get::<Search>(&search_request(&term.into(), 50))
+            .await?;
+
+        Ok(results.into_iter().map(SearchResult::from).collect())
+    }
+
+    /// Returns the companies that developed the given game
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for company in igdb.developers_of(1942).await.unwrap() {
+    ///         println!("{}", company.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn developers_of<I: Into<GameId>>(&self, game_id: I) -> Result<Vec<Company>, Error> {
+        self.involved_companies()
+            .get_companies_by_game_id(game_id, CompanyRole::Developer)
+            .await
+    }
+
+    /// Returns the companies that published the given game
+    pub async fn publishers_of<I: Into<GameId>>(&self, game_id: I) -> Result<Vec<Company>, Error> {
+        self.involved_companies()
+            .get_companies_by_game_id(game_id, CompanyRole::Publisher)
+            .await
+    }
+
+    /// Returns every game built on the given game engine
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for game in igdb.games_using_engine(13).await.unwrap() {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn games_using_engine<I: Into<GameEngineId>>(
+        &self,
+        engine_id: I,
+    ) -> Result<Vec<Game>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .add_where("game_engines", Equality::Equal, vec![engine_id.into()])
+            .sort_by("id", OrderBy::Ascending);
+
+        self.stream::<GamesClient>(&request).try_collect().await
+    }
+
+    /// Returns the most popular games for the given popularity type, most popular first.
+    /// The popularity types, e.g. IGDB visits or Steam 24h peak players, are listed
+    /// by the popularity_types endpoint
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for game in igdb.trending_games(1, 10).await.unwrap() {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn trending_games<I: Into<PopularityTypeId>>(
+        &self,
+        popularity_type: I,
+        limit: usize,
+    ) -> Result<Vec<Game>, Error> {
+        self.popularity_primitives()
+            .get_trending_games(popularity_type, limit)
+            .await
+    }
+
+    /// Returns the events which did not start yet, e.g. showcases like E3 or The Game Awards,
+    /// the soonest first
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for event in igdb.upcoming_events(10).await.unwrap() {
+    ///         println!("{}", event.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn upcoming_events(&self, limit: usize) -> Result<Vec<Event>, Error> {
+        self.events().get_upcoming(limit).await
+    }
+
+    /// Returns the name of the genre with the given id.
+    /// Every genre is retrieved on the first call and cached for the lifetime of the client.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let game = igdb.games().get_first_by_name("Witcher 3").await.unwrap();
+    ///
+    ///     for genre in game.genres {
+    ///         println!("{:?}", igdb.genre_name(genre.id()).await.unwrap());
+    ///     }
+    /// })
+    /// ```
+    pub async fn genre_name<I: Into<GenreId>>(&self, id: I) -> Result<Option<String>, Error> {
+        let client = self.genres().endpoint_client;
+        self.config
+            .names
+            .name(&client, id.into().value(), true)
+            .await
+    }
+
+    /// Returns the name of the theme with the given id, cached like IGDBClient::genre_name
+    pub async fn theme_name<I: Into<ThemeId>>(&self, id: I) -> Result<Option<String>, Error> {
+        let client = self.themes().endpoint_client;
+        self.config
+            .names
+            .name(&client, id.into().value(), true)
+            .await
+    }
+
+    /// Returns the name of the game mode with the given id, cached like IGDBClient::genre_name
+    pub async fn game_mode_name<I: Into<GameModeId>>(
+        &self,
+        id: I,
+    ) -> Result<Option<String>, Error> {
+        let client = self.game_modes().endpoint_client;
+        self.config
+            .names
+            .name(&client, id.into().value(), true)
+            .await
+    }
+
+    /// Returns the name of the player perspective with the given id,
+    /// cached like IGDBClient::genre_name
+    pub async fn player_perspective_name<I: Into<PlayerPerspectiveId>>(
+        &self,
+        id: I,
+    ) -> Result<Option<String>, Error> {
+        let client = self.player_perspectives().endpoint_client;
+        self.config
+            .names
+            .name(&client, id.into().value(), true)
+            .await
+    }
+
+    /// Returns the name of the keyword with the given id. There are too many keywords
+    /// to retrieve them all, so they are retrieved and cached one by one.
+    pub async fn keyword_name<I: Into<KeywordId>>(&self, id: I) -> Result<Option<String>, Error> {
+        let client = self.keywords().endpoint_client;
+        self.config
+            .names
+            .name(&client, id.into().value(), false)
+            .await
+    }
+
+    /// Returns the game with the given id and all its fields, None when it does not exist
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     if let Some(game) = igdb.game(1942).await.unwrap() {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn game<I: Into<GameId>>(&self, id: I) -> Result<Option<Game>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .add_where("id", Equality::Equal, id.into())
+            .limit(1);
+
+        Ok(self.games().get(request).await?.into_iter().next())
+    }
+
+    /// Returns the games with the given ids and all their fields, skipping the missing ones.
+    /// Ids are requested in chunks of at most 500.
+    pub async fn games_by_ids(&self, ids: &[GameId]) -> Result<Vec<Game>, Error> {
+        self.games().get_by_ids(ids).await
+    }
+
+    /// Returns the game with the given slug, the last segment of its igdb.com url,
+    /// and all its fields, None when it does not exist
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let witcher = igdb.game_by_slug("the-witcher-3-wild-hunt").await.unwrap();
+    /// })
+    /// ```
+    pub async fn game_by_slug(&self, slug: &str) -> Result<Option<Game>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .add_where("slug", Equality::Equal, Value::string(slug))
+            .limit(1);
+
+        Ok(self.games().get(request).await?.into_iter().next())
+    }
+
+    /// Returns the games matching a free-form title, e.g. a package name, best first.
+    /// The game with the slug of the title is fully confident, the games found by searching
+    /// the title are scored by how close their names or alternative names are to it once
+    /// normalized, see MatchOptions and normalize_title
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::title_match::MatchOptions;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for found in igdb.match_title("quake2", &MatchOptions::default()).await.unwrap() {
+    ///         println!("{} ({:.2})", found.game.name, found.confidence);
+    ///     }
+    /// })
+    /// ```
+    pub async fn match_title(
+        &self,
+        title: &str,
+        options: &MatchOptions,
+    ) -> Result<Vec<TitleMatch>, Error> {
+        let by_slug = self.game_by_slug(&title_match::slug(title)).await?;
+
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .search(title)
+            .limit(options.candidates.min(MAX_LIMIT));
+        let candidates = self.games().get(request).await?;
+
+        let alternative_names = match candidates.is_empty() {
+            true => Vec::new(),
+            false => {
+                let mut request = RequestBuilder::new();
+                request
+                    .add_fields(["game", "name"])
+                    .add_where_id_in("game", candidates.iter().map(|game| game.id))
+                    .limit(MAX_LIMIT);
+                self.alternative_names().get(request).await?
+            }
+        };
+
+        Ok(title_match::rank(
+            title,
+            by_slug,
+            candidates,
+            &alternative_names,
+            options,
+        ))
+    }
+
+    /// Returns the game sold on Steam with the given app id, None when IGDB does not know it
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     if let Some(game) = igdb.find_by_steam_appid(292030).await.unwrap() {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn find_by_steam_appid(&self, app_id: u64) -> Result<Option<Game>, Error> {
+        self.external_games()
+            .find_game(ExternalGameCategory::Steam, app_id.to_string())
+            .await
+    }
+
+    /// Returns the game sold on GOG with the given product id, None when IGDB does not know it
+    pub async fn find_by_gog_id(&self, gog_id: u64) -> Result<Option<Game>, Error> {
+        self.external_games()
+            .find_game(ExternalGameCategory::Gog, gog_id.to_string())
+            .await
+    }
+
+    /// Returns the given game along with its parents, DLCs, expansions, remakes,
+    /// remasters, editions and other child games, all retrieved in a single multiquery
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let related = igdb.related_games(1942).await.unwrap();
+    ///
+    ///     for dlc in related.dlcs {
+    ///         println!("{}", dlc.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn related_games<I: Into<GameId>>(&self, game_id: I) -> Result<RelatedGames, Error> {
+        let game_id = game_id.into();
+        let ids = vec![game_id];
+
+        let mut game = RequestBuilder::new();
+        game.all_fields()
+            .add_where("id", Equality::Equal, game_id)
+            .limit(1);
+
+        let mut parents = RequestBuilder::new();
+        parents
+            .all_fields()
+            .add_where("dlcs", Equality::Equal, ids.clone())
+            .add_where_or("expansions", Equality::Equal, ids.clone())
+            .add_where_or("standalone_expansions", Equality::Equal, ids.clone())
+            .add_where_or("remakes", Equality::Equal, ids.clone())
+            .add_where_or("remasters", Equality::Equal, ids)
+            .limit(MAX_LIMIT);
+
+        let mut children = RequestBuilder::new();
+        children
+            .all_fields()
+            .add_where("parent_game", Equality::Equal, game_id)
+            .limit(MAX_LIMIT);
+
+        let mut versions = RequestBuilder::new();
+        versions
+            .all_fields()
+            .add_where("version_parent", Equality::Equal, game_id)
+            .limit(MAX_LIMIT);
+
+        let mut multiquery = MultiQueryBuilder::new();
+        multiquery
+            .add::<GamesClient>("game", &game)
+            .add::<GamesClient>("parents", &parents)
+            .add::<GamesClient>("children", &children)
+            .add::<GamesClient>("versions", &versions);
+
+        let results = self.multiquery(&multiquery).await?;
+        let game = match results.get::<Game>("game")?.into_iter().next() {
+            Some(game) => game,
+            None => return Err(Error::NotFound(format!("No game with id: {}", game_id))),
+        };
+
+        Ok(RelatedGames::new(
+            game,
+            results.get("parents")?,
+            results.get("children")?,
+            results.get("versions")?,
+        ))
+    }
+
+    /// Returns how long players take to beat the given game, None when nobody reported it yet
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     if let Some(time) = igdb.time_to_beat(1942).await.unwrap() {
+    ///         println!("{:?}", time.normally_duration());
+    ///     }
+    /// })
+    /// ```
+    pub async fn time_to_beat<I: Into<GameId>>(
+        &self,
+        game_id: I,
+    ) -> Result<Option<GameTimeToBeat>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .add_where("game_id", Equality::Equal, game_id.into())
+            .limit(1);
+
+        Ok(self
+            .game_time_to_beats()
+            .get(request)
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    /// Returns the games similar to the given one, in the order IGDB ranks them,
+    /// e.g. for a "you might also like" section
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for game in igdb.similar_to(1942, 5).await.unwrap() {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn similar_to<I: Into<GameId>>(
+        &self,
+        game_id: I,
+        limit: usize,
+    ) -> Result<Vec<Game>, Error> {
+        self.games().get_similar(game_id, limit).await
+    }
+
+    /// Returns the games developed and published by the given company, the earliest released
+    /// first, so that a company page can be built with one call.
+    /// The games are requested by id in chunks of at most 500.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let catalog = igdb.company_catalog(70).await.unwrap();
+    ///
+    ///     println!("Published by {}:", catalog.company.name);
+    ///     for game in catalog.published {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn company_catalog<I: Into<CompanyId>>(
+        &self,
+        company_id: I,
+    ) -> Result<CompanyCatalog, Error> {
+        let company = self.companies().get_first_by_id(company_id).await?;
+        let mut ids: Vec<GameId> = company
+            .developed
+            .iter()
+            .chain(&company.published)
+            .map(|id| GameId(*id))
+            .collect();
+        ids.sort();
+        ids.dedup();
+
+        let games = self.games_by_ids(&ids).await?;
+        Ok(CompanyCatalog::new(company, games))
+    }
+
+    /// Returns the names and covers of a game in the regions it is localized for,
+    /// along with its release dates in each region, in a single multiquery
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     for title in igdb.regional_titles(7334).await.unwrap() {
+    ///         let region = title.region.expanded().map(|region| region.name.as_str());
+    ///         println!("{:?}: {}", region, title.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn regional_titles<I: Into<GameId>>(
+        &self,
+        game_id: I,
+    ) -> Result<Vec<RegionalTitle>, Error> {
+        let game_id = game_id.into();
+
+        let mut localizations = RequestBuilder::new();
+        localizations
+            .add_fields(vec!["*", "region.*", "cover.*"])
+            .add_where("game", Equality::Equal, game_id)
+            .limit(MAX_LIMIT);
+
+        let mut release_dates = RequestBuilder::new();
+        release_dates
+            .all_fields()
+            .add_where("game", Equality::Equal, game_id)
+            .limit(MAX_LIMIT);
+
+        let mut multiquery = MultiQueryBuilder::new();
+        multiquery
+            .add::<GameLocalizationsClient>("localizations", &localizations)
+            .add::<ReleaseDatesClient>("release_dates", &release_dates);
+
+        let results = self.multiquery(&multiquery).await?;
+        let release_dates: Vec<ReleaseDate> = results.get("release_dates")?;
+        Ok(RegionalTitle::group(
+            results.get("localizations")?,
+            &release_dates,
+        ))
+    }
+
+    /// Returns a Loader batching the lookups by id of the given endpoint made
+    /// within a few milliseconds of each other, see Loader
+    pub fn loader<E: Endpoint>(&self) -> Loader<E>
+    where
+        E::Model: Identifiable + Clone,
+    {
+        Loader::new(EndpointClient::new(
+            self.auth.clone(),
+            E::PATH,
+            self.config.clone(),
+        ))
+    }
+
+    /// The client sending the requests of the given endpoint with the settings of this one
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn endpoint_client<E: Endpoint>(&self) -> EndpointClient {
+        EndpointClient::new(self.auth.clone(), E::PATH, self.config.clone())
+    }
+
+    /// Downloads the image with the given image_id, e.g. the one of a Cover, at the given size
+    /// from IGDB's image CDN. Failed downloads are retried like the queries.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::media_quality::MediaQuality;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let cover = igdb.image_bytes("co1wyy", MediaQuality::CoverBig).await.unwrap();
+    /// })
+    /// ```
+    pub async fn image_bytes<S: Into<String>>(
+        &self,
+        image_id: S,
+        quality: MediaQuality,
+    ) -> Result<Vec<u8>, Error> {
+        self.fetch_image(&ImageUrl::new(image_id, quality)).await
+    }
+
+    /// Downloads the image with the given image_id like IGDBClient::image_bytes,
+    /// and writes it to the given destination, e.g. a file
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::media_quality::MediaQuality;
+    /// use std::fs::File;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let file = File::create("co1wyy.jpg").unwrap();
+    ///     igdb.download_image("co1wyy", MediaQuality::CoverBig, file).await.unwrap();
+    /// })
+    /// ```
+    pub async fn download_image<S: Into<String>, W: std::io::Write>(
+        &self,
+        image_id: S,
+        quality: MediaQuality,
+        mut dest: W,
+    ) -> Result<(), Error> {
+        let image = self.image_bytes(image_id, quality).await?;
+        dest.write_all(&image)?;
+        Ok(dest.flush()?)
+    }
+
+    /// Downloads the image at the given url, e.g. its retina or webp variant,
+    /// like IGDBClient::image_bytes
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::image_url::ImageUrl;
+    /// use igdb::media_quality::MediaQuality;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let url = ImageUrl::new("co1wyy", MediaQuality::CoverBig).retina().webp();
+    ///     let cover = igdb.fetch_image(&url).await.unwrap();
+    /// })
+    /// ```
+    pub async fn fetch_image(&self, url: &ImageUrl) -> Result<Vec<u8>, Error> {
+        media_helpers::fetch_image(&self.config, url).await
+    }
+
+    /// Returns a client managing the webhooks IGDB calls on registry changes,
+    /// see WebhooksClient
+    pub fn webhooks(&self) -> WebhooksClient {
+        WebhooksClient::new(self.auth.clone(), self.config.clone())
+    }
+
+    /// Sends the smallest valid request to the games endpoint and returns the round trip time.
+    /// It is meant to be used as a cheap liveness probe, e.g. in health checks: the request
+    /// waits for the rate limiter but is always sent, never answered by the cache or an
+    /// identical request in flight, nor hedged.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let latency = igdb.ping().await.unwrap();
+    ///     println!("IGDB answered in {:?}", latency);
+    /// })
+    /// ```
+    pub async fn ping(&self) -> Result<Duration, Error> {
+        let mut request = RequestBuilder::new();
+        request.add_field("id").limit(1);
+
+        let mut probe = self.with_options(&RequestOptions::new().cache(CacheMode::Bypass));
+        probe.config.single_flight = None;
+        probe.config.hedge_after = None;
+
+        let started = Instant::now();
+        probe.games().get(request).await?;
+        Ok(started.elapsed())
+    }
+}
+
+#[test]
+fn ping_always_reaches_igdb() {
+    use crate::mock::MockTransport;
+
+    let mock = MockTransport::new().with_fixture("games", "fields id; limit 1;", r#"[{"id":1}]"#);
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock.clone())
+        .with_cache(Duration::from_secs(600), 10)
+        .with_single_flight()
+        .without_rate_limit();
+
+    async_std::task::block_on(async {
+        igdb.ping().await.unwrap();
+        igdb.ping().await.unwrap();
+    });
+    assert_eq!(
+        vec![("/v4/games".to_owned(), "fields id; limit 1;".to_owned()); 2],
+        mock.requests()
+    );
+}
+
+#[test]
+fn tenants_have_their_own_cached_responses_and_rate_limit() {
+    use crate::mock::ScriptedTransport;
+
+    let scripted = ScriptedTransport::new().then(200, r#"[{"id":5,"name":"Shooter"}]"#);
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(scripted.clone())
+        .with_cache(Duration::from_secs(60), 10);
+    let first = igdb.as_tenant(Credentials::new("first", "abc"));
+    let second = igdb.as_tenant(Credentials::new("second", "def"));
+    let request = IGDBClient::create_request();
+
+    for tenant in [&first, &second, &first] {
+        async_std::task::block_on(tenant.query::<GenresClient>(&request)).unwrap();
+    }
+    let client_ids = scripted.map(|request| request.headers()["Client-ID"].clone());
+    assert_eq!(vec!["first", "second"], client_ids);
+
+    let limiter = |igdb: &IGDBClient| igdb.config.rate_limiter.clone().unwrap();
+    let again = igdb.as_tenant(Credentials::new("first", "ghi"));
+    assert!(Arc::ptr_eq(&limiter(&first), &limiter(&again)));
+    assert!(!Arc::ptr_eq(&limiter(&first), &limiter(&second)));
+    assert!(!Arc::ptr_eq(&limiter(&igdb), &limiter(&first)));
+}
+
+#[test]
+fn query_to_channel_requests_the_next_page_once_the_receiver_caught_up() {
+    use crate::mock::ScriptedTransport;
+    use crate::model::genre::Genre;
+    use futures::channel::mpsc;
+
+    let genres = |count: usize| {
+        let genres: Vec<String> = (1..=count).map(|id| format!("{{\"id\":{}}}", id)).collect();
+        format!("[{}]", genres.join(","))
+    };
+    // Answers a full page of genres to the first request and 3 genres to the second one
+    let pages = ScriptedTransport::new()
+        .then(200, genres(MAX_LIMIT))
+        .then(200, genres(3));
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(pages.clone())
+        .without_rate_limit();
+    let request = IGDBClient::create_request();
+    let (sender, mut receiver) = mpsc::channel::<Vec<Genre>>(0);
+
+    let receive = async {
+        let first = receiver.next().await.unwrap();
+        assert_eq!(100, first.len());
+        assert_eq!(1, pages.sent());
+        let mut received = first.len();
+        while let Some(chunk) = receiver.next().await {
+            received += chunk.len();
+        }
+        received
+    };
+    let (sent, received) = async_std::task::block_on(futures::future::join(
+        igdb.query_to_channel::<GenresClient>(&request, sender, 100),
+        receive,
+    ));
+
+    assert_eq!(503, sent.unwrap());
+    assert_eq!(503, received);
+    assert_eq!(2, pages.sent());
 }
@@ -0,0 +1,477 @@
+use crate::auth::{Authenticator, Credentials, TwitchAuthenticator};
+use crate::budget::{Budget, BudgetTracker};
+use crate::circuit_breaker::CircuitBreaker;
+use crate::client::IGDBClient;
+use crate::endpoint_client::ClientConfig;
+use crate::endpoints::{get_endpoint_url, Endpoint};
+use crate::json_numbers::JsonNumbers;
+use crate::middleware::Middleware;
+use crate::query_lint::LintMode;
+use crate::rate_limiter::Priority;
+use crate::response_cache::{CacheStore, MemoryStore, ResponseCache};
+use crate::schema::SchemaMode;
+use crate::signing::RequestSigner;
+use crate::single_flight::SingleFlight;
+use crate::token_store::TokenStore;
+use crate::transport::HttpTransport;
+#[cfg(all(feature = "vcr", not(target_arch = "wasm32")))]
+use crate::vcr::{VcrMode, VcrTransport};
+use crate::Error;
+use std::sync::Arc;
+use std::time::Duration;
+
+const HEADER_USER_AGENT: &str = "User-Agent";
+
+///Configures an IGDBClient before creating it, see IGDBClient::builder
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use std::time::Duration;
+///
+/// let igdb = IGDBClient::builder()
+///     .credentials("client_id", "token")
+///     .timeout(Duration::from_secs(5))
+///     .total_timeout(Duration::from_secs(30))
+///     .user_agent("my-importer/1.0")
+///     .header("x-gateway-key", "secret")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Default)]
+pub struct ClientBuilder {
+    auth: Option<Authenticator>,
+    config: ClientConfig,
+    transport: Option<Arc<dyn HttpTransport>>,
+    proxy: Option<String>,
+    path_prefix: Option<String>,
+    token_store: Option<Arc<dyn TokenStore>>,
+    #[cfg(all(feature = "vcr", not(target_arch = "wasm32")))]
+    cassette: Option<(std::path::PathBuf, VcrMode)>,
+}
+
+impl ClientBuilder {
+    ///Returns a builder authenticating with the credentials of the environment: IGDB_CLIENT_ID
+    /// along with IGDB_ACCESS_TOKEN, or else IGDB_CLIENT_SECRET to acquire the tokens,
+    /// and sending the requests to IGDB_BASE_URL when set.
+    /// Fails listing the missing variables.
+    /// # Examples
+    /// ```no_run
+    /// use igdb::client_builder::ClientBuilder;
+    /// use std::time::Duration;
+    ///
+    /// let igdb = ClientBuilder::from_env()
+    ///     .unwrap()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn from_env() -> Result<ClientBuilder, Error> {
+        ClientBuilder::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<ClientBuilder, Error> {
+        let var = |name: &str| var(name).filter(|value| !value.trim().is_empty());
+        let builder = match (
+            var("IGDB_CLIENT_ID"),
+            var("IGDB_ACCESS_TOKEN"),
+            var("IGDB_CLIENT_SECRET"),
+        ) {
+            (Some(client_id), Some(token), _) => {
+                ClientBuilder::default().credentials(client_id, token)
+            }
+            (Some(client_id), None, Some(secret)) => {
+                ClientBuilder::default().client_secret(client_id, secret)
+            }
+            (client_id, token, secret) => {
+                let mut missing = Vec::new();
+                if client_id.is_none() {
+                    missing.push("IGDB_CLIENT_ID");
+                }
+                if token.is_none() && secret.is_none() {
+                    missing.push("IGDB_ACCESS_TOKEN or IGDB_CLIENT_SECRET");
+                }
+                return Err(Error::InvalidConfig(format!(
+                    "missing environment variables: {}",
+                    missing.join(", ")
+                )));
+            }
+        };
+        Ok(match var("IGDB_BASE_URL") {
+            Some(base_url) => builder.base_url(base_url),
+            None => builder,
+        })
+    }
+
+    ///Authenticates with the given client_id and app access token
+    pub fn credentials<S: Into<String>>(mut self, client_id: S, token: S) -> Self {
+        self.auth = Some(Authenticator::Static(Credentials::new(client_id, token)));
+        self
+    }
+
+    ///Authenticates with app access tokens acquired and refreshed from the
+    /// client_id and client_secret of a Twitch application
+    pub fn client_secret<S: Into<String>>(mut self, client_id: S, client_secret: S) -> Self {
+        self.auth = Some(Authenticator::Twitch(TwitchAuthenticator::new(
+            client_id,
+            client_secret,
+        )));
+        self
+    }
+
+    ///Reuses the app access tokens of the given store while they are fresh,
+    /// see TwitchAuthenticator::with_token_store. Ignored without a client secret.
+    pub fn token_store<S: TokenStore + 'static>(mut self, store: S) -> Self {
+        self.token_store = Some(Arc::new(store));
+        self
+    }
+
+    ///Sends every request to the given base url instead of https://api.igdb.com/v4,
+    /// e.g. the url of a caching proxy or of a corporate gateway
+    pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
+        self.config.base_url = base_url.into();
+        self
+    }
+
+    ///Inserts the given path segments between the base url and the endpoint paths,
+    /// e.g. `igdb/v4` sends the games queries to `{base_url}/igdb/v4/games`
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::builder()
+    ///     .credentials("client_id", "token")
+    ///     .base_url("https://gateway.example.com")
+    ///     .path_prefix("igdb/v4")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn path_prefix<S: Into<String>>(mut self, path_prefix: S) -> Self {
+        self.path_prefix = Some(path_prefix.into());
+        self
+    }
+
+    ///Fails each attempt of a request not answered within the given duration
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    ///Fails the requests not answered within the given duration, retries included
+    pub fn total_timeout(mut self, timeout: Duration) -> Self {
+        self.config.total_timeout = Some(timeout);
+        self
+    }
+
+    ///Sends the requests through the given HTTP(S) proxy, e.g. `http://localhost:3128`.
+    /// Requires the reqwest transport.
+    #[cfg(all(feature = "backend-reqwest", not(target_arch = "wasm32")))]
+    pub fn proxy<S: Into<String>>(mut self, url: S) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    ///Overrides the User-Agent header of every request
+    pub fn user_agent<S: Into<String>>(self, user_agent: S) -> Self {
+        self.header(HEADER_USER_AGENT, user_agent)
+    }
+
+    ///Adds a header sent with every request, e.g. for API gateways
+    pub fn header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.config.headers.push((name.into(), value.into()));
+        self
+    }
+
+    ///Caches the successful responses, see IGDBClient::with_cache
+    pub fn cache(self, ttl: Duration, max_entries: usize) -> Self {
+        self.cache_store(ttl, MemoryStore::new(max_entries))
+    }
+
+    ///Caches the successful responses in the given store, see IGDBClient::with_cache_store
+    pub fn cache_store<S: CacheStore + 'static>(mut self, ttl: Duration, store: S) -> Self {
+        self.config.cache = Some(Arc::new(ResponseCache::new(ttl, store)));
+        self
+    }
+
+    ///Sends the identical queries in flight only once, see IGDBClient::with_single_flight
+    pub fn single_flight(mut self) -> Self {
+        self.config.single_flight = Some(Arc::new(SingleFlight::default()));
+        self
+    }
+
+    ///Sets the priority of the requests to the given endpoint,
+    /// see IGDBClient::with_endpoint_priority
+    pub fn endpoint_priority<E: Endpoint>(mut self, priority: Priority) -> Self {
+        self.config.endpoint_priorities.push((E::PATH, priority));
+        self
+    }
+
+    ///Fails fast once the given number of requests failed in a row,
+    /// see IGDBClient::with_circuit_breaker
+    pub fn circuit_breaker(mut self, failures: u32, cooldown: Duration) -> Self {
+        self.config.circuit_breaker = Some(Arc::new(CircuitBreaker::new(failures, cooldown)));
+        self
+    }
+
+    ///Fails the requests once they spent the given budget, see IGDBClient::with_budget
+    pub fn budget(mut self, budget: Budget) -> Self {
+        self.config.budget = Some(Arc::new(BudgetTracker::new(budget)));
+        self
+    }
+
+    ///Sends the requests again once not answered after the given time,
+    /// see IGDBClient::with_hedging
+    pub fn hedge_after(mut self, after: Duration) -> Self {
+        self.config.hedge_after = Some(after);
+        self
+    }
+
+    ///Signs every request right before it is sent, see IGDBClient::with_request_signer
+    pub fn request_signer<S: RequestSigner + 'static>(mut self, signer: S) -> Self {
+        self.config.signer = Some(Arc::new(signer));
+        self
+    }
+
+    ///Adds a middleware run around every request, see IGDBClient::with_middleware
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.config.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    ///Sets how the fields of the responses missing from the models are handled,
+    /// see IGDBClient::with_schema_mode
+    pub fn schema_mode(mut self, mode: SchemaMode) -> Self {
+        self.config.schema_mode = mode;
+        self
+    }
+
+    ///Checks the queries before sending them, see IGDBClient::with_lint_mode
+    pub fn lint_mode(mut self, mode: LintMode) -> Self {
+        self.config.lint_mode = mode;
+        self
+    }
+
+    ///Sets how the numbers of the json returned without a model are written,
+    /// see IGDBClient::with_json_numbers
+    pub fn json_numbers(mut self, numbers: JsonNumbers) -> Self {
+        self.config.json_numbers = numbers;
+        self
+    }
+
+    ///Sets the transport sending every request, see the transport module
+    pub fn transport<T: HttpTransport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    ///Records the requests sent by the client and their responses to the cassette file
+    /// at the given path, or replays it, see VcrTransport. Requires the `vcr` feature.
+    #[cfg(all(feature = "vcr", not(target_arch = "wasm32")))]
+    pub fn cassette<P: Into<std::path::PathBuf>>(mut self, path: P, mode: VcrMode) -> Self {
+        self.cassette = Some((path.into(), mode));
+        self
+    }
+
+    ///Creates the client, failing when no credentials were given, when the base url is not
+    /// an http(s) url, when the proxy cannot be used or when the cassette to replay cannot be read
+    pub fn build(mut self) -> Result<IGDBClient, Error> {
+        let auth = match (self.auth, self.token_store) {
+            (Some(Authenticator::Twitch(twitch)), Some(store)) => {
+                Authenticator::Twitch(twitch.with_token_store(store))
+            }
+            (Some(auth), _) => auth,
+            (None, _) => {
+                return Err(Error::InvalidConfig(
+                    "credentials or a client secret are required".to_owned(),
+                ))
+            }
+        };
+        validate_base_url(&self.config.base_url)?;
+        if let Some(path_prefix) = self.path_prefix.as_deref().map(|p| p.trim_matches('/')) {
+            if !path_prefix.is_empty() {
+                self.config.base_url = get_endpoint_url(&self.config.base_url, path_prefix);
+            }
+        }
+        let transport = match (self.transport, self.proxy) {
+            (Some(_), Some(_)) => {
+                return Err(Error::InvalidConfig(
+                    "a proxy cannot be set along a custom transport".to_owned(),
+                ))
+            }
+            (transport, None) => transport,
+            (None, Some(proxy)) => Some(proxy_transport(&proxy)?),
+        };
+        #[cfg(all(feature = "vcr", not(target_arch = "wasm32")))]
+        let transport = match self.cassette {
+            Some((path, mode)) => {
+                let inner = transport.unwrap_or_else(crate::transport::default_transport);
+                let vcr = VcrTransport::new(inner, &path, mode).map_err(|e| {
+                    Error::InvalidConfig(format!("cassette {} unreadable: {}", path.display(), e))
+                })?;
+                Some(Arc::new(vcr) as Arc<dyn HttpTransport>)
+            }
+            None => transport,
+        };
+
+        let mut client = IGDBClient::with_config(auth, self.config);
+        if let Some(transport) = transport {
+            client.set_transport(transport);
+        }
+        Ok(client)
+    }
+}
+
+///Checks the base url is an absolute http(s) url without query string
+fn validate_base_url(base_url: &str) -> Result<(), Error> {
+    let invalid =
+        |cause: &str| Error::InvalidConfig(format!("invalid base url {}: {}", base_url, cause));
+    let uri: http::Uri = base_url.parse().map_err(|_| invalid("not an url"))?;
+    match (uri.scheme_str(), uri.host()) {
+        (Some("http" | "https"), Some(_)) if uri.query().is_none() => Ok(()),
+        (Some("http" | "https"), Some(_)) => Err(invalid("query strings are not supported")),
+        (Some("http" | "https"), None) => Err(invalid("no host")),
+        _ => Err(invalid("not an http(s) url")),
+    }
+}
+
+#[cfg(all(feature = "backend-reqwest", not(target_arch = "wasm32")))]
+fn proxy_transport(url: &str) -> Result<Arc<dyn HttpTransport>, Error> {
+    use crate::transport::ReqwestTransport;
+
+    let invalid = |e: reqwest::Error| Error::InvalidConfig(format!("invalid proxy: {}", e));
+    let client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(url).map_err(invalid)?)
+        .build()
+        .map_err(invalid)?;
+    Ok(Arc::new(ReqwestTransport::from(client)))
+}
+
+#[cfg(not(all(feature = "backend-reqwest", not(target_arch = "wasm32"))))]
+fn proxy_transport(_url: &str) -> Result<Arc<dyn HttpTransport>, Error> {
+    Err(Error::InvalidConfig(
+        "proxies require the reqwest transport".to_owned(),
+    ))
+}
+
+#[test]
+fn client_builder_requires_credentials() {
+    assert!(matches!(
+        IGDBClient::builder().build(),
+        Err(Error::InvalidConfig(_))
+    ));
+    assert!(IGDBClient::builder()
+        .credentials("client_id", "token")
+        .build()
+        .is_ok());
+}
+
+#[cfg(feature = "backend-reqwest")]
+#[test]
+fn client_builder_rejects_invalid_proxies() {
+    assert!(matches!(
+        IGDBClient::builder()
+            .credentials("client_id", "token")
+            .proxy("not a url")
+            .build(),
+        Err(Error::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn client_builder_timeout_fails_slow_requests() {
+    use crate::client::GenresClient;
+    use crate::transport::TransportFuture;
+
+    struct Slow;
+
+    impl HttpTransport for Slow {
+        fn send(&self, _request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            Box::pin(async {
+                async_std::task::sleep(Duration::from_secs(5)).await;
+                Ok(http::Response::new(b"[]".to_vec()))
+            })
+        }
+    }
+
+    let igdb = IGDBClient::builder()
+        .credentials("client_id", "token")
+        .timeout(Duration::from_millis(10))
+        .transport(Slow)
+        .build()
+        .unwrap();
+    let request = IGDBClient::create_request();
+
+    let result = async_std::task::block_on(igdb.query::<GenresClient>(&request));
+    assert!(matches!(result, Err(Error::Http(_))));
+}
+
+#[test]
+fn client_builder_sends_requests_under_the_base_url_and_path_prefix() {
+    use crate::client::GenresClient;
+    use crate::mock::MockTransport;
+
+    let mock = MockTransport::new().with_fixture("genres", "fields name; limit 10;", "[]");
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+    let igdb = IGDBClient::builder()
+        .credentials("client_id", "token")
+        .base_url("http://cache.internal:8080/")
+        .path_prefix("/igdb/v4/")
+        .transport(mock.clone())
+        .build()
+        .unwrap()
+        .without_rate_limit();
+
+    async_std::task::block_on(igdb.query::<GenresClient>(&request)).unwrap();
+    assert_eq!("/igdb/v4/genres", mock.requests()[0].0);
+
+    for base_url in [
+        "cache.internal/v4",
+        "ftp://cache.internal",
+        "http://cache.internal?a=b",
+    ] {
+        assert!(matches!(
+            IGDBClient::builder()
+                .credentials("client_id", "token")
+                .base_url(base_url)
+                .build(),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+}
+
+#[test]
+fn from_env_lists_the_missing_variables() {
+    let vars = |vars: &'static [(&'static str, &'static str)]| {
+        move |name: &str| {
+            vars.iter()
+                .find(|(var, _)| *var == name)
+                .map(|(_, value)| value.to_string())
+        }
+    };
+
+    let missing = |result: Result<ClientBuilder, Error>| match result {
+        Err(e) => e.to_string(),
+        Ok(_) => String::new(),
+    };
+    assert_eq!(
+        "invalid client settings: missing environment variables: IGDB_CLIENT_ID, IGDB_ACCESS_TOKEN or IGDB_CLIENT_SECRET",
+        missing(ClientBuilder::from_vars(vars(&[])))
+    );
+    assert_eq!(
+        "invalid client settings: missing environment variables: IGDB_CLIENT_ID",
+        missing(ClientBuilder::from_vars(vars(&[(
+            "IGDB_CLIENT_SECRET",
+            "secret"
+        )])))
+    );
+
+    let builder = ClientBuilder::from_vars(vars(&[
+        ("IGDB_CLIENT_ID", "client_id"),
+        ("IGDB_ACCESS_TOKEN", "token"),
+        ("IGDB_CLIENT_SECRET", ""),
+        ("IGDB_BASE_URL", "http://localhost:8080/v4"),
+    ]))
+    .unwrap();
+    assert!(matches!(builder.auth, Some(Authenticator::Static(_))));
+    assert_eq!("http://localhost:8080/v4", builder.config.base_url);
+}
@@ -1,33 +1,63 @@
 macro_rules! create_client {
     ($i: ident, $j: ident, $k: ident) => {
         pub struct $i {
-            endpoint_client: EndpointClient,
+            pub(crate) endpoint_client: EndpointClient,
         }
 
         impl $i {
             ///Receives a configured RequestBuilder an returns a result containing a collection
             pub async fn get(&self, request_builder: RequestBuilder) -> Result<Vec<$j>, Error> {
-                self.endpoint_client.get::<$j>(request_builder).await
+                self.endpoint_client.get::<$j>(&request_builder).await
+            }
+            ///Streams every registry matching the request, paging through the results
+            /// 500 registries at a time, see IGDBClient::stream
+            pub fn stream(
+                &self,
+                request_builder: RequestBuilder,
+            ) -> impl futures::Stream<Item = Result<$j, Error>> {
+                self.endpoint_client
+                    .with_endpoint(self.endpoint_client.path)
+                    .stream::<$j>(request_builder)
+            }
+            ///Returns the number of registries matching the search and filters of the request
+            pub async fn count(&self, request_builder: &RequestBuilder) -> Result<u64, Error> {
+                self.endpoint_client.count(request_builder).await
             }
             ///Returns a collection filtered by id and limits the retrieved registries using limit parameter value.
-            pub async fn get_by_id(&self, id: usize, limit: usize) -> Result<Vec<$j>, Error> {
+            pub async fn get_by_id<I: Into<<$j as Identifiable>::Id>>(
+                &self,
+                id: I,
+                limit: usize,
+            ) -> Result<Vec<$j>, Error> {
                 let mut request = RequestBuilder::new();
                 request
                     .all_fields()
-                    .add_where("id", Equality::Equal, id.to_string())
+                    .add_where("id", Equality::Equal, id.into())
                     .limit(limit);
 
                 self.get(request).await
             }
+            ///Returns the elements matching the given ids.
+            /// Ids are requested in chunks of at most 500, see IGDBClient::with_concurrency
+            /// to request several chunks in parallel.
+            pub async fn get_by_ids(
+                &self,
+                ids: &[<$j as Identifiable>::Id],
+            ) -> Result<Vec<$j>, Error> {
+                self.endpoint_client.get_by_ids::<$j>(ids).await
+            }
             /// Returns the element by Id for this client in Option<T> format.
-            pub async fn get_first_by_id(&self, id: usize) -> Result<$j, Error> {
+            pub async fn get_first_by_id<I: Into<<$j as Identifiable>::Id>>(
+                &self,
+                id: I,
+            ) -> Result<$j, Error> {
+                let id = id.into();
                 match self.get_by_id(id, 1).await {
                     Ok(ref d) if !d.is_empty() => Ok(d[0].clone()),
-                    Ok(_) => Err(std::io::Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        format!("Empty response from server for query with id: {}", id),
-                    )
-                    .into()),
+                    Ok(_) => Err(Error::NotFound(format!(
+                        "Empty response from server for query with id: {}",
+                        id
+                    ))),
                     Err(e) => {
                         log::error!("{}", e);
                         Err(e)
@@ -36,14 +66,19 @@ macro_rules! create_client {
             }
         }
 
+        impl Endpoint for $i {
+            type Model = $j;
+            const PATH: &'static str = stringify!($k);
+        }
+
         impl IGDBClient {
             /// Returns a reference to the client
             pub fn $k(&self) -> $i {
                 $i {
                     endpoint_client: EndpointClient::new(
-                        self.client_id.clone(),
-                        self.token.clone(),
-                        Endpoint::$k,
+                        self.auth.clone(),
+                        <$i as Endpoint>::PATH,
+                        self.config.clone(),
                     ),
                 }
             }
@@ -54,21 +89,19 @@ macro_rules! create_client {
 macro_rules! expand_get_by_game_id {
     ($i: ident, $j: ident) => {
         impl $i {
-            ///Receives a game_id and a limit of registries and returns an Option<Vec> of elements
-            pub async fn get_by_game_id(&self, game_id: usize, limit: usize) -> Option<Vec<$j>> {
+            ///Receives a game_id and a limit of registries and returns a result containing a collection
+            pub async fn get_by_game_id<I: Into<GameId>>(
+                &self,
+                game_id: I,
+                limit: usize,
+            ) -> Result<Vec<$j>, Error> {
                 let mut request = RequestBuilder::new();
                 request
                     .all_fields()
-                    .add_where("game", Equality::Equal, game_id.to_string())
+                    .add_where("game", Equality::Equal, game_id.into())
                     .limit(limit);
 
-                match self.get(request).await {
-                    Ok(d) => Some(d),
-                    Err(e) => {
-                        log::error!("{}", e);
-                        None
-                    }
-                }
+                self.get(request).await
             }
         }
     };
@@ -84,16 +117,62 @@ macro_rules! request {
 macro_rules! use_client_imports {
     () => {
         use crate::{
-            endpoint_client::EndpointClient, endpoints::Endpoint, media_quality::MediaQuality,
-            model::age_rating::AgeRating, model::artwork::Artwork, model::character::Character,
-            model::character_mug_shot::CharacterMugshot, model::company::Company,
-            model::cover::Cover, model::engine::Engine, model::external_game::ExternalGame,
-            model::franchise::Franchise, model::game_mode::GameMode, model::game_video::GameVideo,
-            model::games::Game, model::multiplayer_mode::MultiplayerMode,
-            model::platform::Platform, model::platform_logo::PlatformLogo,
-            model::player_perspective::PlayerPerspective, model::release_date::ReleaseDate,
-            model::screenshot::Screenshot, model::theme::Theme, model::website::Website,
-            request_builder::Equality, request_builder::RequestBuilder,
+            auth::{Authenticator, Credentials, TwitchAuthenticator},
+            endpoint_client::{ClientConfig, EndpointClient},
+            endpoints::Endpoint,
+            media_quality::MediaQuality,
+            model::age_rating::AgeRating,
+            model::age_rating_content_description::AgeRatingContentDescription,
+            model::alternative_name::AlternativeName,
+            model::artwork::Artwork,
+            model::character::Character,
+            model::character_mug_shot::CharacterMugshot,
+            model::collection::Collection,
+            model::company::Company,
+            model::company_catalog::CompanyCatalog,
+            model::cover::Cover,
+            model::enums::ExternalGameCategory,
+            model::event::Event,
+            model::event_logo::EventLogo,
+            model::event_network::EventNetwork,
+            model::external_game::ExternalGame,
+            model::franchise::Franchise,
+            model::game_engine::GameEngine,
+            model::game_engine_logo::GameEngineLogo,
+            model::game_localization::GameLocalization,
+            model::game_mode::GameMode,
+            model::game_time_to_beat::GameTimeToBeat,
+            model::game_version::GameVersion,
+            model::game_version_feature::GameVersionFeature,
+            model::game_version_feature_value::GameVersionFeatureValue,
+            model::game_video::GameVideo,
+            model::games::Game,
+            model::genre::Genre,
+            model::id_or::Identifiable,
+            model::ids::*,
+            model::involved_company::{CompanyRole, InvolvedCompany},
+            model::keyword::Keyword,
+            model::language::Language,
+            model::language_support::LanguageSupport,
+            model::multiplayer_mode::MultiplayerMode,
+            model::network_type::NetworkType,
+            model::platform::Platform,
+            model::platform_logo::PlatformLogo,
+            model::platform_version::PlatformVersion,
+            model::player_perspective::PlayerPerspective,
+            model::popularity_primitive::PopularityPrimitive,
+            model::popularity_type::PopularityType,
+            model::region::Region,
+            model::regional_title::RegionalTitle,
+            model::related_games::RelatedGames,
+            model::release_date::ReleaseDate,
+            model::screenshot::Screenshot,
+            model::theme::Theme,
+            model::website::Website,
+            request_builder::Equality,
+            request_builder::HttpMethod,
+            request_builder::OrderBy,
+            request_builder::RequestBuilder,
         };
 
         use crate::Error;
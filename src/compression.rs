@@ -0,0 +1,137 @@
+#[cfg(feature = "gzip")]
+use flate2::read::{GzDecoder, ZlibDecoder};
+#[cfg(feature = "gzip")]
+use flate2::{write::GzEncoder, Compression};
+#[cfg(feature = "gzip")]
+use std::io::Read;
+#[cfg(feature = "gzip")]
+use std::io::Write;
+
+pub(crate) const HEADER_CONTENT_ENCODING: &str = "content-encoding";
+pub(crate) const GZIP_ENCODING: &str = "gzip";
+#[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+pub(crate) const HEADER_ACCEPT_ENCODING: &str = "accept-encoding";
+///Response encodings decoded by decode_response
+#[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+pub(crate) const ACCEPTED_ENCODINGS: &str = "gzip, deflate";
+
+///Encodes the request body, gzipping it when the gzip feature is enabled
+/// and the body is longer than the given threshold.
+/// Returns the encoded body and whether it was compressed.
+#[cfg_attr(not(feature = "gzip"), allow(unused_variables))]
+pub(crate) fn encode_body(body: String, compress_above: Option<usize>) -> (Vec<u8>, bool) {
+    #[cfg(feature = "gzip")]
+    if compress_above.is_some_and(|threshold| body.len() > threshold) {
+        return (gzip(body.as_bytes()), true);
+    }
+
+    (body.into_bytes(), false)
+}
+
+#[cfg(feature = "gzip")]
+fn gzip(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(bytes)
+        .and_then(|_| encoder.finish())
+        .expect("writing to an in-memory buffer cannot fail")
+}
+
+///Decompresses the body of a gzip or deflate encoded response,
+/// other responses are returned as is
+#[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+pub(crate) fn decode_response(
+    response: http::Response<Vec<u8>>,
+) -> std::io::Result<http::Response<Vec<u8>>> {
+    let (mut parts, body) = response.into_parts();
+    let encoding = parts
+        .headers
+        .get(HEADER_CONTENT_ENCODING)
+        .and_then(|encoding| encoding.to_str().ok())
+        .map(|encoding| encoding.trim().to_ascii_lowercase());
+    if !matches!(encoding.as_deref(), Some("gzip") | Some("deflate")) {
+        return Ok(http::Response::from_parts(parts, body));
+    }
+
+    let decoded = decode_body(encoding.as_deref(), body)?;
+    parts.headers.remove(HEADER_CONTENT_ENCODING);
+    parts.headers.remove(http::header::CONTENT_LENGTH);
+    Ok(http::Response::from_parts(parts, decoded))
+}
+
+///Decompresses a body of the given lowercase gzip or deflate content encoding,
+/// other bodies are returned as is
+#[cfg_attr(not(feature = "gzip"), allow(unused_variables))]
+pub(crate) fn decode_body(encoding: Option<&str>, body: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    #[cfg(feature = "gzip")]
+    {
+        let mut decoded = Vec::new();
+        match encoding {
+            Some("gzip") => GzDecoder::new(&body[..]).read_to_end(&mut decoded)?,
+            // HTTP deflate is zlib wrapped
+            Some("deflate") => ZlibDecoder::new(&body[..]).read_to_end(&mut decoded)?,
+            _ => return Ok(body),
+        };
+        Ok(decoded)
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    Ok(body)
+}
+
+#[test]
+fn encode_body_without_threshold_keeps_body() {
+    let (body, compressed) = encode_body("fields *; limit 10;".into(), None);
+
+    assert!(!compressed);
+    assert_eq!(b"fields *; limit 10;".to_vec(), body);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn encode_body_gzips_bodies_above_threshold() {
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    let query = "fields *; where id = (1,2,3,4,5,6,7,8,9); limit 10;";
+
+    let (body, compressed) = encode_body(query.into(), Some(query.len()));
+    assert!(!compressed);
+    assert_eq!(query.as_bytes(), &body[..]);
+
+    let (body, compressed) = encode_body(query.into(), Some(10));
+    assert!(compressed);
+
+    let mut decoded = String::new();
+    GzDecoder::new(&body[..])
+        .read_to_string(&mut decoded)
+        .unwrap();
+    assert_eq!(query, decoded);
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn decode_response_decompresses_gzip_and_deflate_bodies() {
+    use flate2::write::ZlibEncoder;
+
+    let body = br#"[{"id":1942,"name":"The Witcher 3"}]"#;
+    let encoded = |encoding: &str, bytes: Vec<u8>| {
+        http::Response::builder()
+            .header(HEADER_CONTENT_ENCODING, encoding)
+            .body(bytes)
+            .unwrap()
+    };
+
+    let response = decode_response(encoded("gzip", gzip(body))).unwrap();
+    assert_eq!(&body[..], &response.body()[..]);
+    assert!(response.headers().get(HEADER_CONTENT_ENCODING).is_none());
+
+    let mut zlib = ZlibEncoder::new(Vec::new(), Compression::default());
+    zlib.write_all(body).unwrap();
+    let response = decode_response(encoded("deflate", zlib.finish().unwrap())).unwrap();
+    assert_eq!(&body[..], &response.body()[..]);
+
+    let response = decode_response(http::Response::new(body.to_vec())).unwrap();
+    assert_eq!(&body[..], &response.body()[..]);
+    assert!(decode_response(encoded("gzip", body.to_vec())).is_err());
+}
@@ -0,0 +1,161 @@
+//! Pagination by id, see IGDBClient::cursor_pager.
+//!
+//! IGDB answers deep offsets slower and slower, and registries added or removed while paging
+//! shift the following pages. Sorting by id and requesting the registries following the last
+//! id seen keeps every page as fast as the first one, without skipping or repeating registries.
+
+use crate::endpoint_client::EndpointClient;
+use crate::model::id_or::Identifiable;
+use crate::model::ids::EntityId;
+use crate::request_builder::{Equality, OrderBy, RequestBuilder};
+use crate::Error;
+use futures::{stream, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+///Pages through the registries matching a request by ascending id, see IGDBClient::cursor_pager
+pub struct CursorPager<T> {
+    client: EndpointClient,
+    request: RequestBuilder,
+    last_id: u64,
+    exhausted: bool,
+    model: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned + Identifiable> CursorPager<T> {
+    pub(crate) fn new(client: EndpointClient, request: RequestBuilder) -> CursorPager<T> {
+        CursorPager {
+            client,
+            request,
+            last_id: 0,
+            exhausted: false,
+            model: PhantomData,
+        }
+    }
+
+    ///Starts after the given id, e.g. the last_id of an interrupted pager
+    pub fn after(mut self, last_id: u64) -> Self {
+        self.last_id = last_id;
+        self
+    }
+
+    ///Id of the last registry returned, 0 before the first page
+    pub fn last_id(&self) -> u64 {
+        self.last_id
+    }
+
+    ///Whether every registry was returned
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    ///Requests the registries following the last page, None once they were all returned
+    pub async fn next_page(&mut self) -> Result<Option<Vec<T>>, Error> {
+        if self.exhausted {
+            return Ok(None);
+        }
+
+        let page = self
+            .client
+            .get::<T>(&page_request(&self.request, self.last_id))
+            .await?;
+        self.exhausted = page.len() < self.request.limit;
+        match page.last() {
+            Some(last) => {
+                self.last_id = last.id().value();
+                Ok(Some(page))
+            }
+            None => Ok(None),
+        }
+    }
+
+    ///Streams the registries of the following pages
+    pub fn into_stream(self) -> impl Stream<Item = Result<T, Error>> {
+        stream::try_unfold(self, |mut pager| async move {
+            Ok::<_, Error>(
+                pager
+                    .next_page()
+                    .await?
+                    .map(|page| (stream::iter(page.into_iter().map(Ok)), pager)),
+            )
+        })
+        .try_flatten()
+    }
+}
+
+///The request filters restricted to the registries following last_id, sorted by id.
+/// The id is requested along the fields, the next page being requested after it.
+fn page_request(request: &RequestBuilder, last_id: u64) -> RequestBuilder {
+    let mut page = RequestBuilder::new();
+    page.add_where("id", Equality::Greater, last_id)
+        .merge(request);
+    if !page.fields.is_empty() && !page.fields.iter().any(|f| f == "id" || f == "*") {
+        page.add_field("id");
+    }
+    page.sort.clear();
+    page.sort_by("id", OrderBy::Ascending).offset(0);
+    page
+}
+
+#[test]
+fn page_requests_follow_the_last_id() {
+    let mut request = RequestBuilder::new();
+    request
+        .add_field("name")
+        .add_where("rating", Equality::Greater, 80)
+        .add_where_or("rating_count", Equality::Greater, 1000)
+        .sort_by("rating", OrderBy::Descending)
+        .limit(50)
+        .offset(100);
+
+    assert_eq!(
+        "fields name,id; where id > 1942 & (rating > 80 | rating_count > 1000); sort id asc; limit 50;",
+        page_request(&request, 1942).build_body()
+    );
+}
+
+#[test]
+fn cursor_pager_pages_until_a_page_is_not_full() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::MockTransport;
+    use crate::model::genre::Genre;
+
+    let mock = MockTransport::new()
+        .with_fixture(
+            "genres",
+            "fields name,id; where id > 0; sort id asc; limit 2;",
+            r#"[{"id": 2, "name": "Point-and-click"}, {"id": 4, "name": "Fighting"}]"#,
+        )
+        .with_fixture(
+            "genres",
+            "fields name,id; where id > 4; sort id asc; limit 2;",
+            r#"[{"id": 5, "name": "Shooter"}]"#,
+        );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock.clone())
+        .without_rate_limit();
+    let mut request = IGDBClient::create_request();
+    request.add_field("name").limit(2);
+
+    let mut pager = igdb.cursor_pager::<GenresClient>(&request);
+    let pages = async_std::task::block_on(async {
+        let mut pages = Vec::new();
+        while let Some(page) = pager.next_page().await.unwrap() {
+            pages.push(page.len());
+        }
+        pages
+    });
+    assert_eq!(vec![2, 1], pages);
+    assert_eq!(5, pager.last_id());
+    assert!(pager.is_exhausted());
+
+    let genres: Vec<Genre> = async_std::task::block_on(
+        igdb.cursor_pager::<GenresClient>(&request)
+            .after(4)
+            .into_stream()
+            .try_collect(),
+    )
+    .unwrap();
+    assert_eq!("Shooter", genres[0].name);
+    assert_eq!(3, mock.requests().len());
+}
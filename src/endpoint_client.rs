@@ -1,40 +1,714 @@
-use crate::endpoints::{get_endpoint_url, Endpoint};
-use crate::request_builder::RequestBuilder;
+use crate::auth::Authenticator;
+use crate::budget::BudgetTracker;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::endpoints::{get_endpoint_url, BASE_URL};
+use crate::error::{ensure_success, from_json_slice};
+use crate::json_numbers::JsonNumbers;
+use crate::limits::LimitHeaders;
+use crate::middleware::{self, BuiltIn, Middleware};
+use crate::model::id_or::Identifiable;
+use crate::name_cache::NameCache;
+use crate::query_lint::{self, LintMode};
+use crate::rate_limiter::{Priority, RateLimiter, TenantLimiters, DEFAULT_REQUESTS_PER_SECOND};
+use crate::request_builder::{
+    build_form_request, build_request, HttpMethod, RequestBuilder, ResponseFormat,
+};
+use crate::request_options::CacheMode;
+use crate::response::{Lenient, Response};
+use crate::response_cache::{CacheKey, Lookup, ResponseCache};
+use crate::retry::RetryPolicy;
+use crate::schema::{from_json_slice_checked, SchemaMode, UnknownFields};
+use crate::signing::{self, RequestSigner};
+use crate::single_flight::{Role, SingleFlight};
+use crate::transport::{default_transport, HttpTransport, TransportError, TransportFuture};
 use crate::Error;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use http::StatusCode;
 use serde::de::DeserializeOwned;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::Duration;
+
+///Maximum number of registries IGDB returns for a single request
+pub(crate) const MAX_LIMIT: usize = 500;
+
+#[derive(Clone)]
+///Settings shared by every endpoint client created from the same IGDBClient
+pub(crate) struct ClientConfig {
+    ///Sends every request, sharing its connection pool
+    pub(crate) transport: Arc<dyn HttpTransport>,
+    pub(crate) base_url: String,
+    pub(crate) method: HttpMethod,
+    pub(crate) format: ResponseFormat,
+    ///Applied to each attempt of a request
+    pub(crate) timeout: Option<Duration>,
+    ///Applied to a request and all its retries
+    pub(crate) total_timeout: Option<Duration>,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) compress_above: Option<usize>,
+    pub(crate) concurrency: usize,
+    ///Shared by every request, None when the rate is limited elsewhere (e.g. by a proxy)
+    pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+    ///Client_id of the tenant the requests are sent for, None for the client's own credentials
+    pub(crate) tenant: Option<String>,
+    ///Rate limiters of the tenants, shared by every client derived from the same one
+    pub(crate) tenant_limiters: Arc<TenantLimiters>,
+    ///Priority of every request, overriding the ones of the endpoints
+    pub(crate) priority: Option<Priority>,
+    ///Priorities of the requests to the given endpoints, Interactive for the other ones
+    pub(crate) endpoint_priorities: Vec<(&'static str, Priority)>,
+    pub(crate) retry: RetryPolicy,
+    ///Shared by every endpoint client, None when responses are not cached
+    pub(crate) cache: Option<Arc<ResponseCache>>,
+    pub(crate) cache_mode: CacheMode,
+    ///Run around every request, in order, see IGDBClient::with_middleware
+    pub(crate) middlewares: Vec<Arc<dyn Middleware>>,
+    ///Names of the small endpoints registries, shared by every endpoint client
+    pub(crate) names: Arc<NameCache>,
+    pub(crate) schema_mode: SchemaMode,
+    pub(crate) lint_mode: LintMode,
+    ///Fields of the responses missing from the models, shared by every endpoint client
+    pub(crate) unknown_fields: Arc<UnknownFields>,
+    ///Identical queries in flight, None when they are all sent
+    pub(crate) single_flight: Option<Arc<SingleFlight>>,
+    ///Shared by every request, None when they are sent whatever the previous failures
+    pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
+    ///Signs each attempt right before it is sent, see IGDBClient::with_request_signer
+    pub(crate) signer: Option<Arc<dyn RequestSigner>>,
+    ///Spent by every request, None when they are not limited
+    pub(crate) budget: Option<Arc<BudgetTracker>>,
+    ///How the numbers of the json returned without a model are written
+    pub(crate) json_numbers: JsonNumbers,
+    ///Whether the responses are returned as json without a model, see EndpointClient::raw
+    pub(crate) raw_json: bool,
+    ///Rate limit headers of the last response, shared by every endpoint client
+    pub(crate) limit_headers: Arc<LimitHeaders>,
+    ///Time after which an attempt is sent again, None when requests are not hedged
+    pub(crate) hedge_after: Option<Duration>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            transport: default_transport(),
+            base_url: BASE_URL.to_owned(),
+            method: HttpMethod::default(),
+            format: ResponseFormat::default(),
+            timeout: None,
+            total_timeout: None,
+            headers: Vec::new(),
+            compress_above: None,
+            concurrency: 1,
+            rate_limiter: Some(Arc::new(RateLimiter::new(DEFAULT_REQUESTS_PER_SECOND))),
+            tenant: None,
+            tenant_limiters: Arc::new(TenantLimiters::default()),
+            priority: None,
+            endpoint_priorities: Vec::new(),
+            retry: RetryPolicy::default(),
+            cache: None,
+            cache_mode: CacheMode::default(),
+            middlewares: Vec::new(),
+            names: Arc::new(NameCache::default()),
+            schema_mode: SchemaMode::default(),
+            lint_mode: LintMode::default(),
+            unknown_fields: Arc::new(UnknownFields::default()),
+            single_flight: None,
+            circuit_breaker: None,
+            signer: None,
+            budget: None,
+            json_numbers: JsonNumbers::default(),
+            raw_json: false,
+            limit_headers: Arc::new(LimitHeaders::default()),
+            hedge_after: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    ///Sends the request through the middlewares, then retries it and waits for the rate limit
+    /// as configured
+    pub(crate) async fn execute(
+        &self,
+        request: http::Request<Vec<u8>>,
+    ) -> Result<http::Response<Vec<u8>>, TransportError> {
+        middleware::run(self, &Attempt(self), request, BuiltIn::All).await
+    }
+
+    ///Sends the request through the middlewares only, for the media served by IGDB's CDN
+    /// which is not rate limited, retrying it when asked
+    pub(crate) async fn fetch(
+        &self,
+        request: http::Request<Vec<u8>>,
+        retried: bool,
+    ) -> Result<http::Response<Vec<u8>>, TransportError> {
+        let built_in = match retried {
+            true => BuiltIn::Retries,
+            false => BuiltIn::None,
+        };
+        middleware::run(self, &Attempt(self), request, built_in).await
+    }
+}
+
+///Sends a single attempt with the configured transport, failing once the timeout elapsed
+struct Attempt<'a>(&'a ClientConfig);
+
+impl HttpTransport for Attempt<'_> {
+    fn send(&self, mut request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            if let Some(signer) = &self.0.signer {
+                signing::sign(signer.as_ref(), &mut request)?;
+            }
+            let sent = request.body().len();
+            let response = self.0.transport.send(request);
+            let response = match self.0.timeout {
+                Some(timeout) => async_std::future::timeout(timeout, response).await,
+                None => Ok(response.await),
+            };
+            if let Some(budget) = &self.0.budget {
+                let received = match &response {
+                    Ok(Ok(response)) => response.body().len(),
+                    _ => 0,
+                };
+                budget.record(sent + received);
+            }
+            let response = response??;
+            self.0.limit_headers.record(response.headers());
+
+            // Browsers decompress the responses on their own
+            #[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+            let response = crate::compression::decode_response(response)?;
+
+            Ok(response)
+        })
+    }
+}
+
+///Response of the count endpoints
+#[derive(Deserialize)]
+struct Count {
+    count: u64,
+}
 
 pub(crate) struct EndpointClient {
-    pub(crate) client_id: String,
-    pub(crate) token: String,
-    pub(crate) endpoint: Endpoint,
+    pub(crate) auth: Arc<Authenticator>,
+    pub(crate) path: &'static str,
+    pub(crate) config: ClientConfig,
 }
 
 impl EndpointClient {
-    pub(crate) fn new(client_id: String, token: String, endpoint: Endpoint) -> EndpointClient {
-        EndpointClient {
-            client_id,
-            token,
-            endpoint,
-        }
+    pub(crate) fn new(
+        auth: Arc<Authenticator>,
+        path: &'static str,
+        config: ClientConfig,
+    ) -> EndpointClient {
+        EndpointClient { auth, path, config }
+    }
+
+    ///Returns this client, returning json without a model written as set with json_numbers
+    pub(crate) fn raw(mut self) -> EndpointClient {
+        self.config.raw_json = true;
+        self
+    }
+
+    ///Returns a client sharing this client configuration for another endpoint
+    pub(crate) fn with_endpoint(&self, path: &'static str) -> EndpointClient {
+        EndpointClient::new(self.auth.clone(), path, self.config.clone())
     }
 
     pub(crate) async fn get<T: DeserializeOwned>(
         &self,
-        request_builder: RequestBuilder,
+        request_builder: &RequestBuilder,
     ) -> Result<Vec<T>, Error> {
-        let request = request_builder.build(
-            &self.client_id,
-            &self.token,
-            &get_endpoint_url(&self.endpoint),
+        let request = self.resolve_names(request_builder).await?;
+        self.post::<Vec<T>>(self.path, request.build_body_for::<T>()?)
+            .await
+    }
+
+    ///Returns the request filtering on the ids its genre or theme names resolved to,
+    /// see RequestBuilder::with_genre_names
+    pub(crate) async fn resolve_names<'a>(
+        &self,
+        request_builder: &'a RequestBuilder,
+    ) -> Result<Cow<'a, RequestBuilder>, Error> {
+        if request_builder.names.is_empty() {
+            return Ok(Cow::Borrowed(request_builder));
+        }
+
+        let mut ids = Vec::new();
+        for filter in &request_builder.names {
+            let client = self.with_endpoint(filter.endpoint);
+            ids.push(self.config.names.ids(&client, &filter.names).await?);
+        }
+        Ok(Cow::Owned(request_builder.with_resolved_names(ids)))
+    }
+
+    ///Returns the number of registries matching the search and filters of the request
+    pub(crate) async fn count(&self, request_builder: &RequestBuilder) -> Result<u64, Error> {
+        let path = format!("{}/count", self.path);
+        let request = self.resolve_names(request_builder).await?;
+        let count = self
+            .post::<Count>(&path, request.build_count_body())
+            .await?;
+        Ok(count.count)
+    }
+
+    pub(crate) async fn post<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: String,
+    ) -> Result<T, Error> {
+        let (body, format) = self.post_bytes(path, body).await?;
+        self.deserialize(path, &body, format)
+    }
+
+    ///Reads the response of the given endpoint, checking its fields are known to T
+    /// unless the schema mode ignores them
+    fn deserialize<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &[u8],
+        format: BodyFormat,
+    ) -> Result<T, Error> {
+        let numbers = self.config.json_numbers;
+        if self.config.raw_json && !numbers.is_as_read() && matches!(format, BodyFormat::Json) {
+            let mut json = from_json_slice::<serde_json::Value>(body)?;
+            numbers.apply(&mut json);
+            return Ok(T::deserialize(json)?);
+        }
+        match (format, self.config.schema_mode) {
+            (_, SchemaMode::Ignore) => format.deserialize(body),
+            (BodyFormat::Json, mode) => {
+                from_json_slice_checked(body, path, mode, &self.config.unknown_fields)
+            }
+            // Only the fields of the models are decoded from protobuf
+            #[cfg(feature = "protobuf")]
+            (BodyFormat::Protobuf(_), _) => format.deserialize(body),
+        }
+        .map_err(|e| e.at_endpoint(path))
+    }
+
+    ///Returns the body of the successful response to the given query, from the cache
+    /// or the identical query in flight when possible, along with the format to read it with
+    pub(crate) async fn post_bytes(
+        &self,
+        path: &str,
+        body: String,
+    ) -> Result<(Vec<u8>, BodyFormat), Error> {
+        query_lint::check(self.config.lint_mode, path, &body)?;
+        let (format, url) = self.url(path);
+        let cache = match self.config.cache_mode {
+            CacheMode::Bypass => None,
+            _ => self.config.cache.as_ref(),
+        };
+        let key = CacheKey::new(path, &url, &body);
+        let key = match self.config.tenant.as_deref() {
+            Some(tenant) => key.for_tenant(tenant),
+            None => key,
+        };
+        let lookup = match self.config.cache_mode {
+            CacheMode::Use => cache.map(|cache| cache.lookup(&key)),
+            _ => None,
+        };
+        let stale = match lookup {
+            Some(Lookup::Fresh(cached)) => return Ok((cached, format)),
+            Some(Lookup::Stale(stale)) => Some(stale),
+            _ => None,
+        };
+
+        let flight = loop {
+            match self.config.single_flight.as_ref() {
+                Some(flights) => match flights.join(self.config.tenant.as_deref(), &url, &body) {
+                    Role::Leader(flight) => break Some(flight),
+                    Role::Follower(response) => {
+                        // Joined again when the identical query failed
+                        if let Ok(response) = response.await {
+                            return Ok((response, format));
+                        }
+                    }
+                },
+                None => break None,
+            }
+        };
+
+        let land = |body: Vec<u8>| match flight {
+            Some(flight) => flight.land(body),
+            None => body,
+        };
+
+        let etag = stale.as_ref().and_then(|stale| stale.etag.as_deref());
+        let (response, format) = self.send_with_retries(path, &body, etag).await?;
+        if let (Some(cache), Some(stale)) = (cache, stale) {
+            if response.status() == StatusCode::NOT_MODIFIED {
+                return Ok((land(cache.revalidated(&key, stale)), format));
+            }
+            cache.record(false);
+        }
+        let response = ensure_success(response)?;
+
+        if let Some(cache) = cache {
+            cache.insert(&key, &response);
+        }
+        Ok((land(response.into_body()), format))
+    }
+
+    ///Retrieves the registries matching the request, setting aside the ones which cannot be
+    /// read into T instead of failing
+    pub(crate) async fn get_lenient<T: DeserializeOwned>(
+        &self,
+        request_builder: &RequestBuilder,
+    ) -> Result<Lenient<T>, Error> {
+        let request = self.resolve_names(request_builder).await?;
+        let registries = self
+            .post::<Vec<serde_json::Value>>(self.path, request.build_body_for::<T>()?)
+            .await?;
+
+        Ok(Lenient::read(self.path, &registries))
+    }
+
+    ///Retrieves the registries matching the request along with the response status and headers
+    pub(crate) async fn get_with_meta<T: DeserializeOwned>(
+        &self,
+        request_builder: &RequestBuilder,
+    ) -> Result<Response<T>, Error> {
+        let request = self.resolve_names(request_builder).await?;
+        let (response, format) = self
+            .send_with_retries(self.path, &request.build_body_for::<T>()?, None)
+            .await?;
+        let (parts, body) = ensure_success(response)?.into_parts();
+
+        Ok(Response {
+            items: self.deserialize(self.path, &body, format)?,
+            status: parts.status,
+            headers: parts.headers,
+            body,
+        })
+    }
+
+    ///Sends a request outside of the query language to the given path, e.g. to manage
+    /// webhooks, and returns its response once successful
+    pub(crate) async fn call(
+        &self,
+        method: http::Method,
+        path: &str,
+        form: Option<String>,
+    ) -> Result<http::Response<Vec<u8>>, Error> {
+        let url = get_endpoint_url(&self.config.base_url, path);
+        let credentials = self.auth.credentials().await?;
+        let request = build_form_request(method, &url, form, &credentials, &self.config)?;
+        ensure_success(self.config.execute(request).await?)
+    }
+
+    async fn send_with_retries(
+        &self,
+        path: &str,
+        body: &str,
+        etag: Option<&str>,
+    ) -> Result<(http::Response<Vec<u8>>, BodyFormat), Error> {
+        let response = async {
+            match self.config.total_timeout {
+                Some(timeout) => {
+                    async_std::future::timeout(timeout, self.send_authorized(path, body, etag))
+                        .await
+                        .map_err(|e| Error::Http(e.into()))?
+                }
+                None => self.send_authorized(path, body, etag).await,
+            }
+        };
+
+        #[cfg(feature = "tracing")]
+        let response = tracing::Instrument::instrument(
+            response,
+            tracing::debug_span!("igdb_request", endpoint = path),
         );
-        let response = request.await;
 
-        match response {
-            Ok(resp) => Ok(resp.json::<Vec<T>>().await?),
+        if let Some(budget) = &self.config.budget {
+            budget.check()?;
+        }
+        match self.config.circuit_breaker.as_ref() {
+            Some(breaker) => {
+                breaker.check()?;
+                let response = response.await;
+                breaker.record(&response);
+                response
+            }
+            None => response.await,
+        }
+    }
+
+    ///Sends the request, renewing the credentials once when they were rejected,
+    /// and returns its last response whatever its status
+    async fn send_authorized(
+        &self,
+        path: &str,
+        body: &str,
+        etag: Option<&str>,
+    ) -> Result<(http::Response<Vec<u8>>, BodyFormat), Error> {
+        let (format, url) = self.url(path);
+        let mut response = self.send(&url, body, etag).await?;
+
+        // The token may have been revoked before its expiry, renew it once
+        if response.status() == StatusCode::UNAUTHORIZED && self.auth.invalidate().await {
+            response = self.send(&url, body, etag).await?;
+        }
+        Ok((response, format))
+    }
+
+    ///Priority of the requests of this client with the rate limiter
+    fn priority(&self) -> Priority {
+        self.config.priority.unwrap_or_else(|| {
+            let priorities = self.config.endpoint_priorities.iter();
+            let mut priorities = priorities.filter(|(path, _)| *path == self.path);
+            priorities.next_back().map(|(_, p)| *p).unwrap_or_default()
+        })
+    }
+
+    ///Returns the format of the responses of the given path and the url to request them from
+    fn url(&self, path: &str) -> (BodyFormat, String) {
+        let format = self.body_format(path);
+        (
+            format,
+            format.url(get_endpoint_url(&self.config.base_url, path)),
+        )
+    }
+
+    ///Protobuf responses are only requested from the endpoints having a protobuf schema
+    #[cfg_attr(not(feature = "protobuf"), allow(unused_variables))]
+    fn body_format(&self, path: &str) -> BodyFormat {
+        match self.config.format {
+            ResponseFormat::Json => BodyFormat::Json,
+            #[cfg(feature = "protobuf")]
+            ResponseFormat::Protobuf => match crate::protobuf::response_message(path) {
+                Some(message) => BodyFormat::Protobuf(message),
+                None => BodyFormat::Json,
+            },
+        }
+    }
+
+    ///Sends the request through the middlewares, revalidating the cached response
+    /// having the given etag
+    async fn send(
+        &self,
+        url: &str,
+        body: &str,
+        etag: Option<&str>,
+    ) -> Result<http::Response<Vec<u8>>, Error> {
+        let credentials = self.auth.credentials().await?;
+        let mut request = build_request(body.to_owned(), &credentials, url, &self.config)?;
+        if let Some(etag) = etag {
+            let etag = http::HeaderValue::from_str(etag).map_err(|e| Error::Http(e.into()))?;
+            request
+                .headers_mut()
+                .insert(http::header::IF_NONE_MATCH, etag);
+        }
+
+        request.extensions_mut().insert(self.priority());
+
+        #[cfg(feature = "tracing")]
+        let started = crate::time::Instant::now();
+
+        match self.config.execute(request).await {
+            Ok(resp) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    url,
+                    body,
+                    status = resp.status().as_u16(),
+                    latency = ?started.elapsed(),
+                    "IGDB request sent"
+                );
+                Ok(resp)
+            }
             Err(e) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(url, body, error = %e, "IGDB request failed");
                 log::error!("{}", e);
-                Err(Box::new(e))
+                Err(e.into())
             }
         }
     }
+
+    ///Retrieves up to the request limit registries even when it exceeds MAX_LIMIT,
+    /// by sending one request per page of MAX_LIMIT registries.
+    /// Up to `config.concurrency` pages are requested in parallel and the results keep the pages order.
+    pub(crate) async fn get_all<T: DeserializeOwned>(
+        &self,
+        request_builder: &RequestBuilder,
+    ) -> Result<Vec<T>, Error> {
+        let requests = split_in_pages(request_builder)
+            .into_iter()
+            .map(|request| async move { self.get::<T>(&request).await });
+
+        let pages: Vec<Vec<T>> = stream::iter(requests)
+            .buffered(self.config.concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        Ok(pages.into_iter().flatten().collect())
+    }
+
+    ///Streams every registry matching the request, starting at its offset and
+    /// paging through the results in requests of MAX_LIMIT registries until exhausted.
+    pub(crate) fn stream<T: DeserializeOwned>(
+        self,
+        request_builder: RequestBuilder,
+    ) -> impl Stream<Item = Result<T, Error>> {
+        let offset = Some(request_builder.offset);
+
+        stream::try_unfold(
+            (self, request_builder, offset),
+            |(client, mut request, offset)| async move {
+                let offset = match offset {
+                    Some(offset) => offset,
+                    None => return Ok::<_, Error>(None),
+                };
+
+                request.limit(MAX_LIMIT).offset(offset);
+                let page = client.get::<T>(&request).await?;
+                let next = next_offset(offset, page.len());
+
+                Ok(Some((
+                    stream::iter(page.into_iter().map(Ok)),
+                    (client, request, next),
+                )))
+            },
+        )
+        .try_flatten()
+    }
+
+    ///Retrieves the registries matching the given ids, splitting them in chunks of
+    /// at most MAX_LIMIT ids. Up to `config.concurrency` chunks are requested in parallel
+    /// and the results keep the chunks order.
+    pub(crate) async fn get_by_ids<T: DeserializeOwned + Identifiable>(
+        &self,
+        ids: &[T::Id],
+    ) -> Result<Vec<T>, Error> {
+        let requests = ids.chunks(MAX_LIMIT).map(|chunk| {
+            let mut request = RequestBuilder::new();
+            request
+                .all_fields()
+                .add_where_in("id", chunk.iter().copied())
+                .limit(chunk.len());
+            async move { self.get::<T>(&request).await }
+        });
+
+        let chunks: Vec<Vec<T>> = stream::iter(requests)
+            .buffered(self.config.concurrency.max(1))
+            .try_collect()
+            .await?;
+
+        Ok(chunks.into_iter().flatten().collect())
+    }
+}
+
+///Format a response body is read from
+#[derive(Clone, Copy)]
+pub(crate) enum BodyFormat {
+    Json,
+    #[cfg(feature = "protobuf")]
+    Protobuf(&'static crate::protobuf::Message),
+}
+
+impl BodyFormat {
+    fn url(self, url: String) -> String {
+        match self {
+            BodyFormat::Json => url,
+            #[cfg(feature = "protobuf")]
+            BodyFormat::Protobuf(_) => format!("{}.pb", url),
+        }
+    }
+
+    pub(crate) fn deserialize<T: DeserializeOwned>(self, body: &[u8]) -> Result<T, Error> {
+        match self {
+            BodyFormat::Json => from_json_slice(body),
+            #[cfg(feature = "protobuf")]
+            BodyFormat::Protobuf(message) => crate::protobuf::from_slice(message, body),
+        }
+    }
+}
+
+///Splits a request in requests of at most MAX_LIMIT registries covering the same range
+pub(crate) fn split_in_pages(request_builder: &RequestBuilder) -> Vec<RequestBuilder> {
+    let start = request_builder.offset;
+    let end = start + request_builder.limit;
+
+    (start..end)
+        .step_by(MAX_LIMIT)
+        .map(|offset| {
+            let mut request = request_builder.clone();
+            request.limit(MAX_LIMIT.min(end - offset)).offset(offset);
+            request
+        })
+        .collect()
+}
+
+///Offset of the page following a page of page_len registries, None once a page is not full
+fn next_offset(offset: usize, page_len: usize) -> Option<usize> {
+    match page_len {
+        MAX_LIMIT => Some(offset + MAX_LIMIT),
+        _ => None,
+    }
+}
+
+#[test]
+fn pagination_stops_after_the_first_page_not_full() {
+    assert_eq!(Some(500), next_offset(0, MAX_LIMIT));
+    assert_eq!(Some(1500), next_offset(1000, MAX_LIMIT));
+    assert_eq!(None, next_offset(1500, 12));
+    assert_eq!(None, next_offset(2000, 0));
+}
+
+#[test]
+fn requests_above_max_limit_are_split_in_pages() {
+    let mut request = RequestBuilder::new();
+    request.add_field("name").limit(1200).offset(10);
+
+    let pages: Vec<(usize, usize)> = split_in_pages(&request)
+        .iter()
+        .map(|page| (page.offset, page.limit))
+        .collect();
+
+    assert_eq!(vec![(10, 500), (510, 500), (1010, 200)], pages);
+
+    request.limit(20);
+    assert_eq!(1, split_in_pages(&request).len());
+}
+
+#[test]
+fn expired_responses_are_revalidated_with_their_etag() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::transport::{HttpTransport, TransportFuture};
+    use std::time::Duration;
+
+    ///Answers the genres with an ETag, then 304 when the ETag is sent back
+    struct Validating;
+
+    impl HttpTransport for Validating {
+        fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            let revalidating = request.headers().get(http::header::IF_NONE_MATCH).is_some();
+            Box::pin(async move {
+                let response = match revalidating {
+                    true => http::Response::builder()
+                        .status(StatusCode::NOT_MODIFIED)
+                        .body(Vec::new()),
+                    false => http::Response::builder()
+                        .header(http::header::ETAG, "\"v1\"")
+                        .body(br#"[{"id":5,"name":"Shooter"}]"#.to_vec()),
+                };
+                Ok(response?)
+            })
+        }
+    }
+
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(Validating)
+        .without_rate_limit()
+        .with_cache(Duration::ZERO, 10);
+    let request = IGDBClient::create_request();
+
+    for _ in 0..2 {
+        let genres = async_std::task::block_on(igdb.query::<GenresClient>(&request)).unwrap();
+        assert_eq!("Shooter", genres[0].name);
+    }
+    let stats = igdb.cache_stats().unwrap();
+    assert_eq!((1, 1), (stats.hits, stats.misses));
 }
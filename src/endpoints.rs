@@ -1,51 +1,57 @@
-const BASE_URL: &str = "https://api.igdb.com/v4";
+use serde::de::DeserializeOwned;
 
-#[allow(non_camel_case_types)]
-///Enum containing the implement IGDB api endpoints
-pub(crate) enum Endpoint {
-    games,
-    game_engines,
-    companies,
-    websites,
-    artworks,
-    characters,
-    game_modes,
-    multiplayer_modes,
-    covers,
-    external_games,
-    screenshots,
-    release_dates,
-    game_videos,
-    platforms,
-    themes,
-    franchises,
-    age_ratings,
-    player_perspectives,
-    platform_logos,
-    character_mug_shots,
+pub(crate) const BASE_URL: &str = "https://api.igdb.com/v4";
+
+///An IGDB api endpoint: the path it is served from and the model its registries deserialize to.
+/// Every client generated by this crate implements it, and you can implement it
+/// for endpoints this crate does not support yet.
+/// Queries naming no field request every field of the Model.
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use igdb::client::IGDBClient;
+/// use igdb::endpoints::Endpoint;
+/// use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Collection {
+///     name: String,
+/// }
+///
+/// struct Collections;
+///
+/// impl Endpoint for Collections {
+///     type Model = Collection;
+///     const PATH: &'static str = "collections";
+/// }
+///
+/// task::block_on(async {
+///     let igdb = IGDBClient::new("client_id", "token");
+///     let mut request = IGDBClient::create_request();
+///     request.add_field("name").search("Zelda");
+///
+///     for collection in igdb.query::<Collections>(&request).await.unwrap() {
+///         println!("{}", collection.name);
+///     }
+/// })
+/// ```
+pub trait Endpoint {
+    type Model: DeserializeOwned;
+    const PATH: &'static str;
+}
+
+pub(crate) fn get_endpoint_url(base_url: &str, path: &str) -> String {
+    format!("{}/{}", base_url.trim_end_matches('/'), path)
 }
 
-pub(crate) fn get_endpoint_url(endpoint: &Endpoint) -> String {
-    match endpoint {
-        Endpoint::games => format!("{}/games", BASE_URL),
-        Endpoint::companies => format!("{}/companies", BASE_URL),
-        Endpoint::websites => format!("{}/websites", BASE_URL),
-        Endpoint::artworks => format!("{}/artworks", BASE_URL),
-        Endpoint::characters => format!("{}/characters", BASE_URL),
-        Endpoint::game_modes => format!("{}/game_modes", BASE_URL),
-        Endpoint::multiplayer_modes => format!("{}/multiplayer_modes", BASE_URL),
-        Endpoint::covers => format!("{}/covers", BASE_URL),
-        Endpoint::external_games => format!("{}/external_games", BASE_URL),
-        Endpoint::screenshots => format!("{}/screenshots", BASE_URL),
-        Endpoint::release_dates => format!("{}/release_dates", BASE_URL),
-        Endpoint::game_engines => format!("{}/game_engines", BASE_URL),
-        Endpoint::game_videos => format!("{}/game_videos", BASE_URL),
-        Endpoint::platforms => format!("{}/platforms", BASE_URL),
-        Endpoint::themes => format!("{}/themes", BASE_URL),
-        Endpoint::franchises => format!("{}/franchises", BASE_URL),
-        Endpoint::age_ratings => format!("{}/age_ratings", BASE_URL),
-        Endpoint::player_perspectives => format!("{}/player_perspectives", BASE_URL),
-        Endpoint::platform_logos => format!("{}/platform_logos", BASE_URL),
-        Endpoint::character_mug_shots => format!("{}/character_mug_shots", BASE_URL),
-    }
+#[test]
+fn endpoint_url_joins_base_url_and_path() {
+    assert_eq!(
+        "https://api.igdb.com/v4/games",
+        get_endpoint_url(BASE_URL, "games")
+    );
+    assert_eq!(
+        "http://localhost:8080/igdb/covers",
+        get_endpoint_url("http://localhost:8080/igdb/", "covers")
+    );
 }
@@ -0,0 +1,359 @@
+use crate::budget::BudgetUsage;
+use crate::retry::retry_after;
+use crate::transport::TransportError;
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::time::Duration;
+
+///Error returned by every request made to IGDB
+#[derive(Debug)]
+pub enum Error {
+    ///The query cannot be built or sent as is
+    InvalidQuery(String),
+    ///The client cannot be created with the given settings, see ClientBuilder
+    InvalidConfig(String),
+    ///The request could not be sent or its response could not be read
+    Http(TransportError),
+    ///IGDB kept answering 429 Too Many Requests after every retry
+    RateLimited { retry_after: Option<Duration> },
+    ///IGDB failed too many times in a row, the request was not sent,
+    /// see IGDBClient::with_circuit_breaker
+    CircuitOpen { retry_after: Duration },
+    ///The budget of the client was spent, the request was not sent, see IGDBClient::with_budget
+    BudgetExceeded(BudgetUsage),
+    ///The credentials were rejected and could not be renewed
+    Unauthorized,
+    ///The response does not match the expected model
+    Deserialize(Box<DeserializeError>),
+    ///The protobuf response could not be decoded
+    #[cfg(feature = "protobuf")]
+    Protobuf(prost::DecodeError),
+    ///IGDB answered with an error status
+    Api { status: u16, message: String },
+    ///The query succeeded but returned no registry
+    NotFound(String),
+    ///A media file could not be written
+    Io(std::io::Error),
+    ///The local mirror could not be read or written, see mirror::sqlite
+    #[cfg(feature = "sqlite")]
+    Sqlite(rusqlite::Error),
+    ///A webhook delivery did not carry the secret the webhook was registered with
+    WebhookSecret,
+}
+
+///Length of the json excerpts of the registries which cannot be read
+const EXCERPT_LENGTH: usize = 200;
+
+///A response, or one of its registries, which does not match the expected model
+#[derive(Debug)]
+pub struct DeserializeError {
+    ///Path of the queried endpoint, e.g. `games`, empty when unknown
+    pub endpoint: String,
+    ///Path of the field which cannot be read, e.g. `[3].release_dates[0].date`
+    pub field: String,
+    ///Start of the json of the registry holding the field
+    pub excerpt: String,
+    pub source: serde_json::Error,
+}
+
+impl DeserializeError {
+    fn new(
+        e: serde_path_to_error::Error<serde_json::Error>,
+        body: Option<&serde_json::Value>,
+    ) -> Self {
+        let field = match e.path().to_string() {
+            root if root == "." => String::new(),
+            field => field,
+        };
+        let registry = match (body, e.path().iter().next()) {
+            (Some(body), Some(serde_path_to_error::Segment::Seq { index })) => body.get(index),
+            (body, _) => body,
+        };
+        DeserializeError {
+            endpoint: String::new(),
+            field,
+            excerpt: registry.map(excerpt).unwrap_or_default(),
+            source: e.into_inner(),
+        }
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.endpoint.is_empty() {
+            write!(f, "{} ", self.endpoint)?;
+        }
+        if !self.field.is_empty() {
+            write!(f, "field {}: ", self.field)?;
+        }
+        write!(f, "{}", self.source)?;
+        if !self.excerpt.is_empty() {
+            write!(f, " in {}", self.excerpt)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DeserializeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+///Reads a json response body, telling which field of which registry cannot be read on failure
+pub(crate) fn from_json_slice<T: DeserializeOwned>(body: &[u8]) -> Result<T, Error> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    match serde_path_to_error::deserialize(&mut deserializer) {
+        Ok(value) => {
+            deserializer.end()?;
+            Ok(value)
+        }
+        Err(e) => {
+            let body = serde_json::from_slice::<serde_json::Value>(body).ok();
+            Err(DeserializeError::new(e, body.as_ref()).into())
+        }
+    }
+}
+
+///Reads json already parsed, telling which field cannot be read on failure
+pub(crate) fn from_json_value<T: DeserializeOwned>(
+    value: &serde_json::Value,
+) -> Result<T, DeserializeError> {
+    serde_path_to_error::deserialize(value).map_err(|e| DeserializeError::new(e, Some(value)))
+}
+
+///The json of the value, cut after EXCERPT_LENGTH characters
+pub(crate) fn excerpt(value: &serde_json::Value) -> String {
+    let json = value.to_string();
+    match json.char_indices().nth(EXCERPT_LENGTH) {
+        Some((end, _)) => format!("{}...", &json[..end]),
+        None => json,
+    }
+}
+
+///Error body returned by IGDB, e.g. `[{"title": "Syntax Error", "status": 400, "cause": "..."}]`
+#[derive(Deserialize)]
+struct ApiError {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    cause: Option<String>,
+}
+
+impl Error {
+    ///Builds the error matching a response that was not successful
+    pub(crate) fn from_response(
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: &[u8],
+    ) -> Error {
+        match status {
+            StatusCode::UNAUTHORIZED => Error::Unauthorized,
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimited { retry_after },
+            _ => Error::Api {
+                status: status.as_u16(),
+                message: api_message(body),
+            },
+        }
+    }
+
+    ///Tells which endpoint answered the response which cannot be read
+    pub(crate) fn at_endpoint(self, path: &str) -> Error {
+        match self {
+            Error::Deserialize(mut e) if e.endpoint.is_empty() => {
+                e.endpoint = path.to_owned();
+                Error::Deserialize(e)
+            }
+            e => e,
+        }
+    }
+}
+
+///Returns the response if successful, or the Error matching its status
+pub(crate) fn ensure_success(
+    response: http::Response<Vec<u8>>,
+) -> Result<http::Response<Vec<u8>>, Error> {
+    let status = response.status();
+    match status.is_success() {
+        true => Ok(response),
+        false => Err(Error::from_response(
+            status,
+            retry_after(response.headers()),
+            response.body(),
+        )),
+    }
+}
+
+fn api_message(body: &[u8]) -> String {
+    match serde_json::from_slice::<Vec<ApiError>>(body) {
+        Ok(errors) if !errors.is_empty() => errors
+            .iter()
+            .map(|error| match &error.cause {
+                Some(cause) => format!("{}: {}", error.title, cause),
+                None => error.title.clone(),
+            })
+            .collect::<Vec<String>>()
+            .join(", "),
+        _ => String::from_utf8_lossy(body).trim().to_owned(),
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidQuery(message) => write!(f, "invalid query: {}", message),
+            Error::InvalidConfig(message) => write!(f, "invalid client settings: {}", message),
+            Error::Http(e) => write!(f, "http error: {}", e),
+            Error::RateLimited {
+                retry_after: Some(retry_after),
+            } => write!(f, "rate limited, retry after {:?}", retry_after),
+            Error::RateLimited { retry_after: None } => f.write_str("rate limited"),
+            Error::CircuitOpen { retry_after } => {
+                write!(f, "IGDB is failing, circuit open for {:?}", retry_after)
+            }
+            Error::BudgetExceeded(usage) => write!(
+                f,
+                "budget exceeded after {} requests and {} bytes",
+                usage.requests, usage.bytes
+            ),
+            Error::Unauthorized => f.write_str("unauthorized, check the client id and token"),
+            Error::Deserialize(e) => write!(f, "unexpected response: {}", e),
+            #[cfg(feature = "protobuf")]
+            Error::Protobuf(e) => write!(f, "invalid protobuf response: {}", e),
+            Error::Api { status, message } => write!(f, "IGDB error {}: {}", status, message),
+            Error::NotFound(message) => write!(f, "not found: {}", message),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            #[cfg(feature = "sqlite")]
+            Error::Sqlite(e) => write!(f, "sqlite error: {}", e),
+            Error::WebhookSecret => f.write_str("webhook delivery with an invalid secret"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Http(e) => Some(e.as_ref()),
+            Error::Deserialize(e) => Some(e.as_ref()),
+            #[cfg(feature = "protobuf")]
+            Error::Protobuf(e) => Some(e),
+            Error::Io(e) => Some(e),
+            #[cfg(feature = "sqlite")]
+            Error::Sqlite(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<TransportError> for Error {
+    fn from(e: TransportError) -> Self {
+        Error::Http(e)
+    }
+}
+
+impl From<http::Error> for Error {
+    fn from(e: http::Error) -> Self {
+        Error::Http(e.into())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Deserialize(Box::new(DeserializeError {
+            endpoint: String::new(),
+            field: String::new(),
+            excerpt: String::new(),
+            source: e,
+        }))
+    }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(e: DeserializeError) -> Self {
+        Error::Deserialize(Box::new(e))
+    }
+}
+
+#[cfg(feature = "protobuf")]
+impl From<prost::DecodeError> for Error {
+    fn from(e: prost::DecodeError) -> Self {
+        Error::Protobuf(e)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        Error::Sqlite(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[test]
+fn error_statuses_are_mapped_to_variants() {
+    let body = br#"[{"title": "Syntax Error", "status": 400, "cause": "Missing `;` at 14"}]"#;
+
+    match Error::from_response(StatusCode::BAD_REQUEST, None, body) {
+        Error::Api { status, message } => {
+            assert_eq!(400, status);
+            assert_eq!("Syntax Error: Missing `;` at 14", message);
+        }
+        e => panic!("unexpected error: {}", e),
+    }
+    match Error::from_response(StatusCode::BAD_GATEWAY, None, b"Bad Gateway\n") {
+        Error::Api { message, .. } => assert_eq!("Bad Gateway", message),
+        e => panic!("unexpected error: {}", e),
+    }
+    assert!(matches!(
+        Error::from_response(StatusCode::UNAUTHORIZED, None, b""),
+        Error::Unauthorized
+    ));
+    assert!(matches!(
+        Error::from_response(
+            StatusCode::TOO_MANY_REQUESTS,
+            Some(Duration::from_secs(1)),
+            b""
+        ),
+        Error::RateLimited {
+            retry_after: Some(_)
+        }
+    ));
+}
+
+#[test]
+fn deserialize_errors_tell_the_endpoint_field_and_registry() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::MockTransport;
+
+    let mock = MockTransport::new().with_fixture(
+        "genres",
+        "fields name; limit 10;",
+        r#"[{"id": 5, "name": "Shooter"}, {"id": 12, "name": ["RPG"]}]"#,
+    );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock)
+        .without_rate_limit();
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+
+    match async_std::task::block_on(igdb.query::<GenresClient>(&request)) {
+        Err(Error::Deserialize(e)) => {
+            assert_eq!("genres", e.endpoint);
+            assert_eq!("[1].name", e.field);
+            assert_eq!(r#"{"id":12,"name":["RPG"]}"#, e.excerpt);
+            assert!(e
+                .to_string()
+                .starts_with("genres field [1].name: invalid type: sequence"));
+        }
+        other => panic!("unexpected result: {:?}", other.map(|genres| genres.len())),
+    }
+
+    let long = serde_json::json!({ "name": "x".repeat(300) });
+    assert_eq!(EXCERPT_LENGTH + 3, excerpt(&long).len());
+}
@@ -0,0 +1,215 @@
+//! Writing query results as NDJSON or CSV, see IGDBClient::export.
+//!
+//! NDJSON writes one registry per line as returned by IGDB. CSV flattens the registries into
+//! columns: nested fields are named by their path, e.g. `cover.image_id`, and the values of
+//! list fields are joined with `;`, e.g. the `platforms.name` of every platform of a game.
+
+use crate::Error;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+///Separator of the values of list fields in CSV cells
+const LIST_SEPARATOR: &str = ";";
+
+///Format of the exported registries
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    ///One json registry per line
+    Ndjson,
+    ///Comma separated values with a header row, nested fields flattened
+    Csv,
+}
+
+///Writes registries one at a time in the given format
+/// # Examples
+/// ```
+/// use igdb::export::{ExportFormat, Exporter};
+/// use serde_json::json;
+///
+/// let mut exporter = Exporter::new(Vec::new(), ExportFormat::Csv);
+/// exporter
+///     .write(&json!({"id": 1942, "name": "The Witcher 3", "platforms": [{"name": "PC"}, {"name": "PS4"}]}))
+///     .unwrap();
+/// exporter.write(&json!({"id": 26226, "name": "Celeste, Farewell"})).unwrap();
+///
+/// let csv = String::from_utf8(exporter.finish().unwrap()).unwrap();
+/// assert_eq!(
+///     "id,name,platforms.name\n1942,The Witcher 3,PC;PS4\n26226,\"Celeste, Farewell\",\n",
+///     csv
+/// );
+/// ```
+pub struct Exporter<W: Write> {
+    writer: W,
+    format: ExportFormat,
+    ///CSV columns, taken from the first registry unless given
+    columns: Option<Vec<String>>,
+    written: usize,
+}
+
+impl<W: Write> Exporter<W> {
+    pub fn new(writer: W, format: ExportFormat) -> Exporter<W> {
+        Exporter {
+            writer,
+            format,
+            columns: None,
+            written: 0,
+        }
+    }
+
+    ///Sets the CSV columns and their order instead of taking the fields of the first registry,
+    /// e.g. when the first registries miss some fields. Ignored by NDJSON.
+    pub fn with_columns<I, S>(mut self, columns: I) -> Exporter<W>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    ///Writes a registry, e.g. a json value of IGDBClient::query_raw or any serializable struct
+    pub fn write<T: Serialize>(&mut self, registry: &T) -> Result<(), Error> {
+        match self.format {
+            ExportFormat::Ndjson => {
+                serde_json::to_writer(&mut self.writer, registry)?;
+                self.writer.write_all(b"\n")?;
+            }
+            ExportFormat::Csv => {
+                let cells = flatten(&serde_json::to_value(registry)?);
+                if self.written == 0 {
+                    let columns = self
+                        .columns
+                        .get_or_insert_with(|| cells.keys().cloned().collect());
+                    write_csv_row(&mut self.writer, columns.iter().map(String::as_str))?;
+                }
+                let columns = self.columns.as_deref().unwrap_or_default();
+                let row = columns
+                    .iter()
+                    .map(|column| cells.get(column).map(String::as_str).unwrap_or_default());
+                write_csv_row(&mut self.writer, row)?;
+            }
+        }
+        self.written += 1;
+        Ok(())
+    }
+
+    ///Writes every registry of the iterator, e.g. every element of a json array
+    pub fn write_all<'a, T, I>(&mut self, registries: I) -> Result<(), Error>
+    where
+        T: Serialize + 'a,
+        I: IntoIterator<Item = &'a T>,
+    {
+        registries
+            .into_iter()
+            .try_for_each(|registry| self.write(registry))
+    }
+
+    ///Registries written so far
+    pub fn written(&self) -> usize {
+        self.written
+    }
+
+    ///Flushes and returns the writer
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+///Flattens a registry into its CSV cells by column name
+fn flatten(registry: &Value) -> BTreeMap<String, String> {
+    let mut values: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    flatten_into("", registry, &mut values);
+    values
+        .into_iter()
+        .map(|(column, values)| (column, values.join(LIST_SEPARATOR)))
+        .collect()
+}
+
+fn flatten_into(path: &str, value: &Value, values: &mut BTreeMap<String, Vec<String>>) {
+    match value {
+        Value::Object(fields) => {
+            for (name, value) in fields {
+                let path = match path {
+                    "" => name.clone(),
+                    path => format!("{}.{}", path, name),
+                };
+                flatten_into(&path, value, values);
+            }
+        }
+        Value::Array(elements) => {
+            // Elements of a list share its columns, their values being joined
+            if !elements.iter().any(Value::is_object) {
+                values.entry(path.to_owned()).or_default();
+            }
+            elements
+                .iter()
+                .for_each(|element| flatten_into(path, element, values));
+        }
+        Value::Null => {
+            values.entry(path.to_owned()).or_default();
+        }
+        Value::String(string) => values
+            .entry(path.to_owned())
+            .or_default()
+            .push(string.clone()),
+        scalar => values
+            .entry(path.to_owned())
+            .or_default()
+            .push(scalar.to_string()),
+    }
+}
+
+fn write_csv_row<'a, W: Write, I: Iterator<Item = &'a str>>(
+    writer: &mut W,
+    cells: I,
+) -> Result<(), Error> {
+    for (i, cell) in cells.enumerate() {
+        if i != 0 {
+            writer.write_all(b",")?;
+        }
+        match cell.contains([',', '"', '\n', '\r']) {
+            true => write!(writer, "\"{}\"", cell.replace('"', "\"\""))?,
+            false => writer.write_all(cell.as_bytes())?,
+        }
+    }
+    writer.write_all(b"\n")?;
+    Ok(())
+}
+
+#[test]
+fn registries_are_flattened_into_csv_and_written_as_ndjson() {
+    use serde_json::json;
+
+    let games = json!([
+        {"id": 1, "name": "Say \"Hi\"", "cover": {"image_id": "co1"}, "genres": [5, 12], "platforms": []},
+        {"id": 2, "name": "Plain", "cover": null, "genres": [31]}
+    ]);
+    let registries = games.as_array().unwrap();
+
+    let mut csv = Exporter::new(Vec::new(), ExportFormat::Csv);
+    csv.write_all(registries).unwrap();
+    assert_eq!(2, csv.written());
+    assert_eq!(
+        "cover.image_id,genres,id,name,platforms\nco1,5;12,1,\"Say \"\"Hi\"\"\",\n,31,2,Plain,\n",
+        String::from_utf8(csv.finish().unwrap()).unwrap()
+    );
+
+    let mut csv = Exporter::new(Vec::new(), ExportFormat::Csv).with_columns(["name", "missing"]);
+    csv.write_all(registries).unwrap();
+    assert_eq!(
+        "name,missing\n\"Say \"\"Hi\"\"\",\nPlain,\n",
+        String::from_utf8(csv.finish().unwrap()).unwrap()
+    );
+
+    let mut ndjson = Exporter::new(Vec::new(), ExportFormat::Ndjson);
+    ndjson.write_all(registries).unwrap();
+    let ndjson = String::from_utf8(ndjson.finish().unwrap()).unwrap();
+    assert_eq!(2, ndjson.lines().count());
+    assert_eq!(
+        registries[1],
+        serde_json::from_str::<Value>(ndjson.lines().nth(1).unwrap()).unwrap()
+    );
+}
@@ -0,0 +1,313 @@
+//! The where clause of a query as an expression tree, see RequestBuilder::filter_expr.
+//!
+//! The builder methods, e.g. add_where and add_where_or, grow the tree of the request, `&`
+//! binding tighter than `|`. The tree can also be built, inspected and rewritten directly,
+//! e.g. to constrain every query of a tenant, and is rendered with every nested `&` or `|`
+//! parenthesized, so that a rewritten query keeps its meaning.
+
+use crate::request_builder::{Equality, Match, Value};
+use std::fmt;
+
+///Comparison operator of a condition
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    ///Case-insensitive string match, `~`
+    Like,
+    ///Case-insensitive string mismatch, `!~`
+    NotLike,
+}
+
+impl Operator {
+    ///Every operator with its symbol, longest first so `!=` is not read as `!`
+    pub(crate) const SYMBOLS: [(&'static str, Operator); 8] = [
+        ("!=", Operator::NotEqual),
+        (">=", Operator::GreaterOrEqual),
+        ("<=", Operator::LessOrEqual),
+        ("!~", Operator::NotLike),
+        ("=", Operator::Equal),
+        ("<", Operator::Less),
+        (">", Operator::Greater),
+        ("~", Operator::Like),
+    ];
+
+    pub fn symbol(self) -> &'static str {
+        Operator::SYMBOLS
+            .iter()
+            .find(|(_, operator)| *operator == self)
+            .map(|(symbol, _)| *symbol)
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn from_symbol(symbol: &str) -> Option<Operator> {
+        Operator::SYMBOLS
+            .iter()
+            .find(|(s, _)| *s == symbol)
+            .map(|(_, operator)| *operator)
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl From<Equality> for Operator {
+    fn from(equality: Equality) -> Self {
+        match equality {
+            Equality::Equal => Operator::Equal,
+            Equality::NotEqual => Operator::NotEqual,
+            Equality::Less => Operator::Less,
+            Equality::LessOrEqual => Operator::LessOrEqual,
+            Equality::Greater => Operator::Greater,
+            Equality::GreaterOrEqual => Operator::GreaterOrEqual,
+        }
+    }
+}
+
+///A where clause, or a part of it
+/// # Examples
+/// ```
+/// use igdb::expr::Expr;
+/// use igdb::request_builder::{Equality, RequestBuilder};
+///
+/// let platforms = Expr::is_in("platforms", [6, 48]).or(Expr::null("platforms"));
+/// let expr = Expr::cmp("rating", Equality::Greater, 80).and(platforms);
+/// assert_eq!("rating > 80 & (platforms = (6,48) | platforms = null)", expr.to_string());
+///
+/// let mut request = RequestBuilder::new();
+/// request.add_field("name").add_expr(expr);
+/// assert_eq!(
+///     "fields name; where rating > 80 & (platforms = (6,48) | platforms = null); limit 10;",
+///     request.to_query()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    ///Expressions which must all match, joined with `&`
+    And(Vec<Expr>),
+    ///Expressions of which one must match, joined with `|`
+    Or(Vec<Expr>),
+    ///A comparison of a field to a value, e.g. `rating > 80`
+    Cmp {
+        field: String,
+        op: Operator,
+        value: Value,
+    },
+    ///Matches the fields equal to any of the values, `field = (a,b)`,
+    /// or to none of them when negated, `field != (a,b)`
+    In {
+        field: String,
+        values: Vec<Value>,
+        negated: bool,
+    },
+    ///Matches the fields without value, `field = null`, or with one when negated,
+    /// `field != null`
+    Null { field: String, negated: bool },
+}
+
+impl Expr {
+    ///Compares the field to the value, null values and id lists giving Null and In
+    pub fn cmp<F: Into<String>, V: Into<Value>>(field: F, equality: Equality, value: V) -> Expr {
+        let field = field.into();
+        let op = Operator::from(equality);
+        let negated = op == Operator::NotEqual;
+        match (value.into(), op) {
+            (Value::Null, Operator::Equal | Operator::NotEqual) => Expr::Null { field, negated },
+            (Value::Ids(ids), Operator::Equal | Operator::NotEqual) => Expr::In {
+                field,
+                values: ids.into_iter().map(Value::from).collect(),
+                negated,
+            },
+            (value, op) => Expr::Cmp { field, op, value },
+        }
+    }
+
+    ///Matches the string field case-insensitively, see RequestBuilder::add_where_like
+    pub fn like<F: Into<String>, S: Into<String>>(field: F, matching: Match, value: S) -> Expr {
+        Expr::Cmp {
+            field: field.into(),
+            op: Operator::Like,
+            value: Value::Raw(matching.pattern(&value.into())),
+        }
+    }
+
+    ///Matches the fields equal to any of the values
+    pub fn is_in<F, I, V>(field: F, values: I) -> Expr
+    where
+        F: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        Expr::In {
+            field: field.into(),
+            values: values.into_iter().map(Into::into).collect(),
+            negated: false,
+        }
+    }
+
+    ///Matches the fields equal to none of the values
+    pub fn not_in<F, I, V>(field: F, values: I) -> Expr
+    where
+        F: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        match Expr::is_in(field, values) {
+            Expr::In { field, values, .. } => Expr::In {
+                field,
+                values,
+                negated: true,
+            },
+            expr => expr,
+        }
+    }
+
+    ///Matches the fields without value
+    pub fn null<F: Into<String>>(field: F) -> Expr {
+        Expr::Null {
+            field: field.into(),
+            negated: false,
+        }
+    }
+
+    ///Matches the fields with a value
+    pub fn not_null<F: Into<String>>(field: F) -> Expr {
+        Expr::Null {
+            field: field.into(),
+            negated: true,
+        }
+    }
+
+    ///Requires both expressions to match, appending to this one when it is an And
+    /// and leaving out conditions it already has
+    pub fn and(self, other: Expr) -> Expr {
+        let mut exprs = match self {
+            Expr::And(exprs) => exprs,
+            expr => vec![expr],
+        };
+        if !exprs.contains(&other) {
+            exprs.push(other);
+        }
+        match exprs.len() {
+            1 => exprs.remove(0),
+            _ => Expr::And(exprs),
+        }
+    }
+
+    ///Requires either expression to match, appending to this one when it is an Or
+    pub fn or(self, other: Expr) -> Expr {
+        let mut exprs = match self {
+            Expr::Or(exprs) => exprs,
+            expr => vec![expr],
+        };
+        exprs.push(other);
+        Expr::Or(exprs)
+    }
+
+    ///Rebuilds the tree bottom-up, replacing each node by what the function returns for it,
+    /// its children being rewritten first
+    /// # Examples
+    /// ```
+    /// use igdb::expr::Expr;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let expr = Expr::cmp("rating", Equality::Greater, 80).or(Expr::null("rating"));
+    /// let renamed = expr.rewrite(&mut |expr| match expr {
+    ///     Expr::Cmp { field, op, value } if field == "rating" => Expr::Cmp {
+    ///         field: "total_rating".to_owned(),
+    ///         op,
+    ///         value,
+    ///     },
+    ///     expr => expr,
+    /// });
+    /// assert_eq!("total_rating > 80 | rating = null", renamed.to_string());
+    /// ```
+    pub fn rewrite<F: FnMut(Expr) -> Expr>(self, rewrite: &mut F) -> Expr {
+        let expr = match self {
+            Expr::And(exprs) => Expr::And(exprs.into_iter().map(|e| e.rewrite(rewrite)).collect()),
+            Expr::Or(exprs) => Expr::Or(exprs.into_iter().map(|e| e.rewrite(rewrite)).collect()),
+            expr => expr,
+        };
+        rewrite(expr)
+    }
+
+    ///The field, operator and value of a condition as rendered, None for And and Or
+    pub(crate) fn parts(&self) -> Option<(String, String, String)> {
+        let equality = |negated: bool| match negated {
+            true => "!=".to_owned(),
+            false => "=".to_owned(),
+        };
+        match self {
+            Expr::And(_) | Expr::Or(_) => None,
+            Expr::Cmp { field, op, value } => {
+                Some((field.clone(), op.to_string(), value.to_string()))
+            }
+            Expr::In {
+                field,
+                values,
+                negated,
+            } => {
+                let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+                let values = format!("({})", values.join(","));
+                Some((field.clone(), equality(*negated), values))
+            }
+            Expr::Null { field, negated } => {
+                Some((field.clone(), equality(*negated), "null".to_owned()))
+            }
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        matches!(self, Expr::And(exprs) | Expr::Or(exprs) if exprs.len() > 1)
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (exprs, separator) = match self {
+            Expr::And(exprs) => (exprs, " & "),
+            Expr::Or(exprs) => (exprs, " | "),
+            condition => {
+                let (field, op, value) = condition.parts().unwrap_or_default();
+                return write!(f, "{} {} {}", field, op, value);
+            }
+        };
+        for (i, expr) in exprs.iter().enumerate() {
+            if i != 0 {
+                f.write_str(separator)?;
+            }
+            match expr.is_compound() {
+                true => write!(f, "({})", expr)?,
+                false => write!(f, "{}", expr)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn nested_expressions_are_parenthesized() {
+    let expr = Expr::cmp("rating", Equality::Greater, 80)
+        .and(Expr::cmp("rating", Equality::Greater, 80))
+        .or(Expr::cmp("id", Equality::Equal, vec![1, 2]))
+        .or(Expr::like("name", Match::StartsWith, "zelda").and(Expr::not_null("cover")));
+
+    assert_eq!(
+        r#"rating > 80 | id = (1,2) | (name ~ "zelda"* & cover != null)"#,
+        expr.to_string()
+    );
+    assert_eq!(
+        "(a = 1 | b = 2) & c != (3)",
+        Expr::cmp("a", Equality::Equal, 1)
+            .or(Expr::cmp("b", Equality::Equal, 2))
+            .and(Expr::not_in("c", [3]))
+            .to_string()
+    );
+}
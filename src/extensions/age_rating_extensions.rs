@@ -0,0 +1,44 @@
+use crate::client::{AgeRatingContentDescriptionsClient, AgeRatingsClient};
+use crate::endpoints::Endpoint;
+use crate::media_helpers::get_resource_as_is;
+use crate::model::age_rating::AgeRating;
+use crate::model::age_rating_content_description::AgeRatingContentDescription;
+use crate::model::ids::AgeRatingId;
+use crate::Error;
+
+impl AgeRatingsClient {
+    ///Retrieves the official rating badge image (ESRB, PEGI...) provided by IGDB
+    /// for the given age rating id. Returns None when IGDB has no badge for it.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb_client = IGDBClient::new("client_id", "token");
+    /// let age_ratings_client = igdb_client.age_ratings();
+    /// let badge = age_ratings_client.get_rating_cover_by_id(35466);
+    /// ```
+    pub async fn get_rating_cover_by_id<I: Into<AgeRatingId>>(
+        &self,
+        id: I,
+    ) -> Result<Option<Vec<u8>>, Error> {
+        match self.get_first_by_id(id).await?.rating_cover_url {
+            Some(url) => Ok(Some(
+                get_resource_as_is(&self.endpoint_client.config, url).await?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    ///Retrieves the content descriptors (violence, language...) of the given age rating.
+    /// The age rating must have been retrieved with the content_descriptions field.
+    pub async fn get_content_descriptions(
+        &self,
+        age_rating: &AgeRating,
+    ) -> Result<Vec<AgeRatingContentDescription>, Error> {
+        self.endpoint_client
+            .with_endpoint(AgeRatingContentDescriptionsClient::PATH)
+            .get_by_ids::<AgeRatingContentDescription>(&age_rating.content_descriptions)
+            .await
+    }
+}
@@ -1,5 +1,8 @@
-use crate::client::CharactersClient;
+use crate::client::{CharacterMugshotsClient, CharactersClient};
+use crate::endpoints::Endpoint;
 use crate::model::character::Character;
+use crate::model::character_mug_shot::CharacterMugshot;
+use crate::model::ids::{CharacterMugshotId, GameId};
 use crate::request_builder::RequestBuilder;
 use crate::Error;
 
@@ -15,17 +18,65 @@ impl CharactersClient {
     /// ///Retrieve 8 first characters for The Witcher 3 game
     /// let characters = characters_client.get_by_game_id(1942, 8);
     /// ```
-    pub async fn get_by_game_id(
+    pub async fn get_by_game_id<I: Into<GameId>>(
         &self,
-        game_id: usize,
+        game_id: I,
         limit: usize,
     ) -> Result<Vec<Character>, Error> {
         let mut request = RequestBuilder::new();
         request
             .all_fields()
-            .add_where_in("games".to_owned(), vec![game_id.to_string()])
+            .add_where_id_in("games", vec![game_id.into()])
             .limit(limit);
 
         self.get(request).await
     }
+
+    ///Returns a characters collection containing the given name
+    pub async fn get_by_name<S: Into<String>>(
+        &self,
+        name: S,
+        limit: usize,
+    ) -> Result<Vec<Character>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .contains("name", &name.into())
+            .limit(limit);
+
+        self.get(request).await
+    }
+
+    ///Returns the mug shot of the given character, None when it has none.
+    /// The character must have been retrieved with the mug_shot field.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::media_quality::MediaQuality;
+    ///
+    /// task::block_on(async {
+    ///     let characters_client = IGDBClient::new("client_id", "token").characters();
+    ///     let geralt = characters_client.get_first_by_id(1).await.unwrap();
+    ///
+    ///     if let Some(mug_shot) = characters_client.get_mug_shot(&geralt).await.unwrap() {
+    ///         println!("{}", mug_shot.image_url(MediaQuality::Thumb));
+    ///     }
+    /// })
+    /// ```
+    pub async fn get_mug_shot(
+        &self,
+        character: &Character,
+    ) -> Result<Option<CharacterMugshot>, Error> {
+        match character.mug_shot {
+            CharacterMugshotId(0) => Ok(None),
+            id => Ok(self
+                .endpoint_client
+                .with_endpoint(CharacterMugshotsClient::PATH)
+                .get_by_ids::<CharacterMugshot>(&[id])
+                .await?
+                .into_iter()
+                .next()),
+        }
+    }
 }
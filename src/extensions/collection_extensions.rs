@@ -0,0 +1,32 @@
+use crate::client::{CollectionsClient, GamesClient};
+use crate::endpoints::Endpoint;
+use crate::model::collection::Collection;
+use crate::model::games::Game;
+use crate::request_builder::RequestBuilder;
+use crate::Error;
+
+impl CollectionsClient {
+    ///Returns a collections collection containing the given name
+    pub async fn get_by_name<S: Into<String>>(
+        &self,
+        name: S,
+        limit: usize,
+    ) -> Result<Vec<Collection>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .contains("name", &name.into())
+            .limit(limit);
+
+        self.get(request).await
+    }
+
+    ///Returns the games of the given collection, which must have been retrieved
+    /// with the games field
+    pub async fn get_games(&self, collection: &Collection) -> Result<Vec<Game>, Error> {
+        self.endpoint_client
+            .with_endpoint(GamesClient::PATH)
+            .get_by_ids::<Game>(&collection.games)
+            .await
+    }
+}
@@ -0,0 +1,69 @@
+use crate::client::EventsClient;
+use crate::model::event::Event;
+use crate::request_builder::{Equality, OrderBy, RequestBuilder};
+use crate::time::SystemTime;
+use crate::Error;
+
+impl EventsClient {
+    ///Returns the events starting after the given time, in seconds since the unix epoch,
+    /// the soonest first
+    pub async fn get_starting_after(&self, time: u64, limit: usize) -> Result<Vec<Event>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .add_where("start_time", Equality::Greater, time)
+            .sort_by("start_time", OrderBy::Ascending)
+            .limit(limit);
+
+        self.get(request).await
+    }
+
+    ///Returns the events which did not start yet, the soonest first
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let events_client = IGDBClient::new("client_id", "token").events();
+    ///     for event in events_client.get_upcoming(10).await.unwrap() {
+    ///         println!("{} ({})", event.name, event.live_stream_url);
+    ///     }
+    /// })
+    /// ```
+    pub async fn get_upcoming(&self, limit: usize) -> Result<Vec<Event>, Error> {
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        self.get_starting_after(now, limit).await
+    }
+}
+
+#[test]
+fn events_are_filtered_and_sorted_by_start_time() {
+    use crate::client::IGDBClient;
+    use crate::mock::MockTransport;
+
+    let mock = MockTransport::new().with_fixture(
+        "events",
+        "fields *; where start_time > 1700000000; sort start_time asc; limit 2;",
+        r#"[
+            {"id": 1, "name": "The Game Awards", "start_time": 1702000000, "event_logo": 10},
+            {"id": 2, "name": "Summer Game Fest", "start_time": 1717700000,
+             "event_logo": {"id": 11, "image_id": "evl11"}}
+        ]"#,
+    );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock)
+        .without_rate_limit();
+
+    let events =
+        async_std::task::block_on(igdb.events().get_starting_after(1700000000, 2)).unwrap();
+    let names: Vec<&str> = events.iter().map(|event| event.name.as_str()).collect();
+    assert_eq!(vec!["The Game Awards", "Summer Game Fest"], names);
+    assert_eq!(
+        Some("evl11"),
+        events[1].event_logo.expanded().map(|l| l.image_id.as_str())
+    );
+}
@@ -0,0 +1,49 @@
+use crate::client::{ExternalGameClient, GamesClient};
+use crate::endpoints::Endpoint;
+use crate::model::enums::ExternalGameCategory;
+use crate::model::external_game::ExternalGame;
+use crate::model::games::Game;
+use crate::model::ids::GameId;
+use crate::request_builder::{Equality, RequestBuilder, Value};
+use crate::Error;
+
+impl ExternalGameClient {
+    ///Returns the IGDB game matching the given store id, None when the store id is unknown.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::enums::ExternalGameCategory;
+    ///
+    /// task::block_on(async {
+    ///     let external_games_client = IGDBClient::new("client_id", "token").external_games();
+    ///     let game = external_games_client
+    ///         .find_game(ExternalGameCategory::ItchIo, "1234567")
+    ///         .await
+    ///         .unwrap();
+    /// })
+    /// ```
+    pub async fn find_game<S: Into<String>>(
+        &self,
+        category: ExternalGameCategory,
+        uid: S,
+    ) -> Result<Option<Game>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .add_field("game")
+            .add_where("category", Equality::Equal, category.value())
+            .add_where("uid", Equality::Equal, Value::string(uid))
+            .limit(1);
+
+        match self.get(request).await?.first() {
+            Some(ExternalGame { game, .. }) if *game != GameId(0) => Ok(self
+                .endpoint_client
+                .with_endpoint(GamesClient::PATH)
+                .get_by_ids::<Game>(&[*game])
+                .await?
+                .into_iter()
+                .next()),
+            _ => Ok(None),
+        }
+    }
+}
@@ -1,5 +1,7 @@
-use crate::client::FranchisesClient;
+use crate::client::{FranchisesClient, GamesClient};
+use crate::endpoints::Endpoint;
 use crate::model::franchise::Franchise;
+use crate::model::games::Game;
 use crate::request_builder::RequestBuilder;
 use crate::Error;
 
@@ -17,4 +19,13 @@ impl FranchisesClient {
 
         self.get(request).await
     }
+
+    ///Returns the games of the given franchise, which must have been retrieved
+    /// with the games field
+    pub async fn get_games(&self, franchise: &Franchise) -> Result<Vec<Game>, Error> {
+        self.endpoint_client
+            .with_endpoint(GamesClient::PATH)
+            .get_by_ids::<Game>(&franchise.games)
+            .await
+    }
 }
@@ -1,8 +1,12 @@
-use crate::client::GamesClient;
+use crate::client::{ArtworksClient, GamesClient, ScreenshotsClient};
+use crate::endpoints::Endpoint;
+use crate::model::artwork::Artwork;
 use crate::model::games::Game;
-use crate::request_builder::RequestBuilder;
+use crate::model::id_or::{IdOr, Identifiable};
+use crate::model::ids::GameId;
+use crate::model::screenshot::Screenshot;
+use crate::request_builder::{Equality, RequestBuilder};
 use crate::Error;
-use std::future::Future;
 
 impl GamesClient {
     ///Returns a games collection containing the given name
@@ -20,8 +24,12 @@ impl GamesClient {
         self.get(request).await
     }
     ///Returns the first game found inside a collection for a given name
-    pub async fn get_first_by_name<S: Into<String>>(&self, name: S) -> Option<Game> {
-        get_game_result(self.get_by_name(name, 1)).await
+    pub async fn get_first_by_name<S: Into<String>>(&self, name: S) -> Result<Game, Error> {
+        let name = name.into();
+        match self.get_by_name(name.as_str(), 1).await?.into_iter().next() {
+            Some(game) => Ok(game),
+            None => Err(Error::NotFound(format!("No game named: {}", name))),
+        }
     }
     /// Returns a games collection containing the specified field value
     pub async fn contains<S: Into<String>>(
@@ -43,16 +51,104 @@ impl GamesClient {
 
         self.get(request).await
     }
-}
 
-async fn get_game_result(
-    games_future: impl Future<Output = Result<Vec<Game>, Error>>,
-) -> Option<Game> {
-    match games_future.await {
-        Ok(games) => games.get(0).cloned(),
-        Err(e) => {
-            log::error!("{}", e);
-            None
+    ///Returns the screenshots of the given game, which must have been retrieved
+    /// with the screenshots field
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::media_quality::MediaQuality;
+    ///
+    /// task::block_on(async {
+    ///     let games_client = IGDBClient::new("client_id", "token").games();
+    ///     let game = games_client.get_first_by_name("Witcher 3").await.unwrap();
+    ///
+    ///     for screenshot in games_client.get_screenshots(&game).await.unwrap() {
+    ///         println!("{}", screenshot.image_url(MediaQuality::ScreenshotBig));
+    ///     }
+    /// })
+    /// ```
+    pub async fn get_screenshots(&self, game: &Game) -> Result<Vec<Screenshot>, Error> {
+        self.endpoint_client
+            .with_endpoint(ScreenshotsClient::PATH)
+            .get_by_ids::<Screenshot>(&ids(&game.screenshots))
+            .await
+    }
+
+    ///Returns the artworks of the given game, which must have been retrieved
+    /// with the artworks field
+    pub async fn get_artworks(&self, game: &Game) -> Result<Vec<Artwork>, Error> {
+        self.endpoint_client
+            .with_endpoint(ArtworksClient::PATH)
+            .get_by_ids::<Artwork>(&ids(&game.artworks))
+            .await
+    }
+
+    ///Returns the games similar to the given one, in the order IGDB ranks them. They are
+    /// expanded from its similar_games references in a single request.
+    pub async fn get_similar<I: Into<GameId>>(
+        &self,
+        game_id: I,
+        limit: usize,
+    ) -> Result<Vec<Game>, Error> {
+        let game_id = game_id.into();
+        let mut request = RequestBuilder::new();
+        request
+            .add_field("similar_games.*")
+            .add_where("id", Equality::Equal, game_id)
+            .limit(1);
+
+        match self.get(request).await?.into_iter().next() {
+            Some(game) => Ok(game
+                .similar_games
+                .into_iter()
+                .filter_map(|similar| match similar {
+                    IdOr::Object(similar) => Some(similar),
+                    IdOr::Id(_) => None,
+                })
+                .take(limit)
+                .collect()),
+            None => Err(Error::NotFound(format!("No game with id: {}", game_id))),
         }
     }
 }
+
+///The ids of the given references, expanded or not
+fn ids<T: Identifiable>(references: &[IdOr<T>]) -> Vec<T::Id> {
+    references.iter().map(IdOr::id).collect()
+}
+
+#[test]
+fn similar_games_are_expanded() {
+    use crate::client::IGDBClient;
+    use crate::mock::MockTransport;
+
+    let mock = MockTransport::new()
+        .with_fixture(
+            "games",
+            "fields similar_games.*; where id = 1942; limit 1;",
+            r#"[{"id": 1942, "similar_games": [
+            {"id": 1877, "name": "Cyberpunk 2077", "similar_games": [1942]},
+            {"id": 472, "name": "The Elder Scrolls V: Skyrim"},
+            {"id": 119133, "name": "Elden Ring"}
+        ]}]"#,
+        )
+        .with_fixture(
+            "games",
+            "fields similar_games.*; where id = 1; limit 1;",
+            "[]",
+        );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock)
+        .without_rate_limit();
+
+    let similar = async_std::task::block_on(igdb.similar_to(1942, 2)).unwrap();
+    let names: Vec<&str> = similar.iter().map(|game| game.name.as_str()).collect();
+    assert_eq!(vec!["Cyberpunk 2077", "The Elder Scrolls V: Skyrim"], names);
+    assert_eq!(GameId(1942), similar[0].similar_games[0].id());
+    assert!(matches!(
+        async_std::task::block_on(igdb.similar_to(1, 2)),
+        Err(Error::NotFound(_))
+    ));
+}
@@ -0,0 +1,84 @@
+use crate::client::GameVersionsClient;
+use crate::model::game_version::GameVersion;
+use crate::model::ids::GameId;
+use crate::request_builder::{Equality, RequestBuilder};
+use crate::Error;
+
+impl GameVersionsClient {
+    ///Returns the editions of the given game along with their features and the values of
+    /// the features for each edition, None when the game has a single edition.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let versions_client = IGDBClient::new("client_id", "token").game_versions();
+    ///     let version = versions_client.get_comparison(1942).await.unwrap().unwrap();
+    ///
+    ///     for feature in version.features.iter().filter_map(|f| f.expanded()) {
+    ///         let editions = version.games.iter().map(|edition| feature.value_for(*edition));
+    ///         let values: Vec<_> = editions.map(|v| v.map(|v| v.included_feature)).collect();
+    ///         println!("{}: {:?}", feature.title, values);
+    ///     }
+    /// })
+    /// ```
+    pub async fn get_comparison<I: Into<GameId>>(
+        &self,
+        game_id: I,
+    ) -> Result<Option<GameVersion>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .add_fields(vec!["*", "features.*", "features.values.*"])
+            .add_where("game", Equality::Equal, game_id.into())
+            .limit(1);
+
+        Ok(self.get(request).await?.into_iter().next())
+    }
+}
+
+#[test]
+fn editions_are_compared_on_their_features() {
+    use crate::client::IGDBClient;
+    use crate::mock::MockTransport;
+    use crate::model::enums::IncludedFeature;
+
+    let mock = MockTransport::new().with_fixture(
+        "game_versions",
+        "fields *,features.*,features.values.*; where game = 1942; limit 1;",
+        r#"[{
+            "id": 7,
+            "game": 1942,
+            "games": [1942, 22439],
+            "features": [{
+                "id": 3,
+                "title": "Expansion Pass",
+                "values": [
+                    {"id": 30, "game": 1942, "game_feature": 3, "included_feature": 0},
+                    {"id": 31, "game": 22439, "game_feature": 3, "included_feature": 1}
+                ]
+            }]
+        }]"#,
+    );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock)
+        .without_rate_limit();
+
+    let version = async_std::task::block_on(igdb.game_versions().get_comparison(1942))
+        .unwrap()
+        .unwrap();
+    let feature = version.features[0].expanded().unwrap();
+    let included: Vec<Option<IncludedFeature>> = version
+        .games
+        .iter()
+        .map(|edition| feature.value_for(*edition).map(|v| v.included_feature))
+        .collect();
+    assert_eq!(
+        vec![
+            Some(IncludedFeature::NotIncluded),
+            Some(IncludedFeature::Included)
+        ],
+        included
+    );
+    assert!(feature.value_for(1).is_none());
+}
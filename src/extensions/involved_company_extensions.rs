@@ -0,0 +1,51 @@
+use crate::client::{CompaniesClient, InvolvedCompaniesClient};
+use crate::endpoint_client::MAX_LIMIT;
+use crate::endpoints::Endpoint;
+use crate::model::company::Company;
+use crate::model::ids::{CompanyId, GameId};
+use crate::model::involved_company::{CompanyRole, InvolvedCompany};
+use crate::request_builder::{Equality, RequestBuilder};
+use crate::Error;
+
+impl InvolvedCompaniesClient {
+    ///Returns the companies having the given role in the making of the given game,
+    /// resolving the involved companies and then the companies in two requests.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::involved_company::CompanyRole;
+    ///
+    /// task::block_on(async {
+    ///     let involved_companies_client = IGDBClient::new("client_id", "token").involved_companies();
+    ///     let publishers = involved_companies_client
+    ///         .get_companies_by_game_id(1942, CompanyRole::Publisher)
+    ///         .await
+    ///         .unwrap();
+    /// })
+    /// ```
+    pub async fn get_companies_by_game_id<I: Into<GameId>>(
+        &self,
+        game_id: I,
+        role: CompanyRole,
+    ) -> Result<Vec<Company>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .add_field("company")
+            .add_where("game", Equality::Equal, game_id.into())
+            .add_where(role.field(), Equality::Equal, true)
+            .limit(MAX_LIMIT);
+
+        let ids: Vec<CompanyId> = self
+            .get(request)
+            .await?
+            .iter()
+            .map(|involved: &InvolvedCompany| involved.company)
+            .collect();
+
+        self.endpoint_client
+            .with_endpoint(CompaniesClient::PATH)
+            .get_by_ids::<Company>(&ids)
+            .await
+    }
+}
@@ -1,3 +1,11 @@
+pub mod age_rating_extensions;
 pub mod character_extensions;
+pub mod collection_extensions;
+pub mod event_extensions;
+pub mod external_game_extensions;
 pub mod franchise_extensions;
 pub mod game_extensions;
+pub mod game_version_extensions;
+pub mod involved_company_extensions;
+pub mod platform_extensions;
+pub mod popularity_extensions;
@@ -0,0 +1,63 @@
+use crate::client::{PlatformLogosClient, PlatformVersionsClient, PlatformsClient};
+use crate::endpoints::Endpoint;
+use crate::model::ids::PlatformLogoId;
+use crate::model::platform::Platform;
+use crate::model::platform_logo::PlatformLogo;
+use crate::model::platform_version::PlatformVersion;
+use crate::request_builder::RequestBuilder;
+use crate::Error;
+
+impl PlatformsClient {
+    ///Returns a platforms collection containing the given name
+    pub async fn get_by_name<S: Into<String>>(
+        &self,
+        name: S,
+        limit: usize,
+    ) -> Result<Vec<Platform>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .all_fields()
+            .contains("name", &name.into())
+            .limit(limit);
+
+        self.get(request).await
+    }
+
+    ///Returns the versions of the given platform, e.g. the PlayStation 4 Pro for the PlayStation 4.
+    /// The platform must have been retrieved with the versions field.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let platforms_client = IGDBClient::new("client_id", "token").platforms();
+    ///     let ps4 = platforms_client.get_first_by_id(48).await.unwrap();
+    ///
+    ///     for version in platforms_client.get_versions(&ps4).await.unwrap() {
+    ///         println!("{}: {}", version.name, version.cpu);
+    ///     }
+    /// })
+    /// ```
+    pub async fn get_versions(&self, platform: &Platform) -> Result<Vec<PlatformVersion>, Error> {
+        self.endpoint_client
+            .with_endpoint(PlatformVersionsClient::PATH)
+            .get_by_ids::<PlatformVersion>(&platform.versions)
+            .await
+    }
+
+    ///Returns the logo of the given platform, None when it has none.
+    /// The platform must have been retrieved with the platform_logo field.
+    pub async fn get_logo(&self, platform: &Platform) -> Result<Option<PlatformLogo>, Error> {
+        match platform.platform_logo {
+            PlatformLogoId(0) => Ok(None),
+            id => Ok(self
+                .endpoint_client
+                .with_endpoint(PlatformLogosClient::PATH)
+                .get_by_ids::<PlatformLogo>(&[id])
+                .await?
+                .into_iter()
+                .next()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::client::{GamesClient, PopularityPrimitivesClient};
+use crate::endpoints::Endpoint;
+use crate::model::games::Game;
+use crate::model::ids::{GameId, PopularityTypeId};
+use crate::model::popularity_primitive::PopularityPrimitive;
+use crate::request_builder::{Equality, OrderBy, RequestBuilder};
+use crate::Error;
+
+impl PopularityPrimitivesClient {
+    ///Returns the most popular games for the given popularity type, most popular first,
+    /// resolving the popularity primitives and then the games in two requests.
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let primitives_client = IGDBClient::new("client_id", "token").popularity_primitives();
+    ///     // Most visited games on IGDB
+    ///     for game in primitives_client.get_trending_games(1, 10).await.unwrap() {
+    ///         println!("{}", game.name);
+    ///     }
+    /// })
+    /// ```
+    pub async fn get_trending_games<I: Into<PopularityTypeId>>(
+        &self,
+        popularity_type: I,
+        limit: usize,
+    ) -> Result<Vec<Game>, Error> {
+        let mut request = RequestBuilder::new();
+        request
+            .add_fields(vec!["game_id", "value"])
+            .add_where("popularity_type", Equality::Equal, popularity_type.into())
+            .sort_by("value", OrderBy::Descending)
+            .limit(limit);
+
+        let ids: Vec<GameId> = self
+            .get(request)
+            .await?
+            .iter()
+            .map(|primitive: &PopularityPrimitive| primitive.game_id)
+            .collect();
+
+        let games = self
+            .endpoint_client
+            .with_endpoint(GamesClient::PATH)
+            .get_by_ids::<Game>(&ids)
+            .await?;
+
+        Ok(sort_by_ids(games, &ids))
+    }
+}
+
+///Sorts the games in the order of the given ids, dropping the ones not found
+fn sort_by_ids(mut games: Vec<Game>, ids: &[GameId]) -> Vec<Game> {
+    ids.iter()
+        .filter_map(|id| {
+            let position = games.iter().position(|game| game.id == *id)?;
+            Some(games.swap_remove(position))
+        })
+        .collect()
+}
+
+#[test]
+fn games_are_sorted_by_popularity() {
+    let game =
+        |id: usize| -> Game { serde_json::from_str(&format!(r#"{{"id": {}}}"#, id)).unwrap() };
+
+    let games = sort_by_ids(
+        vec![game(1), game(2), game(3)],
+        &[GameId(3), GameId(4), GameId(1), GameId(2)],
+    );
+
+    let ids: Vec<GameId> = games.iter().map(|game| game.id).collect();
+    assert_eq!(vec![GameId(3), GameId(1), GameId(2)], ids);
+}
@@ -0,0 +1,65 @@
+//! Models generated at build time from IGDB's protobuf schema, with the generated-models feature.
+//!
+//! A module is generated per message of proto/igdbapi.proto, e.g. `game` for Game, holding
+//! the model, its field names and, for the registries served by an endpoint, an Endpoint to
+//! query them with, e.g. `game::GameEndpoint`. Building with IGDB_PROTO pointing to a copy of
+//! https://api.igdb.com/v4/igdbapi.proto generates the models of the current schema instead
+//! of the vendored one, without waiting for the handwritten models in model to follow it.
+//!
+//! The fields are typed after the schema: enums are read as their integer values, timestamps
+//! as seconds since the unix epoch, and references to other registries as IdOr.
+//! # Examples
+//! ```no_run
+//! use async_std::task;
+//! use igdb::client::IGDBClient;
+//! use igdb::generated::game::{fields, GameEndpoint};
+//!
+//! task::block_on(async {
+//!     let igdb = IGDBClient::new("client_id", "token");
+//!     let mut request = IGDBClient::create_request();
+//!     request.add_fields([fields::NAME, "cover.image_id"]).search("Zelda");
+//!
+//!     for game in igdb.query::<GameEndpoint>(&request).await.unwrap() {
+//!         println!("{}: {:?}", game.name, game.cover.expanded().map(|c| &c.image_id));
+//!     }
+//! })
+//! ```
+
+use crate::endpoints::Endpoint;
+use crate::model::id_or::{IdOr, Identifiable};
+
+include!(concat!(env!("OUT_DIR"), "/generated_models.rs"));
+
+#[test]
+fn generated_models_follow_the_schema() {
+    use crate::model::ids::EntityId;
+
+    let game: game::Game = serde_json::from_str(
+        r#"{
+            "id": 1942,
+            "name": "The Witcher 3: Wild Hunt",
+            "category": 0,
+            "first_release_date": 1431993600,
+            "cover": {"id": 89386, "image_id": "co1wyy"},
+            "parent_game": 1941,
+            "genres": [12, 31]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!("The Witcher 3: Wild Hunt", game.name);
+    assert_eq!(1431993600, game.first_release_date);
+    assert_eq!(
+        Some("co1wyy"),
+        game.cover.expanded().map(|c| c.image_id.as_str())
+    );
+    assert_eq!(1941, game.parent_game.id().value());
+    assert_eq!(
+        vec![12, 31],
+        game.genres.iter().map(IdOr::id).collect::<Vec<u64>>()
+    );
+    assert_eq!("first_release_date", game::fields::FIRST_RELEASE_DATE);
+    assert_eq!("games", game::GameEndpoint::PATH);
+    assert_eq!("game_engines", game_engine::GameEngineEndpoint::PATH);
+    assert_eq!("companies", company::CompanyEndpoint::PATH);
+}
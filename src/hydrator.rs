@@ -0,0 +1,201 @@
+use crate::client::{CoversClient, GamesClient, GenresClient, InvolvedCompaniesClient};
+use crate::client::{PlatformsClient, ThemesClient};
+use crate::endpoint_client::EndpointClient;
+use crate::endpoints::Endpoint;
+use crate::model::games::Game;
+use crate::model::id_or::{IdOr, Identifiable};
+use crate::model::ids::EntityId;
+use crate::request_builder::RequestBuilder;
+use crate::Error;
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeSet, HashMap};
+
+///A reference field of Game which can be resolved by a GameHydrator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ref {
+    Cover,
+    Genres,
+    InvolvedCompanies,
+    Platforms,
+    Themes,
+}
+
+///Resolves the reference ids of queried games into their objects, sending one batched
+/// request per reference type for the whole result set, see GamesClient::hydrate
+pub struct GameHydrator<'a> {
+    client: &'a GamesClient,
+    refs: Vec<Ref>,
+}
+
+impl GamesClient {
+    ///Returns a GameHydrator resolving the given references of the queried games
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::hydrator::Ref;
+    ///
+    /// task::block_on(async {
+    ///     let games_client = IGDBClient::new("client_id", "token").games();
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.all_fields().search("Zelda").limit(20);
+    ///
+    ///     let games = games_client
+    ///         .hydrate(&[Ref::Genres, Ref::Cover])
+    ///         .query(request)
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     for game in games {
+    ///         let genres: Vec<String> = game
+    ///             .genres
+    ///             .iter()
+    ///             .filter_map(|genre| genre.expanded())
+    ///             .map(|genre| genre.name.clone())
+    ///             .collect();
+    ///         println!("{}: {:?}", game.name, genres);
+    ///     }
+    /// })
+    /// ```
+    pub fn hydrate(&self, refs: &[Ref]) -> GameHydrator<'_> {
+        GameHydrator {
+            client: self,
+            refs: refs.to_vec(),
+        }
+    }
+}
+
+impl GameHydrator<'_> {
+    ///Queries the games and resolves their references
+    pub async fn query(&self, request_builder: RequestBuilder) -> Result<Vec<Game>, Error> {
+        let mut games = self.client.get(request_builder).await?;
+        self.resolve(&mut games).await?;
+        Ok(games)
+    }
+
+    ///Resolves the references of already retrieved games
+    pub async fn resolve(&self, games: &mut [Game]) -> Result<(), Error> {
+        let client = &self.client.endpoint_client;
+
+        for reference in &self.refs {
+            match reference {
+                Ref::Cover => {
+                    let covers = games.iter_mut().map(|game| &mut game.cover).collect();
+                    resolve(client, CoversClient::PATH, covers).await?
+                }
+                Ref::Genres => {
+                    let genres = games.iter_mut().flat_map(|game| &mut game.genres).collect();
+                    resolve(client, GenresClient::PATH, genres).await?
+                }
+                Ref::InvolvedCompanies => {
+                    let involved = games
+                        .iter_mut()
+                        .flat_map(|game| &mut game.involved_companies)
+                        .collect();
+                    resolve(client, InvolvedCompaniesClient::PATH, involved).await?
+                }
+                Ref::Platforms => {
+                    let platforms = games
+                        .iter_mut()
+                        .flat_map(|game| &mut game.platforms)
+                        .collect();
+                    resolve(client, PlatformsClient::PATH, platforms).await?
+                }
+                Ref::Themes => {
+                    let themes = games.iter_mut().flat_map(|game| &mut game.themes).collect();
+                    resolve(client, ThemesClient::PATH, themes).await?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+///Fetches every distinct unresolved id in a single batch and replaces the references
+async fn resolve<T>(
+    client: &EndpointClient,
+    path: &'static str,
+    references: Vec<&mut IdOr<T>>,
+) -> Result<(), Error>
+where
+    T: DeserializeOwned + Identifiable + Clone,
+{
+    let ids = unresolved_ids(&references);
+    if ids.is_empty() {
+        return Ok(());
+    }
+
+    let objects: HashMap<T::Id, T> = client
+        .with_endpoint(path)
+        .get_by_ids::<T>(&ids)
+        .await?
+        .into_iter()
+        .map(|object| (object.id(), object))
+        .collect();
+
+    replace(references, &objects);
+    Ok(())
+}
+
+///The distinct non-zero ids of the references not expanded yet
+fn unresolved_ids<T: Identifiable>(references: &[&mut IdOr<T>]) -> Vec<T::Id> {
+    references
+        .iter()
+        .filter_map(|reference| match reference {
+            IdOr::Id(id) if id.value() != 0 => Some(*id),
+            _ => None,
+        })
+        .collect::<BTreeSet<T::Id>>()
+        .into_iter()
+        .collect()
+}
+
+///Replaces the ids found among the given objects, unknown ids are left as they are
+fn replace<T: Identifiable + Clone>(references: Vec<&mut IdOr<T>>, objects: &HashMap<T::Id, T>) {
+    for reference in references {
+        if let IdOr::Id(id) = reference {
+            if let Some(object) = objects.get(id) {
+                *reference = IdOr::Object(object.clone());
+            }
+        }
+    }
+}
+
+#[test]
+fn unresolved_ids_are_deduplicated() {
+    use crate::model::genre::Genre;
+    use crate::model::ids::GenreId;
+
+    let mut games: Vec<Game> = serde_json::from_str(
+        r#"[
+            {"id": 1, "genres": [12, 31]},
+            {"id": 2, "genres": [31, {"id": 5, "name": "Shooter"}]},
+            {"id": 3}
+        ]"#,
+    )
+    .unwrap();
+
+    let references: Vec<&mut IdOr<Genre>> =
+        games.iter_mut().flat_map(|game| &mut game.genres).collect();
+    assert_eq!(vec![GenreId(12), GenreId(31)], unresolved_ids(&references));
+
+    let objects: HashMap<GenreId, Genre> = serde_json::from_str::<Vec<Genre>>(
+        r#"[{"id": 12, "name": "Role-playing (RPG)"}, {"id": 31, "name": "Adventure"}]"#,
+    )
+    .unwrap()
+    .into_iter()
+    .map(|genre| (genre.id, genre))
+    .collect();
+    replace(references, &objects);
+
+    let names: Vec<&str> = games
+        .iter()
+        .flat_map(|game| &game.genres)
+        .filter_map(|genre| genre.expanded())
+        .map(|genre| genre.name.as_str())
+        .collect();
+    assert_eq!(
+        vec!["Role-playing (RPG)", "Adventure", "Adventure", "Shooter"],
+        names
+    );
+}
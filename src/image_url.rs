@@ -0,0 +1,111 @@
+use crate::media_quality::MediaQuality;
+use crate::model::artwork::Artwork;
+use crate::model::character_mug_shot::CharacterMugshot;
+use crate::model::cover::Cover;
+use crate::model::game_engine_logo::GameEngineLogo;
+use crate::model::platform_logo::PlatformLogo;
+use crate::model::screenshot::Screenshot;
+use std::fmt;
+
+const IMAGES_URL: &str = "https://images.igdb.com/igdb/image/upload";
+
+///Builds the url of an IGDB image from its image_id, with the requested size preset.
+/// # Examples
+/// ```
+/// use igdb::image_url::ImageUrl;
+/// use igdb::media_quality::MediaQuality;
+///
+/// let url = ImageUrl::new("co1wyy", MediaQuality::CoverBig);
+/// assert_eq!(
+///     "https://images.igdb.com/igdb/image/upload/t_cover_big/co1wyy.jpg",
+///     url.to_string()
+/// );
+///
+/// let url = ImageUrl::new("co1wyy", MediaQuality::CoverBig).retina().webp();
+/// assert_eq!(
+///     "https://images.igdb.com/igdb/image/upload/t_cover_big_2x/co1wyy.webp",
+///     url.to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageUrl {
+    image_id: String,
+    quality: MediaQuality,
+    retina: bool,
+    webp: bool,
+}
+
+impl ImageUrl {
+    pub fn new<S: Into<String>>(image_id: S, quality: MediaQuality) -> ImageUrl {
+        ImageUrl {
+            image_id: image_id.into(),
+            quality,
+            retina: false,
+            webp: false,
+        }
+    }
+
+    /// Requests the image at twice the size of the preset, for high density screens
+    pub fn retina(mut self) -> ImageUrl {
+        self.retina = true;
+        self
+    }
+
+    /// Requests the image in webp format instead of jpg
+    pub fn webp(mut self) -> ImageUrl {
+        self.webp = true;
+        self
+    }
+}
+
+impl fmt::Display for ImageUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/t_{}{}/{}.{}",
+            IMAGES_URL,
+            self.quality.get_value(),
+            if self.retina { "_2x" } else { "" },
+            self.image_id,
+            if self.webp { "webp" } else { "jpg" }
+        )
+    }
+}
+
+expand_image_url!(Cover);
+expand_image_url!(Screenshot);
+expand_image_url!(Artwork);
+expand_image_url!(PlatformLogo);
+expand_image_url!(GameEngineLogo);
+expand_image_url!(CharacterMugshot);
+
+#[test]
+fn image_url_uses_preset_and_variants() {
+    let url = ImageUrl::new("sc6lsn", MediaQuality::FullHD);
+    assert_eq!(
+        "https://images.igdb.com/igdb/image/upload/t_1080p/sc6lsn.jpg",
+        url.to_string()
+    );
+    assert_eq!(
+        "https://images.igdb.com/igdb/image/upload/t_1080p/sc6lsn.webp",
+        url.clone().webp().to_string()
+    );
+    assert_eq!(
+        "https://images.igdb.com/igdb/image/upload/t_1080p_2x/sc6lsn.jpg",
+        url.retina().to_string()
+    );
+}
+
+#[test]
+fn artwork_deserializes_with_its_image_url() {
+    let artwork: Artwork = serde_json::from_str(
+        r#"{"id": 5, "animated": false, "game": 1942, "height": 1080, "width": 1920, "image_id": "ar5l8"}"#,
+    )
+    .unwrap();
+
+    assert_eq!((1920, 1080), (artwork.width, artwork.height));
+    assert_eq!(
+        "https://images.igdb.com/igdb/image/upload/t_screenshot_big/ar5l8.jpg",
+        artwork.image_url(MediaQuality::ScreenshotBig).to_string()
+    );
+}
@@ -0,0 +1,94 @@
+//! How the numbers of the json returned without a model are written, see
+//! IGDBClient::with_json_numbers.
+//!
+//! IGDB ids and counters are integers up to u64 and its ratings are decimals: read as is,
+//! the ids beyond 2^53 cannot be held exactly by consumers reading every number as an f64,
+//! e.g. JavaScript. The models are not affected, their ids and counters are u64 and their
+//! timestamps i64, negative before 1970.
+
+use serde_json::{Number, Value};
+
+///Largest integer an f64 holds exactly, along with every smaller one
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+///How the numbers of the json returned by IGDBClient::query_raw, IGDBClient::page_raw,
+/// IGDBClient::batch, IGDBClient::multiquery and IGDBClient::export are written
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonNumbers {
+    ///As read by serde_json: integers exact up to u64 and i64, decimals rounded to an f64
+    #[default]
+    Native,
+    ///Like Native, but the integers an f64 cannot hold exactly are written as strings
+    Safe,
+    ///As written by IGDB, whatever their size and precision, with the `arbitrary-precision`
+    /// feature enabling the `arbitrary_precision` feature of serde_json
+    #[cfg(feature = "arbitrary-precision")]
+    Exact,
+}
+
+impl JsonNumbers {
+    ///Whether the numbers are written as read by serde_json, without a pass over them
+    pub(crate) fn is_as_read(self) -> bool {
+        // With arbitrary_precision, serde_json keeps the numbers as written
+        self == JsonNumbers::Native && !cfg!(feature = "arbitrary-precision")
+    }
+
+    ///Rewrites the numbers of the value in place
+    pub(crate) fn apply(self, value: &mut Value) {
+        match value {
+            Value::Array(values) => values.iter_mut().for_each(|value| self.apply(value)),
+            Value::Object(fields) => fields.values_mut().for_each(|value| self.apply(value)),
+            Value::Number(number) => {
+                if let Some(rewritten) = self.rewrite(number) {
+                    *value = rewritten;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn rewrite(self, number: &Number) -> Option<Value> {
+        let is_unsafe = match (number.as_u64(), number.as_i64()) {
+            (Some(integer), _) => integer > MAX_SAFE_INTEGER,
+            (_, Some(integer)) => integer.unsigned_abs() > MAX_SAFE_INTEGER,
+            // Integers beyond u64 are only read with arbitrary_precision
+            _ => !number.to_string().contains(['.', 'e', 'E']),
+        };
+        match self {
+            JsonNumbers::Safe if is_unsafe => Some(Value::String(number.to_string())),
+            JsonNumbers::Native | JsonNumbers::Safe => native(number),
+            #[cfg(feature = "arbitrary-precision")]
+            JsonNumbers::Exact => None,
+        }
+    }
+}
+
+///The number as serde_json reads it without arbitrary_precision, None when it is already
+#[cfg(feature = "arbitrary-precision")]
+fn native(number: &Number) -> Option<Value> {
+    match (number.as_u64(), number.as_i64()) {
+        (Some(integer), _) => Some(Value::from(integer)),
+        (_, Some(integer)) => Some(Value::from(integer)),
+        _ => number
+            .as_f64()
+            .and_then(Number::from_f64)
+            .map(Value::Number),
+    }
+}
+
+#[cfg(not(feature = "arbitrary-precision"))]
+fn native(_: &Number) -> Option<Value> {
+    None
+}
+
+#[test]
+fn safe_numbers_write_the_large_integers_as_strings() {
+    let mut value: Value =
+        serde_json::from_str(r#"[{"id": 9007199254740993, "rating": 85.5, "count": -3}]"#).unwrap();
+
+    JsonNumbers::Safe.apply(&mut value);
+    assert_eq!(
+        r#"[{"count":-3,"id":"9007199254740993","rating":85.5}]"#,
+        value.to_string()
+    );
+}
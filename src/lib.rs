@@ -101,7 +101,7 @@
 //!
 //!        for release in releases {
 //!            let platform = platform_client
-//!                .get_first_by_id(release.platform as usize)
+//!                .get_first_by_id(release.platform)
 //!                .await
 //!                .unwrap();
 //!
@@ -171,20 +171,89 @@
 #[macro_use]
 extern crate serde_derive;
 
-type Error = Box<dyn std::error::Error + Send + Sync + 'static>;
-
+mod circuit_breaker;
+mod compression;
 mod endpoint_client;
-mod endpoints;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod model_fields;
+mod name_cache;
+#[cfg(feature = "protobuf")]
+mod protobuf;
+mod query_parser;
+mod retry;
+mod single_flight;
+mod time;
 
 #[macro_use]
 mod client_macros;
 #[macro_use]
 mod media_macros;
+#[macro_use]
+mod query_macros;
 
+pub mod auth;
+pub mod batch;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+pub mod budget;
+pub mod canonical;
 pub mod client;
+pub mod client_builder;
+pub mod cursor;
+pub mod endpoints;
+pub mod error;
+pub mod export;
+pub mod expr;
 pub mod extensions;
+#[cfg(feature = "generated-models")]
+pub mod generated;
+pub mod hydrator;
+pub mod image_url;
+pub mod json_numbers;
+pub mod limits;
+pub mod loader;
 pub mod media_helpers;
 pub mod media_quality;
+pub mod middleware;
+pub mod mirror;
+pub mod mock;
 pub mod model;
+pub mod multiquery;
+#[cfg(feature = "serialize")]
+pub mod naming;
+pub mod page;
+pub mod pipeline;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod prefetch;
+pub mod prelude;
+pub mod query_lint;
+pub mod query_plan;
+pub mod query_spec;
+pub mod query_template;
+pub mod rate_limiter;
 pub mod request_builder;
 pub mod request_filters;
+pub mod request_options;
+pub mod response;
+pub mod response_cache;
+pub mod schema;
+pub mod search;
+pub mod signing;
+pub mod sortkey;
+pub mod sync;
+pub mod timestamp;
+pub mod title_match;
+pub mod token_store;
+pub mod transport;
+#[cfg(all(feature = "vcr", not(target_arch = "wasm32")))]
+pub mod vcr;
+pub mod watch;
+pub mod webhooks;
+
+pub use crate::auth::Credentials;
+pub use crate::client::IGDBClient as Client;
+pub use crate::client_builder::ClientBuilder;
+pub use crate::error::Error;
+pub use crate::request_options::RequestOptions;
+pub use crate::transport::HttpTransport;
@@ -0,0 +1,187 @@
+//! What is left of the request limits of a client, see IGDBClient::limits.
+//!
+//! IGDB documents a rate limit of 4 requests per second but no quota headers. The view is
+//! thus mostly synthetic, built from the client's own rate limiter and budget, along with
+//! the rate limit headers of the last response carrying some, e.g. from a proxy in front of
+//! IGDB, or should IGDB start sending them.
+
+use crate::budget::{Budget, BudgetUsage};
+use crate::rate_limiter::ThrottleState;
+use crate::retry::retry_after;
+use crate::time::SystemTime;
+use http::HeaderMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+///Headers of the limit of requests of the current window, e.g. `x-ratelimit-limit`
+const LIMIT_HEADERS: [&str; 2] = ["x-ratelimit-limit", "ratelimit-limit"];
+///Headers of the requests left in the current window
+const REMAINING_HEADERS: [&str; 2] = ["x-ratelimit-remaining", "ratelimit-remaining"];
+///Headers of the seconds until the window resets
+const RESET_HEADERS: [&str; 2] = ["x-ratelimit-reset", "ratelimit-reset"];
+
+///Rate limit headers of a response
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReportedLimits {
+    ///Requests allowed in the current window
+    pub limit: Option<u64>,
+    ///Requests left in the current window
+    pub remaining: Option<u64>,
+    ///Time until the window resets, from when the response was received
+    pub reset: Option<Duration>,
+    ///Time to wait before the next request, from a Retry-After header
+    pub retry_after: Option<Duration>,
+    pub received_at: SystemTime,
+}
+
+impl ReportedLimits {
+    ///Reads the headers, None when the response has no rate limit header
+    fn read(headers: &HeaderMap, received_at: SystemTime) -> Option<ReportedLimits> {
+        let number = |names: [&str; 2]| {
+            names
+                .iter()
+                .filter_map(|name| headers.get(*name)?.to_str().ok()?.trim().parse().ok())
+                .next()
+        };
+        let limits = ReportedLimits {
+            limit: number(LIMIT_HEADERS),
+            remaining: number(REMAINING_HEADERS),
+            reset: number(RESET_HEADERS).map(Duration::from_secs),
+            retry_after: retry_after(headers),
+            received_at,
+        };
+        match (
+            limits.limit,
+            limits.remaining,
+            limits.reset,
+            limits.retry_after,
+        ) {
+            (None, None, None, None) => None,
+            _ => Some(limits),
+        }
+    }
+}
+
+///Limits of the requests of a client and what is left of them
+#[derive(Clone, Debug, PartialEq)]
+pub struct Limits {
+    ///How fast requests are currently sent, None when the rate is not limited
+    pub throttle: Option<ThrottleState>,
+    ///Rate limit headers of the last response carrying some
+    pub reported: Option<ReportedLimits>,
+    ///What was spent of the budget, None when requests are not limited
+    pub budget_usage: Option<BudgetUsage>,
+    budget: Option<Budget>,
+}
+
+impl Limits {
+    ///Requests which can still be sent before a limit is reached: the fewest of the ones
+    /// left by the budget and by the reported window. None when neither is limited
+    pub fn remaining_requests(&self) -> Option<u64> {
+        let budget = self
+            .budget
+            .zip(self.budget_usage)
+            .and_then(|(budget, usage)| {
+                budget
+                    .max_requests
+                    .map(|max_requests| max_requests.saturating_sub(usage.requests))
+            });
+        let reported = self.reported.and_then(|reported| reported.remaining);
+        match (budget, reported) {
+            (Some(budget), Some(reported)) => Some(budget.min(reported)),
+            (budget, reported) => budget.or(reported),
+        }
+    }
+
+    ///Whether requests are currently delayed, by the throttling of the rate limiter or
+    /// because IGDB asked to retry later
+    pub fn is_throttled(&self) -> bool {
+        let asked_to_wait = self.reported.is_some_and(|reported| {
+            reported.retry_after.is_some_and(|retry_after| {
+                SystemTime::now()
+                    .duration_since(reported.received_at)
+                    .map_or(true, |elapsed| elapsed < retry_after)
+            })
+        });
+        asked_to_wait || self.throttle.is_some_and(|state| state.is_throttled())
+    }
+
+    pub(crate) fn new(
+        throttle: Option<ThrottleState>,
+        reported: Option<ReportedLimits>,
+        budget: Option<(Budget, BudgetUsage)>,
+    ) -> Limits {
+        Limits {
+            throttle,
+            reported,
+            budget_usage: budget.map(|(_, usage)| usage),
+            budget: budget.map(|(budget, _)| budget),
+        }
+    }
+}
+
+///Keeps the rate limit headers of the last response carrying some, shared by every
+/// endpoint client created from the same IGDBClient
+#[derive(Default)]
+pub(crate) struct LimitHeaders {
+    last: Mutex<Option<ReportedLimits>>,
+}
+
+impl LimitHeaders {
+    pub(crate) fn record(&self, headers: &HeaderMap) {
+        if let Some(limits) = ReportedLimits::read(headers, SystemTime::now()) {
+            *self.last.lock().unwrap() = Some(limits);
+        }
+    }
+
+    pub(crate) fn last(&self) -> Option<ReportedLimits> {
+        *self.last.lock().unwrap()
+    }
+}
+
+#[test]
+fn limits_are_read_from_the_last_response_and_the_budget() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::transport::{HttpTransport, TransportFuture};
+
+    ///Answers a genre, with the requests left in the window
+    struct Quota;
+
+    impl HttpTransport for Quota {
+        fn send(&self, _: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            Box::pin(async move {
+                Ok(http::Response::builder()
+                    .header("x-ratelimit-limit", "100")
+                    .header("x-ratelimit-remaining", "42")
+                    .header("x-ratelimit-reset", "30")
+                    .body(br#"[{"id":5}]"#.to_vec())
+                    .unwrap())
+            })
+        }
+    }
+
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(Quota)
+        .with_budget(Budget::new().max_requests(10));
+    let limits = igdb.limits();
+    assert_eq!(None, limits.reported);
+    assert_eq!(Some(10), limits.remaining_requests());
+    assert!(!limits.is_throttled());
+
+    async_std::task::block_on(igdb.query::<GenresClient>(&IGDBClient::create_request())).unwrap();
+    let limits = igdb.limits();
+    let reported = limits.reported.unwrap();
+    assert_eq!(
+        (Some(100), Some(42), Some(Duration::from_secs(30)), None),
+        (
+            reported.limit,
+            reported.remaining,
+            reported.reset,
+            reported.retry_after
+        )
+    );
+    assert_eq!(Some(9), limits.remaining_requests());
+
+    let unlimited = Limits::new(None, Some(reported), None);
+    assert_eq!(Some(42), unlimited.remaining_requests());
+}
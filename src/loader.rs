@@ -0,0 +1,182 @@
+//! Batching of the by-id lookups made while rendering lists of related entities,
+//! see IGDBClient::loader.
+
+use crate::endpoint_client::EndpointClient;
+use crate::endpoints::Endpoint;
+use crate::model::id_or::Identifiable;
+use crate::Error;
+use futures::channel::oneshot;
+use futures::future::{self, Either};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+///Time a lookup waits for others to be batched with, see Loader::with_window
+pub(crate) const DEFAULT_WINDOW: Duration = Duration::from_millis(10);
+
+type Waiter<T> = (
+    <T as Identifiable>::Id,
+    oneshot::Sender<Result<Option<T>, Error>>,
+);
+
+///Coalesces the lookups by id made within a small time window into a single
+/// `where id = (...)` query, and hands each caller the registry it asked for.
+/// Rendering a list of games then sends one request per related endpoint
+/// instead of one per game.
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use futures::future::try_join_all;
+/// use igdb::client::{GenresClient, IGDBClient};
+///
+/// task::block_on(async {
+///     let igdb = IGDBClient::new("client_id", "token");
+///     let genres = igdb.loader::<GenresClient>();
+///
+///     // A single request is sent for the three genres
+///     let names = try_join_all([5, 12, 31].map(|id| genres.load(id))).await.unwrap();
+///     for genre in names.into_iter().flatten() {
+///         println!("{}", genre.name);
+///     }
+/// })
+/// ```
+pub struct Loader<E: Endpoint>
+where
+    E::Model: Identifiable,
+{
+    client: EndpointClient,
+    window: Duration,
+    pending: Mutex<Vec<Waiter<E::Model>>>,
+}
+
+impl<E: Endpoint> Loader<E>
+where
+    E::Model: Identifiable + Clone,
+{
+    pub(crate) fn new(client: EndpointClient) -> Loader<E> {
+        Loader {
+            client,
+            window: DEFAULT_WINDOW,
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    ///Sets the time a lookup waits for others to be batched with, 10ms by default
+    pub fn with_window(mut self, window: Duration) -> Loader<E> {
+        self.window = window;
+        self
+    }
+
+    ///Returns the registry with the given id, None when it does not exist
+    pub async fn load<I: Into<<E::Model as Identifiable>::Id>>(
+        &self,
+        id: I,
+    ) -> Result<Option<E::Model>, Error> {
+        let (sender, receiver) = oneshot::channel();
+        self.waiters().push((id.into(), sender));
+
+        // Whichever lookup of the batch waited the whole window first sends it
+        let timer = Box::pin(async_std::task::sleep(self.window));
+        let receiver = match future::select(receiver, timer).await {
+            Either::Left((loaded, _)) => return loaded.unwrap_or_else(|_| Err(cancelled())),
+            Either::Right((_, receiver)) => receiver,
+        };
+        let batch = std::mem::take(&mut *self.waiters());
+        if !batch.is_empty() {
+            self.send(batch).await;
+        }
+        receiver.await.unwrap_or_else(|_| Err(cancelled()))
+    }
+
+    ///Returns the registries with the given ids, in the same batch, skipping the missing ones
+    pub async fn load_many(
+        &self,
+        ids: &[<E::Model as Identifiable>::Id],
+    ) -> Result<Vec<E::Model>, Error> {
+        let loaded = future::try_join_all(ids.iter().map(|id| self.load(*id))).await?;
+        Ok(loaded.into_iter().flatten().collect())
+    }
+
+    async fn send(&self, batch: Vec<Waiter<E::Model>>) {
+        let ids: Vec<_> = batch
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        match self.client.get_by_ids::<E::Model>(&ids).await {
+            Ok(registries) => {
+                let registries: HashMap<_, _> = registries
+                    .into_iter()
+                    .map(|registry| (registry.id(), registry))
+                    .collect();
+                for (id, waiter) in batch {
+                    let _ = waiter.send(Ok(registries.get(&id).cloned()));
+                }
+            }
+            Err(e) => {
+                for (_, waiter) in batch {
+                    let _ = waiter.send(Err(share(&e)));
+                }
+            }
+        }
+    }
+
+    fn waiters(&self) -> std::sync::MutexGuard<'_, Vec<Waiter<E::Model>>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+///The lookup sending the batch was dropped before it was answered
+fn cancelled() -> Error {
+    Error::Http("the batched request was cancelled".into())
+}
+
+///Copies the error of a batch for each of its lookups, as text when it cannot be cloned
+fn share(e: &Error) -> Error {
+    match e {
+        Error::InvalidQuery(message) => Error::InvalidQuery(message.clone()),
+        Error::InvalidConfig(message) => Error::InvalidConfig(message.clone()),
+        Error::RateLimited { retry_after } => Error::RateLimited {
+            retry_after: *retry_after,
+        },
+        Error::CircuitOpen { retry_after } => Error::CircuitOpen {
+            retry_after: *retry_after,
+        },
+        Error::BudgetExceeded(usage) => Error::BudgetExceeded(*usage),
+        Error::Unauthorized => Error::Unauthorized,
+        Error::Api { status, message } => Error::Api {
+            status: *status,
+            message: message.clone(),
+        },
+        Error::NotFound(message) => Error::NotFound(message.clone()),
+        e => Error::Http(e.to_string().into()),
+    }
+}
+
+#[test]
+fn loader_batches_concurrent_lookups() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+    use crate::model::ids::GenreId;
+
+    // Answers the genres 5 and 12 whatever the request
+    let scripted =
+        ScriptedTransport::new().then(200, r#"[{"id":5,"name":"Shooter"},{"id":12,"name":"RPG"}]"#);
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(scripted.clone())
+        .without_rate_limit();
+    let genres = igdb.loader::<GenresClient>();
+
+    let loaded = async_std::task::block_on(future::join3(
+        genres.load(5),
+        genres.load(12),
+        genres.load(GenreId(404)),
+    ));
+
+    assert_eq!("Shooter", loaded.0.unwrap().unwrap().name);
+    assert_eq!("RPG", loaded.1.unwrap().unwrap().name);
+    assert!(loaded.2.unwrap().is_none());
+    assert_eq!(1, scripted.sent());
+}
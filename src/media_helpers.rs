@@ -1,10 +1,15 @@
+use crate::endpoint_client::ClientConfig;
+use crate::error::ensure_success;
+use crate::image_url::ImageUrl;
 use crate::media_quality::MediaQuality;
 use crate::Error;
 
 #[allow(dead_code)]
+#[cfg(not(target_arch = "wasm32"))]
 ///This function receives a path, an IGDB provided url and it normalices the path and downloads
 /// the resource to the specified path file using the specified MediaQuality"
 pub(crate) async fn download_resource(
+    config: &ClientConfig,
     path: String,
     url: String,
     quality: MediaQuality,
@@ -15,7 +20,7 @@ pub(crate) async fn download_resource(
     let parsed_url = parse_url(url, quality);
     log::debug!("Downloading resource: {}", parsed_url);
 
-    let content = reqwest::get(parsed_url).await?.bytes().await?;
+    let content = fetch(config, parsed_url).await?;
 
     let mut file = File::create(path).await?;
     file.write(&content[..]).await?;
@@ -26,19 +31,86 @@ pub(crate) async fn download_resource(
 ///This function receives a path, an IGDB provided url and it normalices the path and
 ///returns the resource content in bytes
 pub(crate) async fn get_resource<S: Into<String>>(
+    config: &ClientConfig,
     url: S,
     quality: MediaQuality,
 ) -> Result<Vec<u8>, Error> {
-    let parsed_url = parse_url(url, quality);
-    let contents = reqwest::get(parsed_url).await?.bytes().await?.to_vec();
-    Ok(contents)
+    fetch(config, parse_url(url, quality)).await
+}
+
+///This function receives an IGDB provided url and returns the resource content in bytes
+/// without changing the requested quality
+pub(crate) async fn get_resource_as_is<S: Into<String>>(
+    config: &ClientConfig,
+    url: S,
+) -> Result<Vec<u8>, Error> {
+    fetch(config, normalize_url(url)).await
+}
+
+async fn fetch(config: &ClientConfig, url: String) -> Result<Vec<u8>, Error> {
+    let request = http::Request::get(url).body(Vec::new())?;
+    Ok(ensure_success(config.fetch(request, false).await?)?.into_body())
+}
+
+///Downloads an image from IGDB's CDN, retrying it like the queries and failing when
+/// something else than an image is answered, e.g. the html page of a proxy
+pub(crate) async fn fetch_image(config: &ClientConfig, url: &ImageUrl) -> Result<Vec<u8>, Error> {
+    let url = url.to_string();
+    log::debug!("Downloading image: {}", url);
+    let request = http::Request::get(&url).body(Vec::new())?;
+    let response = ensure_success(config.fetch(request, true).await?)?;
+
+    let content_type = response
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok());
+    match content_type {
+        Some(content_type) if !content_type.starts_with("image/") => Err(Error::Http(
+            format!("{} answered {} instead of an image", url, content_type).into(),
+        )),
+        _ => Ok(response.into_body()),
+    }
 }
 
 pub(crate) fn parse_url<S: Into<String>>(url: S, quality: MediaQuality) -> String {
-    let parsed_url = match url.into() {
+    normalize_url(url).replace("thumb", quality.get_value())
+}
+
+fn normalize_url<S: Into<String>>(url: S) -> String {
+    match url.into() {
         ref u if !u.starts_with("http") => format!("{}{}", "http:", u),
         u => u,
-    };
+    }
+}
+
+#[test]
+fn images_are_retried_and_checked() {
+    use crate::client::IGDBClient;
+    use crate::media_quality::MediaQuality;
+    use crate::mock::ScriptedTransport;
+    use std::time::Duration;
+
+    // Answers 503 to the first request, then an image, then an html page
+    let cdn = ScriptedTransport::new()
+        .then(503, b"\xff\xd8")
+        .content_type("text/plain")
+        .then(200, b"\xff\xd8")
+        .content_type("image/jpeg")
+        .then(200, b"\xff\xd8")
+        .content_type("text/html");
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(cdn.clone())
+        .with_retries(2, Duration::from_millis(1));
+
+    let mut image = Vec::new();
+    async_std::task::block_on(igdb.download_image("co1wyy", MediaQuality::CoverBig, &mut image))
+        .unwrap();
+    assert_eq!(b"\xff\xd8".to_vec(), image);
 
-    parsed_url.replace("thumb", quality.get_value())
+    let html = async_std::task::block_on(igdb.image_bytes("co1wyy", MediaQuality::CoverBig));
+    assert!(matches!(html, Err(Error::Http(_))));
+    assert_eq!(
+        vec!["https://images.igdb.com/igdb/image/upload/t_cover_big/co1wyy.jpg"; 3],
+        cdn.map(|request| request.uri().to_string())
+    );
 }
@@ -12,16 +12,27 @@ macro_rules! expand_media_download {
             /// screenshot_client.download_by_id(12400, "screen.jpg".to_string(), MediaQuality::ScreenshotHuge,);
             /// ```
             ///
-            pub async fn download_by_id<S: Into<String>>(
+            #[cfg(not(target_arch = "wasm32"))]
+            pub async fn download_by_id<I, S>(
                 &self,
-                id: usize,
+                id: I,
                 path: S,
                 media_quality: MediaQuality,
-            ) -> Result<(), Error> {
+            ) -> Result<(), Error>
+            where
+                I: Into<<<$i as Endpoint>::Model as Identifiable>::Id>,
+                S: Into<String>,
+            {
                 use crate::media_helpers::download_resource;
 
                 let media = self.get_first_by_id(id).await?;
-                download_resource(path.into(), media.url.clone(), media_quality).await
+                download_resource(
+                    &self.endpoint_client.config,
+                    path.into(),
+                    media.url.clone(),
+                    media_quality,
+                )
+                .await
             }
             ///Retrieves content in bytes for the given media resource
             /// MediaQuality is an enum that specifies different image sizes
@@ -34,15 +45,35 @@ macro_rules! expand_media_download {
             /// screenshot_client.get_resource_by_id(12400, MediaQuality::ScreenshotHuge,);
             /// ```
             ///
-            pub async fn get_resource_by_id(
+            pub async fn get_resource_by_id<I>(
                 &self,
-                id: usize,
+                id: I,
                 media_quality: MediaQuality,
-            ) -> Result<Vec<u8>, Error> {
+            ) -> Result<Vec<u8>, Error>
+            where
+                I: Into<<<$i as Endpoint>::Model as Identifiable>::Id>,
+            {
                 use crate::media_helpers::get_resource;
 
                 let media = self.get_first_by_id(id).await?;
-                get_resource(media.url.clone(), media_quality).await
+                get_resource(
+                    &self.endpoint_client.config,
+                    media.url.clone(),
+                    media_quality,
+                )
+                .await
+            }
+        }
+    };
+}
+
+macro_rules! expand_image_url {
+    ($i: ident) => {
+        impl $i {
+            ///Returns the url of this image with the given size preset,
+            /// see ImageUrl for the retina and webp variants
+            pub fn image_url(&self, quality: MediaQuality) -> ImageUrl {
+                ImageUrl::new(self.image_id.clone(), quality)
             }
         }
     };
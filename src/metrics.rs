@@ -0,0 +1,101 @@
+//! Counters and histograms of the requests sent to IGDB, emitted through the `metrics`
+//! facade when the `metrics` feature is enabled. Every metric is labelled with the
+//! requested endpoint, e.g. `games` or `games/count`:
+//!
+//! - `igdb_requests_total`, per response status
+//! - `igdb_request_errors_total`, requests that could not be sent or whose response
+//!   could not be read
+//! - `igdb_rate_limited_total`, requests answered with 429 Too Many Requests
+//! - `igdb_retries_total`
+//! - `igdb_response_bytes_total`
+//! - `igdb_request_duration_seconds`, histogram of the latency of each attempt
+
+use crate::middleware::{Middleware, Next};
+use crate::time::Instant;
+use crate::transport::TransportFuture;
+use http::StatusCode;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+///Records the metrics of each attempt of a request
+pub(crate) struct Metrics {
+    endpoint: String,
+    attempts: AtomicU32,
+}
+
+impl Metrics {
+    pub(crate) fn new(base_url: &str, request: &http::Request<Vec<u8>>) -> Metrics {
+        Metrics {
+            endpoint: endpoint(base_url, request.uri().path()),
+            attempts: AtomicU32::new(0),
+        }
+    }
+}
+
+impl Middleware for Metrics {
+    fn handle<'a>(
+        &'a self,
+        request: http::Request<Vec<u8>>,
+        next: Next<'a>,
+    ) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let endpoint = self.endpoint.clone();
+            if self.attempts.fetch_add(1, Ordering::Relaxed) > 0 {
+                metrics::counter!("igdb_retries_total", "endpoint" => endpoint.clone())
+                    .increment(1);
+            }
+
+            let started = Instant::now();
+            let response = next.run(request).await;
+            metrics::histogram!("igdb_request_duration_seconds", "endpoint" => endpoint.clone())
+                .record(started.elapsed().as_secs_f64());
+
+            match &response {
+                Ok(response) => {
+                    let status = response.status();
+                    metrics::counter!(
+                        "igdb_requests_total",
+                        "endpoint" => endpoint.clone(),
+                        "status" => status.as_str().to_owned()
+                    )
+                    .increment(1);
+                    metrics::counter!("igdb_response_bytes_total", "endpoint" => endpoint.clone())
+                        .increment(response.body().len() as u64);
+                    if status == StatusCode::TOO_MANY_REQUESTS {
+                        metrics::counter!("igdb_rate_limited_total", "endpoint" => endpoint)
+                            .increment(1);
+                    }
+                }
+                Err(_) => {
+                    metrics::counter!("igdb_request_errors_total", "endpoint" => endpoint)
+                        .increment(1);
+                }
+            }
+            response
+        })
+    }
+}
+
+///The endpoint path of the url path relative to the base url, without the protobuf extension
+fn endpoint(base_url: &str, path: &str) -> String {
+    let base_path = base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest)
+        .split_once('/')
+        .map_or("", |(_, path)| path);
+    let path = path.trim_start_matches('/');
+    let path = path
+        .strip_prefix(base_path.trim_matches('/'))
+        .unwrap_or(path)
+        .trim_matches('/');
+    path.strip_suffix(".pb").unwrap_or(path).to_owned()
+}
+
+#[test]
+fn endpoints_are_relative_to_the_base_url() {
+    assert_eq!("games", endpoint("https://api.igdb.com/v4", "/v4/games"));
+    assert_eq!(
+        "games/count",
+        endpoint("https://api.igdb.com/v4/", "/v4/games/count.pb")
+    );
+    assert_eq!("genres", endpoint("http://localhost:8080", "/genres"));
+}
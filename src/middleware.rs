@@ -0,0 +1,309 @@
+//! Hooks run around every request sent to IGDB, see IGDBClient::with_middleware.
+//!
+//! The middlewares added to a client run in the order they were added, each one handing
+//! the request to the next one, then come the built-in retries, hedging and rate limiter,
+//! and last the transport. With the `metrics` feature, the metrics of each attempt are recorded
+//! right before the transport. A middleware can therefore log or measure the requests, add headers,
+//! rewrite them, or answer them on its own without calling the next one.
+
+use crate::endpoint_client::ClientConfig;
+use crate::rate_limiter::{Priority, RateLimiter};
+use crate::retry::RetryPolicy;
+use crate::transport::{HttpTransport, TransportError, TransportFuture};
+use futures::future::{self, Either};
+use std::time::Duration;
+
+///Handles a request before and after the rest of the chain
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use igdb::middleware::{Middleware, Next};
+/// use igdb::transport::TransportFuture;
+///
+/// struct Log;
+///
+/// impl Middleware for Log {
+///     fn handle<'a>(&'a self, request: http::Request<Vec<u8>>, next: Next<'a>) -> TransportFuture<'a> {
+///         Box::pin(async move {
+///             let url = request.uri().to_string();
+///             let response = next.run(request).await?;
+///             println!("{} answered {}", url, response.status());
+///             Ok(response)
+///         })
+///     }
+/// }
+///
+/// let igdb = IGDBClient::new("client_id", "token").with_middleware(Log);
+/// ```
+pub trait Middleware: Send + Sync {
+    fn handle<'a>(&'a self, request: http::Request<Vec<u8>>, next: Next<'a>)
+        -> TransportFuture<'a>;
+}
+
+///Functions taking the request and the rest of the chain are middlewares
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use igdb::middleware::Next;
+/// use igdb::transport::TransportFuture;
+///
+/// fn tag(mut request: http::Request<Vec<u8>>, next: Next<'_>) -> TransportFuture<'_> {
+///     request.headers_mut().insert("x-importer", "ports".parse().unwrap());
+///     next.run(request)
+/// }
+///
+/// let igdb = IGDBClient::new("client_id", "token").with_middleware(tag);
+/// ```
+impl<F> Middleware for F
+where
+    F: for<'a> Fn(http::Request<Vec<u8>>, Next<'a>) -> TransportFuture<'a> + Send + Sync,
+{
+    fn handle<'a>(
+        &'a self,
+        request: http::Request<Vec<u8>>,
+        next: Next<'a>,
+    ) -> TransportFuture<'a> {
+        self(request, next)
+    }
+}
+
+///The rest of the chain following a middleware
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middlewares: &'a [&'a dyn Middleware],
+    transport: &'a dyn HttpTransport,
+}
+
+impl<'a> Next<'a> {
+    ///Hands the request to the next middleware, or sends it once the chain is exhausted
+    pub fn run(self, request: http::Request<Vec<u8>>) -> TransportFuture<'a> {
+        match self.middlewares.split_first() {
+            Some((middleware, middlewares)) => middleware.handle(
+                request,
+                Next {
+                    middlewares,
+                    transport: self.transport,
+                },
+            ),
+            None => self.transport.send(request),
+        }
+    }
+}
+
+///Built-in middlewares run after the ones of the client
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum BuiltIn {
+    None,
+    ///Only the retries, for the media served by IGDB's CDN which is not rate limited
+    Retries,
+    All,
+}
+
+///Runs the request through the middlewares of the client, then the wanted built-in
+/// retries and rate limiter, then through the given transport
+pub(crate) async fn run(
+    config: &ClientConfig,
+    transport: &dyn HttpTransport,
+    request: http::Request<Vec<u8>>,
+    built_in: BuiltIn,
+) -> Result<http::Response<Vec<u8>>, TransportError> {
+    let retry = Retry(config.retry);
+    #[cfg(feature = "metrics")]
+    let metrics = crate::metrics::Metrics::new(&config.base_url, &request);
+    let mut middlewares: Vec<&dyn Middleware> =
+        config.middlewares.iter().map(|m| m.as_ref()).collect();
+    if built_in != BuiltIn::None {
+        middlewares.push(&retry);
+    }
+    let hedge = config.hedge_after.map(|after| Hedge {
+        after,
+        rate_limiter: config.rate_limiter.as_deref(),
+    });
+    if built_in == BuiltIn::All {
+        if let Some(hedge) = &hedge {
+            middlewares.push(hedge);
+        }
+        if let Some(rate_limiter) = &config.rate_limiter {
+            middlewares.push(rate_limiter.as_ref());
+        }
+        #[cfg(feature = "metrics")]
+        middlewares.push(&metrics);
+    }
+
+    Next {
+        middlewares: &middlewares,
+        transport,
+    }
+    .run(request)
+    .await
+}
+
+///Sends the request again while the retry policy asks for it,
+/// returning the last response whatever its status
+struct Retry(RetryPolicy);
+
+impl Middleware for Retry {
+    fn handle<'a>(
+        &'a self,
+        request: http::Request<Vec<u8>>,
+        next: Next<'a>,
+    ) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let mut attempt = 1;
+            loop {
+                let response = next.run(clone_request(&request)).await?;
+                let status = response.status();
+                match self.0.delay(attempt, status, response.headers()) {
+                    Some(delay) => {
+                        log::warn!(
+                            "{} answered {}, retrying in {:?}",
+                            request.uri(),
+                            status,
+                            delay
+                        );
+                        async_std::task::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    None => return Ok(response),
+                }
+            }
+        })
+    }
+}
+
+///Sends a duplicate of an attempt not answered after a while and returns the first response,
+/// dropping the other attempt. Background requests are not hedged, nor the requests which
+/// would have to wait for the rate limit, so that hedging never delays other requests.
+struct Hedge<'a> {
+    after: Duration,
+    rate_limiter: Option<&'a RateLimiter>,
+}
+
+impl Middleware for Hedge<'_> {
+    fn handle<'a>(
+        &'a self,
+        request: http::Request<Vec<u8>>,
+        next: Next<'a>,
+    ) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let first = next.run(clone_request(&request));
+            let timer = Box::pin(async_std::task::sleep(self.after));
+            let first = match future::select(first, timer).await {
+                Either::Left((response, _)) => return response,
+                Either::Right((_, first)) => first,
+            };
+
+            let priority: Priority = request.extensions().get().copied().unwrap_or_default();
+            let has_token = self.rate_limiter.is_none_or(RateLimiter::has_token);
+            if priority == Priority::Background || !has_token {
+                return first.await;
+            }
+            log::debug!(
+                "{} not answered after {:?}, sending it again",
+                request.uri(),
+                self.after
+            );
+            let (response, other) = match future::select(first, next.run(request)).await {
+                Either::Left(answered) | Either::Right(answered) => answered,
+            };
+            match response {
+                Ok(response) => Ok(response),
+                // The other attempt may still succeed
+                Err(e) => other.await.or(Err(e)),
+            }
+        })
+    }
+}
+
+///Waits for the rate limit before each attempt, according to the priority of the request,
+/// and adapts it to the response
+impl Middleware for RateLimiter {
+    fn handle<'a>(
+        &'a self,
+        request: http::Request<Vec<u8>>,
+        next: Next<'a>,
+    ) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let priority = request.extensions().get().copied().unwrap_or_default();
+            self.acquire(priority).await;
+            let response = next.run(request).await?;
+            self.record(response.status());
+            Ok(response)
+        })
+    }
+}
+
+fn clone_request(request: &http::Request<Vec<u8>>) -> http::Request<Vec<u8>> {
+    let mut clone = http::Request::new(request.body().clone());
+    *clone.method_mut() = request.method().clone();
+    *clone.uri_mut() = request.uri().clone();
+    *clone.version_mut() = request.version();
+    *clone.headers_mut() = request.headers().clone();
+    if let Some(priority) = request.extensions().get::<Priority>() {
+        clone.extensions_mut().insert(*priority);
+    }
+    clone
+}
+
+#[test]
+fn middlewares_run_in_order_around_the_retries() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+    use crate::transport::TransportFuture;
+    use std::time::Duration;
+
+    fn first(mut request: http::Request<Vec<u8>>, next: Next<'_>) -> TransportFuture<'_> {
+        request
+            .headers_mut()
+            .append("x-tag", "first".parse().unwrap());
+        next.run(request)
+    }
+
+    fn second(mut request: http::Request<Vec<u8>>, next: Next<'_>) -> TransportFuture<'_> {
+        request
+            .headers_mut()
+            .append("x-tag", "second".parse().unwrap());
+        next.run(request)
+    }
+
+    // Answers 503 to the first attempt
+    let flaky = ScriptedTransport::new().then(503, "[]").then(200, "[]");
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(flaky.clone())
+        .with_retries(2, Duration::from_millis(1))
+        .with_middleware(first)
+        .with_middleware(second);
+
+    async_std::task::block_on(igdb.query::<GenresClient>(&IGDBClient::create_request())).unwrap();
+
+    let tags = flaky.map(|request| {
+        let tags = request.headers().get_all("x-tag").iter();
+        tags.map(|tag| tag.to_str().unwrap())
+            .collect::<Vec<&str>>()
+            .join(",")
+    });
+    assert_eq!(vec!["first,second", "first,second"], tags);
+}
+
+#[test]
+fn slow_attempts_are_hedged_with_the_first_response_returned() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+    use crate::time::Instant;
+
+    // Answers the first attempt after a second, the other ones right away
+    let stalled = ScriptedTransport::new()
+        .then(200, r#"[{"id":0}]"#)
+        .after(Duration::from_secs(1))
+        .then(200, r#"[{"id":1}]"#);
+    let igdb = IGDBClient::new("client_id", "token").with_transport(stalled.clone());
+    let lookups = igdb.with_hedging(Duration::from_millis(20));
+
+    let started = Instant::now();
+    let genres =
+        async_std::task::block_on(lookups.query::<GenresClient>(&IGDBClient::create_request()))
+            .unwrap();
+    assert!(started.elapsed() < Duration::from_millis(500));
+    assert_eq!(1, genres[0].id.0);
+    assert_eq!(2, stalled.sent());
+}
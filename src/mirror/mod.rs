@@ -0,0 +1,6 @@
+//! Local copies of IGDB endpoints kept up to date with IGDBClient::changes_since.
+//!
+//! Each adapter is behind its own feature, e.g. `sqlite` for mirror::sqlite.
+
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
@@ -0,0 +1,226 @@
+//! A SQLite mirror of IGDB endpoints, with the `sqlite` feature.
+//!
+//! Every mirrored endpoint gets a table named after its path, e.g. `games`, holding the
+//! `id`, `updated_at` and json `data` of each registry, so that the registries can be queried
+//! with the json functions of SQLite. The delta sync state of each endpoint is stored in the
+//! `igdb_sync_state` table, in the same transaction as the registries it covers.
+//! Registries deleted from IGDB are not reported by the delta sync and stay in the mirror.
+
+use crate::client::IGDBClient;
+use crate::endpoints::Endpoint;
+use crate::model_fields::model_fields;
+use crate::request_builder::RequestBuilder;
+use crate::sync::{self, Changes, SyncState};
+use crate::Error;
+use rusqlite::{Connection, OptionalExtension};
+use std::path::Path;
+
+///A SQLite database holding the registries of the synced endpoints
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use igdb::client::{GamesClient, IGDBClient};
+/// use igdb::mirror::sqlite::SqliteMirror;
+///
+/// task::block_on(async {
+///     let igdb = IGDBClient::new("client_id", "token");
+///     let mut mirror = SqliteMirror::open("igdb.sqlite").unwrap();
+///     let mut request = IGDBClient::create_request();
+///     request.add_fields(["name", "slug", "first_release_date"]);
+///
+///     let updated = mirror.sync::<GamesClient>(&igdb, &request).await.unwrap();
+///     let name: String = mirror
+///         .connection()
+///         .query_row("SELECT data ->> 'name' FROM games WHERE id = 1942", [], |row| row.get(0))
+///         .unwrap();
+///     println!("{} games updated, {}", updated, name);
+/// })
+/// ```
+pub struct SqliteMirror {
+    connection: Connection,
+}
+
+impl SqliteMirror {
+    ///Opens or creates the database at the given path
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<SqliteMirror, Error> {
+        SqliteMirror::from_connection(Connection::open(path)?)
+    }
+
+    ///Mirrors into an open database, creating the sync state table if needed
+    pub fn from_connection(connection: Connection) -> Result<SqliteMirror, Error> {
+        connection.execute(
+            "CREATE TABLE IF NOT EXISTS igdb_sync_state (
+                endpoint TEXT PRIMARY KEY,
+                updated_at INTEGER NOT NULL,
+                id INTEGER NOT NULL
+            )",
+            [],
+        )?;
+        Ok(SqliteMirror { connection })
+    }
+
+    ///The database, e.g. to query the mirrored registries
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    ///Creates the table of the endpoint if needed, sync creates it as well
+    pub fn create_table<E: Endpoint>(&self) -> Result<(), Error> {
+        self.connection.execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} (
+                    id INTEGER PRIMARY KEY,
+                    updated_at INTEGER NOT NULL,
+                    data TEXT NOT NULL
+                )",
+                table::<E>()
+            ),
+            [],
+        )?;
+        Ok(())
+    }
+
+    ///The delta sync state of the endpoint, the default one before its first sync
+    pub fn state<E: Endpoint>(&self) -> Result<SyncState, Error> {
+        let state = self
+            .connection
+            .query_row(
+                "SELECT updated_at, id FROM igdb_sync_state WHERE endpoint = ?1",
+                [E::PATH],
+                |row| {
+                    Ok(SyncState {
+                        updated_at: row.get::<_, i64>(0)? as u64,
+                        id: row.get::<_, i64>(1)? as u64,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(state.unwrap_or_default())
+    }
+
+    ///Inserts or replaces the changed registries of the endpoint and stores the state
+    /// following them, in a single transaction
+    pub fn upsert<E: Endpoint>(
+        &mut self,
+        changes: &Changes<serde_json::Value>,
+    ) -> Result<(), Error> {
+        self.create_table::<E>()?;
+        let transaction = self.connection.transaction()?;
+        {
+            let mut insert = transaction.prepare(&format!(
+                "INSERT INTO {} (id, updated_at, data) VALUES (?1, ?2, ?3)
+                ON CONFLICT (id) DO UPDATE SET updated_at = excluded.updated_at, data = excluded.data",
+                table::<E>()
+            ))?;
+            for registry in &changes.registries {
+                let field = |name: &str| registry.get(name).and_then(serde_json::Value::as_u64);
+                insert.execute(rusqlite::params![
+                    field("id").unwrap_or_default() as i64,
+                    field("updated_at").unwrap_or_default() as i64,
+                    registry.to_string(),
+                ])?;
+            }
+        }
+        transaction.execute(
+            "INSERT INTO igdb_sync_state (endpoint, updated_at, id) VALUES (?1, ?2, ?3)
+            ON CONFLICT (endpoint) DO UPDATE SET updated_at = excluded.updated_at, id = excluded.id",
+            rusqlite::params![
+                E::PATH,
+                changes.state.updated_at as i64,
+                changes.state.id as i64
+            ],
+        )?;
+        transaction.commit()?;
+        Ok(())
+    }
+
+    ///Requests the registries of the endpoint matching the request filters and changed since
+    /// the last sync until the mirror is up to date, upserting each page as it is received.
+    /// Requests naming no field request the fields of the endpoint model.
+    /// Returns the number of registries upserted.
+    pub async fn sync<E: Endpoint>(
+        &mut self,
+        igdb: &IGDBClient,
+        request: &RequestBuilder,
+    ) -> Result<usize, Error> {
+        let mut request = request.clone();
+        if request.fields.is_empty() {
+            request.add_fields(
+                model_fields::<E::Model>()
+                    .unwrap_or_default()
+                    .iter()
+                    .copied(),
+            );
+        }
+        let client = igdb.endpoint_client::<E>();
+        let mut state = self.state::<E>()?;
+        let mut upserted = 0;
+
+        loop {
+            let changes =
+                sync::changes_since::<serde_json::Value>(&client, &request, state).await?;
+            if changes.is_empty() {
+                return Ok(upserted);
+            }
+            self.upsert::<E>(&changes)?;
+            upserted += changes.registries.len();
+            state = changes.state;
+        }
+    }
+}
+
+///The quoted name of the table of the endpoint
+fn table<E: Endpoint>() -> String {
+    format!("\"{}\"", E::PATH.replace(['/', '"'], "_"))
+}
+
+#[test]
+fn synced_registries_are_upserted_with_their_state() {
+    use crate::client::GenresClient;
+    use crate::transport::{HttpTransport, TransportFuture};
+    use std::sync::Mutex;
+
+    ///Answers each page once, then no registry
+    struct Pages(Mutex<Vec<&'static str>>);
+
+    impl HttpTransport for Pages {
+        fn send(&self, _: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            let page = self.0.lock().unwrap().pop().unwrap_or("[]");
+            Box::pin(async move { Ok(http::Response::new(page.as_bytes().to_vec())) })
+        }
+    }
+
+    let pages = Pages(Mutex::new(vec![
+        r#"[{"id": 5, "name": "Shooter", "updated_at": 300}]"#,
+        r#"[{"id": 12, "name": "RPG", "updated_at": 100}, {"id": 5, "name": "Shoot", "updated_at": 200}]"#,
+    ]));
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(pages)
+        .without_rate_limit();
+    let mut mirror = SqliteMirror::from_connection(Connection::open_in_memory().unwrap()).unwrap();
+
+    let upserted = async_std::task::block_on(
+        mirror.sync::<GenresClient>(&igdb, &IGDBClient::create_request()),
+    );
+    assert_eq!(3, upserted.unwrap());
+    assert_eq!(
+        SyncState {
+            updated_at: 300,
+            id: 5
+        },
+        mirror.state::<GenresClient>().unwrap()
+    );
+
+    let names: Vec<(i64, String)> = mirror
+        .connection()
+        .prepare("SELECT id, data ->> 'name' FROM genres ORDER BY id")
+        .unwrap()
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        vec![(5, "Shooter".to_owned()), (12, "RPG".to_owned())],
+        names
+    );
+}
@@ -0,0 +1,349 @@
+//! Transports answering canned responses, to test IGDB integrations without network
+//! access or credentials. MockTransport answers the fixtures it was given, and
+//! RecordingTransport saves the responses of a real transport as fixtures
+//! MockTransport::from_dir replays.
+//!
+//! Fixtures match the endpoint path and the query body, use static credentials
+//! along with them since Twitch token requests are not answered.
+
+use crate::compression::{decode_body, HEADER_CONTENT_ENCODING};
+use crate::transport::{HttpTransport, TransportError, TransportFuture};
+use std::sync::{Arc, Mutex};
+
+///A canned response: the endpoint path and query it answers, and the response status and body
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    ///Endpoint path, e.g. `games` or `games/count`, or the full url path when recorded
+    pub path: String,
+    pub query: String,
+    pub status: u16,
+    pub body: String,
+}
+
+impl Fixture {
+    ///Whether the fixture answers the given query sent to the given url path
+    fn answers(&self, path: &str, query: &str) -> bool {
+        let wanted = self.path.trim_matches('/');
+        let path = path.trim_matches('/');
+        (path == wanted || path.ends_with(&format!("/{}", wanted)))
+            && self.query.trim() == query.trim()
+    }
+
+    ///Name of the file the fixture is recorded to, e.g. `v4_games-0123456789abcdef.json`
+    #[cfg(not(target_arch = "wasm32"))]
+    fn file_name(&self) -> String {
+        use crate::response_cache::{fnv1a, FNV_OFFSET};
+
+        let hash = fnv1a(
+            fnv1a(FNV_OFFSET, self.path.as_bytes()),
+            self.query.as_bytes(),
+        );
+        format!(
+            "{}-{:016x}.json",
+            self.path.trim_matches('/').replace('/', "_"),
+            hash
+        )
+    }
+}
+
+///Answers the queries with the fixtures matching their endpoint and body,
+/// and the ones no fixture matches with a 404 naming them.
+/// Clones share the log of the sent queries.
+/// # Examples
+/// ```
+/// use async_std::task;
+/// use igdb::client::{GenresClient, IGDBClient};
+/// use igdb::mock::MockTransport;
+///
+/// let mock = MockTransport::new().with_fixture(
+///     "genres",
+///     "fields name; limit 10;",
+///     r#"[{"id": 12, "name": "Role-playing (RPG)"}]"#,
+/// );
+/// let igdb = IGDBClient::new("client_id", "token").with_transport(mock.clone());
+///
+/// let mut request = IGDBClient::create_request();
+/// request.add_field("name");
+/// let genres = task::block_on(igdb.query::<GenresClient>(&request)).unwrap();
+///
+/// assert_eq!("Role-playing (RPG)", genres[0].name);
+/// assert_eq!(1, mock.requests().len());
+/// ```
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    fixtures: Arc<Vec<Fixture>>,
+    requests: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl MockTransport {
+    pub fn new() -> MockTransport {
+        MockTransport::default()
+    }
+
+    ///Answers the given json to the given query sent to the endpoint path
+    pub fn with_fixture<P, Q, B>(self, path: P, query: Q, body: B) -> Self
+    where
+        P: Into<String>,
+        Q: Into<String>,
+        B: Into<String>,
+    {
+        self.with(Fixture {
+            path: path.into(),
+            query: query.into(),
+            status: 200,
+            body: body.into(),
+        })
+    }
+
+    ///Adds the given fixture, fixtures added first answer first
+    pub fn with(mut self, fixture: Fixture) -> Self {
+        Arc::make_mut(&mut self.fixtures).push(fixture);
+        self
+    }
+
+    ///Answers the fixtures recorded in the given directory by a RecordingTransport
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_dir<P: AsRef<std::path::Path>>(dir: P) -> std::io::Result<MockTransport> {
+        let mut mock = MockTransport::new();
+        let mut paths: Vec<std::path::PathBuf> = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<_>>()?;
+        paths.sort();
+
+        for path in paths {
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                let fixture = serde_json::from_slice(&std::fs::read(&path)?)?;
+                mock = mock.with(fixture);
+            }
+        }
+        Ok(mock)
+    }
+
+    ///The url path and query of the requests sent so far, in order
+    pub fn requests(&self) -> Vec<(String, String)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let (path, query) = path_and_query(&request)?;
+            self.requests
+                .lock()
+                .unwrap()
+                .push((path.clone(), query.clone()));
+
+            let response = match self.fixtures.iter().find(|f| f.answers(&path, &query)) {
+                Some(fixture) => http::Response::builder()
+                    .status(fixture.status)
+                    .body(fixture.body.clone().into_bytes())?,
+                None => {
+                    let cause = format!("no fixture answers `{}` sent to {}", query, path);
+                    let body = serde_json::json!([{ "title": "No fixture", "cause": cause }]);
+                    http::Response::builder()
+                        .status(404)
+                        .body(body.to_string().into_bytes())?
+                }
+            };
+            Ok(response)
+        })
+    }
+}
+
+///Answers the scripted responses in turn, the last one to every later request, recording the
+/// requests sent. Clones share the script and the requests
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub(crate) struct ScriptedTransport {
+    answers: Arc<Vec<Answer>>,
+    requests: Arc<Mutex<Vec<http::Request<Vec<u8>>>>>,
+}
+
+#[cfg(test)]
+#[derive(Clone)]
+struct Answer {
+    status: u16,
+    body: Vec<u8>,
+    content_type: Option<&'static str>,
+    delay: std::time::Duration,
+}
+
+#[cfg(test)]
+impl ScriptedTransport {
+    pub(crate) fn new() -> ScriptedTransport {
+        ScriptedTransport::default()
+    }
+
+    ///Answers the given status and body to the next request
+    pub(crate) fn then<B: Into<Vec<u8>>>(mut self, status: u16, body: B) -> Self {
+        Arc::make_mut(&mut self.answers).push(Answer {
+            status,
+            body: body.into(),
+            content_type: None,
+            delay: std::time::Duration::ZERO,
+        });
+        self
+    }
+
+    ///Answers the last scripted response after the given delay
+    pub(crate) fn after(mut self, delay: std::time::Duration) -> Self {
+        Arc::make_mut(&mut self.answers).last_mut().unwrap().delay = delay;
+        self
+    }
+
+    ///Answers the last scripted response with the given content type
+    pub(crate) fn content_type(mut self, content_type: &'static str) -> Self {
+        Arc::make_mut(&mut self.answers)
+            .last_mut()
+            .unwrap()
+            .content_type = Some(content_type);
+        self
+    }
+
+    ///The number of requests sent so far
+    pub(crate) fn sent(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    ///Maps the requests sent so far, in order
+    pub(crate) fn map<R, F: Fn(&http::Request<Vec<u8>>) -> R>(&self, f: F) -> Vec<R> {
+        self.requests.lock().unwrap().iter().map(f).collect()
+    }
+
+    ///The bodies of the requests sent so far, in order
+    pub(crate) fn bodies(&self) -> Vec<String> {
+        self.map(|request| String::from_utf8_lossy(request.body()).into_owned())
+    }
+}
+
+#[cfg(test)]
+impl HttpTransport for ScriptedTransport {
+    fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        let mut requests = self.requests.lock().unwrap();
+        let answer = self.answers[requests.len().min(self.answers.len() - 1)].clone();
+        requests.push(request);
+        Box::pin(async move {
+            if !answer.delay.is_zero() {
+                async_std::task::sleep(answer.delay).await;
+            }
+            let mut response = http::Response::builder().status(answer.status);
+            if let Some(content_type) = answer.content_type {
+                response = response.header(http::header::CONTENT_TYPE, content_type);
+            }
+            Ok(response.body(answer.body)?)
+        })
+    }
+}
+
+///Sends the requests with another transport and saves each response as a fixture file
+/// in the given directory, see MockTransport::from_dir. Responses are decompressed and
+/// stored as text, so only json responses can be replayed.
+/// # Examples
+/// ```no_run
+/// use igdb::client::IGDBClient;
+/// use igdb::mock::RecordingTransport;
+/// use igdb::transport::ReqwestTransport;
+///
+/// let igdb = IGDBClient::new("client_id", "token")
+///     .with_transport(RecordingTransport::new(ReqwestTransport::default(), "tests/fixtures"));
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub struct RecordingTransport<T> {
+    inner: T,
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: HttpTransport> RecordingTransport<T> {
+    pub fn new<P: Into<std::path::PathBuf>>(inner: T, dir: P) -> RecordingTransport<T> {
+        RecordingTransport {
+            inner,
+            dir: dir.into(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: HttpTransport> HttpTransport for RecordingTransport<T> {
+    fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let (path, query) = path_and_query(&request)?;
+            let response = self.inner.send(request).await?;
+
+            let (mut parts, body) = response.into_parts();
+            let body = decode_body(content_encoding(&parts.headers).as_deref(), body)?;
+            let fixture = Fixture {
+                path,
+                query,
+                status: parts.status.as_u16(),
+                body: String::from_utf8_lossy(&body).into_owned(),
+            };
+            std::fs::create_dir_all(&self.dir)?;
+            std::fs::write(
+                self.dir.join(fixture.file_name()),
+                serde_json::to_vec_pretty(&fixture)?,
+            )?;
+
+            parts.headers.remove(HEADER_CONTENT_ENCODING);
+            parts.headers.remove(http::header::CONTENT_LENGTH);
+            Ok(http::Response::from_parts(parts, body))
+        })
+    }
+}
+
+///The url path and the decompressed query body of a request
+fn path_and_query(request: &http::Request<Vec<u8>>) -> Result<(String, String), TransportError> {
+    let encoding = content_encoding(request.headers());
+    let body = decode_body(encoding.as_deref(), request.body().clone())?;
+    Ok((
+        request.uri().path().to_owned(),
+        String::from_utf8_lossy(&body).into_owned(),
+    ))
+}
+
+fn content_encoding(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get(HEADER_CONTENT_ENCODING)
+        .and_then(|encoding| encoding.to_str().ok())
+        .map(|encoding| encoding.trim().to_ascii_lowercase())
+}
+
+#[test]
+fn recorded_responses_are_replayed() {
+    use crate::client::{GenresClient, IGDBClient};
+
+    let dir = std::env::temp_dir().join(format!("igdb-fixtures-{}", std::process::id()));
+    let live = MockTransport::new().with_fixture(
+        "genres",
+        "fields name; limit 10;",
+        r#"[{"id": 12, "name": "Role-playing (RPG)"}]"#,
+    );
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+
+    let recording = IGDBClient::new("client_id", "token")
+        .with_transport(RecordingTransport::new(live, &dir))
+        .without_rate_limit();
+    async_std::task::block_on(recording.query::<GenresClient>(&request)).unwrap();
+
+    let replay = MockTransport::from_dir(&dir).unwrap();
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(replay.clone())
+        .without_rate_limit();
+    let genres = async_std::task::block_on(igdb.query::<GenresClient>(&request)).unwrap();
+    assert_eq!("Role-playing (RPG)", genres[0].name);
+
+    request.add_field("slug");
+    let missing = async_std::task::block_on(igdb.query::<GenresClient>(&request));
+    assert!(matches!(
+        missing,
+        Err(crate::Error::Api { status: 404, .. })
+    ));
+    assert_eq!(2, replay.requests().len());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -1,16 +1,35 @@
 use crate::model::enums::{Rating, RatingCategory};
+use crate::model::ids::{AgeRatingContentDescriptionId, AgeRatingId};
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct AgeRating {
     #[serde(default)]
-    pub id: usize,
+    pub id: AgeRatingId,
     #[serde(default)]
     pub category: RatingCategory,
     #[serde(default)]
-    pub content_descriptions: Vec<usize>,
+    pub content_descriptions: Vec<AgeRatingContentDescriptionId>,
     pub rating: Rating,
     #[serde(default)]
-    pub rating_cover_url: String,
+    pub rating_cover_url: Option<String>,
     #[serde(default)]
-    pub synopsis: String,
+    pub synopsis: Option<String>,
+}
+
+///Field names of AgeRating, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/age_rating.rs"));
+}
+
+#[test]
+fn age_rating_without_cover_and_synopsis_deserializes() {
+    let rating: AgeRating =
+        serde_json::from_str(r#"{"id": 12, "category": 2, "rating": 5}"#).unwrap();
+
+    assert_eq!(AgeRatingId(12), rating.id);
+    assert_eq!(RatingCategory::PEGI, rating.category);
+    assert_eq!(Rating::Eighteen, rating.rating);
+    assert!(rating.rating_cover_url.is_none());
+    assert!(rating.synopsis.is_none());
 }
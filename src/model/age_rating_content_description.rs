@@ -0,0 +1,21 @@
+use crate::model::ids::AgeRatingContentDescriptionId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct AgeRatingContentDescription {
+    #[serde(default)]
+    pub id: AgeRatingContentDescriptionId,
+    ///IGDB code of the descriptor, e.g. 1 for ESRB Alcohol Reference
+    #[serde(default)]
+    pub category: u64,
+    #[serde(default)]
+    pub description: String,
+}
+
+///Field names of AgeRatingContentDescription, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/fields/age_rating_content_description.rs"
+    ));
+}
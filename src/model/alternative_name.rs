@@ -0,0 +1,21 @@
+use crate::model::ids::{AlternativeNameId, GameId};
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct AlternativeName {
+    #[serde(default)]
+    pub id: AlternativeNameId,
+    #[serde(default)]
+    pub comment: String,
+    #[serde(default)]
+    pub game: GameId,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub checksum: String,
+}
+
+///Field names of AlternativeName, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/alternative_name.rs"));
+}
@@ -1,33 +1,52 @@
 use crate::model::enums::{Gender, Species};
+use crate::model::ids::{CharacterId, CharacterMugshotId, GameId};
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Character {
     #[serde(default)]
-    pub id: usize,
+    pub id: CharacterId,
     #[serde(default)]
     pub akas: Vec<String>,
     #[serde(default)]
     pub country_name: String,
     #[serde(default)]
-    pub created_at: u64,
+    pub created_at: i64,
     #[serde(default)]
     pub description: String,
     #[serde(default)]
-    pub games: Vec<usize>,
+    pub games: Vec<GameId>,
     #[serde(default)]
     pub gender: Gender,
     #[serde(default)]
-    pub mug_shot: usize,
+    pub mug_shot: CharacterMugshotId,
     #[serde(default)]
     pub name: String,
     #[serde(default)]
-    pub people: Vec<usize>,
+    pub people: Vec<u64>,
     #[serde(default)]
     pub slug: String,
     #[serde(default)]
     pub species: Species,
     #[serde(default)]
-    pub updated_at: u64,
+    pub updated_at: i64,
     #[serde(default)]
     pub url: String,
 }
+
+///Field names of Character, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/character.rs"));
+}
+
+#[test]
+fn deserializes_gender_and_species() {
+    let character: Character =
+        serde_json::from_str(r#"{"id": 1, "name": "Geralt", "gender": 0, "species": 1}"#).unwrap();
+    assert_eq!(Gender::Male, character.gender);
+    assert_eq!(Species::Human, character.species);
+
+    let character: Character = serde_json::from_str(r#"{"id": 2}"#).unwrap();
+    assert_eq!(Gender::Unknown(3), character.gender);
+    assert_eq!(Species::Unknown(5), character.species);
+}
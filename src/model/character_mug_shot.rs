@@ -1,10 +1,25 @@
+use crate::model::ids::CharacterMugshotId;
+
 #[derive(Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct CharacterMugshot {
-    pub id: usize,
+    #[serde(default)]
+    pub id: CharacterMugshotId,
+    #[serde(default)]
     pub alpha_channel: bool,
+    #[serde(default)]
     pub animated: bool,
-    pub height: usize,
+    #[serde(default)]
+    pub height: u64,
+    #[serde(default)]
     pub image_id: String,
+    #[serde(default)]
     pub url: String,
-    pub width: usize,
+    #[serde(default)]
+    pub width: u64,
+}
+
+///Field names of CharacterMugshot, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/character_mug_shot.rs"));
 }
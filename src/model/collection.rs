@@ -0,0 +1,25 @@
+use crate::model::ids::{CollectionId, GameId};
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct Collection {
+    #[serde(default)]
+    pub id: CollectionId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub games: Vec<GameId>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub slug: String,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub url: String,
+}
+
+///Field names of Collection, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/collection.rs"));
+}
@@ -1,17 +1,39 @@
+use crate::model::ids::CompanyId;
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Company {
     #[serde(default)]
-    pub id: usize,
+    pub id: CompanyId,
     #[serde(default)]
     pub name: String,
     #[serde(default)]
+    pub country: u64,
+    #[serde(default)]
     pub description: String,
     #[serde(default)]
-    pub published: usize,
+    pub developed: Vec<u64>,
+    #[serde(default)]
+    pub logo: u64,
+    #[serde(default)]
+    pub parent: u64,
+    #[serde(default)]
+    pub published: Vec<u64>,
     #[serde(default)]
-    pub created_at: u64,
+    pub start_date: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
     #[serde(default)]
     pub slug: String,
     #[serde(default)]
     pub url: String,
+    #[serde(default)]
+    pub websites: Vec<u64>,
+}
+
+///Field names of Company, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/company.rs"));
 }
@@ -0,0 +1,61 @@
+use crate::model::company::Company;
+use crate::model::games::Game;
+
+///A company and the games it developed and published, see IGDBClient::company_catalog
+#[derive(Debug, Clone)]
+pub struct CompanyCatalog {
+    pub company: Company,
+    ///Games developed by the company, the earliest released first
+    pub developed: Vec<Game>,
+    ///Games published by the company, the earliest released first
+    pub published: Vec<Game>,
+}
+
+impl CompanyCatalog {
+    ///Splits the games between the developed and published ones,
+    /// the games released on an unknown date coming last
+    pub(crate) fn new(company: Company, games: Vec<Game>) -> CompanyCatalog {
+        let mut catalog = CompanyCatalog {
+            developed: Vec::new(),
+            published: Vec::new(),
+            company,
+        };
+
+        for game in games {
+            let id = game.id.0;
+            if catalog.company.developed.contains(&id) {
+                catalog.developed.push(game.clone());
+            }
+            if catalog.company.published.contains(&id) {
+                catalog.published.push(game);
+            }
+        }
+        for games in [&mut catalog.developed, &mut catalog.published] {
+            games.sort_by_key(|game| match game.first_release_date {
+                0 => (1, 0),
+                date => (0, date),
+            });
+        }
+        catalog
+    }
+}
+
+#[test]
+fn games_are_split_and_sorted_by_release_date() {
+    let company: Company =
+        serde_json::from_str(r#"{"id": 70, "developed": [1, 2, 3], "published": [2, 4]}"#).unwrap();
+    let games: Vec<Game> = serde_json::from_str(
+        r#"[
+            {"id": 1, "first_release_date": 1431993600},
+            {"id": 2},
+            {"id": 3, "first_release_date": 1180656000},
+            {"id": 4, "first_release_date": 1000000000}
+        ]"#,
+    )
+    .unwrap();
+
+    let catalog = CompanyCatalog::new(company, games);
+    let ids = |games: &[Game]| games.iter().map(|game| game.id.0).collect::<Vec<u64>>();
+    assert_eq!(vec![3, 1, 2], ids(&catalog.developed));
+    assert_eq!(vec![4, 2], ids(&catalog.published));
+}
@@ -1,19 +1,27 @@
+use crate::model::ids::{CoverId, GameId};
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Cover {
     #[serde(default)]
-    pub id: usize,
+    pub id: CoverId,
     #[serde(default)]
     pub alpha_channel: bool,
     #[serde(default)]
     pub animated: bool,
     #[serde(default)]
-    pub game: usize,
+    pub game: GameId,
     #[serde(default)]
-    pub height: usize,
+    pub height: u64,
     #[serde(default)]
     pub image_id: String,
     #[serde(default)]
     pub url: String,
     #[serde(default)]
-    pub width: usize,
+    pub width: u64,
+}
+
+///Field names of Cover, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/cover.rs"));
 }
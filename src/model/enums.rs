@@ -1,243 +1,320 @@
-use serde_repr::Deserialize_repr;
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum GameCategory {
-    MainGame = 0,
-    DlcAddon = 1,
-    Expansion = 2,
-    Bundle = 3,
-    StandaloneExpansion = 4,
-    Mod = 5,
-    Episode = 6,
-    Season = 7,
-    Remake = 8,
-    Remaster = 9,
-    ExpandedGame = 10,
-    Port = 11,
-    Fork = 12,
-    Pack = 13,
-    Update = 14,
-}
-
-impl Default for GameCategory {
-    fn default() -> Self {
-        GameCategory::MainGame
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum PlatformCategory {
-    Console = 1,
-    Arcade = 2,
-    Platform = 3,
-    OperativeSystem = 4,
-    PortableConsole = 5,
-    Computer = 6,
-}
-
-impl Default for PlatformCategory {
-    fn default() -> Self {
-        PlatformCategory::Console
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum Gender {
-    Male = 0,
-    Female = 1,
-    Other = 2,
-    Unknown = 3,
-}
-
-impl Default for Gender {
-    fn default() -> Self {
-        Gender::Unknown
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum Platform {
-    Console = 1,
-    Arcade = 2,
-    Platform = 3,
-    OperativeSystem = 4,
-    PortableConsole = 5,
-    Computer = 6,
-}
-
-impl Default for Platform {
-    fn default() -> Self {
-        Platform::Console
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum Rating {
-    Three = 1,
-    Seven = 2,
-    Twelve = 3,
-    Sixteen = 4,
-    Eighteen = 5,
-    RP = 6,
-    EC = 7,
-    E = 8,
-    E10 = 9,
-    T = 10,
-    M = 11,
-    AO = 12,
-    CeroA = 13,
-    CeroB = 14,
-    CeroC = 15,
-    CeroD = 16,
-    CeroZ = 17,
-    Usk0 = 18,
-    Usk6 = 19,
-    Usk12 = 20,
-    Usk18 = 21,
-    GracAll = 22,
-    GracTwelve = 23,
-    GracFifteen = 24,
-    GracEighteen = 25,
-    GracTesting = 26,
-    ClassIndL = 27,
-    ClassIndTen = 28,
-    ClassIndTwelve = 29,
-    ClassIndFourteen = 30,
-    ClassIndSixteen = 31,
-    ClassIndEighteen = 32,
-    AcbG = 33,
-    AcbPg = 34,
-    AcbM = 35,
-    AcbMa15 = 36,
-    AcbR18 = 37,
-    AcbRc = 38,
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum RatingCategory {
-    ESRB = 1,
-    PEGI = 2,
-    CERO = 3,
-    USK = 4,
-    GRAC = 5,
-    ClassInd = 6,
-    ACB = 7,
-}
-
-impl Default for RatingCategory {
-    fn default() -> Self {
-        RatingCategory::ESRB
-    }
-}
-
-impl Default for Rating {
-    fn default() -> Self {
-        Rating::Eighteen
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum Species {
-    Human = 1,
-    Alien = 2,
-    Animal = 3,
-    Android = 4,
-    Unknown = 5,
-}
-
-impl Default for Species {
-    fn default() -> Self {
-        Species::Human
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum Status {
-    Released = 0,
-    Alpha = 2,
-    Beta = 3,
-    EarlyAccess = 4,
-    Offline = 5,
-    Cancelled = 6,
-    Rumored = 7,
-    Delisted = 8,
-}
-
-impl Default for Status {
-    fn default() -> Self {
-        Status::Released
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum Region {
-    Europe = 1,
-    NorthAmerica = 2,
-    Australia = 3,
-    NewZealand = 4,
-    Japan = 5,
-    China = 6,
-    Asia = 7,
-    WorldWide = 8,
-    Korea = 9,
-    Brazil = 10,
-}
-
-impl Default for Region {
-    fn default() -> Self {
-        Region::Europe
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum ExternalGameCategory {
-    Steam = 1,
-    Gog = 5,
-    Youtube = 10,
-    Microsoft = 11,
-    Apple = 13,
-    Twitch = 14,
-    Android = 15,
-    AmazonAsin = 20,
-    AmazonLuna = 22,
-    AmazonAdg = 23,
-    EpicGameStore = 26,
-    Oculus = 28,
-    Utomik = 29,
-    ItchIo = 30,
-    XboxMarketplace = 31,
-    Kartridge = 32,
-    PlaystationStoreUs = 36,
-    FocusEntertainment = 37,
-    XboxGamePassUltimateCloud = 54,
-    Gamejolt = 55,
-}
-
-impl Default for ExternalGameCategory {
-    fn default() -> Self {
-        ExternalGameCategory::Steam
-    }
-}
-
-#[derive(Deserialize_repr, Debug, Clone)]
-#[repr(u8)]
-pub enum Media {
-    Digital = 1,
-    Physical = 5,
-}
-
-impl Default for Media {
-    fn default() -> Self {
-        Media::Digital
-    }
+use serde::de::{Deserialize, Deserializer};
+
+pub use crate::model::ids::PlatformId;
+
+///Defines an enum of the integers IGDB sends for a field. Values this version of the crate
+/// does not know are read as `Unknown(value)` instead of failing the whole response,
+/// so that new IGDB values do not break deserialization.
+macro_rules! igdb_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($variant:ident = $value:literal,)+
+        } default $($default:tt)+
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($variant,)+
+            ///A value unknown to this version of the crate
+            Unknown(i32),
+        }
+
+        impl $name {
+            ///The integer IGDB sends for this value
+            pub fn value(self) -> i32 {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Unknown(value) => value,
+                }
+            }
+
+            ///Reads the integer IGDB sent, unknown values being kept as Unknown
+            pub fn from_value(value: i32) -> $name {
+                match value {
+                    $($value => $name::$variant,)+
+                    value => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name::$($default)+
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                i32::deserialize(deserializer).map($name::from_value)
+            }
+        }
+
+        ///Written as the integer IGDB sends
+        #[cfg(feature = "serialize")]
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_i32(self.value())
+            }
+        }
+    };
+}
+
+igdb_enum! {
+    GameCategory {
+        MainGame = 0,
+        DlcAddon = 1,
+        Expansion = 2,
+        Bundle = 3,
+        StandaloneExpansion = 4,
+        Mod = 5,
+        Episode = 6,
+        Season = 7,
+        Remake = 8,
+        Remaster = 9,
+        ExpandedGame = 10,
+        Port = 11,
+        Fork = 12,
+        Pack = 13,
+        Update = 14,
+    } default MainGame
+}
+
+igdb_enum! {
+    PlatformCategory {
+        Console = 1,
+        Arcade = 2,
+        Platform = 3,
+        OperativeSystem = 4,
+        PortableConsole = 5,
+        Computer = 6,
+    } default Console
+}
+
+igdb_enum! {
+    ///Gender of a character, IGDB's own unknown gender being Unknown(3)
+    Gender {
+        Male = 0,
+        Female = 1,
+        Other = 2,
+    } default Unknown(3)
+}
+
+igdb_enum! {
+    Platform {
+        Console = 1,
+        Arcade = 2,
+        Platform = 3,
+        OperativeSystem = 4,
+        PortableConsole = 5,
+        Computer = 6,
+    } default Console
+}
+
+igdb_enum! {
+    Rating {
+        Three = 1,
+        Seven = 2,
+        Twelve = 3,
+        Sixteen = 4,
+        Eighteen = 5,
+        RP = 6,
+        EC = 7,
+        E = 8,
+        E10 = 9,
+        T = 10,
+        M = 11,
+        AO = 12,
+        CeroA = 13,
+        CeroB = 14,
+        CeroC = 15,
+        CeroD = 16,
+        CeroZ = 17,
+        Usk0 = 18,
+        Usk6 = 19,
+        Usk12 = 20,
+        Usk18 = 21,
+        GracAll = 22,
+        GracTwelve = 23,
+        GracFifteen = 24,
+        GracEighteen = 25,
+        GracTesting = 26,
+        ClassIndL = 27,
+        ClassIndTen = 28,
+        ClassIndTwelve = 29,
+        ClassIndFourteen = 30,
+        ClassIndSixteen = 31,
+        ClassIndEighteen = 32,
+        AcbG = 33,
+        AcbPg = 34,
+        AcbM = 35,
+        AcbMa15 = 36,
+        AcbR18 = 37,
+        AcbRc = 38,
+    } default Eighteen
+}
+
+igdb_enum! {
+    RatingCategory {
+        ESRB = 1,
+        PEGI = 2,
+        CERO = 3,
+        USK = 4,
+        GRAC = 5,
+        ClassInd = 6,
+        ACB = 7,
+    } default ESRB
+}
+
+igdb_enum! {
+    ///Species of a character, IGDB's own unknown species being Unknown(5)
+    Species {
+        Human = 1,
+        Alien = 2,
+        Animal = 3,
+        Android = 4,
+    } default Unknown(5)
+}
+
+igdb_enum! {
+    Status {
+        Released = 0,
+        Alpha = 2,
+        Beta = 3,
+        EarlyAccess = 4,
+        Offline = 5,
+        Cancelled = 6,
+        Rumored = 7,
+        Delisted = 8,
+    } default Released
+}
+
+igdb_enum! {
+    Region {
+        Europe = 1,
+        NorthAmerica = 2,
+        Australia = 3,
+        NewZealand = 4,
+        Japan = 5,
+        China = 6,
+        Asia = 7,
+        WorldWide = 8,
+        Korea = 9,
+        Brazil = 10,
+    } default Europe
+}
+
+igdb_enum! {
+    ///Precision of a release date
+    DateCategory {
+        YYYYMMMMDD = 0,
+        YYYYMMMM = 1,
+        YYYY = 2,
+        YYYYQ1 = 3,
+        YYYYQ2 = 4,
+        YYYYQ3 = 5,
+        YYYYQ4 = 6,
+        TBD = 7,
+    } default YYYYMMMMDD
+}
+
+igdb_enum! {
+    ExternalGameCategory {
+        Steam = 1,
+        Gog = 5,
+        Youtube = 10,
+        Microsoft = 11,
+        Apple = 13,
+        Twitch = 14,
+        Android = 15,
+        AmazonAsin = 20,
+        AmazonLuna = 22,
+        AmazonAdg = 23,
+        EpicGameStore = 26,
+        Oculus = 28,
+        Utomik = 29,
+        ItchIo = 30,
+        XboxMarketplace = 31,
+        Kartridge = 32,
+        PlaystationStoreUs = 36,
+        FocusEntertainment = 37,
+        XboxGamePassUltimateCloud = 54,
+        Gamejolt = 55,
+    } default Steam
+}
+
+igdb_enum! {
+    WebsiteCategory {
+        Official = 1,
+        Wikia = 2,
+        Wikipedia = 3,
+        Facebook = 4,
+        Twitter = 5,
+        Twitch = 6,
+        Instagram = 8,
+        Youtube = 9,
+        Iphone = 10,
+        Ipad = 11,
+        Android = 12,
+        Steam = 13,
+        Reddit = 14,
+        Itch = 15,
+        EpicGames = 16,
+        Gog = 17,
+        Discord = 18,
+    } default Official
+}
+
+igdb_enum! {
+    ///The way a game supports a language, see /language_support_types
+    SupportType {
+        Audio = 1,
+        Subtitles = 2,
+        Interface = 3,
+    } default Audio
+}
+
+igdb_enum! {
+    ///Kind of a feature compared across the versions of a game: included in a version or not,
+    /// or described by a note for each version
+    GameVersionFeatureCategory {
+        Boolean = 0,
+        Description = 1,
+    } default Boolean
+}
+
+igdb_enum! {
+    ///Whether a version of a game includes a feature
+    IncludedFeature {
+        NotIncluded = 0,
+        Included = 1,
+        PreOrderOnly = 2,
+    } default NotIncluded
+}
+
+igdb_enum! {
+    Media {
+        Digital = 1,
+        Physical = 5,
+    } default Digital
+}
+
+#[test]
+fn unknown_values_deserialize() {
+    let categories: Vec<GameCategory> = serde_json::from_str("[0, 9, 42]").unwrap();
+    assert_eq!(
+        vec![
+            GameCategory::MainGame,
+            GameCategory::Remaster,
+            GameCategory::Unknown(42)
+        ],
+        categories
+    );
+    assert_eq!(42, categories[2].value());
+    assert_eq!(
+        Status::Delisted,
+        Status::from_value(Status::Delisted.value())
+    );
+    assert_eq!(Gender::Unknown(3), Gender::default());
 }
@@ -0,0 +1,47 @@
+use crate::model::event_logo::EventLogo;
+use crate::model::id_or::IdOr;
+use crate::model::ids::{EventId, EventNetworkId, GameId, GameVideoId};
+
+///A gaming event, e.g. E3 or The Game Awards
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct Event {
+    #[serde(default)]
+    pub id: EventId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub description: String,
+    ///End time in seconds since the unix epoch, 0 when unknown
+    #[serde(default)]
+    pub end_time: i64,
+    #[serde(default)]
+    pub event_logo: IdOr<EventLogo>,
+    #[serde(default)]
+    pub event_networks: Vec<EventNetworkId>,
+    ///Games featured in the event
+    #[serde(default)]
+    pub games: Vec<GameId>,
+    #[serde(default)]
+    pub live_stream_url: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub slug: String,
+    ///Start time in seconds since the unix epoch
+    #[serde(default)]
+    pub start_time: i64,
+    ///Time zone of the event, e.g. America/Los_Angeles
+    #[serde(default)]
+    pub time_zone: String,
+    #[serde(default)]
+    pub updated_at: i64,
+    ///Videos shown during the event
+    #[serde(default)]
+    pub videos: Vec<GameVideoId>,
+}
+
+///Field names of Event, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/event.rs"));
+}
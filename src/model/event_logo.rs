@@ -0,0 +1,31 @@
+use crate::model::ids::{EventId, EventLogoId};
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct EventLogo {
+    #[serde(default)]
+    pub id: EventLogoId,
+    #[serde(default)]
+    pub alpha_channel: bool,
+    #[serde(default)]
+    pub animated: bool,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub event: EventId,
+    #[serde(default)]
+    pub height: u64,
+    #[serde(default)]
+    pub image_id: String,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub width: u64,
+}
+
+///Field names of EventLogo, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/event_logo.rs"));
+}
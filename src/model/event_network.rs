@@ -0,0 +1,24 @@
+use crate::model::ids::{EventId, EventNetworkId, NetworkTypeId};
+
+///A url where an event is streamed
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct EventNetwork {
+    #[serde(default)]
+    pub id: EventNetworkId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub event: EventId,
+    #[serde(default)]
+    pub network_type: NetworkTypeId,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub url: String,
+}
+
+///Field names of EventNetwork, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/event_network.rs"));
+}
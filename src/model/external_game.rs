@@ -1,18 +1,22 @@
 use crate::model::enums::{ExternalGameCategory, Media, PlatformCategory};
+use crate::model::ids::{ExternalGameId, GameId};
 use std::vec::Vec;
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct ExternalGame {
+    #[serde(default)]
+    pub id: ExternalGameId,
     #[serde(default)]
     pub category: ExternalGameCategory,
     #[serde(default)]
-    pub uuid: usize,
+    pub uuid: u64,
     #[serde(default)]
     pub countries: Vec<u32>,
     #[serde(default)]
-    pub created_at: u64,
+    pub created_at: i64,
     #[serde(default)]
-    pub game: usize,
+    pub game: GameId,
     #[serde(default)]
     pub media: Media,
     #[serde(default)]
@@ -22,9 +26,14 @@ pub struct ExternalGame {
     #[serde(default)]
     pub uid: String,
     #[serde(default)]
-    pub updated_at: u64,
+    pub updated_at: i64,
     #[serde(default)]
     pub url: String,
     #[serde(default)]
     pub year: u16,
 }
+
+///Field names of ExternalGame, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/external_game.rs"));
+}
@@ -1,17 +1,25 @@
+use crate::model::ids::{FranchiseId, GameId};
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Franchise {
     #[serde(default)]
-    pub id: usize,
+    pub id: FranchiseId,
     #[serde(default)]
-    pub created_at: u64,
+    pub created_at: i64,
     #[serde(default)]
-    pub games: Vec<usize>,
+    pub games: Vec<GameId>,
     #[serde(default)]
     pub name: String,
     #[serde(default)]
     pub slug: String,
     #[serde(default)]
-    pub updated_at: u64,
+    pub updated_at: i64,
     #[serde(default)]
     pub url: String,
 }
+
+///Field names of Franchise, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/franchise.rs"));
+}
@@ -0,0 +1,31 @@
+use crate::model::ids::GameEngineId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct GameEngine {
+    #[serde(default)]
+    pub id: GameEngineId,
+    #[serde(default)]
+    pub companies: Vec<u64>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub logo: u64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub platforms: Vec<u64>,
+    #[serde(default)]
+    pub slug: String,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub url: String,
+}
+
+///Field names of GameEngine, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/game_engine.rs"));
+}
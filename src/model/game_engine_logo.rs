@@ -0,0 +1,25 @@
+use crate::model::ids::GameEngineLogoId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct GameEngineLogo {
+    #[serde(default)]
+    pub id: GameEngineLogoId,
+    #[serde(default)]
+    pub alpha_channel: bool,
+    #[serde(default)]
+    pub animated: bool,
+    #[serde(default)]
+    pub height: u64,
+    #[serde(default)]
+    pub image_id: String,
+    #[serde(default)]
+    pub url: String,
+    #[serde(default)]
+    pub width: u64,
+}
+
+///Field names of GameEngineLogo, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/game_engine_logo.rs"));
+}
@@ -0,0 +1,29 @@
+use crate::model::cover::Cover;
+use crate::model::id_or::IdOr;
+use crate::model::ids::{GameId, GameLocalizationId};
+use crate::model::region::Region;
+
+///The name and cover of a game in a region, e.g. its Japanese title
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct GameLocalization {
+    #[serde(default)]
+    pub id: GameLocalizationId,
+    #[serde(default)]
+    pub cover: IdOr<Cover>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub game: GameId,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub region: IdOr<Region>,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+///Field names of GameLocalization, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/game_localization.rs"));
+}
@@ -0,0 +1,69 @@
+use crate::model::ids::{GameId, GameTimeToBeatId};
+use std::time::Duration;
+
+///How long players take to beat a game, in seconds, 0 when unknown
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct GameTimeToBeat {
+    #[serde(default)]
+    pub id: GameTimeToBeatId,
+    ///Time to beat the game while completing everything
+    #[serde(default)]
+    pub completely: u64,
+    ///Number of players who reported their times
+    #[serde(default)]
+    pub count: u64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub game_id: GameId,
+    ///Time to beat the main story only
+    #[serde(default)]
+    pub hastily: u64,
+    ///Time to beat the main story and some extras
+    #[serde(default)]
+    pub normally: u64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+impl GameTimeToBeat {
+    ///Time to beat the main story only, None when unknown
+    pub fn hastily_duration(&self) -> Option<Duration> {
+        duration(self.hastily)
+    }
+
+    ///Time to beat the main story and some extras, None when unknown
+    pub fn normally_duration(&self) -> Option<Duration> {
+        duration(self.normally)
+    }
+
+    ///Time to beat the game while completing everything, None when unknown
+    pub fn completely_duration(&self) -> Option<Duration> {
+        duration(self.completely)
+    }
+}
+
+fn duration(seconds: u64) -> Option<Duration> {
+    match seconds {
+        0 => None,
+        seconds => Some(Duration::from_secs(seconds)),
+    }
+}
+
+///Field names of GameTimeToBeat, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/game_time_to_beat.rs"));
+}
+
+#[test]
+fn unknown_times_are_none() {
+    let time: GameTimeToBeat = serde_json::from_str(
+        r#"{"id": 1, "game_id": 1942, "hastily": 185400, "normally": 369000, "count": 12}"#,
+    )
+    .unwrap();
+
+    assert_eq!(GameId(1942), time.game_id);
+    assert_eq!(Some(Duration::from_secs(185400)), time.hastily_duration());
+    assert_eq!(None, time.completely_duration());
+}
@@ -0,0 +1,31 @@
+use crate::model::game_version_feature::GameVersionFeature;
+use crate::model::id_or::IdOr;
+use crate::model::ids::{GameId, GameVersionId};
+
+///The editions of a game, e.g. standard, deluxe or game of the year,
+/// and the features they are compared on
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct GameVersion {
+    #[serde(default)]
+    pub id: GameVersionId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub features: Vec<IdOr<GameVersionFeature>>,
+    ///The game the editions are versions of
+    #[serde(default)]
+    pub game: GameId,
+    ///The editions, each one being a game
+    #[serde(default)]
+    pub games: Vec<GameId>,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub url: String,
+}
+
+///Field names of GameVersion, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/game_version.rs"));
+}
@@ -0,0 +1,41 @@
+use crate::model::enums::GameVersionFeatureCategory;
+use crate::model::game_version_feature_value::GameVersionFeatureValue;
+use crate::model::id_or::IdOr;
+use crate::model::ids::{GameId, GameVersionFeatureId};
+
+///A feature compared across the editions of a game, e.g. a soundtrack or a season pass
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct GameVersionFeature {
+    #[serde(default)]
+    pub id: GameVersionFeatureId,
+    #[serde(default)]
+    pub category: GameVersionFeatureCategory,
+    #[serde(default)]
+    pub description: String,
+    ///Position of the feature in the comparison table
+    #[serde(default)]
+    pub position: i32,
+    #[serde(default)]
+    pub title: String,
+    ///Whether each edition includes the feature
+    #[serde(default)]
+    pub values: Vec<IdOr<GameVersionFeatureValue>>,
+}
+
+impl GameVersionFeature {
+    ///The value of the feature for the given edition, when the values were expanded,
+    /// e.g. with `features.values.*`
+    pub fn value_for<I: Into<GameId>>(&self, edition: I) -> Option<&GameVersionFeatureValue> {
+        let edition = edition.into();
+        self.values
+            .iter()
+            .filter_map(IdOr::expanded)
+            .find(|value| value.game == edition)
+    }
+}
+
+///Field names of GameVersionFeature, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/game_version_feature.rs"));
+}
@@ -0,0 +1,28 @@
+use crate::model::enums::IncludedFeature;
+use crate::model::ids::{GameId, GameVersionFeatureId, GameVersionFeatureValueId};
+
+///Whether an edition of a game includes a feature
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct GameVersionFeatureValue {
+    #[serde(default)]
+    pub id: GameVersionFeatureValueId,
+    ///The edition
+    #[serde(default)]
+    pub game: GameId,
+    #[serde(default)]
+    pub game_feature: GameVersionFeatureId,
+    #[serde(default)]
+    pub included_feature: IncludedFeature,
+    ///Describes the feature in the edition, for the Description features
+    #[serde(default)]
+    pub note: String,
+}
+
+///Field names of GameVersionFeatureValue, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(
+        env!("OUT_DIR"),
+        "/fields/game_version_feature_value.rs"
+    ));
+}
@@ -1,9 +1,51 @@
+use crate::model::ids::{GameId, GameVideoId};
+
+const YOUTUBE_URL: &str = "https://www.youtube.com/watch?v=";
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct GameVideo {
     #[serde(default)]
-    pub id: usize,
+    pub id: GameVideoId,
     #[serde(default)]
-    pub game: usize,
+    pub game: GameId,
+    #[serde(default)]
+    pub name: String,
     #[serde(default)]
     pub video_id: String,
 }
+
+impl GameVideo {
+    ///Returns the url of the video on YouTube, where IGDB videos are hosted
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// task::block_on(async {
+    ///     let videos_client = IGDBClient::new("client_id", "token").game_videos();
+    ///     for video in videos_client.get_by_game_id(1942, 8).await.unwrap() {
+    ///         println!("{}: {}", video.name, video.youtube_url());
+    ///     }
+    /// })
+    /// ```
+    pub fn youtube_url(&self) -> String {
+        format!("{}{}", YOUTUBE_URL, self.video_id)
+    }
+}
+
+///Field names of GameVideo, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/game_video.rs"));
+}
+
+#[test]
+fn game_video_youtube_url() {
+    let video: GameVideo =
+        serde_json::from_str(r#"{"id": 5993, "game": 1942, "video_id": "xQGam9OHSUo"}"#).unwrap();
+
+    assert_eq!(
+        "https://www.youtube.com/watch?v=xQGam9OHSUo",
+        video.youtube_url()
+    );
+}
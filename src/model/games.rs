@@ -1,78 +1,111 @@
+use crate::image_url::ImageUrl;
+use crate::media_helpers::parse_url;
+use crate::media_quality::MediaQuality;
+use crate::model::artwork::Artwork;
+use crate::model::cover::Cover;
 use crate::model::enums::{GameCategory, Status};
+use crate::model::genre::Genre;
+use crate::model::id_or::IdOr;
+use crate::model::ids::{
+    AgeRatingId, AlternativeNameId, CollectionId, ExternalGameId, FranchiseId, GameEngineId,
+    GameId, GameLocalizationId, GameModeId, GameVideoId, KeywordId, LanguageSupportId,
+    MultiplayerModeId, PlatformId, PlayerPerspectiveId, ReleaseDateId, WebsiteId,
+};
+use crate::model::involved_company::InvolvedCompany;
+use crate::model::platform::Platform;
+use crate::model::screenshot::Screenshot;
+use crate::model::theme::Theme;
 use std::vec::Vec;
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Game {
     #[serde(default)]
-    pub age_ratings: Vec<usize>,
+    pub age_ratings: Vec<AgeRatingId>,
     #[serde(default)]
-    pub aggregated_rating: f32,
+    pub aggregated_rating: f64,
     #[serde(default)]
-    pub aggregated_rating_count: usize,
+    pub aggregated_rating_count: u64,
     #[serde(default)]
-    pub alternative_names: Vec<usize>,
+    pub alternative_names: Vec<AlternativeNameId>,
     #[serde(default)]
-    pub artworks: Vec<usize>,
+    pub artworks: Vec<IdOr<Artwork>>,
     #[serde(default)]
-    pub bundles: Vec<usize>,
+    pub bundles: Vec<GameId>,
     #[serde(default)]
     pub category: GameCategory,
     #[serde(default)]
-    pub collection: usize,
+    pub collection: CollectionId,
     #[serde(default)]
-    pub cover: usize,
+    pub cover: IdOr<Cover>,
     #[serde(default)]
-    pub created_at: u64,
+    pub created_at: i64,
     #[serde(default)]
-    pub dlcs: Vec<usize>,
+    pub dlcs: Vec<GameId>,
     #[serde(default)]
-    pub external_games: Vec<usize>,
+    pub expanded_games: Vec<GameId>,
     #[serde(default)]
-    pub first_release_date: u64,
+    pub expansions: Vec<GameId>,
     #[serde(default)]
-    pub follows: usize,
+    pub external_games: Vec<ExternalGameId>,
     #[serde(default)]
-    pub franchise: usize,
+    pub first_release_date: i64,
     #[serde(default)]
-    pub franchises: Vec<usize>,
+    pub follows: u64,
     #[serde(default)]
-    pub game_engines: Vec<usize>,
+    pub forks: Vec<GameId>,
     #[serde(default)]
-    pub game_modes: Vec<usize>,
+    pub franchise: FranchiseId,
     #[serde(default)]
-    pub genres: Vec<usize>,
+    pub franchises: Vec<FranchiseId>,
     #[serde(default)]
-    pub hypes: usize,
+    pub game_engines: Vec<GameEngineId>,
     #[serde(default)]
-    pub id: usize,
+    pub game_localizations: Vec<GameLocalizationId>,
     #[serde(default)]
-    pub involved_companies: Vec<usize>,
+    pub game_modes: Vec<GameModeId>,
     #[serde(default)]
-    pub keywords: Vec<usize>,
+    pub genres: Vec<IdOr<Genre>>,
     #[serde(default)]
-    pub multiplayer_modes: Vec<usize>,
+    pub hypes: u64,
+    #[serde(default)]
+    pub id: GameId,
+    #[serde(default)]
+    pub involved_companies: Vec<IdOr<InvolvedCompany>>,
+    #[serde(default)]
+    pub keywords: Vec<KeywordId>,
+    #[serde(default)]
+    pub language_supports: Vec<LanguageSupportId>,
+    #[serde(default)]
+    pub multiplayer_modes: Vec<MultiplayerModeId>,
     #[serde(default)]
     pub name: String,
     #[serde(default)]
-    pub parent_game: usize,
+    pub parent_game: GameId,
+    #[serde(default)]
+    pub platforms: Vec<IdOr<Platform>>,
+    #[serde(default)]
+    pub player_perspectives: Vec<PlayerPerspectiveId>,
     #[serde(default)]
-    pub platforms: Vec<usize>,
+    pub ports: Vec<GameId>,
     #[serde(default)]
-    pub player_perspectives: Vec<usize>,
+    pub rating: f64,
     #[serde(default)]
-    pub rating: f32,
+    pub rating_count: u64,
     #[serde(default)]
-    pub rating_count: usize,
+    pub release_dates: Vec<ReleaseDateId>,
     #[serde(default)]
-    pub release_dates: Vec<usize>,
+    pub remakes: Vec<GameId>,
     #[serde(default)]
-    pub screenshots: Vec<usize>,
+    pub remasters: Vec<GameId>,
     #[serde(default)]
-    pub similar_games: Vec<usize>,
+    pub screenshots: Vec<IdOr<Screenshot>>,
+    #[serde(default)]
+    pub similar_games: Vec<IdOr<Game>>,
     #[serde(default)]
     pub slug: String,
     #[serde(default)]
-    pub standalone_expansions: Vec<usize>,
+    pub standalone_expansions: Vec<GameId>,
     #[serde(default)]
     pub status: Status,
     #[serde(default)]
@@ -80,23 +113,198 @@ pub struct Game {
     #[serde(default)]
     pub summary: String,
     #[serde(default)]
-    pub tags: Vec<usize>,
+    pub tags: Vec<u64>,
     #[serde(default)]
-    pub themes: Vec<usize>,
+    pub themes: Vec<IdOr<Theme>>,
     #[serde(default)]
-    pub total_rating: f32,
+    pub total_rating: f64,
     #[serde(default)]
-    pub total_rating_count: usize,
+    pub total_rating_count: u64,
     #[serde(default)]
-    pub updated_at: u64,
+    pub updated_at: i64,
     #[serde(default)]
     pub url: String,
     #[serde(default)]
-    pub version_parent: usize,
+    pub version_parent: GameId,
     #[serde(default)]
     pub version_title: String,
     #[serde(default)]
-    pub videos: Vec<usize>,
+    pub videos: Vec<GameVideoId>,
     #[serde(default)]
-    pub websites: Vec<usize>,
+    pub websites: Vec<WebsiteId>,
+}
+
+///Number of ratings at which total_rating_confidence is 0.5
+const HALF_CONFIDENCE_RATING_COUNT: f64 = 10.0;
+
+impl Game {
+    ///Whether the game was released on the given platform, the platforms being requested
+    pub fn is_on(&self, platform: PlatformId) -> bool {
+        self.platforms.iter().any(|p| p.id() == platform)
+    }
+
+    ///Returns the average of the user and critic ratings weighted by their counts,
+    /// None when the game was rated by neither or the ratings were not requested
+    pub fn combined_rating(&self) -> Option<f64> {
+        let count = self.rating_count + self.aggregated_rating_count;
+        match count {
+            0 => None,
+            count => Some(
+                (self.rating * self.rating_count as f64
+                    + self.aggregated_rating * self.aggregated_rating_count as f64)
+                    / count as f64,
+            ),
+        }
+    }
+
+    ///Returns how much the total rating can be trusted from 0 to 1, growing with the number
+    /// of ratings: 0 without any, 0.5 with 10 and 0.9 with 90
+    pub fn total_rating_confidence(&self) -> f64 {
+        let count = self.total_rating_count as f64;
+        count / (count + HALF_CONFIDENCE_RATING_COUNT)
+    }
+
+    ///Returns the url of the best available image with the given size preset: the cover,
+    /// else the first screenshot, else the first artwork. Only expanded references are read,
+    /// e.g. with the `cover.image_id`, `screenshots.image_id` and `artworks.image_id` fields
+    /// # Examples
+    /// ```
+    /// use igdb::media_quality::MediaQuality;
+    /// use igdb::model::games::Game;
+    ///
+    /// let game: Game = serde_json::from_str(
+    ///     r#"{"id": 1942, "cover": 89386, "screenshots": [{"id": 7, "image_id": "sc6lsn"}]}"#,
+    /// ).unwrap();
+    /// assert_eq!(
+    ///     Some("https://images.igdb.com/igdb/image/upload/t_screenshot_big/sc6lsn.jpg".to_owned()),
+    ///     game.thumbnail_url(MediaQuality::ScreenshotBig)
+    /// );
+    /// ```
+    pub fn thumbnail_url(&self, quality: MediaQuality) -> Option<String> {
+        let cover = self.cover.expanded().map(|c| (&c.image_id, &c.url));
+        let screenshot = self.screenshots.first().and_then(IdOr::expanded);
+        let artwork = self.artworks.first().and_then(IdOr::expanded);
+        cover
+            .into_iter()
+            .chain(screenshot.map(|s| (&s.image_id, &s.url)))
+            .chain(artwork.map(|a| (&a.image_id, &a.url)))
+            .find_map(|(image_id, url)| match (image_id.as_str(), url.as_str()) {
+                ("", "") => None,
+                ("", url) => Some(parse_url(url, quality)),
+                (image_id, _) => Some(ImageUrl::new(image_id, quality).to_string()),
+            })
+    }
+
+    ///Returns the first release date, None when unknown or not requested
+    #[cfg(feature = "chrono")]
+    pub fn first_release_date_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        crate::timestamp::to_chrono(self.first_release_date)
+    }
+
+    ///Returns the first release date, None when unknown or not requested
+    #[cfg(feature = "time")]
+    pub fn first_release_date_time(&self) -> Option<time::OffsetDateTime> {
+        crate::timestamp::to_offset_date_time(self.first_release_date)
+    }
+}
+
+///Field names of Game, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/games.rs"));
+}
+
+#[test]
+fn game_with_selected_fields_deserializes() {
+    let game: Game = serde_json::from_str(
+        r#"{
+            "id": 1942,
+            "name": "The Witcher 3: Wild Hunt",
+            "slug": "the-witcher-3-wild-hunt",
+            "first_release_date": 1431993600,
+            "cover": 89386,
+            "genres": [12, 31],
+            "platforms": [6, 48, 49],
+            "rating": 93.5
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(GameId(1942), game.id);
+    assert_eq!("the-witcher-3-wild-hunt", game.slug);
+    assert_eq!(1431993600, game.first_release_date);
+    assert_eq!(
+        vec![
+            PlatformId::PC,
+            PlatformId::PlayStation4,
+            PlatformId::XboxOne
+        ],
+        game.platforms
+            .iter()
+            .map(IdOr::id)
+            .collect::<Vec<PlatformId>>()
+    );
+    assert!(game.is_on(PlatformId::Windows));
+    assert!(!game.is_on(PlatformId::Linux));
+    assert!(game.summary.is_empty());
+}
+
+#[test]
+fn game_ratings_are_combined_by_count() {
+    let game: Game = serde_json::from_str(
+        r#"{"id": 1, "rating": 90.0, "rating_count": 30, "aggregated_rating": 70.0,
+            "aggregated_rating_count": 10, "total_rating_count": 40}"#,
+    )
+    .unwrap();
+
+    assert_eq!(Some(85.0), game.combined_rating());
+    assert_eq!(0.8, game.total_rating_confidence());
+
+    let unrated: Game = serde_json::from_str(r#"{"id": 2}"#).unwrap();
+    assert_eq!(None, unrated.combined_rating());
+    assert_eq!(0.0, unrated.total_rating_confidence());
+}
+
+#[test]
+fn thumbnail_falls_back_to_the_first_screenshot_then_artwork() {
+    let thumbnail = |json: &str| {
+        serde_json::from_str::<Game>(json)
+            .unwrap()
+            .thumbnail_url(MediaQuality::CoverBig)
+    };
+    let url = |image_id: &str| {
+        Some(format!(
+            "https://images.igdb.com/igdb/image/upload/t_cover_big/{}.jpg",
+            image_id
+        ))
+    };
+
+    let media = r#""screenshots": [{"id": 7, "image_id": "sc6lsn"}, {"id": 8, "image_id": "sc6lso"}],
+        "artworks": [{"id": 5, "image_id": "ar5l8"}]"#;
+    assert_eq!(
+        url("co1wyy"),
+        thumbnail(&format!(
+            r#"{{"id": 1942, "cover": {{"id": 89386, "image_id": "co1wyy"}}, {}}}"#,
+            media
+        ))
+    );
+    assert_eq!(
+        url("sc6lsn"),
+        thumbnail(&format!(r#"{{"id": 1942, "cover": 89386, {}}}"#, media))
+    );
+    assert_eq!(
+        url("ar5l8"),
+        thumbnail(
+            r#"{"id": 1942, "screenshots": [7], "artworks": [{"id": 5, "image_id": "ar5l8"}]}"#
+        )
+    );
+    assert_eq!(
+        Some("http://images.igdb.com/igdb/image/upload/t_cover_big/co1wyy.jpg".to_owned()),
+        thumbnail(
+            r#"{"id": 1942, "cover": {"id": 89386, "url": "//images.igdb.com/igdb/image/upload/t_thumb/co1wyy.jpg"}}"#
+        )
+    );
+    assert_eq!(
+        None,
+        thumbnail(r#"{"id": 1942, "cover": 89386, "artworks": [5]}"#)
+    );
 }
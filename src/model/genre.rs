@@ -0,0 +1,23 @@
+use crate::model::ids::GenreId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct Genre {
+    #[serde(default)]
+    pub id: GenreId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub slug: String,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub url: String,
+}
+
+///Field names of Genre, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/genre.rs"));
+}
@@ -0,0 +1,223 @@
+use crate::model::age_rating::AgeRating;
+use crate::model::age_rating_content_description::AgeRatingContentDescription;
+use crate::model::alternative_name::AlternativeName;
+use crate::model::artwork::Artwork;
+use crate::model::character::Character;
+use crate::model::character_mug_shot::CharacterMugshot;
+use crate::model::collection::Collection;
+use crate::model::company::Company;
+use crate::model::cover::Cover;
+use crate::model::event::Event;
+use crate::model::event_logo::EventLogo;
+use crate::model::event_network::EventNetwork;
+use crate::model::external_game::ExternalGame;
+use crate::model::franchise::Franchise;
+use crate::model::game_engine::GameEngine;
+use crate::model::game_engine_logo::GameEngineLogo;
+use crate::model::game_localization::GameLocalization;
+use crate::model::game_mode::GameMode;
+use crate::model::game_time_to_beat::GameTimeToBeat;
+use crate::model::game_version::GameVersion;
+use crate::model::game_version_feature::GameVersionFeature;
+use crate::model::game_version_feature_value::GameVersionFeatureValue;
+use crate::model::game_video::GameVideo;
+use crate::model::games::Game;
+use crate::model::genre::Genre;
+use crate::model::ids::*;
+use crate::model::involved_company::InvolvedCompany;
+use crate::model::keyword::Keyword;
+use crate::model::language::Language;
+use crate::model::language_support::LanguageSupport;
+use crate::model::multiplayer_mode::MultiplayerMode;
+use crate::model::network_type::NetworkType;
+use crate::model::platform::Platform;
+use crate::model::platform_logo::PlatformLogo;
+use crate::model::platform_version::PlatformVersion;
+use crate::model::player_perspective::PlayerPerspective;
+use crate::model::popularity_primitive::PopularityPrimitive;
+use crate::model::popularity_type::PopularityType;
+use crate::model::region::Region;
+use crate::model::release_date::ReleaseDate;
+use crate::model::screenshot::Screenshot;
+use crate::model::theme::Theme;
+use crate::model::website::Website;
+use serde::de::value::{I64Deserializer, MapAccessDeserializer, U64Deserializer};
+use serde::de::{self, Deserialize, DeserializeOwned, Deserializer, MapAccess, Visitor};
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+///A reference to another registry, deserialized either from its raw id
+/// or from the expanded object when the request asked for nested fields,
+/// e.g. `fields name, cover.image_id;`
+/// # Examples
+/// ```
+/// use igdb::model::games::Game;
+/// use igdb::model::ids::{CoverId, GenreId};
+///
+/// let game: Game = serde_json::from_str(
+///     r#"{"id": 1942, "cover": {"id": 89386, "image_id": "co1wyy"}, "genres": [12]}"#,
+/// ).unwrap();
+///
+/// assert_eq!(CoverId(89386), game.cover.id());
+/// assert_eq!("co1wyy", game.cover.expanded().unwrap().image_id);
+/// assert_eq!(GenreId(12), game.genres[0].id());
+/// ```
+#[derive(Debug, Clone)]
+pub enum IdOr<T: Identifiable> {
+    Id(T::Id),
+    Object(T),
+}
+
+///Models referenced by other models, which know their own typed id
+pub trait Identifiable {
+    type Id: EntityId + Debug + Default + Eq + Hash + Ord + DeserializeOwned;
+
+    fn id(&self) -> Self::Id;
+}
+
+macro_rules! identifiable {
+    ($($model: ty => $id: ty),+ $(,)?) => {
+        $(
+            impl Identifiable for $model {
+                type Id = $id;
+
+                fn id(&self) -> $id {
+                    self.id
+                }
+            }
+        )+
+    };
+}
+
+identifiable!(
+    AgeRating => AgeRatingId,
+    AgeRatingContentDescription => AgeRatingContentDescriptionId,
+    AlternativeName => AlternativeNameId,
+    Artwork => ArtworkId,
+    Character => CharacterId,
+    CharacterMugshot => CharacterMugshotId,
+    Collection => CollectionId,
+    Company => CompanyId,
+    Cover => CoverId,
+    Event => EventId,
+    EventLogo => EventLogoId,
+    EventNetwork => EventNetworkId,
+    ExternalGame => ExternalGameId,
+    Franchise => FranchiseId,
+    GameEngine => GameEngineId,
+    GameEngineLogo => GameEngineLogoId,
+    Game => GameId,
+    GameLocalization => GameLocalizationId,
+    GameMode => GameModeId,
+    GameTimeToBeat => GameTimeToBeatId,
+    GameVersion => GameVersionId,
+    GameVersionFeature => GameVersionFeatureId,
+    GameVersionFeatureValue => GameVersionFeatureValueId,
+    GameVideo => GameVideoId,
+    Genre => GenreId,
+    InvolvedCompany => InvolvedCompanyId,
+    Keyword => KeywordId,
+    Language => LanguageId,
+    LanguageSupport => LanguageSupportId,
+    MultiplayerMode => MultiplayerModeId,
+    NetworkType => NetworkTypeId,
+    Platform => PlatformId,
+    PlatformLogo => PlatformLogoId,
+    PlatformVersion => PlatformVersionId,
+    PlayerPerspective => PlayerPerspectiveId,
+    PopularityPrimitive => PopularityPrimitiveId,
+    PopularityType => PopularityTypeId,
+    Region => RegionId,
+    ReleaseDate => ReleaseDateId,
+    Screenshot => ScreenshotId,
+    Theme => ThemeId,
+    Website => WebsiteId,
+);
+
+///Boxed models, referenced by the models they reference in turn, e.g. a game and its parent game
+impl<T: Identifiable> Identifiable for Box<T> {
+    type Id = T::Id;
+
+    fn id(&self) -> T::Id {
+        self.as_ref().id()
+    }
+}
+
+impl<T: Identifiable> IdOr<T> {
+    ///The id of the referenced registry, whether it was expanded or not
+    pub fn id(&self) -> T::Id {
+        match self {
+            IdOr::Id(id) => *id,
+            IdOr::Object(object) => object.id(),
+        }
+    }
+
+    ///The referenced registry when it was expanded
+    pub fn expanded(&self) -> Option<&T> {
+        match self {
+            IdOr::Id(_) => None,
+            IdOr::Object(object) => Some(object),
+        }
+    }
+}
+
+///Reads the expanded objects in place rather than buffering them like an untagged enum would,
+/// so that their errors and unknown fields are reported with their path
+impl<'de, T: Identifiable + Deserialize<'de>> Deserialize<'de> for IdOr<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Reference<T>(PhantomData<T>);
+
+        impl<'de, T: Identifiable + Deserialize<'de>> Visitor<'de> for Reference<T> {
+            type Value = IdOr<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an id or an expanded object")
+            }
+
+            fn visit_u64<E: de::Error>(self, id: u64) -> Result<IdOr<T>, E> {
+                T::Id::deserialize(U64Deserializer::new(id)).map(IdOr::Id)
+            }
+
+            fn visit_i64<E: de::Error>(self, id: i64) -> Result<IdOr<T>, E> {
+                T::Id::deserialize(I64Deserializer::new(id)).map(IdOr::Id)
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<IdOr<T>, A::Error> {
+                T::deserialize(MapAccessDeserializer::new(map)).map(IdOr::Object)
+            }
+        }
+
+        deserializer.deserialize_any(Reference(PhantomData))
+    }
+}
+
+///Written as IGDB sends it, the raw id or the expanded object
+#[cfg(feature = "serialize")]
+impl<T: Identifiable + serde::Serialize> serde::Serialize for IdOr<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            IdOr::Id(id) => serializer.serialize_u64(id.value()),
+            IdOr::Object(registry) => registry.serialize(serializer),
+        }
+    }
+}
+
+impl<T: Identifiable> Default for IdOr<T> {
+    fn default() -> Self {
+        IdOr::Id(T::Id::default())
+    }
+}
+
+#[test]
+fn deserializes_ids_and_expanded_objects() {
+    let references: Vec<IdOr<Genre>> =
+        serde_json::from_str(r#"[12, {"id": 31, "name": "Adventure"}]"#).unwrap();
+
+    assert_eq!(
+        vec![GenreId(12), GenreId(31)],
+        references.iter().map(IdOr::id).collect::<Vec<GenreId>>()
+    );
+    assert!(references[0].expanded().is_none());
+    assert_eq!("Adventure", references[1].expanded().unwrap().name);
+}
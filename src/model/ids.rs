@@ -0,0 +1,196 @@
+use crate::request_builder::Value;
+use std::fmt;
+
+///Typed id of a registry of a given endpoint, so that an id of one entity
+/// cannot be mistaken for an id of another, e.g. a genre id for a platform id
+pub trait EntityId: Copy + Into<Value> {
+    ///The raw IGDB id
+    fn value(&self) -> u64;
+}
+
+///Raw ids, e.g. of the models generated from the protobuf schema
+impl EntityId for u64 {
+    fn value(&self) -> u64 {
+        *self
+    }
+}
+
+macro_rules! entity_ids {
+    ($($id: ident),+ $(,)?) => {
+        $(
+            #[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[cfg_attr(feature = "serialize", derive(Serialize))]
+            #[serde(transparent)]
+            pub struct $id(pub u64);
+
+            impl EntityId for $id {
+                fn value(&self) -> u64 {
+                    self.0
+                }
+            }
+
+            impl fmt::Display for $id {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+
+            impl From<u64> for $id {
+                fn from(id: u64) -> Self {
+                    $id(id)
+                }
+            }
+
+            impl From<$id> for Value {
+                fn from(id: $id) -> Self {
+                    Value::Int(id.0 as i64)
+                }
+            }
+
+            impl From<Vec<$id>> for Value {
+                fn from(ids: Vec<$id>) -> Self {
+                    Value::Ids(ids.iter().map(|id| id.0 as usize).collect())
+                }
+            }
+
+            impl From<&[$id]> for Value {
+                fn from(ids: &[$id]) -> Self {
+                    Value::Ids(ids.iter().map(|id| id.0 as usize).collect())
+                }
+            }
+        )+
+    };
+}
+
+entity_ids!(
+    AgeRatingId,
+    AgeRatingContentDescriptionId,
+    AlternativeNameId,
+    ArtworkId,
+    CharacterId,
+    CharacterMugshotId,
+    CollectionId,
+    CompanyId,
+    CoverId,
+    EventId,
+    EventLogoId,
+    EventNetworkId,
+    ExternalGameId,
+    FranchiseId,
+    GameEngineId,
+    GameEngineLogoId,
+    GameId,
+    GameLocalizationId,
+    GameModeId,
+    GameTimeToBeatId,
+    GameVersionId,
+    GameVersionFeatureId,
+    GameVersionFeatureValueId,
+    GameVideoId,
+    GenreId,
+    InvolvedCompanyId,
+    KeywordId,
+    LanguageId,
+    LanguageSupportId,
+    MultiplayerModeId,
+    NetworkTypeId,
+    PlatformId,
+    PlatformLogoId,
+    PlatformVersionId,
+    PlayerPerspectiveId,
+    PopularityPrimitiveId,
+    PopularityTypeId,
+    RegionId,
+    ReleaseDateId,
+    ScreenshotId,
+    ThemeId,
+    WebsiteId,
+);
+
+///Defines the ids of the well-known platforms from a table of their name, id and IGDB name
+macro_rules! platform_ids {
+    ($($platform: ident = $id: literal, $name: literal;)+) => {
+        #[allow(non_upper_case_globals)]
+        impl PlatformId {
+            $(
+                #[doc = $name]
+                pub const $platform: PlatformId = PlatformId($id);
+            )+
+
+            ///The well-known platforms along with their IGDB name
+            pub const KNOWN: &'static [(PlatformId, &'static str)] = &[$((PlatformId($id), $name)),+];
+        }
+    };
+}
+
+platform_ids! {
+    Linux = 3, "Linux";
+    Nintendo64 = 4, "Nintendo 64";
+    Wii = 5, "Wii";
+    PC = 6, "PC (Microsoft Windows)";
+    PlayStation = 7, "PlayStation";
+    PlayStation2 = 8, "PlayStation 2";
+    PlayStation3 = 9, "PlayStation 3";
+    Xbox = 11, "Xbox";
+    Xbox360 = 12, "Xbox 360";
+    Mac = 14, "Mac";
+    NES = 18, "Nintendo Entertainment System";
+    SNES = 19, "Super Nintendo Entertainment System";
+    NintendoDS = 20, "Nintendo DS";
+    GameCube = 21, "Nintendo GameCube";
+    GameBoyColor = 22, "Game Boy Color";
+    Dreamcast = 23, "Dreamcast";
+    GameBoyAdvance = 24, "Game Boy Advance";
+    MegaDrive = 29, "Sega Mega Drive/Genesis";
+    GameBoy = 33, "Game Boy";
+    Android = 34, "Android";
+    Nintendo3DS = 37, "Nintendo 3DS";
+    PSP = 38, "PlayStation Portable";
+    IOS = 39, "iOS";
+    WiiU = 41, "Wii U";
+    PlayStationVita = 46, "PlayStation Vita";
+    PlayStation4 = 48, "PlayStation 4";
+    XboxOne = 49, "Xbox One";
+    Web = 82, "Web browser";
+    Switch = 130, "Nintendo Switch";
+    PlayStation5 = 167, "PlayStation 5";
+    XboxSeriesXS = 169, "Xbox Series X|S";
+    Stadia = 170, "Google Stadia";
+}
+
+///Ids of well-known platforms, usable directly as filter values.
+/// The platforms missing from the table are created from their id, e.g. `PlatformId(487)`
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use igdb::model::ids::PlatformId;
+/// use igdb::request_builder::Equality;
+///
+/// let mut request = IGDBClient::create_request();
+/// request
+/// .add_field("name")
+/// .add_where("platforms", Equality::Equal, PlatformId::Linux);
+/// ```
+impl PlatformId {
+    ///Same as PC, the id IGDB gives to Microsoft Windows
+    #[allow(non_upper_case_globals)]
+    pub const Windows: PlatformId = PlatformId::PC;
+
+    ///Returns the IGDB name of a well-known platform, None for the other ids
+    pub fn name(self) -> Option<&'static str> {
+        PlatformId::KNOWN
+            .iter()
+            .find(|(id, _)| *id == self)
+            .map(|(_, name)| *name)
+    }
+}
+
+#[test]
+fn ids_deserialize_from_raw_numbers() {
+    let ids: Vec<GenreId> = serde_json::from_str("[12, 31]").unwrap();
+    assert_eq!(vec![GenreId(12), GenreId(31)], ids);
+    assert_eq!("(12,31)", Value::from(ids).to_string());
+    assert_eq!(PlatformId(3), PlatformId::Linux);
+    assert_eq!(Some("PC (Microsoft Windows)"), PlatformId::Windows.name());
+    assert_eq!(None, PlatformId(99999).name());
+}
@@ -0,0 +1,75 @@
+use crate::model::ids::{CompanyId, GameId, InvolvedCompanyId};
+
+///Role of a company in the making of a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompanyRole {
+    Developer,
+    Publisher,
+    Porting,
+    Supporting,
+}
+
+impl CompanyRole {
+    ///Name of the boolean InvolvedCompany field flagging this role
+    pub fn field(&self) -> &'static str {
+        match self {
+            CompanyRole::Developer => "developer",
+            CompanyRole::Publisher => "publisher",
+            CompanyRole::Porting => "porting",
+            CompanyRole::Supporting => "supporting",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct InvolvedCompany {
+    #[serde(default)]
+    pub id: InvolvedCompanyId,
+    #[serde(default)]
+    pub company: CompanyId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub developer: bool,
+    #[serde(default)]
+    pub game: GameId,
+    #[serde(default)]
+    pub porting: bool,
+    #[serde(default)]
+    pub publisher: bool,
+    #[serde(default)]
+    pub supporting: bool,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+impl InvolvedCompany {
+    ///Returns whether the company had the given role
+    pub fn has_role(&self, role: CompanyRole) -> bool {
+        match role {
+            CompanyRole::Developer => self.developer,
+            CompanyRole::Publisher => self.publisher,
+            CompanyRole::Porting => self.porting,
+            CompanyRole::Supporting => self.supporting,
+        }
+    }
+}
+
+///Field names of InvolvedCompany, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/involved_company.rs"));
+}
+
+#[test]
+fn involved_company_roles_deserialize() {
+    let involved: InvolvedCompany = serde_json::from_str(
+        r#"{"id": 9, "company": 908, "game": 1942, "developer": true, "publisher": true}"#,
+    )
+    .unwrap();
+
+    assert!(involved.has_role(CompanyRole::Developer));
+    assert!(involved.has_role(CompanyRole::Publisher));
+    assert!(!involved.has_role(CompanyRole::Porting));
+    assert!(!involved.has_role(CompanyRole::Supporting));
+}
@@ -0,0 +1,23 @@
+use crate::model::ids::KeywordId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct Keyword {
+    #[serde(default)]
+    pub id: KeywordId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub slug: String,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub url: String,
+}
+
+///Field names of Keyword, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/keyword.rs"));
+}
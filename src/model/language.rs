@@ -0,0 +1,23 @@
+use crate::model::ids::LanguageId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct Language {
+    #[serde(default)]
+    pub id: LanguageId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub locale: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub native_name: String,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+///Field names of Language, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/language.rs"));
+}
@@ -0,0 +1,24 @@
+use crate::model::enums::SupportType;
+use crate::model::ids::{GameId, LanguageId, LanguageSupportId};
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct LanguageSupport {
+    #[serde(default)]
+    pub id: LanguageSupportId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub game: GameId,
+    #[serde(default)]
+    pub language: LanguageId,
+    #[serde(default)]
+    pub language_support_type: SupportType,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+///Field names of LanguageSupport, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/language_support.rs"));
+}
@@ -1,21 +1,51 @@
 pub mod age_rating;
+pub mod age_rating_content_description;
+pub mod alternative_name;
 pub mod artwork;
 pub mod character;
 pub mod character_mug_shot;
+pub mod collection;
 pub mod company;
+pub mod company_catalog;
 pub mod cover;
 pub mod engine;
+#[macro_use]
 pub mod enums;
+pub mod event;
+pub mod event_logo;
+pub mod event_network;
 pub mod external_game;
 pub mod franchise;
+pub mod game_engine;
+pub mod game_engine_logo;
+pub mod game_localization;
 pub mod game_mode;
+pub mod game_time_to_beat;
+pub mod game_version;
+pub mod game_version_feature;
+pub mod game_version_feature_value;
 pub mod game_video;
 pub mod games;
+pub mod genre;
+pub mod id_or;
+pub mod ids;
+pub mod involved_company;
+pub mod keyword;
+pub mod language;
+pub mod language_support;
 pub mod multiplayer_mode;
+pub mod network_type;
 pub mod platform;
 pub mod platform_logo;
+pub mod platform_version;
 pub mod player_perspective;
+pub mod popularity_primitive;
+pub mod popularity_type;
+pub mod region;
+pub mod regional_title;
+pub mod related_games;
 pub mod release_date;
 pub mod screenshot;
 pub mod theme;
+pub mod webhook;
 pub mod website;
@@ -1,31 +1,39 @@
+use crate::model::ids::{GameId, MultiplayerModeId, PlatformId};
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct MultiplayerMode {
     #[serde(default)]
-    pub id: usize,
+    pub id: MultiplayerModeId,
     #[serde(default)]
     pub campaingcoop: bool,
     #[serde(default)]
     pub dropin: bool,
     #[serde(default)]
-    pub game: usize,
+    pub game: GameId,
     #[serde(default)]
     pub lancoop: bool,
     #[serde(default)]
     pub offlinecoop: bool,
     #[serde(default)]
-    pub offlinecoopmax: usize,
+    pub offlinecoopmax: u64,
     #[serde(default)]
-    pub offlinemax: usize,
+    pub offlinemax: u64,
     #[serde(default)]
     pub onlinecoop: bool,
     #[serde(default)]
-    pub onlinecoopmax: usize,
+    pub onlinecoopmax: u64,
     #[serde(default)]
-    pub onlinemax: usize,
+    pub onlinemax: u64,
     #[serde(default)]
-    pub platform: usize,
+    pub platform: PlatformId,
     #[serde(default)]
     pub splitscreen: bool,
     #[serde(default)]
     pub splitscreenonline: bool,
 }
+
+///Field names of MultiplayerMode, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/multiplayer_mode.rs"));
+}
@@ -0,0 +1,22 @@
+use crate::model::ids::{EventNetworkId, NetworkTypeId};
+
+///A network events are streamed on, e.g. YouTube or Twitch
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct NetworkType {
+    #[serde(default)]
+    pub id: NetworkTypeId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub event_networks: Vec<EventNetworkId>,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+///Field names of NetworkType, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/network_type.rs"));
+}
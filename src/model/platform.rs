@@ -1,9 +1,11 @@
 use crate::model::enums::PlatformCategory;
+use crate::model::ids::{PlatformId, PlatformLogoId, PlatformVersionId};
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Platform {
     #[serde(default)]
-    pub id: usize,
+    pub id: PlatformId,
     #[serde(default)]
     pub abbreviation: String,
     #[serde(default)]
@@ -11,15 +13,15 @@ pub struct Platform {
     #[serde(default)]
     pub category: PlatformCategory,
     #[serde(default)]
-    pub created_at: u64,
+    pub created_at: i64,
     #[serde(default)]
-    pub generation: usize,
+    pub generation: u64,
     #[serde(default)]
     pub name: String,
     #[serde(default)]
-    pub platform_logo: usize,
+    pub platform_logo: PlatformLogoId,
     #[serde(default)]
-    pub platform_family: usize,
+    pub platform_family: u64,
     #[serde(default)]
     pub slug: String,
     #[serde(default)]
@@ -27,7 +29,12 @@ pub struct Platform {
     #[serde(default)]
     pub url: String,
     #[serde(default)]
-    pub versions: Vec<u32>,
+    pub versions: Vec<PlatformVersionId>,
     #[serde(default)]
     pub websites: Vec<u32>,
 }
+
+///Field names of Platform, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/platform.rs"));
+}
@@ -1,15 +1,25 @@
+use crate::model::ids::PlatformLogoId;
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct PlatformLogo {
+    #[serde(default)]
+    pub id: PlatformLogoId,
     #[serde(default)]
     pub alpha_channel: bool,
     #[serde(default)]
     pub animated: bool,
     #[serde(default)]
-    pub height: usize,
+    pub height: u64,
     #[serde(default)]
     pub image_id: String,
     #[serde(default)]
     pub url: String,
     #[serde(default)]
-    pub width: usize,
+    pub width: u64,
+}
+
+///Field names of PlatformLogo, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/platform_logo.rs"));
 }
@@ -0,0 +1,49 @@
+use crate::model::ids::PlatformVersionId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct PlatformVersion {
+    #[serde(default)]
+    pub id: PlatformVersionId,
+    #[serde(default)]
+    pub companies: Vec<u64>,
+    #[serde(default)]
+    pub connectivity: String,
+    #[serde(default)]
+    pub cpu: String,
+    #[serde(default)]
+    pub graphics: String,
+    #[serde(default)]
+    pub main_manufacturer: u64,
+    #[serde(default)]
+    pub media: String,
+    #[serde(default)]
+    pub memory: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub os: String,
+    #[serde(default)]
+    pub output: String,
+    #[serde(default)]
+    pub platform_logo: u64,
+    #[serde(default)]
+    pub platform_version_release_dates: Vec<u64>,
+    #[serde(default)]
+    pub resolutions: String,
+    #[serde(default)]
+    pub slug: String,
+    #[serde(default)]
+    pub sound: String,
+    #[serde(default)]
+    pub storage: String,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub url: String,
+}
+
+///Field names of PlatformVersion, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/platform_version.rs"));
+}
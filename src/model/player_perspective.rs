@@ -1,13 +1,21 @@
+use crate::model::ids::PlayerPerspectiveId;
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct PlayerPerspective {
     #[serde(default)]
-    pub id: usize,
+    pub id: PlayerPerspectiveId,
     #[serde(default)]
     pub name: String,
     #[serde(default)]
     pub slug: String,
     #[serde(default)]
-    pub updated_at: u64,
+    pub updated_at: i64,
     #[serde(default)]
     pub url: String,
 }
+
+///Field names of PlayerPerspective, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/player_perspective.rs"));
+}
@@ -0,0 +1,27 @@
+use crate::model::ids::{GameId, PopularityPrimitiveId};
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct PopularityPrimitive {
+    #[serde(default)]
+    pub id: PopularityPrimitiveId,
+    #[serde(default)]
+    pub calculated_at: i64,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub external_popularity_source: u64,
+    #[serde(default)]
+    pub game_id: GameId,
+    #[serde(default)]
+    pub popularity_type: u64,
+    #[serde(default)]
+    pub updated_at: i64,
+    #[serde(default)]
+    pub value: f64,
+}
+
+///Field names of PopularityPrimitive, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/popularity_primitive.rs"));
+}
@@ -0,0 +1,21 @@
+use crate::model::ids::PopularityTypeId;
+
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct PopularityType {
+    #[serde(default)]
+    pub id: PopularityTypeId,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub external_popularity_source: u64,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+///Field names of PopularityType, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/popularity_type.rs"));
+}
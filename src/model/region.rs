@@ -0,0 +1,57 @@
+use crate::model::enums;
+use crate::model::ids::RegionId;
+
+///A region games are localized for, see GameLocalization
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct Region {
+    #[serde(default)]
+    pub id: RegionId,
+    ///Either `locale` or `continent`
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub identifier: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+impl Region {
+    ///The region of the release dates matching this one by identifier or name,
+    /// e.g. enums::Region::NorthAmerica for North America
+    pub fn release_region(&self) -> Option<enums::Region> {
+        [&self.identifier, &self.name]
+            .into_iter()
+            .find_map(|name| release_region(name))
+    }
+}
+
+fn release_region(name: &str) -> Option<enums::Region> {
+    let name: String = name
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    match name.as_str() {
+        "europe" | "eu" => Some(enums::Region::Europe),
+        "northamerica" | "na" => Some(enums::Region::NorthAmerica),
+        "australia" => Some(enums::Region::Australia),
+        "newzealand" => Some(enums::Region::NewZealand),
+        "japan" | "jp" => Some(enums::Region::Japan),
+        "china" | "cn" => Some(enums::Region::China),
+        "asia" => Some(enums::Region::Asia),
+        "worldwide" => Some(enums::Region::WorldWide),
+        "korea" | "kr" => Some(enums::Region::Korea),
+        "brazil" | "br" => Some(enums::Region::Brazil),
+        _ => None,
+    }
+}
+
+///Field names of Region, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/region.rs"));
+}
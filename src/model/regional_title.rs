@@ -0,0 +1,70 @@
+use crate::model::cover::Cover;
+use crate::model::game_localization::GameLocalization;
+use crate::model::id_or::IdOr;
+use crate::model::region::Region;
+use crate::model::release_date::ReleaseDate;
+
+///The name and cover of a game in a region, along with its release dates there,
+/// see IGDBClient::regional_titles
+#[derive(Debug, Clone)]
+pub struct RegionalTitle {
+    pub region: IdOr<Region>,
+    pub name: String,
+    pub cover: IdOr<Cover>,
+    ///The release dates in the region, on every platform
+    pub release_dates: Vec<ReleaseDate>,
+}
+
+impl RegionalTitle {
+    ///Matches the release dates with the regions of the localizations
+    pub(crate) fn group(
+        localizations: Vec<GameLocalization>,
+        release_dates: &[ReleaseDate],
+    ) -> Vec<RegionalTitle> {
+        localizations
+            .into_iter()
+            .map(|localization| {
+                let region = localization
+                    .region
+                    .expanded()
+                    .and_then(Region::release_region);
+                RegionalTitle {
+                    release_dates: release_dates
+                        .iter()
+                        .filter(|date| Some(date.region) == region)
+                        .cloned()
+                        .collect(),
+                    region: localization.region,
+                    name: localization.name,
+                    cover: localization.cover,
+                }
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn release_dates_are_matched_with_the_regions() {
+    let localizations: Vec<GameLocalization> = serde_json::from_str(
+        r#"[
+            {"id": 1, "name": "Bloodborne", "region": {"id": 1, "name": "Europe", "identifier": "EU"}},
+            {"id": 2, "name": "ブラッドボーン", "region": {"id": 3, "name": "Japan", "identifier": "JP"},
+             "cover": {"id": 7, "image_id": "co7"}},
+            {"id": 3, "name": "Bloodborne", "region": 9}
+        ]"#,
+    )
+    .unwrap();
+    let release_dates: Vec<ReleaseDate> = serde_json::from_str(
+        r#"[{"id": 10, "region": 1}, {"id": 11, "region": 5}, {"id": 12, "region": 5}]"#,
+    )
+    .unwrap();
+
+    let titles = RegionalTitle::group(localizations, &release_dates);
+    let dates: Vec<usize> = titles.iter().map(|t| t.release_dates.len()).collect();
+    assert_eq!(vec![1, 2, 0], dates);
+    assert_eq!("ブラッドボーン", titles[1].name);
+    assert_eq!(
+        Some("co7"),
+        titles[1].cover.expanded().map(|c| c.image_id.as_str())
+    );
+}
@@ -0,0 +1,81 @@
+use crate::model::enums::GameCategory;
+use crate::model::games::Game;
+
+///A game and the games related to it, see IGDBClient::related_games
+#[derive(Debug, Clone)]
+pub struct RelatedGames {
+    pub game: Game,
+    ///Games this game is a DLC, expansion, remake or remaster of
+    pub parents: Vec<Game>,
+    pub dlcs: Vec<Game>,
+    ///Expansions and standalone expansions
+    pub expansions: Vec<Game>,
+    pub remakes: Vec<Game>,
+    pub remasters: Vec<Game>,
+    ///Editions of this game, e.g. Game of the Year edition
+    pub versions: Vec<Game>,
+    ///Other games having this game as parent: episodes, seasons, mods, ports...
+    pub others: Vec<Game>,
+}
+
+impl RelatedGames {
+    pub(crate) fn new(
+        game: Game,
+        parents: Vec<Game>,
+        children: Vec<Game>,
+        versions: Vec<Game>,
+    ) -> RelatedGames {
+        let mut related = RelatedGames {
+            game,
+            parents,
+            dlcs: Vec::new(),
+            expansions: Vec::new(),
+            remakes: Vec::new(),
+            remasters: Vec::new(),
+            versions,
+            others: Vec::new(),
+        };
+
+        for child in children {
+            match child.category {
+                GameCategory::DlcAddon => related.dlcs.push(child),
+                GameCategory::Expansion | GameCategory::StandaloneExpansion => {
+                    related.expansions.push(child)
+                }
+                GameCategory::Remake => related.remakes.push(child),
+                GameCategory::Remaster => related.remasters.push(child),
+                _ => related.others.push(child),
+            }
+        }
+        related
+    }
+}
+
+#[test]
+fn children_are_sorted_by_category() {
+    let game = |id: usize, category: u8| -> Game {
+        serde_json::from_str(&format!(r#"{{"id": {}, "category": {}}}"#, id, category)).unwrap()
+    };
+
+    let related = RelatedGames::new(
+        game(1942, 0),
+        vec![],
+        vec![
+            game(1, 1),
+            game(2, 2),
+            game(3, 4),
+            game(4, 8),
+            game(5, 9),
+            game(6, 6),
+        ],
+        vec![game(7, 0)],
+    );
+
+    let ids = |games: &[Game]| games.iter().map(|g| g.id.0).collect::<Vec<u64>>();
+    assert_eq!(vec![1], ids(&related.dlcs));
+    assert_eq!(vec![2, 3], ids(&related.expansions));
+    assert_eq!(vec![4], ids(&related.remakes));
+    assert_eq!(vec![5], ids(&related.remasters));
+    assert_eq!(vec![6], ids(&related.others));
+    assert_eq!(vec![7], ids(&related.versions));
+}
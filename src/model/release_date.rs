@@ -1,27 +1,64 @@
-use crate::model::enums::Region;
+use crate::model::enums::{DateCategory, Region};
+use crate::model::ids::{GameId, PlatformId, ReleaseDateId};
 
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct ReleaseDate {
     #[serde(default)]
-    pub id: usize,
+    pub id: ReleaseDateId,
     #[serde(default)]
-    pub category: usize,
+    pub category: DateCategory,
     #[serde(default)]
-    pub created_at: u64,
+    pub created_at: i64,
     #[serde(default)]
-    pub date: u64,
+    pub date: i64,
     #[serde(default)]
-    pub game: usize,
+    pub game: GameId,
     #[serde(default)]
     pub human: String,
     #[serde(default)]
-    pub m: usize,
+    pub m: u64,
     #[serde(default)]
-    pub platform: usize,
+    pub platform: PlatformId,
     #[serde(default)]
     pub region: Region,
     #[serde(default)]
-    pub updated_at: u64,
+    pub updated_at: i64,
     #[serde(default)]
-    pub y: usize,
+    pub y: u64,
+}
+
+impl ReleaseDate {
+    ///Returns the release day, None when the date is unknown or only known
+    /// with a lesser precision (year, quarter...)
+    #[cfg(feature = "chrono")]
+    pub fn naive_date(&self) -> Option<chrono::NaiveDate> {
+        match (self.category, self.date) {
+            (DateCategory::YYYYMMMMDD, date) if date != 0 => {
+                chrono::DateTime::from_timestamp(date, 0).map(|date| date.date_naive())
+            }
+            _ => None,
+        }
+    }
+}
+
+///Field names of ReleaseDate, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/release_date.rs"));
+}
+
+#[test]
+fn release_date_with_category_deserializes() {
+    let release: ReleaseDate = serde_json::from_str(
+        r#"{"id": 1, "category": 0, "date": 1568332800, "human": "2019-Sep-13", "region": 8}"#,
+    )
+    .unwrap();
+
+    assert_eq!(DateCategory::YYYYMMMMDD, release.category);
+
+    #[cfg(feature = "chrono")]
+    assert_eq!(
+        chrono::NaiveDate::from_ymd_opt(2019, 9, 13),
+        release.naive_date()
+    );
 }
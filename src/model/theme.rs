@@ -1,15 +1,23 @@
+use crate::model::ids::ThemeId;
+
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Theme {
     #[serde(default)]
-    pub id: usize,
+    pub id: ThemeId,
     #[serde(default)]
-    pub created_at: u64,
+    pub created_at: i64,
     #[serde(default)]
     pub name: String,
     #[serde(default)]
     pub slug: String,
     #[serde(default)]
-    pub updated_at: u64,
+    pub updated_at: i64,
     #[serde(default)]
     pub url: String,
 }
+
+///Field names of Theme, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/theme.rs"));
+}
@@ -0,0 +1,71 @@
+use serde::de::{Deserialize, Deserializer};
+
+igdb_enum! {
+    ///Event a webhook is called for, see WebhooksClient::register
+    WebhookMethod {
+        Create = 0,
+        Delete = 1,
+        Update = 2,
+    } default Create
+}
+
+impl WebhookMethod {
+    ///Name of the method in the registration form, None for the unknown ones
+    pub(crate) fn as_str(self) -> Option<&'static str> {
+        match self {
+            WebhookMethod::Create => Some("create"),
+            WebhookMethod::Delete => Some("delete"),
+            WebhookMethod::Update => Some("update"),
+            WebhookMethod::Unknown(_) => None,
+        }
+    }
+}
+
+///A webhook registered for the client id, IGDB posts the changed registries to its url
+#[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
+pub struct Webhook {
+    #[serde(default)]
+    pub id: u64,
+    #[serde(default)]
+    pub url: String,
+    ///Id of the endpoint the webhook listens to
+    #[serde(default)]
+    pub category: u64,
+    #[serde(default, rename = "sub_category")]
+    pub method: WebhookMethod,
+    #[serde(default)]
+    pub active: bool,
+    ///Failed deliveries since the last successful one, IGDB disables the webhook after too many
+    #[serde(default)]
+    pub number_of_retries: u64,
+    ///Client id the webhook was registered with
+    #[serde(default)]
+    pub api_key: String,
+    ///Sent back in the X-Secret header of every delivery
+    #[serde(default)]
+    pub secret: String,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+#[test]
+fn webhook_deserializes() {
+    let webhook: Webhook = serde_json::from_str(
+        r#"{"id": 42, "url": "https://example.com/games", "category": 1, "sub_category": 2,
+            "active": true, "number_of_retries": 0, "api_key": "client_id", "secret": "s3cr3t",
+            "created_at": 1600000000, "updated_at": 1600000000}"#,
+    )
+    .unwrap();
+
+    assert_eq!(WebhookMethod::Update, webhook.method);
+    assert!(webhook.active);
+
+    let webhooks: Vec<Webhook> =
+        serde_json::from_str(r#"[{"id": 42, "sub_category": 0}, {"id": 43, "sub_category": 7}]"#)
+            .unwrap();
+    assert_eq!(WebhookMethod::Create, webhooks[0].method);
+    assert_eq!(WebhookMethod::Unknown(7), webhooks[1].method);
+}
@@ -1,13 +1,32 @@
+use crate::model::enums::WebsiteCategory;
+use crate::model::ids::{GameId, WebsiteId};
+
 #[derive(Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(Serialize))]
 pub struct Website {
     #[serde(default)]
-    pub id: usize,
+    pub id: WebsiteId,
     #[serde(default)]
-    pub category: usize,
+    pub category: WebsiteCategory,
     #[serde(default)]
-    pub game: usize,
+    pub game: GameId,
     #[serde(default)]
     pub trusted: bool,
     #[serde(default)]
     pub url: String,
 }
+
+///Field names of Website, e.g. for RequestBuilder::add_field
+pub mod fields {
+    include!(concat!(env!("OUT_DIR"), "/fields/website.rs"));
+}
+
+#[test]
+fn website_category_deserializes() {
+    let website: Website = serde_json::from_str(
+        r#"{"id": 17, "category": 13, "game": 1942, "trusted": true, "url": "https://store.steampowered.com/app/292030"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(WebsiteCategory::Steam, website.category);
+}
@@ -0,0 +1,274 @@
+//! Default fields of the queries naming none, read from the Deserialize implementation
+//! of the model the registries are read into, so that they always match it.
+
+use serde::de::{self, DeserializeOwned, Deserializer, IntoDeserializer, Visitor};
+use std::fmt;
+
+///Returns the fields of the given model, None when it is not a struct (e.g. serde_json::Value)
+pub(crate) fn model_fields<T: DeserializeOwned>() -> Option<&'static [&'static str]> {
+    let mut recorder = FieldRecorder(None);
+    let _ = T::deserialize(&mut recorder);
+    recorder.0
+}
+
+///Deserializer failing on any input, recording the fields of the struct it was asked for
+struct FieldRecorder(Option<&'static [&'static str]>);
+
+#[derive(Debug)]
+struct Recorded;
+
+impl fmt::Display for Recorded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("model fields recorded")
+    }
+}
+
+impl std::error::Error for Recorded {}
+
+impl de::Error for Recorded {
+    fn custom<M: fmt::Display>(_msg: M) -> Self {
+        Recorded
+    }
+}
+
+impl<'de> Deserializer<'de> for &mut FieldRecorder {
+    type Error = Recorded;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Recorded> {
+        Err(Recorded)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Recorded> {
+        self.0 = Some(fields);
+        Err(Recorded)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+///Returns the name of the given model along with the fields its registries cannot be read
+/// without, the ones neither defaulted nor optional. No field when it is not a struct.
+pub(crate) fn required_fields<T: DeserializeOwned>() -> (&'static str, Vec<&'static str>) {
+    let mut model = Model {
+        name: "",
+        required: Vec::new(),
+    };
+    // Each attempt reports the first field missing, which the next one provides
+    while let Err(Missing(Some(field))) = T::deserialize(&mut model) {
+        if model.required.contains(&field) {
+            break;
+        }
+        model.required.push(field);
+    }
+    (model.name, model.required)
+}
+
+///Deserializer providing a placeholder value for each of the required fields found so far
+struct Model {
+    name: &'static str,
+    required: Vec<&'static str>,
+}
+
+///The field reported missing, None on any other error
+#[derive(Debug)]
+struct Missing(Option<&'static str>);
+
+impl fmt::Display for Missing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(field) => write!(f, "missing field {}", field),
+            None => f.write_str("not a model"),
+        }
+    }
+}
+
+impl std::error::Error for Missing {}
+
+impl de::Error for Missing {
+    fn custom<M: fmt::Display>(_msg: M) -> Self {
+        Missing(None)
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Missing(Some(field))
+    }
+}
+
+impl<'de> Deserializer<'de> for &mut Model {
+    type Error = Missing;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Missing> {
+        Err(Missing(None))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Missing> {
+        self.name = name;
+        visitor.visit_map(Placeholders(self.required.iter()))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+        option unit unit_struct newtype_struct seq tuple tuple_struct map enum identifier
+        ignored_any
+    }
+}
+
+///The required fields found so far, each with a placeholder value
+struct Placeholders<'a>(std::slice::Iter<'a, &'static str>);
+
+impl<'de> de::MapAccess<'de> for Placeholders<'_> {
+    type Error = Missing;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Missing> {
+        self.0
+            .next()
+            .map(|field| seed.deserialize(field.into_deserializer()))
+            .transpose()
+    }
+
+    fn next_value_seed<S: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, Missing> {
+        seed.deserialize(Placeholder)
+    }
+}
+
+///The zero or empty value of whatever is asked, failing on structs and enums
+struct Placeholder;
+
+impl<'de> Deserializer<'de> for Placeholder {
+    type Error = Missing;
+
+    // Ids, e.g. of IdOr
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_u64(0)
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_bool(false)
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_i64(0)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_f64(0.0)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_bytes(&[])
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_none()
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Missing> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Missing> {
+        visitor.visit_seq(de::value::SeqDeserializer::new(std::iter::empty::<u64>()))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Missing> {
+        Err(Missing(None))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Missing> {
+        Err(Missing(None))
+    }
+
+    serde::forward_to_deserialize_any! {
+        i8 i16 i32 i128 u8 u16 u32 u64 u128 f32 char byte_buf
+        unit_struct tuple tuple_struct map identifier ignored_any
+    }
+}
+
+#[test]
+fn model_fields_are_read_from_the_model() {
+    use crate::model::genre::Genre;
+
+    assert_eq!(
+        Some(&["id", "created_at", "name", "slug", "updated_at", "url"][..]),
+        model_fields::<Genre>()
+    );
+    assert_eq!(None, model_fields::<serde_json::Value>());
+}
+
+#[test]
+fn required_fields_are_the_ones_neither_defaulted_nor_optional() {
+    use crate::model::age_rating::AgeRating;
+    use crate::model::cover::Cover;
+    use crate::model::enums::Rating;
+    use crate::model::genre::Genre;
+    use crate::model::id_or::IdOr;
+
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct Listing {
+        cover: IdOr<Cover>,
+        #[serde(default)]
+        summary: String,
+        rating: Rating,
+        storyline: Option<String>,
+        name: String,
+        platforms: Vec<u64>,
+    }
+
+    assert_eq!(
+        ("AgeRating", vec!["rating"]),
+        required_fields::<AgeRating>()
+    );
+    assert_eq!(("Genre", Vec::<&str>::new()), required_fields::<Genre>());
+    assert_eq!(
+        ("Listing", vec!["cover", "rating", "name", "platforms"]),
+        required_fields::<Listing>()
+    );
+    assert_eq!(Vec::<&str>::new(), required_fields::<serde_json::Value>().1);
+}
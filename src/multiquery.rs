@@ -0,0 +1,226 @@
+use crate::endpoints::Endpoint;
+use crate::pipeline::Pipeline;
+use crate::request_builder::{escape, RequestBuilder};
+use crate::response::Lenient;
+use crate::Error;
+use serde::de::DeserializeOwned;
+
+pub(crate) const MULTIQUERY_PATH: &str = "multiquery";
+
+///Batches several named queries, possibly on different endpoints, in a single request
+/// to the multiquery endpoint. IGDB accepts up to 10 queries per multiquery.
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use igdb::client::{GamesClient, IGDBClient, PlatformsClient};
+/// use igdb::model::games::Game;
+/// use igdb::multiquery::MultiQueryBuilder;
+/// use igdb::request_builder::Equality;
+///
+/// task::block_on(async {
+///     let igdb = IGDBClient::new("client_id", "token");
+///
+///     let mut games = IGDBClient::create_request();
+///     games.add_field("name").add_where("platforms", Equality::Equal, 48);
+///
+///     let mut multiquery = MultiQueryBuilder::new();
+///     multiquery
+///         .add::<GamesClient>("Playstation Games", &games)
+///         .add_count::<PlatformsClient>("Count of Platforms", &IGDBClient::create_request());
+///
+///     let results = igdb.multiquery(&multiquery).await.unwrap();
+///     let games: Vec<Game> = results.get("Playstation Games").unwrap();
+///     let platforms = results.count("Count of Platforms");
+/// })
+/// ```
+#[derive(Clone, Default)]
+pub struct MultiQueryBuilder {
+    queries: Vec<SubQuery>,
+}
+
+#[derive(Clone)]
+struct SubQuery {
+    name: String,
+    path: String,
+    body: String,
+}
+
+impl MultiQueryBuilder {
+    pub fn new() -> MultiQueryBuilder {
+        MultiQueryBuilder::default()
+    }
+
+    /// Adds a named query returning the registries of the given endpoint
+    pub fn add<E: Endpoint>(
+        &mut self,
+        name: impl Into<String>,
+        request: &RequestBuilder,
+    ) -> &mut Self {
+        self.queries.push(SubQuery {
+            name: name.into(),
+            path: E::PATH.to_owned(),
+            // Sent as is when the fields of the model are unknown, for IGDB to report it
+            body: request
+                .build_body_for::<E::Model>()
+                .unwrap_or_else(|_| request.build_body()),
+        });
+        self
+    }
+
+    /// Adds a named query returning the number of registries of the given endpoint
+    /// matching the search and filters of the request
+    pub fn add_count<E: Endpoint>(
+        &mut self,
+        name: impl Into<String>,
+        request: &RequestBuilder,
+    ) -> &mut Self {
+        self.queries.push(SubQuery {
+            name: name.into(),
+            path: format!("{}/count", E::PATH),
+            body: request.build_count_body(),
+        });
+        self
+    }
+
+    pub(crate) fn build_body(&self) -> String {
+        self.queries
+            .iter()
+            .map(|query| {
+                format!(
+                    "query {} \"{}\" {{ {} }};",
+                    query.path,
+                    escape(&query.name),
+                    query.body
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+///Result of one of the queries of a multiquery
+#[derive(Deserialize, Debug, Clone)]
+pub struct MultiQueryResult {
+    pub name: String,
+    ///Registries returned by queries added with MultiQueryBuilder::add
+    #[serde(default)]
+    pub result: Vec<serde_json::Value>,
+    ///Count returned by queries added with MultiQueryBuilder::add_count
+    #[serde(default)]
+    pub count: Option<u64>,
+}
+
+///Results of a multiquery, in the order the queries were added
+#[derive(Deserialize, Debug, Clone)]
+#[serde(transparent)]
+pub struct MultiQueryResults {
+    pub results: Vec<MultiQueryResult>,
+}
+
+impl MultiQueryResults {
+    /// Deserializes the registries of the query with the given name into the given model
+    pub fn get<T: DeserializeOwned>(&self, name: &str) -> Result<Vec<T>, Error> {
+        match self.results.iter().find(|result| result.name == name) {
+            Some(result) => result
+                .result
+                .iter()
+                .cloned()
+                .map(|value| serde_json::from_value(value).map_err(Into::into))
+                .collect(),
+            None => Err(Error::NotFound(format!(
+                "No multiquery result named: {}",
+                name
+            ))),
+        }
+    }
+
+    /// Deserializes the registries of the query with the given name into the given model,
+    /// setting aside the ones which cannot be read instead of failing
+    pub fn get_lenient<T: DeserializeOwned>(&self, name: &str) -> Result<Lenient<T>, Error> {
+        match self.results.iter().find(|result| result.name == name) {
+            Some(result) => Ok(Lenient::read("", &result.result)),
+            None => Err(Error::NotFound(format!(
+                "No multiquery result named: {}",
+                name
+            ))),
+        }
+    }
+
+    /// Deserializes the registries of the query with the given name like get,
+    /// then applies the given pipeline to them
+    pub fn get_with<T: DeserializeOwned + 'static>(
+        &self,
+        name: &str,
+        pipeline: &Pipeline<T>,
+    ) -> Result<Vec<T>, Error> {
+        Ok(pipeline.apply(self.get(name)?))
+    }
+
+    /// Returns the count of the count query with the given name
+    pub fn count(&self, name: &str) -> Option<u64> {
+        self.results
+            .iter()
+            .find(|result| result.name == name)
+            .and_then(|result| result.count)
+    }
+}
+
+#[test]
+fn multiquery_body_build() {
+    use crate::client::{GamesClient, PlatformsClient};
+    use crate::request_builder::Equality;
+
+    let mut games = RequestBuilder::new();
+    games
+        .add_field("name")
+        .add_where("platforms", Equality::Equal, 48)
+        .limit(5);
+
+    let mut platforms = RequestBuilder::new();
+    platforms.add_where("category", Equality::Equal, 1);
+
+    let mut multiquery = MultiQueryBuilder::new();
+    multiquery
+        .add::<GamesClient>("Playstation \"4\" Games", &games)
+        .add_count::<PlatformsClient>("Consoles", &platforms);
+
+    assert_eq!(
+        "query games \"Playstation \\\"4\\\" Games\" { fields name; where platforms = 48; limit 5; };\n\
+         query platforms/count \"Consoles\" { where category = 1; };",
+        multiquery.build_body()
+    );
+}
+
+#[test]
+fn multiquery_results_deserialize_per_query() {
+    use crate::model::games::Game;
+
+    let results: MultiQueryResults = serde_json::from_str(
+        r#"[
+            {"name": "Games", "result": [{"id": 1942, "name": "The Witcher 3"}]},
+            {"name": "Consoles", "count": 155}
+        ]"#,
+    )
+    .unwrap();
+
+    let games: Vec<Game> = results.get("Games").unwrap();
+    assert_eq!(1942, games[0].id.0);
+    assert_eq!(Some(155), results.count("Consoles"));
+    assert_eq!(None, results.count("Games"));
+    assert!(results.get::<Game>("Missing").is_err());
+}
+
+#[test]
+fn multiquery_results_set_aside_the_invalid_registries() {
+    use crate::model::genre::Genre;
+
+    let results: MultiQueryResults = serde_json::from_str(
+        r#"[{"name": "Genres", "result": [{"id": 5, "name": "Shooter"}, {"id": "twelve"}]}]"#,
+    )
+    .unwrap();
+
+    assert!(results.get::<Genre>("Genres").is_err());
+    let genres = results.get_lenient::<Genre>("Genres").unwrap();
+    assert_eq!("Shooter", genres.registries[0].name);
+    assert_eq!("[1].id", genres.invalid[0].field);
+}
@@ -0,0 +1,151 @@
+use crate::endpoint_client::{EndpointClient, MAX_LIMIT};
+use crate::request_builder::{Equality, RequestBuilder};
+use crate::Error;
+use async_std::sync::Mutex;
+use std::collections::{HashMap, HashSet};
+
+///Caches the names of the registries of small endpoints (genres, themes...)
+/// which change rarely and are referenced by id from nearly every game.
+#[derive(Default)]
+pub(crate) struct NameCache {
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    names: HashMap<(&'static str, u64), String>,
+    ///Endpoints whose registries were all retrieved at once
+    loaded: HashSet<&'static str>,
+}
+
+#[derive(Deserialize)]
+struct Named {
+    id: u64,
+    #[serde(default)]
+    name: String,
+}
+
+impl NameCache {
+    ///Returns the name of the registry with the given id of the client endpoint.
+    /// When load_all is set every registry of the endpoint is retrieved on the first lookup,
+    /// else registries are retrieved one by one.
+    pub(crate) async fn name(
+        &self,
+        client: &EndpointClient,
+        id: u64,
+        load_all: bool,
+    ) -> Result<Option<String>, Error> {
+        let key = (client.path, id);
+        let mut state = self.state.lock().await;
+
+        if let Some(name) = state.names.get(&key) {
+            return Ok(Some(name.clone()));
+        }
+        if load_all && state.loaded.contains(client.path) {
+            return Ok(None);
+        }
+
+        match load_all {
+            true => state.load_all(client).await?,
+            false => {
+                let mut request = RequestBuilder::new();
+                request
+                    .add_fields(vec!["id", "name"])
+                    .add_where("id", Equality::Equal, id)
+                    .limit(1);
+                state.insert(client.path, named(client, &request).await?);
+            }
+        }
+
+        Ok(state.names.get(&key).cloned())
+    }
+
+    ///Returns the ids of the registries of the client endpoint having the given names,
+    /// compared case insensitively, retrieving every registry of the endpoint on the first lookup
+    pub(crate) async fn ids(
+        &self,
+        client: &EndpointClient,
+        names: &[String],
+    ) -> Result<Vec<u64>, Error> {
+        let mut state = self.state.lock().await;
+        if !state.loaded.contains(client.path) {
+            state.load_all(client).await?;
+        }
+
+        names
+            .iter()
+            .map(|name| {
+                state
+                    .names
+                    .iter()
+                    .find(|((path, _), known)| {
+                        *path == client.path && known.eq_ignore_ascii_case(name.trim())
+                    })
+                    .map(|((_, id), _)| *id)
+                    .ok_or_else(|| {
+                        Error::InvalidQuery(format!(
+                            "no registry of {} is named {}",
+                            client.path, name
+                        ))
+                    })
+            })
+            .collect()
+    }
+}
+
+impl CacheState {
+    ///Retrieves every registry of the client endpoint
+    async fn load_all(&mut self, client: &EndpointClient) -> Result<(), Error> {
+        let mut request = RequestBuilder::new();
+        request.add_fields(vec!["id", "name"]).limit(MAX_LIMIT);
+        self.insert(client.path, named(client, &request).await?);
+        self.loaded.insert(client.path);
+        Ok(())
+    }
+
+    fn insert(&mut self, path: &'static str, registries: Vec<Named>) {
+        for named in registries {
+            self.names.insert((path, named.id), named.name);
+        }
+    }
+}
+
+///Retrieves the registries of the client endpoint, the request naming no genre or theme
+async fn named(client: &EndpointClient, request: &RequestBuilder) -> Result<Vec<Named>, Error> {
+    client.post(client.path, request.build_body()).await
+}
+
+#[test]
+fn genre_names_are_resolved_once_per_client() {
+    use crate::client::{GamesClient, IGDBClient};
+    use crate::mock::MockTransport;
+
+    let mock = MockTransport::new()
+        .with_fixture(
+            "genres",
+            "fields id,name; limit 500;",
+            r#"[{"id": 5, "name": "Shooter"}, {"id": 32, "name": "Indie"}]"#,
+        )
+        .with_fixture(
+            "games",
+            "fields name; where genres = (5,32); limit 10;",
+            r#"[{"id": 1, "name": "Superhot"}]"#,
+        );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock.clone())
+        .without_rate_limit();
+    let mut request = IGDBClient::create_request();
+    request
+        .add_field("name")
+        .with_genre_names(&["shooter", "Indie"]);
+
+    for _ in 0..2 {
+        let games = async_std::task::block_on(igdb.query::<GamesClient>(&request)).unwrap();
+        assert_eq!("Superhot", games[0].name);
+    }
+    assert_eq!(3, mock.requests().len());
+
+    request.with_genre_names(&["Bullet Hell"]);
+    let unknown = async_std::task::block_on(igdb.query::<GamesClient>(&request));
+    assert!(matches!(unknown, Err(Error::InvalidQuery(_))));
+}
@@ -0,0 +1,114 @@
+//! Json of the models with their fields renamed, e.g. in camelCase for JavaScript frontends,
+//! with the `serialize` feature, see ToJsonWith.
+//!
+//! The models are written with the snake_case names IGDB uses. Web backends handing them
+//! over to clients expecting another naming convention can rename the fields on the way out
+//! instead of declaring a second set of structs.
+
+use crate::Error;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+///Naming convention of the fields of the json written by ToJsonWith::to_json_with
+#[derive(Clone, Copy, Debug)]
+pub enum Profile {
+    ///As IGDB names them, e.g. `total_rating_count`
+    SnakeCase,
+    ///e.g. `totalRatingCount`
+    CamelCase,
+    ///e.g. `TotalRatingCount`
+    PascalCase,
+    ///Renamed by the given function from the snake_case name
+    Custom(fn(&str) -> String),
+}
+
+impl Profile {
+    ///Returns the name of the given snake_case field in this profile
+    /// # Examples
+    /// ```
+    /// use igdb::naming::Profile;
+    ///
+    /// assert_eq!("firstReleaseDate", Profile::CamelCase.rename("first_release_date"));
+    /// assert_eq!("ImageId", Profile::PascalCase.rename("image_id"));
+    /// ```
+    pub fn rename(&self, field: &str) -> String {
+        match self {
+            Profile::SnakeCase => field.to_owned(),
+            Profile::CamelCase => capitalized(field, false),
+            Profile::PascalCase => capitalized(field, true),
+            Profile::Custom(rename) => rename(field),
+        }
+    }
+
+    ///Renames the fields of the objects of the value, nested ones included
+    fn apply(&self, value: Value) -> Value {
+        match value {
+            Value::Array(values) => values.into_iter().map(|value| self.apply(value)).collect(),
+            Value::Object(fields) => Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(field, value)| (self.rename(&field), self.apply(value)))
+                    .collect::<Map<String, Value>>(),
+            ),
+            value => value,
+        }
+    }
+}
+
+///Joins the words of a snake_case name, capitalizing each one but the first unless asked
+fn capitalized(field: &str, first: bool) -> String {
+    let mut renamed = String::with_capacity(field.len());
+    for (i, word) in field.split('_').filter(|word| !word.is_empty()).enumerate() {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(c) if i > 0 || first => {
+                renamed.extend(c.to_uppercase());
+                renamed.push_str(chars.as_str());
+            }
+            _ => renamed.push_str(word),
+        }
+    }
+    renamed
+}
+
+///Writes the models, or any serializable value, as json named after a Profile
+pub trait ToJsonWith {
+    ///Returns the json of the value with its fields renamed, nested registries included
+    /// # Examples
+    /// ```
+    /// use igdb::model::games::Game;
+    /// use igdb::naming::{Profile, ToJsonWith};
+    ///
+    /// let game: Game = serde_json::from_str(
+    ///     r#"{"id": 1942, "total_rating_count": 3000, "cover": {"id": 89386, "image_id": "co1wyy"}}"#,
+    /// ).unwrap();
+    ///
+    /// let json = game.to_json_with(Profile::CamelCase).unwrap();
+    /// assert_eq!(3000, json["totalRatingCount"]);
+    /// assert_eq!("co1wyy", json["cover"]["imageId"]);
+    /// ```
+    fn to_json_with(&self, profile: Profile) -> Result<Value, Error>;
+}
+
+impl<T: Serialize + ?Sized> ToJsonWith for T {
+    fn to_json_with(&self, profile: Profile) -> Result<Value, Error> {
+        Ok(profile.apply(serde_json::to_value(self)?))
+    }
+}
+
+#[test]
+fn fields_are_renamed_in_nested_registries_and_lists() {
+    use crate::model::genre::Genre;
+
+    let genres: Vec<Genre> =
+        serde_json::from_str(r#"[{"id": 5, "name": "Shooter", "updated_at": 1}]"#).unwrap();
+    let json = genres
+        .to_json_with(Profile::Custom(|field| field.to_uppercase()))
+        .unwrap();
+    assert_eq!(5, json[0]["ID"]);
+    assert_eq!(1, json[0]["UPDATED_AT"]);
+
+    let json = genres.to_json_with(Profile::PascalCase).unwrap();
+    assert_eq!("Shooter", json[0]["Name"]);
+    assert!(json[0].get("updated_at").is_none());
+}
@@ -0,0 +1,111 @@
+//! Offset pagination handles, see IGDBClient::page.
+//!
+//! A Page keeps the request and offset it was requested with, so pagination controls only
+//! call next or prev instead of tracking offsets themselves.
+
+use crate::endpoint_client::EndpointClient;
+use crate::request_builder::RequestBuilder;
+use crate::Error;
+use serde::de::DeserializeOwned;
+
+///A page of the registries matching a request, requesting the pages around it
+/// # Examples
+/// ```
+/// use async_std::task;
+/// use igdb::client::{GenresClient, IGDBClient};
+/// use igdb::mock::MockTransport;
+///
+/// let mock = MockTransport::new()
+///     .with_fixture("genres", "fields name; limit 2;", r#"[{"id": 2, "name": "Point-and-click"}, {"id": 4, "name": "Fighting"}]"#)
+///     .with_fixture("genres", "fields name; limit 2; offset 2;", r#"[{"id": 5, "name": "Shooter"}]"#);
+/// let igdb = IGDBClient::new("client_id", "token").with_transport(mock);
+/// let mut request = IGDBClient::create_request();
+/// request.add_field("name").limit(2);
+///
+/// task::block_on(async {
+///     let first = igdb.page::<GenresClient>(&request).await.unwrap();
+///     let second = first.next().await.unwrap().unwrap();
+///     assert_eq!("Shooter", second.registries[0].name);
+///     assert_eq!(2, second.number());
+///     assert!(!second.has_next());
+///
+///     let back = second.prev().await.unwrap().unwrap();
+///     assert_eq!("Fighting", back.registries[1].name);
+/// })
+/// ```
+pub struct Page<T> {
+    client: EndpointClient,
+    request: RequestBuilder,
+    pub registries: Vec<T>,
+}
+
+impl<T: DeserializeOwned> Page<T> {
+    ///Requests the page at the offset of the request
+    pub(crate) async fn request(
+        client: EndpointClient,
+        request: RequestBuilder,
+    ) -> Result<Page<T>, Error> {
+        let registries = client.get::<T>(&request).await?;
+        Ok(Page {
+            client,
+            request,
+            registries,
+        })
+    }
+
+    ///Offset of the first registry of the page
+    pub fn offset(&self) -> usize {
+        self.request.offset
+    }
+
+    ///Registries per page, the limit of the request
+    pub fn limit(&self) -> usize {
+        self.request.limit
+    }
+
+    ///Number of the page, starting at 1
+    pub fn number(&self) -> usize {
+        self.offset() / self.limit().max(1) + 1
+    }
+
+    ///Whether a next page may hold registries: this one is full
+    pub fn has_next(&self) -> bool {
+        self.registries.len() >= self.limit()
+    }
+
+    ///Whether a previous page exists: this one does not start at the first registry
+    pub fn has_prev(&self) -> bool {
+        self.offset() > 0
+    }
+
+    ///Requests the following page, None when there is no registry after this one
+    pub async fn next(&self) -> Result<Option<Page<T>>, Error> {
+        if !self.has_next() {
+            return Ok(None);
+        }
+        let page = self.at(self.offset() + self.limit()).await?;
+        Ok(Some(page).filter(|page| !page.registries.is_empty()))
+    }
+
+    ///Requests the preceding page, None on the first page
+    pub async fn prev(&self) -> Result<Option<Page<T>>, Error> {
+        match self.has_prev() {
+            true => self
+                .at(self.offset().saturating_sub(self.limit()))
+                .await
+                .map(Some),
+            false => Ok(None),
+        }
+    }
+
+    ///Requests this page again, e.g. to refresh it
+    pub async fn reload(&self) -> Result<Page<T>, Error> {
+        self.at(self.offset()).await
+    }
+
+    async fn at(&self, offset: usize) -> Result<Page<T>, Error> {
+        let mut request = self.request.clone();
+        request.offset(offset);
+        Page::request(self.client.with_endpoint(self.client.path), request).await
+    }
+}
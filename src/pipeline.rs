@@ -0,0 +1,169 @@
+//! Client side post-processing of query results, see IGDBClient::query_all_with and
+//! MultiQueryResults::get_with.
+//!
+//! Registries added or removed while paging shift the following pages, so results gathered
+//! over several requests can repeat registries, and results of sub-queries sent in parallel
+//! come in the order of the sub-queries rather than the wanted one. A Pipeline cleans them up.
+
+use crate::model::id_or::Identifiable;
+use crate::model::ids::EntityId;
+use crate::sortkey::title_key;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+type Step<T> = Box<dyn Fn(&mut Vec<T>) + Send + Sync>;
+
+///Steps applied in order to query results
+/// # Examples
+/// ```
+/// use igdb::model::games::Game;
+/// use igdb::pipeline::Pipeline;
+///
+/// let pipeline = Pipeline::<Game>::new()
+///     .dedupe_by_id()
+///     .sort_by_key(|game| std::cmp::Reverse(game.total_rating_count));
+///
+/// let first: Vec<Game> = serde_json::from_str(r#"[{"id": 1, "total_rating_count": 10}]"#).unwrap();
+/// let second: Vec<Game> = serde_json::from_str(
+///     r#"[{"id": 2, "total_rating_count": 50}, {"id": 1, "total_rating_count": 10}]"#,
+/// ).unwrap();
+///
+/// let counts = pipeline.project(pipeline.merge([first, second]), |game| {
+///     (game.id.0, game.total_rating_count)
+/// });
+/// assert_eq!(vec![(2, 50), (1, 10)], counts);
+/// ```
+pub struct Pipeline<T> {
+    steps: Vec<Step<T>>,
+}
+
+impl<T: 'static> Default for Pipeline<T> {
+    fn default() -> Self {
+        Pipeline { steps: Vec::new() }
+    }
+}
+
+impl<T: 'static> Pipeline<T> {
+    pub fn new() -> Pipeline<T> {
+        Pipeline::default()
+    }
+
+    ///Keeps the first registry of each id
+    pub fn dedupe_by_id(self) -> Self
+    where
+        T: Identifiable,
+    {
+        self.dedupe_by_key(|registry| registry.id().value())
+    }
+
+    ///Keeps the first registry of each key
+    pub fn dedupe_by_key<K, F>(self, key: F) -> Self
+    where
+        K: Hash + Eq,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.step(move |registries| {
+            let mut seen = HashSet::with_capacity(registries.len());
+            registries.retain(|registry| seen.insert(key(registry)));
+        })
+    }
+
+    ///Sorts by the given key, the registries of equal keys keeping their order
+    pub fn sort_by_key<K, F>(self, key: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.step(move |registries| registries.sort_by_key(&key))
+    }
+
+    ///Sorts with the given comparison, the registries found equal keeping their order
+    pub fn sort_by<F>(self, compare: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        self.step(move |registries| registries.sort_by(&compare))
+    }
+
+    ///Sorts by the title returned for each registry in the expected order, e.g. `Doom II`
+    /// before `Doom 3` and the editions of a game right after it, see sortkey::title_key
+    pub fn sort_by_title<F>(self, title: F) -> Self
+    where
+        F: Fn(&T) -> &str + Send + Sync + 'static,
+    {
+        self.step(move |registries| {
+            registries.sort_by_cached_key(|registry| title_key(title(registry)))
+        })
+    }
+
+    ///Keeps the registries matching the predicate
+    pub fn retain<F>(self, keep: F) -> Self
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        self.step(move |registries| registries.retain(&keep))
+    }
+
+    fn step<F: Fn(&mut Vec<T>) + Send + Sync + 'static>(mut self, step: F) -> Self {
+        self.steps.push(Box::new(step));
+        self
+    }
+
+    ///Applies the steps to the registries
+    pub fn apply(&self, mut registries: Vec<T>) -> Vec<T> {
+        for step in &self.steps {
+            step(&mut registries);
+        }
+        registries
+    }
+
+    ///Concatenates the results of several queries, e.g. sub-queries sent in parallel,
+    /// then applies the steps to them
+    pub fn merge<I: IntoIterator<Item = Vec<T>>>(&self, results: I) -> Vec<T> {
+        self.apply(results.into_iter().flatten().collect())
+    }
+
+    ///Applies the steps, then maps each registry, e.g. to a tuple of the wanted fields
+    pub fn project<U, F: FnMut(T) -> U>(&self, registries: Vec<T>, projection: F) -> Vec<U> {
+        self.apply(registries).into_iter().map(projection).collect()
+    }
+}
+
+#[test]
+fn pipeline_applies_its_steps_in_order() {
+    use crate::model::genre::Genre;
+
+    let genres = |json: &str| serde_json::from_str::<Vec<Genre>>(json).unwrap();
+    let pipeline = Pipeline::<Genre>::new()
+        .retain(|genre| !genre.name.is_empty())
+        .dedupe_by_id()
+        .sort_by(|a, b| a.name.len().cmp(&b.name.len()));
+
+    let merged = pipeline.merge([
+        genres(r#"[{"id": 5, "name": "Shooter"}, {"id": 4, "name": "Fighting"}]"#),
+        genres(r#"[{"id": 4, "name": "Fighting"}, {"id": 9, "name": "Puzzle"}, {"id": 7}]"#),
+        genres(r#"[{"id": 12, "name": "Role-playing (RPG)"}, {"id": 2, "name": "Indie"}]"#),
+    ]);
+    let ids: Vec<u64> = merged.iter().map(|genre| genre.id.value()).collect();
+    assert_eq!(vec![2, 9, 5, 4, 12], ids);
+
+    let projected = Pipeline::new().project(merged, |genre| (genre.id.value(), genre.name.len()));
+    assert_eq!((2, 5), projected[0]);
+}
+
+#[test]
+fn pipeline_sorts_by_title() {
+    use crate::model::games::Game;
+
+    let games: Vec<Game> = serde_json::from_str(
+        r#"[{"id": 1, "name": "The Witcher 3: Wild Hunt"}, {"id": 2, "name": "Witcher 2"},
+            {"id": 3, "name": "The Witcher 3: Wild Hunt - Game of the Year Edition"},
+            {"id": 4, "name": "The Witcher"}]"#,
+    )
+    .unwrap();
+    let sorted = Pipeline::<Game>::new()
+        .sort_by_title(|game| &game.name)
+        .project(games, |game| game.id.value());
+    assert_eq!(vec![4, 2, 1, 3], sorted);
+}
@@ -0,0 +1,366 @@
+//! Bulk download of the artwork of many games at once, e.g. to browse a game library offline,
+//! see IGDBClient::prefetcher.
+//!
+//! The image ids of the covers, screenshots and artworks are queried in batches, then the
+//! images are downloaded in parallel from IGDB's CDN. Files already on disk are skipped,
+//! so a library can be prefetched again after adding games to it.
+
+use crate::client::IGDBClient;
+use crate::image_url::ImageUrl;
+use crate::media_quality::MediaQuality;
+use crate::model::games::Game;
+use crate::model::id_or::{IdOr, Identifiable};
+use crate::model::ids::EntityId;
+use crate::Error;
+use futures::StreamExt;
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+///Number of images downloaded at the same time by default
+const DEFAULT_PARALLELISM: usize = 4;
+
+///The kinds of game images a Prefetcher downloads
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageKind {
+    Cover,
+    Screenshot,
+    Artwork,
+}
+
+impl ImageKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            ImageKind::Cover => "cover",
+            ImageKind::Screenshot => "screenshot",
+            ImageKind::Artwork => "artwork",
+        }
+    }
+}
+
+///An image to download, named by the naming scheme of the Prefetcher
+#[derive(Clone, Copy, Debug)]
+pub struct ImageFile<'a> {
+    pub game: &'a Game,
+    pub kind: ImageKind,
+    ///Position of the image among the ones of its kind for the game, 0 for covers
+    pub index: usize,
+    pub image_id: &'a str,
+}
+
+///What a Prefetcher did with each image
+#[derive(Debug, Default)]
+pub struct PrefetchReport {
+    pub downloaded: Vec<PathBuf>,
+    ///Images already on disk
+    pub skipped: Vec<PathBuf>,
+    pub failed: Vec<(PathBuf, Error)>,
+}
+
+type Naming = Box<dyn Fn(&ImageFile<'_>) -> PathBuf + Send + Sync>;
+
+///Downloads the images of games to a directory, see IGDBClient::prefetcher
+pub struct Prefetcher<'a> {
+    client: &'a IGDBClient,
+    dir: PathBuf,
+    covers: Option<MediaQuality>,
+    screenshots: Option<MediaQuality>,
+    artworks: Option<MediaQuality>,
+    parallelism: usize,
+    naming: Naming,
+}
+
+impl IGDBClient {
+    ///Returns a Prefetcher downloading the covers of games to the given directory,
+    /// as `{game id}/cover.jpg` unless another naming scheme is set
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::IGDBClient;
+    /// use igdb::media_quality::MediaQuality;
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request.add_fields(vec!["cover", "screenshots"]).search("Zelda");
+    ///     let games = igdb.games().get(request).await.unwrap();
+    ///
+    ///     let report = igdb
+    ///         .prefetcher("artwork")
+    ///         .screenshots(MediaQuality::ScreenshotBig)
+    ///         .parallelism(8)
+    ///         .run(&games)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{} images downloaded", report.downloaded.len());
+    /// })
+    /// ```
+    pub fn prefetcher<P: Into<PathBuf>>(&self, dir: P) -> Prefetcher<'_> {
+        Prefetcher {
+            client: self,
+            dir: dir.into(),
+            covers: Some(MediaQuality::CoverBig),
+            screenshots: None,
+            artworks: None,
+            parallelism: DEFAULT_PARALLELISM,
+            naming: Box::new(default_name),
+        }
+    }
+}
+
+impl Prefetcher<'_> {
+    ///Downloads the covers at the given size, CoverBig by default
+    pub fn covers(mut self, quality: MediaQuality) -> Self {
+        self.covers = Some(quality);
+        self
+    }
+
+    ///Skips the covers
+    pub fn without_covers(mut self) -> Self {
+        self.covers = None;
+        self
+    }
+
+    ///Downloads the screenshots at the given size as well
+    pub fn screenshots(mut self, quality: MediaQuality) -> Self {
+        self.screenshots = Some(quality);
+        self
+    }
+
+    ///Downloads the artworks at the given size as well
+    pub fn artworks(mut self, quality: MediaQuality) -> Self {
+        self.artworks = Some(quality);
+        self
+    }
+
+    ///Sets how many images are downloaded at the same time
+    pub fn parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    ///Names the files with the given function, returning paths relative to the directory
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let igdb = IGDBClient::new("client_id", "token");
+    /// let prefetcher = igdb.prefetcher("artwork").naming(|image| {
+    ///     format!("{}-{}-{}.jpg", image.game.slug, image.kind.name(), image.index).into()
+    /// });
+    /// ```
+    pub fn naming<F>(mut self, naming: F) -> Self
+    where
+        F: Fn(&ImageFile<'_>) -> PathBuf + Send + Sync + 'static,
+    {
+        self.naming = Box::new(naming);
+        self
+    }
+
+    ///Downloads the images of the given games which are not on disk yet.
+    /// Fails when their image ids cannot be queried, the failed downloads are reported instead.
+    pub async fn run(&self, games: &[Game]) -> Result<PrefetchReport, Error> {
+        let covers = match self.covers {
+            Some(_) => {
+                let ids = distinct(games.iter().filter_map(|game| match &game.cover {
+                    IdOr::Id(id) => Some(*id),
+                    IdOr::Object(_) => None,
+                }));
+                let covers = self.client.covers().get_by_ids(&ids).await?;
+                covers.into_iter().map(|c| (c.id, c.image_id)).collect()
+            }
+            None => HashMap::new(),
+        };
+        let screenshots = match self.screenshots {
+            Some(_) => {
+                let ids = distinct(games.iter().flat_map(|game| unexpanded(&game.screenshots)));
+                let screenshots = self.client.screenshots().get_by_ids(&ids).await?;
+                screenshots
+                    .into_iter()
+                    .map(|s| (s.id, s.image_id))
+                    .collect()
+            }
+            None => HashMap::new(),
+        };
+        let artworks = match self.artworks {
+            Some(_) => {
+                let ids = distinct(games.iter().flat_map(|game| unexpanded(&game.artworks)));
+                let artworks = self.client.artworks().get_by_ids(&ids).await?;
+                artworks.into_iter().map(|a| (a.id, a.image_id)).collect()
+            }
+            None => HashMap::new(),
+        };
+
+        let mut files = Vec::new();
+        for game in games {
+            if let Some(quality) = self.covers {
+                let image_id = match &game.cover {
+                    IdOr::Id(id) => covers.get(id),
+                    IdOr::Object(cover) => Some(&cover.image_id),
+                };
+                files.extend(self.file(game, ImageKind::Cover, 0, image_id, quality));
+            }
+            if let Some(quality) = self.screenshots {
+                for (index, screenshot) in game.screenshots.iter().enumerate() {
+                    let image_id = match screenshot {
+                        IdOr::Id(id) => screenshots.get(id),
+                        IdOr::Object(screenshot) => Some(&screenshot.image_id),
+                    };
+                    files.extend(self.file(game, ImageKind::Screenshot, index, image_id, quality));
+                }
+            }
+            if let Some(quality) = self.artworks {
+                for (index, artwork) in game.artworks.iter().enumerate() {
+                    let image_id = match artwork {
+                        IdOr::Id(id) => artworks.get(id),
+                        IdOr::Object(artwork) => Some(&artwork.image_id),
+                    };
+                    files.extend(self.file(game, ImageKind::Artwork, index, image_id, quality));
+                }
+            }
+        }
+
+        let mut results = futures::stream::iter(files)
+            .map(|(path, url)| async move {
+                let result = self.download(&path, &url).await;
+                (path, result)
+            })
+            .buffer_unordered(self.parallelism);
+
+        let mut report = PrefetchReport::default();
+        while let Some((path, result)) = results.next().await {
+            match result {
+                Ok(true) => report.downloaded.push(path),
+                Ok(false) => report.skipped.push(path),
+                Err(e) => {
+                    log::warn!("Failed to prefetch {}: {}", path.display(), e);
+                    report.failed.push((path, e));
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    ///The path and url of the image, None when its image id is unknown
+    fn file(
+        &self,
+        game: &Game,
+        kind: ImageKind,
+        index: usize,
+        image_id: Option<&String>,
+        quality: MediaQuality,
+    ) -> Option<(PathBuf, ImageUrl)> {
+        let image_id = image_id.filter(|image_id| !image_id.is_empty())?;
+        let file = ImageFile {
+            game,
+            kind,
+            index,
+            image_id,
+        };
+        Some((
+            self.dir.join((self.naming)(&file)),
+            ImageUrl::new(image_id.as_str(), quality),
+        ))
+    }
+
+    ///Downloads the image unless its file exists, writing a temporary file first so that
+    /// an interrupted download is not mistaken for a complete one afterwards
+    async fn download(&self, path: &PathBuf, url: &ImageUrl) -> Result<bool, Error> {
+        if async_std::path::Path::new(path).exists().await {
+            return Ok(false);
+        }
+
+        let image = self.client.fetch_image(url).await?;
+        if let Some(parent) = path.parent() {
+            async_std::fs::create_dir_all(parent).await?;
+        }
+        let partial = path.with_extension("part");
+        async_std::fs::write(&partial, image).await?;
+        async_std::fs::rename(&partial, path).await?;
+        Ok(true)
+    }
+}
+
+///Names the covers `{game id}/cover.jpg` and the other images e.g. `{game id}/screenshot-1.jpg`
+fn default_name(image: &ImageFile<'_>) -> PathBuf {
+    let name = match image.kind {
+        ImageKind::Cover => "cover.jpg".to_owned(),
+        kind => format!("{}-{}.jpg", kind.name(), image.index + 1),
+    };
+    PathBuf::from(image.game.id.to_string()).join(name)
+}
+
+///The ids of the references which were not expanded
+fn unexpanded<T: Identifiable>(references: &[IdOr<T>]) -> impl Iterator<Item = T::Id> + '_ {
+    references.iter().filter_map(|reference| match reference {
+        IdOr::Id(id) => Some(*id),
+        IdOr::Object(_) => None,
+    })
+}
+
+///The distinct non-zero ids among the given ones
+fn distinct<I: EntityId + Ord>(ids: impl Iterator<Item = I>) -> Vec<I> {
+    ids.filter(|id| id.value() != 0)
+        .collect::<BTreeSet<I>>()
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn prefetcher_downloads_missing_images_only() {
+    use crate::transport::{HttpTransport, TransportFuture};
+
+    ///Answers the covers and screenshots queries, and the images from the CDN
+    struct Igdb;
+
+    impl HttpTransport for Igdb {
+        fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            let body: &[u8] = match request.uri().path() {
+                "/v4/covers" => br#"[{"id": 10, "image_id": "co10"}]"#,
+                "/v4/screenshots" => br#"[{"id": 20, "image_id": "sc20"}, {"id": 21}]"#,
+                _ => b"\xff\xd8",
+            };
+            let content_type = match request.uri().host() {
+                Some("images.igdb.com") => "image/jpeg",
+                _ => "application/json",
+            };
+            Box::pin(async move {
+                Ok(http::Response::builder()
+                    .header(http::header::CONTENT_TYPE, content_type)
+                    .body(body.to_vec())?)
+            })
+        }
+    }
+
+    let dir = std::env::temp_dir().join(format!("igdb-prefetch-{}", std::process::id()));
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(Igdb)
+        .without_rate_limit();
+    let games: Vec<Game> = serde_json::from_str(
+        r#"[
+            {"id": 1, "cover": 10, "screenshots": [20, 21]},
+            {"id": 2, "cover": {"id": 11, "image_id": "co11"}},
+            {"id": 3}
+        ]"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(dir.join("2")).unwrap();
+    std::fs::write(dir.join("2").join("cover.jpg"), b"").unwrap();
+
+    let prefetcher = igdb
+        .prefetcher(&dir)
+        .screenshots(MediaQuality::ScreenshotBig)
+        .parallelism(2);
+    let report = async_std::task::block_on(prefetcher.run(&games)).unwrap();
+
+    let mut downloaded = report.downloaded.clone();
+    downloaded.sort();
+    assert_eq!(
+        vec![dir.join("1/cover.jpg"), dir.join("1/screenshot-1.jpg")],
+        downloaded
+    );
+    assert_eq!(vec![dir.join("2/cover.jpg")], report.skipped);
+    assert!(report.failed.is_empty());
+    assert_eq!(b"\xff\xd8".to_vec(), std::fs::read(&downloaded[0]).unwrap());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,54 @@
+//! The types most queries need, in a single import.
+//! # Examples
+//! ```no_run
+//! use async_std::task;
+//! use igdb::prelude::*;
+//!
+//! task::block_on(async {
+//!     let igdb = Client::new("client_id", "token");
+//!     let mut request = RequestBuilder::new();
+//!     request
+//!         .add_fields([game::fields::NAME, game::fields::RATING])
+//!         .add_where("rating", Equality::Greater, 90)
+//!         .sort_by("rating", OrderBy::Descending);
+//!
+//!     let games: Vec<Game> = igdb.query::<GamesClient>(&request).await.unwrap();
+//! })
+//! ```
+
+pub use crate::auth::Credentials;
+pub use crate::client::{
+    ArtworksClient, CharactersClient, CollectionsClient, CompaniesClient, CoversClient,
+    FranchisesClient, GameModesClient, GamesClient, GenresClient, IGDBClient,
+    InvolvedCompaniesClient, PlatformsClient, ReleaseDatesClient, ScreenshotsClient, ThemesClient,
+    WebsitesClient,
+};
+pub use crate::client_builder::ClientBuilder;
+pub use crate::endpoints::Endpoint;
+pub use crate::error::Error;
+pub use crate::image_url::ImageUrl;
+pub use crate::media_quality::MediaQuality;
+pub use crate::model::artwork::Artwork;
+pub use crate::model::character::Character;
+pub use crate::model::collection::Collection;
+pub use crate::model::company::Company;
+pub use crate::model::cover::Cover;
+pub use crate::model::franchise::Franchise;
+pub use crate::model::game_mode::GameMode;
+pub use crate::model::games::{self as game, Game};
+pub use crate::model::genre::Genre;
+pub use crate::model::id_or::{IdOr, Identifiable};
+pub use crate::model::ids::*;
+pub use crate::model::involved_company::InvolvedCompany;
+pub use crate::model::platform::Platform;
+pub use crate::model::release_date::ReleaseDate;
+pub use crate::model::screenshot::Screenshot;
+pub use crate::model::theme::Theme;
+pub use crate::model::website::Website;
+pub use crate::multiquery::MultiQueryBuilder;
+pub use crate::rate_limiter::Priority;
+pub use crate::request_builder::{Equality, OrderBy, RequestBuilder, Value};
+pub use crate::request_options::{CacheMode, RequestOptions};
+pub use crate::timestamp::Timestamped;
+pub use crate::transport::HttpTransport;
+pub use crate::Client;
@@ -0,0 +1,197 @@
+//! Decoding of the protobuf responses returned by the `.pb` endpoints, following
+//! the schema generated at build time from IGDB's proto/igdbapi.proto.
+//!
+//! Responses are decoded to the json IGDB would have answered, so that every model
+//! is read by the same serde implementation whatever the response format.
+
+use crate::error::from_json_value;
+use crate::Error;
+use prost::bytes::Buf;
+use prost::encoding::{check_wire_type, decode_key, decode_varint, skip_field};
+use prost::encoding::{DecodeContext, WireType};
+use prost::DecodeError;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+pub(crate) struct Message {
+    fields: &'static [Field],
+}
+
+struct Field {
+    number: u32,
+    name: &'static str,
+    kind: Kind,
+    repeated: bool,
+}
+
+enum Kind {
+    Bool,
+    Double,
+    Int,
+    Uint,
+    String,
+    Enum,
+    ///google.protobuf.Timestamp, answered as a unix timestamp in json
+    Timestamp,
+    Message(&'static Message),
+}
+
+include!(concat!(env!("OUT_DIR"), "/protobuf_schema.rs"));
+
+static TIMESTAMP: Message = Message {
+    fields: &[Field {
+        number: 1,
+        name: "seconds",
+        kind: Kind::Int,
+        repeated: false,
+    }],
+};
+
+///Returns the schema of the response of the given endpoint path,
+/// None when the endpoint has no protobuf response (e.g. multiquery)
+pub(crate) fn response_message(path: &str) -> Option<&'static Message> {
+    match path.ends_with("/count") {
+        true => Some(&COUNT),
+        false => result_message(&path.replace('_', "")),
+    }
+}
+
+///Deserializes a protobuf response body
+pub(crate) fn from_slice<T: DeserializeOwned>(message: &Message, body: &[u8]) -> Result<T, Error> {
+    let mut value = decode(message, &mut &*body)?;
+
+    // Results wrap the registries in their only field, json answers the array itself
+    let value = match message.fields {
+        [field] if field.repeated => value
+            .remove(field.name)
+            .unwrap_or_else(|| Value::Array(Vec::new())),
+        _ => Value::Object(value),
+    };
+
+    Ok(from_json_value(&value)?)
+}
+
+fn decode(message: &Message, buf: &mut &[u8]) -> Result<Map<String, Value>, DecodeError> {
+    let mut values = Map::new();
+
+    while buf.has_remaining() {
+        let (number, wire_type) = decode_key(buf)?;
+        let field = match message.fields.iter().find(|field| field.number == number) {
+            Some(field) => field,
+            None => {
+                skip_field(wire_type, number, buf, DecodeContext::default())?;
+                continue;
+            }
+        };
+
+        if !field.repeated {
+            values.insert(
+                field.name.to_owned(),
+                decode_value(&field.kind, wire_type, buf)?,
+            );
+            continue;
+        }
+
+        let repeated = values
+            .entry(field.name)
+            .or_insert_with(|| Value::Array(Vec::new()));
+        if let Value::Array(repeated) = repeated {
+            match (wire_type, field.kind.wire_type()) {
+                // Repeated scalars are packed in a single length delimited field
+                (WireType::LengthDelimited, scalar) if scalar != WireType::LengthDelimited => {
+                    let mut packed = take(buf)?;
+                    while packed.has_remaining() {
+                        repeated.push(decode_value(&field.kind, scalar, &mut packed)?);
+                    }
+                }
+                _ => repeated.push(decode_value(&field.kind, wire_type, buf)?),
+            }
+        }
+    }
+
+    Ok(values)
+}
+
+fn decode_value(kind: &Kind, wire_type: WireType, buf: &mut &[u8]) -> Result<Value, DecodeError> {
+    check_wire_type(kind.wire_type(), wire_type)?;
+
+    Ok(match kind {
+        Kind::Bool => Value::Bool(decode_varint(buf)? != 0),
+        Kind::Int | Kind::Enum => Value::from(decode_varint(buf)? as i64),
+        Kind::Uint => Value::from(decode_varint(buf)?),
+        Kind::Double => {
+            if buf.remaining() < 8 {
+                return Err(DecodeError::new("buffer underflow"));
+            }
+            Value::from(buf.get_f64_le())
+        }
+        Kind::String => {
+            let string = String::from_utf8(take(buf)?.to_vec())
+                .map_err(|_| DecodeError::new("invalid string value: data is not UTF-8 encoded"))?;
+            Value::String(string)
+        }
+        Kind::Timestamp => decode(&TIMESTAMP, &mut take(buf)?)?
+            .remove("seconds")
+            .unwrap_or_else(|| Value::from(0)),
+        Kind::Message(message) => {
+            let values = decode(message, &mut take(buf)?)?;
+            // References that were not expanded only carry their id, answered as is in json
+            match values.get("id") {
+                Some(id) if values.len() == 1 => id.clone(),
+                _ => Value::Object(values),
+            }
+        }
+    })
+}
+
+impl Kind {
+    fn wire_type(&self) -> WireType {
+        match self {
+            Kind::Bool | Kind::Int | Kind::Uint | Kind::Enum => WireType::Varint,
+            Kind::Double => WireType::SixtyFourBit,
+            Kind::String | Kind::Timestamp | Kind::Message(_) => WireType::LengthDelimited,
+        }
+    }
+}
+
+///Splits the next length delimited value from the buffer
+fn take<'a>(buf: &mut &'a [u8]) -> Result<&'a [u8], DecodeError> {
+    let len = decode_varint(buf)? as usize;
+    if len > buf.len() {
+        return Err(DecodeError::new("buffer underflow"));
+    }
+    let (value, rest) = buf.split_at(len);
+    *buf = rest;
+    Ok(value)
+}
+
+#[test]
+fn protobuf_results_are_decoded_as_json_registries() {
+    use crate::model::games::Game;
+    use crate::model::ids::{CoverId, GameId, PlatformId};
+
+    // GameResult { games: [Game { id: 1942, name: "The Witcher 3", cover: Cover { id: 89 },
+    // first_release_date: 1431993600, platforms: [Platform { id: 6 }, Platform { id: 48 }] }] }
+    let body = [
+        0x0a, 0x29, 0x08, 0x96, 0x0f, 0xd2, 0x01, 0x0d, 0x54, 0x68, 0x65, 0x20, 0x57, 0x69, 0x74,
+        0x63, 0x68, 0x65, 0x72, 0x20, 0x33, 0x52, 0x02, 0x08, 0x59, 0x7a, 0x06, 0x08, 0x80, 0xfa,
+        0xe9, 0xaa, 0x05, 0xe2, 0x01, 0x02, 0x08, 0x06, 0xe2, 0x01, 0x02, 0x08, 0x30,
+    ];
+
+    let games: Vec<Game> = from_slice(response_message("games").unwrap(), &body).unwrap();
+
+    assert_eq!(1, games.len());
+    assert_eq!(GameId(1942), games[0].id);
+    assert_eq!("The Witcher 3", games[0].name);
+    assert_eq!(CoverId(89), games[0].cover.id());
+    assert_eq!(1431993600, games[0].first_release_date);
+    let platforms: Vec<PlatformId> = games[0].platforms.iter().map(|p| p.id()).collect();
+    assert_eq!(vec![PlatformId(6), PlatformId(48)], platforms);
+
+    let empty: Vec<Game> = from_slice(response_message("games").unwrap(), &[]).unwrap();
+    assert!(empty.is_empty());
+    let count: Value = from_slice(response_message("games/count").unwrap(), &[0x08, 0x2a]).unwrap();
+    assert_eq!(serde_json::json!({ "count": 42 }), count);
+    assert!(response_message("multiquery").is_none());
+    assert!(response_message("game_engines").is_some());
+}
@@ -0,0 +1,206 @@
+//! Guardrails against queries answered with huge responses, see RequestBuilder::lint and
+//! IGDBClient::with_lint_mode.
+//!
+//! `fields *` is handy while exploring Apicalypse, but on endpoints like games it returns
+//! dozens of fields, ids lists included, and every `field.*` expansion multiplies the size of
+//! each registry. The response sizes are estimated from rough per-endpoint averages: good
+//! enough to tell a query asking for a few fields from one asking for everything.
+
+use crate::request_builder::RequestBuilder;
+use crate::Error;
+use std::fmt;
+
+///Estimated bytes of a registry with every field, for the endpoints with many or large
+/// fields. Registries of the other endpoints weigh DEFAULT_REGISTRY_BYTES
+const HEAVY_ENDPOINTS: [(&str, usize); 9] = [
+    ("games", 6000),
+    ("companies", 2000),
+    ("collections", 1500),
+    ("franchises", 1500),
+    ("events", 1500),
+    ("characters", 1200),
+    ("platforms", 1200),
+    ("game_versions", 1000),
+    ("game_localizations", 1000),
+];
+///Estimated bytes of a registry with every field, for the endpoints not listed as heavy
+const DEFAULT_REGISTRY_BYTES: usize = 400;
+///Estimated bytes of a single field, its name included
+const FIELD_BYTES: usize = 40;
+///Estimated number of registries an expanded field references, most being lists
+const EXPANSION_FANOUT: usize = 4;
+///Responses estimated larger than this are reported
+pub const LARGE_RESPONSE_BYTES: usize = 1024 * 1024;
+
+///How the queries are checked before being sent
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LintMode {
+    ///Sends them unchecked
+    #[default]
+    Ignore,
+    ///Logs a warning for each advisory
+    Warn,
+    ///Fails the queries with advisories with Error::InvalidQuery, e.g. in tests
+    Strict,
+}
+
+///A query likely to be answered with more than what it needs, see RequestBuilder::lint
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Advisory {
+    ///`fields *` on an endpoint whose registries have many fields
+    AllFields { endpoint: String },
+    ///Every field of the registries referenced by a field, e.g. `involved_companies.*`
+    AllNestedFields { endpoint: String, field: String },
+    ///A response estimated larger than LARGE_RESPONSE_BYTES
+    LargeResponse {
+        endpoint: String,
+        limit: usize,
+        estimated_bytes: usize,
+    },
+}
+
+impl fmt::Display for Advisory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Advisory::AllFields { endpoint } => write!(
+                f,
+                "`fields *` requests every field of {}, list the fields needed instead",
+                endpoint
+            ),
+            Advisory::AllNestedFields { endpoint, field } => write!(
+                f,
+                "`{}` requests every field of the registries referenced by {}, list the nested fields needed instead",
+                field, endpoint
+            ),
+            Advisory::LargeResponse {
+                endpoint,
+                limit,
+                estimated_bytes,
+            } => write!(
+                f,
+                "{} registries of {} are estimated at {} KiB, request fewer fields or a lower limit",
+                limit,
+                endpoint,
+                estimated_bytes / 1024
+            ),
+        }
+    }
+}
+
+impl RequestBuilder {
+    ///Returns the advisories of the request sent to the given endpoint, empty when its fields
+    /// and limit look reasonable: `fields *` on heavy endpoints, every field of referenced
+    /// registries, and responses estimated larger than LARGE_RESPONSE_BYTES
+    /// # Examples
+    /// ```
+    /// use igdb::query_lint::Advisory;
+    /// use igdb::request_builder::RequestBuilder;
+    ///
+    /// let mut request = RequestBuilder::new();
+    /// request.all_fields().limit(500);
+    /// let advisories = request.lint("games");
+    /// assert!(matches!(advisories[0], Advisory::AllFields { .. }));
+    /// assert!(matches!(advisories[1], Advisory::LargeResponse { .. }));
+    ///
+    /// let mut request = RequestBuilder::new();
+    /// request.add_fields(["name", "cover.image_id"]).limit(500);
+    /// assert!(request.lint("games").is_empty());
+    /// ```
+    pub fn lint(&self, endpoint: &str) -> Vec<Advisory> {
+        let registry_bytes = HEAVY_ENDPOINTS
+            .iter()
+            .find(|(heavy, _)| *heavy == endpoint)
+            .map(|(_, bytes)| *bytes);
+        let mut advisories = Vec::new();
+        let mut estimated_bytes = 0;
+        for field in &self.fields {
+            let field = field.trim();
+            let depth = field.matches('.').count();
+            let fanout = EXPANSION_FANOUT.pow(depth as u32);
+            estimated_bytes += match (field, field.ends_with(".*")) {
+                ("*", _) => {
+                    if registry_bytes.is_some() {
+                        advisories.push(Advisory::AllFields {
+                            endpoint: endpoint.to_owned(),
+                        });
+                    }
+                    registry_bytes.unwrap_or(DEFAULT_REGISTRY_BYTES)
+                }
+                (_, true) => {
+                    advisories.push(Advisory::AllNestedFields {
+                        endpoint: endpoint.to_owned(),
+                        field: field.to_owned(),
+                    });
+                    fanout * DEFAULT_REGISTRY_BYTES
+                }
+                _ => fanout * FIELD_BYTES,
+            };
+        }
+
+        let estimated_bytes = estimated_bytes * self.limit;
+        if estimated_bytes > LARGE_RESPONSE_BYTES {
+            advisories.push(Advisory::LargeResponse {
+                endpoint: endpoint.to_owned(),
+                limit: self.limit,
+                estimated_bytes,
+            });
+        }
+        advisories
+    }
+}
+
+///Checks the query about to be sent to the endpoint as the mode asks. Queries which cannot
+/// be read back into a RequestBuilder, e.g. multiqueries, are not checked
+pub(crate) fn check(mode: LintMode, endpoint: &str, query: &str) -> Result<(), Error> {
+    if mode == LintMode::Ignore || endpoint.contains('/') {
+        return Ok(());
+    }
+    let advisories = match query.parse::<RequestBuilder>() {
+        Ok(request) => request.lint(endpoint),
+        Err(_) => return Ok(()),
+    };
+    match mode {
+        _ if advisories.is_empty() => Ok(()),
+        LintMode::Ignore => Ok(()),
+        LintMode::Warn => {
+            for advisory in advisories {
+                log::warn!("{}", advisory);
+            }
+            Ok(())
+        }
+        LintMode::Strict => Err(Error::InvalidQuery(
+            advisories
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join("; "),
+        )),
+    }
+}
+
+#[test]
+fn expansions_multiply_the_estimated_size() {
+    let mut request = RequestBuilder::new();
+    request
+        .add_fields(["name", "involved_companies.company.*"])
+        .limit(500);
+    let advisories = request.lint("games");
+    assert_eq!(
+        vec![
+            Advisory::AllNestedFields {
+                endpoint: "games".to_owned(),
+                field: "involved_companies.company.*".to_owned(),
+            },
+            Advisory::LargeResponse {
+                endpoint: "games".to_owned(),
+                limit: 500,
+                estimated_bytes: 500 * (40 + 16 * 400),
+            },
+        ],
+        advisories
+    );
+
+    assert!(check(LintMode::Strict, "games", &request.to_query()).is_err());
+    assert!(check(LintMode::Strict, "games/count", &request.to_query()).is_ok());
+    assert!(check(LintMode::Strict, "genres", "fields *; limit 500;").is_ok());
+}
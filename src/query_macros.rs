@@ -0,0 +1,219 @@
+///Builds a RequestBuilder from an Apicalypse query written as in the IGDB docs,
+/// checked when compiling instead of when IGDB answers.
+///
+///Supported clauses are `fields` (`*` or field paths such as `cover.image_id`), `exclude`,
+/// `where`, `sort`, `search`, `limit` and `offset`. Conditions use `=`, `!=`, `>`, `>=`, `<`
+/// and `<=`, are joined with `&` or `|` and grouped with parentheses. Values are literals,
+/// `null`, lists `(6, 14)`, `[6, 14]` or `{6, 14}`, and Rust expressions between braces.
+/// # Examples
+/// ```
+/// use igdb::apicalypse;
+///
+/// let min_rating = 80;
+/// let request = apicalypse! {
+///     fields name, rating, cover.image_id;
+///     where rating > { min_rating } & platforms = [6, 14] & (category = 0 | category = 8);
+///     sort rating desc;
+///     limit 20;
+/// };
+///
+/// assert_eq!(
+///     "fields name,rating,cover.image_id; \
+///      where rating > 80 & platforms = [6,14] & (category = 0 | category = 8); \
+///      sort rating desc; limit 20;",
+///     request.to_query()
+/// );
+/// ```
+#[macro_export]
+macro_rules! apicalypse {
+    (@clauses $r:ident;) => {};
+    (@clauses $r:ident; fields * ; $($rest:tt)*) => {
+        $r.all_fields();
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; fields $($($field:ident).+),+ ; $($rest:tt)*) => {
+        $r.add_fields([$([$(stringify!($field)),+].join(".")),+]);
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; exclude $($($field:ident).+),+ ; $($rest:tt)*) => {
+        $r.exclude_fields([$([$(stringify!($field)),+].join(".")),+]);
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; where $($rest:tt)*) => {
+        $crate::apicalypse!(@where $r, and; $($rest)*);
+    };
+    (@clauses $r:ident; sort $($field:ident).+ asc ; $($rest:tt)*) => {
+        $r.sort_by(
+            [$(stringify!($field)),+].join("."),
+            $crate::request_builder::OrderBy::Ascending,
+        );
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; sort $($field:ident).+ desc ; $($rest:tt)*) => {
+        $r.sort_by(
+            [$(stringify!($field)),+].join("."),
+            $crate::request_builder::OrderBy::Descending,
+        );
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; search $search:expr ; $($rest:tt)*) => {
+        $r.search($search);
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; limit $limit:expr ; $($rest:tt)*) => {
+        $r.limit($limit);
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; offset $offset:expr ; $($rest:tt)*) => {
+        $r.offset($offset);
+        $crate::apicalypse!(@clauses $r; $($rest)*);
+    };
+    (@clauses $r:ident; $($rest:tt)+) => {
+        compile_error!(concat!("invalid apicalypse clause: ", stringify!($($rest)+)));
+    };
+
+    // Conditions, added to a RequestBuilder or a FilterGroup joined by $conj (and / or)
+    (@where $t:ident, and; ( $($group:tt)+ ) $($rest:tt)*) => {
+        $t.group(|group| {
+            $crate::apicalypse!(@where group, and; $($group)+);
+            group
+        });
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@where $t:ident, or; ( $($group:tt)+ ) $($rest:tt)*) => {
+        $t.group_or(|group| {
+            $crate::apicalypse!(@where group, and; $($group)+);
+            group
+        });
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@where $t:ident, $conj:ident; $($field:ident).+ = $($rest:tt)*) => {
+        $crate::apicalypse!(@value $t, $conj, [$(stringify!($field)),+].join("."), Equal; $($rest)*);
+    };
+    (@where $t:ident, $conj:ident; $($field:ident).+ != $($rest:tt)*) => {
+        $crate::apicalypse!(@value $t, $conj, [$(stringify!($field)),+].join("."), NotEqual; $($rest)*);
+    };
+    (@where $t:ident, $conj:ident; $($field:ident).+ > $($rest:tt)*) => {
+        $crate::apicalypse!(@value $t, $conj, [$(stringify!($field)),+].join("."), Greater; $($rest)*);
+    };
+    (@where $t:ident, $conj:ident; $($field:ident).+ >= $($rest:tt)*) => {
+        $crate::apicalypse!(@value $t, $conj, [$(stringify!($field)),+].join("."), GreaterOrEqual; $($rest)*);
+    };
+    (@where $t:ident, $conj:ident; $($field:ident).+ < $($rest:tt)*) => {
+        $crate::apicalypse!(@value $t, $conj, [$(stringify!($field)),+].join("."), Less; $($rest)*);
+    };
+    (@where $t:ident, $conj:ident; $($field:ident).+ <= $($rest:tt)*) => {
+        $crate::apicalypse!(@value $t, $conj, [$(stringify!($field)),+].join("."), LessOrEqual; $($rest)*);
+    };
+    (@where $t:ident, $conj:ident; $($rest:tt)*) => {
+        compile_error!(concat!("invalid apicalypse condition: ", stringify!($($rest)*)));
+    };
+
+    (@value $t:ident, $conj:ident, $field:expr, $eq:ident; null $($rest:tt)*) => {
+        $crate::apicalypse!(@add $t, $conj, $field, $eq, $crate::request_builder::Value::Null);
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@value $t:ident, $conj:ident, $field:expr, $eq:ident; ( $($v:literal),+ ) $($rest:tt)*) => {
+        $crate::apicalypse!(@add $t, $conj, $field, $eq, $crate::apicalypse!(@list "(", ")", $($v),+));
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@value $t:ident, $conj:ident, $field:expr, $eq:ident; [ $($v:literal),+ ] $($rest:tt)*) => {
+        $crate::apicalypse!(@add $t, $conj, $field, $eq, $crate::apicalypse!(@list "[", "]", $($v),+));
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@value $t:ident, $conj:ident, $field:expr, $eq:ident; { $($v:literal),+ } $($rest:tt)*) => {
+        $crate::apicalypse!(@add $t, $conj, $field, $eq, $crate::apicalypse!(@list "{", "}", $($v),+));
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@value $t:ident, $conj:ident, $field:expr, $eq:ident; { $value:expr } $($rest:tt)*) => {
+        $crate::apicalypse!(@add $t, $conj, $field, $eq, $value);
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@value $t:ident, $conj:ident, $field:expr, $eq:ident; $v:literal $($rest:tt)*) => {
+        $crate::apicalypse!(@add $t, $conj, $field, $eq, $crate::request_builder::QueryLiteral::into_value($v));
+        $crate::apicalypse!(@next $t; $($rest)*);
+    };
+    (@value $t:ident, $conj:ident, $field:expr, $eq:ident; $($rest:tt)*) => {
+        compile_error!(concat!("invalid apicalypse value: ", stringify!($($rest)*)));
+    };
+
+    (@list $open:literal, $close:literal, $($v:literal),+) => {
+        $crate::request_builder::Value::Raw(format!(
+            "{}{}{}",
+            $open,
+            [$($crate::request_builder::QueryLiteral::into_value($v).to_string()),+].join(","),
+            $close
+        ))
+    };
+    (@add $t:ident, and, $field:expr, $eq:ident, $value:expr) => {
+        $t.add_where($field, $crate::request_builder::Equality::$eq, $value);
+    };
+    (@add $t:ident, or, $field:expr, $eq:ident, $value:expr) => {
+        $t.add_where_or($field, $crate::request_builder::Equality::$eq, $value);
+    };
+
+    // After a condition: the next one, the end of a group or the end of the where clause
+    (@next $t:ident;) => {};
+    (@next $t:ident; & $($rest:tt)+) => {
+        $crate::apicalypse!(@where $t, and; $($rest)+);
+    };
+    (@next $t:ident; | $($rest:tt)+) => {
+        $crate::apicalypse!(@where $t, or; $($rest)+);
+    };
+    (@next $t:ident; ; $($rest:tt)*) => {
+        $crate::apicalypse!(@clauses $t; $($rest)*);
+    };
+    (@next $t:ident; $($rest:tt)+) => {
+        compile_error!(concat!("expected `&`, `|` or `;` before: ", stringify!($($rest)+)));
+    };
+
+    ($($query:tt)+) => {{
+        let mut request = $crate::request_builder::RequestBuilder::new();
+        $crate::apicalypse!(@clauses request; $($query)+);
+        request
+    }};
+}
+
+///Builds the Params of a QueryTemplate, each value being converted with Into<Value>
+/// # Examples
+/// ```
+/// use igdb::params;
+/// use igdb::request_builder::Value;
+///
+/// let params = params! { "platform" => 6, "name" => Value::string("Celeste") };
+/// assert_eq!(Some(&Value::Int(6)), params.get("platform"));
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($($name:expr => $value:expr),* $(,)?) => {{
+        let mut params = $crate::query_template::Params::new();
+        $(params.insert(
+            ::std::string::String::from($name),
+            $crate::request_builder::Value::from($value),
+        );)*
+        params
+    }};
+}
+
+#[test]
+fn apicalypse_macro_builds_the_query() {
+    let genre = crate::model::ids::GenreId(12);
+    let request = apicalypse! {
+        fields *;
+        exclude storyline, summary;
+        search "zelda";
+        where (genres = {31, 12} | genres = { genre }) & name != "Zelda II" & cover != null
+            & rating >= 75.5 & themes = (1) | category <= -1;
+        sort first_release_date asc;
+        limit 5;
+        offset 10;
+    };
+
+    assert_eq!(
+        "fields *; exclude storyline,summary; search \"zelda\"; \
+         where ((genres = {31,12} | genres = 12) & name != \"Zelda II\" & cover != null \
+         & rating >= 75.5 & themes = (1)) | category <= -1; \
+         sort first_release_date asc; limit 5; offset 10;",
+        request.to_query()
+    );
+}
@@ -0,0 +1,267 @@
+use crate::expr::{Expr, Operator};
+use crate::request_builder::{RequestBuilder, Value};
+use crate::request_filters::{Conjunction, FilterChain};
+use crate::Error;
+use std::str::FromStr;
+
+///Parses an Apicalypse query, e.g. `fields name; where rating > 80; sort rating desc; limit 5;`
+/// # Examples
+/// ```
+/// use igdb::request_builder::{OrderBy, RequestBuilder};
+///
+/// let mut request: RequestBuilder = "fields name; where rating > 80; limit 5;".parse().unwrap();
+/// request.sort_by("rating", OrderBy::Descending);
+///
+/// assert_eq!(
+///     "fields name; where rating > 80; sort rating desc; limit 5;",
+///     request.to_query()
+/// );
+/// ```
+impl FromStr for RequestBuilder {
+    type Err = Error;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        let mut request = RequestBuilder::new();
+
+        for (_, clause) in split_top_level(query, &[';']) {
+            let clause = clause.trim();
+            if clause.is_empty() {
+                continue;
+            }
+
+            let (keyword, rest) = match clause.split_once(char::is_whitespace) {
+                Some((keyword, rest)) => (keyword, rest.trim()),
+                None => (clause, ""),
+            };
+
+            match keyword {
+                "fields" | "f" => request.fields = parse_list(rest),
+                "exclude" | "x" => request.exclude = parse_list(rest),
+                "search" => request.search = parse_string(rest)?,
+                "where" | "w" => request.filters.set(Some(parse_filters(rest)?)),
+                "sort" | "s" => request.sort = parse_sort(rest)?,
+                "limit" | "l" => {
+                    request.limit(parse_number(keyword, rest)?);
+                }
+                "offset" | "o" => {
+                    request.offset(parse_number(keyword, rest)?);
+                }
+                _ => return Err(invalid(format!("Unknown clause: {}", clause))),
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+fn invalid(message: String) -> Error {
+    Error::InvalidQuery(message)
+}
+
+///Splits the text on the given separators, ignoring the ones inside strings and brackets.
+/// Every part comes with the separator preceding it.
+fn split_top_level(text: &str, separators: &[char]) -> Vec<(Option<char>, String)> {
+    let mut parts = vec![(None, String::new())];
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in text.chars() {
+        let top_level = depth == 0 && !in_string;
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth = depth.saturating_sub(1),
+            _ if top_level && separators.contains(&c) => {
+                parts.push((Some(c), String::new()));
+                continue;
+            }
+            _ => {}
+        }
+        if let Some((_, part)) = parts.last_mut() {
+            part.push(c);
+        }
+    }
+
+    parts
+}
+
+fn parse_list(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+fn parse_string(text: &str) -> Result<String, Error> {
+    match text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        Some(value) => Ok(unescape(value)),
+        None => Err(invalid(format!("Expected a quoted string: {}", text))),
+    }
+}
+
+///Reverts the escaping of the quotes and backslashes of a string literal
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+fn parse_number(keyword: &str, text: &str) -> Result<usize, Error> {
+    text.parse()
+        .map_err(|_| invalid(format!("Invalid {}: {}", keyword, text)))
+}
+
+fn parse_sort(text: &str) -> Result<Vec<(String, String)>, Error> {
+    parse_list(text)
+        .into_iter()
+        .map(|sort| {
+            let mut parts = sort.split_whitespace();
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(field), Some(order @ ("asc" | "desc")), None) => {
+                    Ok((field.to_owned(), order.to_owned()))
+                }
+                (Some(field), None, None) => Ok((field.to_owned(), "asc".to_owned())),
+                _ => Err(invalid(format!("Invalid sort: {}", sort))),
+            }
+        })
+        .collect()
+}
+
+///Parses a where clause, `&` binding tighter than `|`
+pub(crate) fn parse_filters(text: &str) -> Result<Expr, Error> {
+    let mut filters = FilterChain::default();
+    for (separator, condition) in split_top_level(text, &['&', '|']) {
+        let conjunction = match separator {
+            Some('|') => Conjunction::Or,
+            _ => Conjunction::And,
+        };
+        filters.push(conjunction, parse_condition(condition.trim())?);
+    }
+    filters
+        .expr
+        .ok_or_else(|| invalid(format!("Invalid condition: {}", text)))
+}
+
+fn parse_condition(condition: &str) -> Result<Expr, Error> {
+    if let Some(group) = condition
+        .strip_prefix('(')
+        .and_then(|condition| condition.strip_suffix(')'))
+    {
+        return parse_filters(group);
+    }
+
+    let key_end = condition
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+        .unwrap_or(condition.len());
+    let (key, rest) = condition.split_at(key_end);
+    let rest = rest.trim_start();
+
+    match Operator::SYMBOLS
+        .iter()
+        .find(|(symbol, _)| rest.starts_with(symbol))
+    {
+        Some((symbol, _)) if !key.is_empty() => {
+            parse_leaf(key, symbol, rest[symbol.len()..].trim())
+        }
+        _ => Err(invalid(format!("Invalid condition: {}", condition))),
+    }
+}
+
+///Reads a condition from its parts, its value being kept as written when it would not
+/// be rendered back the same
+pub(crate) fn parse_leaf(field: &str, symbol: &str, value: &str) -> Result<Expr, Error> {
+    let op = Operator::from_symbol(symbol)
+        .ok_or_else(|| invalid(format!("Invalid operator: {}", symbol)))?;
+    let leaf = read_leaf(field, op, value);
+
+    match leaf.parts() {
+        Some((_, _, rendered)) if rendered == value => Ok(leaf),
+        _ => Ok(Expr::Cmp {
+            field: field.to_owned(),
+            op,
+            value: Value::Raw(value.to_owned()),
+        }),
+    }
+}
+
+///Reads a condition from its parts, whatever the spacing of its value
+pub(crate) fn read_leaf(field: &str, op: Operator, value: &str) -> Expr {
+    let negated = op == Operator::NotEqual;
+    let list = value
+        .strip_prefix('(')
+        .and_then(|value| value.strip_suffix(')'));
+    match (list, op) {
+        (_, Operator::Equal | Operator::NotEqual) if value == "null" => Expr::Null {
+            field: field.to_owned(),
+            negated,
+        },
+        (Some(list), Operator::Equal | Operator::NotEqual) => Expr::In {
+            field: field.to_owned(),
+            values: split_top_level(list, &[','])
+                .into_iter()
+                .map(|(_, value)| parse_value(value.trim()))
+                .collect(),
+            negated,
+        },
+        _ => Expr::Cmp {
+            field: field.to_owned(),
+            op,
+            value: parse_value(value),
+        },
+    }
+}
+
+fn parse_value(value: &str) -> Value {
+    if let Ok(int) = value.parse::<i64>() {
+        return Value::Int(int);
+    }
+    match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => match parse_string(value) {
+            Ok(string) => Value::Str(string),
+            Err(_) => match value.parse::<f64>() {
+                Ok(float) if float.is_finite() => Value::Float(float),
+                _ => Value::Raw(value.to_owned()),
+            },
+        },
+    }
+}
+
+#[test]
+fn parsed_query_renders_the_same_body() {
+    let queries = [
+        "fields *; limit 10;",
+        "fields name,rating; exclude summary; search \"Zelda; \\\"Link\\\"\"; where rating >= 80 & platforms != 6; limit 5;",
+        "fields name; where ((platforms = 6 | platforms = 14) & rating > 80) | rating_count > 1000 | (category = 0 & (status = 2 | status = 3)); sort rating desc, id asc; limit 10; offset 20;",
+        "fields name; where id = (5,8) & name ~ *\"Ast\"* & cover = null; limit 3;",
+    ];
+
+    for query in queries {
+        let request: RequestBuilder = query.parse().unwrap();
+        assert_eq!(query, request.to_query());
+    }
+}
+
+#[test]
+fn invalid_queries_are_rejected() {
+    for query in [
+        "fields name; limit ten;",
+        "fields name; where rating;",
+        "fields name; sort name up;",
+        "fields name; search Zelda;",
+        "fields name; having id = 1;",
+    ] {
+        assert!(query.parse::<RequestBuilder>().is_err(), "{}", query);
+    }
+}
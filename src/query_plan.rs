@@ -0,0 +1,188 @@
+//! Structured description of a query, see RequestBuilder::explain.
+//!
+//! Unlike the flat query string, the plan exposes the where clause as a tree and tells how
+//! many requests IGDBClient::query_all sends for it, e.g. to render queries in admin tooling.
+
+use crate::endpoint_client::MAX_LIMIT;
+use crate::expr::Expr;
+use crate::query_spec::SortSpec;
+use crate::request_builder::{distinct, RequestBuilder};
+
+///What a query requests, see RequestBuilder::explain
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct QueryPlan {
+    ///Fields requested, without the repeated ones. Empty when every field of the model
+    /// queried is requested
+    pub fields: Vec<String>,
+    pub exclude: Vec<String>,
+    pub search: Option<String>,
+    ///The where clause, None without filters
+    pub filter: Option<FilterNode>,
+    pub sort: Vec<SortSpec>,
+    pub limit: usize,
+    pub offset: usize,
+    ///Names the client resolves to ids before sending the query, see
+    /// RequestBuilder::with_genre_names
+    pub name_lookups: Vec<NameLookup>,
+    ///Requests IGDBClient::query_all sends: one per page of 500 registries,
+    /// and at most one per name lookup, the names already resolved being cached
+    pub estimated_requests: usize,
+}
+
+///A node of the where clause, `&` binding tighter than `|`
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FilterNode {
+    ///A single condition, e.g. `rating > 80`
+    Condition {
+        field: String,
+        operator: String,
+        ///The value as written in the query, e.g. `"Zelda"` or `(6,48)`
+        value: String,
+    },
+    ///Nodes which must all match
+    All { filters: Vec<FilterNode> },
+    ///Nodes of which one must match
+    Any { filters: Vec<FilterNode> },
+}
+
+///Names of the registries of an endpoint a field must reference
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct NameLookup {
+    pub field: String,
+    ///Endpoint the names are looked up in, e.g. `genres`
+    pub endpoint: String,
+    pub names: Vec<String>,
+}
+
+impl RequestBuilder {
+    ///Describes the query as it would be sent, the filters being a tree
+    /// # Examples
+    /// ```
+    /// use igdb::query_plan::FilterNode;
+    /// use igdb::request_builder::{Equality, RequestBuilder};
+    ///
+    /// let mut request = RequestBuilder::new();
+    /// request
+    ///     .add_field("name")
+    ///     .add_where("rating", Equality::Greater, 80)
+    ///     .add_where_or("rating_count", Equality::Greater, 1000)
+    ///     .limit(1200);
+    ///
+    /// let plan = request.explain();
+    /// assert_eq!(3, plan.estimated_requests);
+    /// match plan.filter {
+    ///     Some(FilterNode::Any { filters }) => assert_eq!(2, filters.len()),
+    ///     other => panic!("unexpected filter: {:?}", other),
+    /// }
+    /// ```
+    pub fn explain(&self) -> QueryPlan {
+        let name_lookups: Vec<NameLookup> = self
+            .names
+            .iter()
+            .map(|filter| NameLookup {
+                field: filter.field.to_owned(),
+                endpoint: filter.endpoint.to_owned(),
+                names: filter.names.clone(),
+            })
+            .collect();
+        let pages = self.limit.div_ceil(MAX_LIMIT).max(1);
+
+        QueryPlan {
+            fields: distinct(&self.fields)
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            exclude: distinct(&self.exclude)
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            search: Some(self.search.clone()).filter(|search| !search.is_empty()),
+            filter: self.filters.expr.as_ref().map(FilterNode::from),
+            sort: self
+                .sort
+                .iter()
+                .map(|(field, order)| SortSpec {
+                    field: field.clone(),
+                    order: order.clone(),
+                })
+                .collect(),
+            limit: self.limit,
+            offset: self.offset,
+            estimated_requests: pages + name_lookups.len(),
+            name_lookups,
+        }
+    }
+}
+
+impl From<&Expr> for FilterNode {
+    fn from(expr: &Expr) -> Self {
+        match expr {
+            Expr::And(exprs) => FilterNode::All {
+                filters: exprs.iter().map(FilterNode::from).collect(),
+            },
+            Expr::Or(exprs) => FilterNode::Any {
+                filters: exprs.iter().map(FilterNode::from).collect(),
+            },
+            condition => {
+                let (field, operator, value) = condition.parts().unwrap_or_default();
+                FilterNode::Condition {
+                    field,
+                    operator,
+                    value,
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn explained_filters_follow_the_groups_and_precedence() {
+    use crate::request_builder::{Equality, OrderBy};
+
+    let condition = |field: &str, operator: &str, value: &str| FilterNode::Condition {
+        field: field.to_owned(),
+        operator: operator.to_owned(),
+        value: value.to_owned(),
+    };
+    let mut request = RequestBuilder::new();
+    request
+        .add_fields(["name", "rating", "name"])
+        .group(|group| {
+            group
+                .add_where("platforms", Equality::Equal, 6)
+                .add_where_or("platforms", Equality::Equal, 14)
+        })
+        .add_where("rating", Equality::Greater, 80)
+        .add_where_or("rating_count", Equality::Greater, 1000)
+        .sort_by("rating", OrderBy::Descending)
+        .with_genre_names(&["Indie"]);
+
+    let plan = request.explain();
+    assert_eq!(vec!["name", "rating"], plan.fields);
+    assert_eq!(
+        Some(FilterNode::Any {
+            filters: vec![
+                FilterNode::All {
+                    filters: vec![
+                        FilterNode::Any {
+                            filters: vec![
+                                condition("platforms", "=", "6"),
+                                condition("platforms", "=", "14"),
+                            ]
+                        },
+                        condition("rating", ">", "80"),
+                    ]
+                },
+                condition("rating_count", ">", "1000"),
+            ]
+        }),
+        plan.filter
+    );
+    assert_eq!("desc", plan.sort[0].order);
+    assert_eq!("genres", plan.name_lookups[0].endpoint);
+    assert_eq!(2, plan.estimated_requests);
+
+    let json = serde_json::to_value(&plan).unwrap();
+    assert_eq!("any", json["filter"]["type"]);
+}
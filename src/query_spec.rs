@@ -0,0 +1,252 @@
+use crate::expr::Expr;
+use crate::query_parser::{parse_filters, parse_leaf};
+use crate::request_builder::RequestBuilder;
+use crate::request_filters::Conjunction;
+use crate::Error;
+use std::convert::TryFrom;
+
+///Serializable representation of a RequestBuilder state.
+/// It can be stored in a database or a config file and turned back into a RequestBuilder.
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use igdb::query_spec::QuerySpec;
+/// use igdb::request_builder::{Equality, RequestBuilder};
+/// use std::convert::TryInto;
+///
+/// let mut request = IGDBClient::create_request();
+/// request
+/// .add_field("name")
+/// .add_where("rating", Equality::Greater, 80);
+///
+/// let spec = QuerySpec::from(&request);
+/// let restored: RequestBuilder = spec.try_into().unwrap();
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct QuerySpec {
+    #[serde(default)]
+    pub fields: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    #[serde(default)]
+    pub filters: Vec<FilterSpec>,
+    #[serde(default)]
+    pub sort: Vec<SortSpec>,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+    #[serde(default)]
+    pub offset: usize,
+    #[serde(default)]
+    pub search: Option<String>,
+    ///See RequestBuilder::with_genre_names
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub genre_names: Vec<String>,
+    ///See RequestBuilder::with_theme_names
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub theme_names: Vec<String>,
+}
+
+///A single `where` condition, e.g. `rating > 80`, or a parenthesized group of conditions
+/// held by the field, e.g. `(rating > 80 | rating_count > 1000)`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FilterSpec {
+    pub field: String,
+    #[serde(default)]
+    pub symbol: String,
+    #[serde(default)]
+    pub value: String,
+    ///Joins the filter to the previous one with `|` instead of `&`
+    #[serde(default)]
+    pub or: bool,
+}
+
+///A field of the sort clause, order is either `asc` or `desc`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SortSpec {
+    pub field: String,
+    pub order: String,
+}
+
+fn default_limit() -> usize {
+    RequestBuilder::default().limit
+}
+
+impl From<&RequestBuilder> for QuerySpec {
+    fn from(request: &RequestBuilder) -> Self {
+        QuerySpec {
+            fields: request.fields.clone(),
+            exclude: request.exclude.clone(),
+            filters: filter_specs(request.filters.expr.as_ref()),
+            sort: request
+                .sort
+                .iter()
+                .map(|(field, order)| SortSpec {
+                    field: field.clone(),
+                    order: order.clone(),
+                })
+                .collect(),
+            limit: request.limit,
+            offset: request.offset,
+            search: match &request.search {
+                search if search.is_empty() => None,
+                search => Some(search.clone()),
+            },
+            genre_names: names(request, "genres"),
+            theme_names: names(request, "themes"),
+        }
+    }
+}
+
+impl From<RequestBuilder> for QuerySpec {
+    fn from(request: RequestBuilder) -> Self {
+        QuerySpec::from(&request)
+    }
+}
+
+///The top level conditions of the where clause, the nested ones being grouped
+fn filter_specs(expr: Option<&Expr>) -> Vec<FilterSpec> {
+    let (filters, or) = match expr {
+        None => return Vec::new(),
+        Some(Expr::And(exprs)) => (&exprs[..], false),
+        Some(Expr::Or(exprs)) => (&exprs[..], true),
+        Some(condition) => (std::slice::from_ref(condition), false),
+    };
+    filters
+        .iter()
+        .enumerate()
+        .map(|(i, filter)| {
+            let (field, symbol, value) = filter
+                .parts()
+                .unwrap_or_else(|| (format!("({})", filter), String::new(), String::new()));
+            FilterSpec {
+                field,
+                symbol,
+                value,
+                or: or && i != 0,
+            }
+        })
+        .collect()
+}
+
+///The names the request filters the given endpoint registries on
+fn names(request: &RequestBuilder, endpoint: &str) -> Vec<String> {
+    request
+        .names
+        .iter()
+        .filter(|filter| filter.endpoint == endpoint)
+        .flat_map(|filter| filter.names.iter().cloned())
+        .collect()
+}
+
+impl TryFrom<QuerySpec> for RequestBuilder {
+    type Error = Error;
+
+    fn try_from(spec: QuerySpec) -> Result<Self, Self::Error> {
+        let sort = spec
+            .sort
+            .into_iter()
+            .map(|sort| match sort.order.as_str() {
+                "asc" | "desc" => Ok((sort.field, sort.order)),
+                _ => Err(Error::InvalidQuery(format!(
+                    "Unknown sort order: {}",
+                    sort.order
+                ))),
+            })
+            .collect::<Result<Vec<(String, String)>, Error>>()?;
+
+        let mut request = RequestBuilder {
+            fields: spec.fields,
+            exclude: spec.exclude,
+            filters: Default::default(),
+            sort,
+            limit: spec.limit,
+            offset: spec.offset,
+            // The spec does not tell a default limit or offset from an unset one
+            limit_set: spec.limit != default_limit(),
+            offset_set: spec.offset != 0,
+            search: spec.search.unwrap_or_default(),
+            names: Vec::new(),
+        };
+        for filter in spec.filters {
+            let conjunction = match filter.or {
+                true => Conjunction::Or,
+                false => Conjunction::And,
+            };
+            let group = filter
+                .field
+                .strip_prefix('(')
+                .and_then(|group| group.strip_suffix(')'))
+                .filter(|_| filter.symbol.is_empty());
+            let expr = match group {
+                Some(group) => parse_filters(group)?,
+                None => parse_leaf(&filter.field, &filter.symbol, &filter.value)?,
+            };
+            request.filters.push(conjunction, expr);
+        }
+        request
+            .with_genre_names(&spec.genre_names)
+            .with_theme_names(&spec.theme_names);
+        Ok(request)
+    }
+}
+
+#[test]
+fn query_spec_round_trips_through_json() {
+    use crate::request_builder::{Equality, OrderBy};
+
+    let mut request = RequestBuilder::new();
+    request
+        .add_fields(vec!["name", "rating"])
+        .exclude_field("summary")
+        .add_where("rating", Equality::Greater, 80)
+        .contains("name", "Zelda")
+        .group_or(|g| {
+            g.add_where("id", Equality::Equal, 1)
+                .add_where_or("id", Equality::Equal, 2)
+        })
+        .sort_by("rating", OrderBy::Descending)
+        .sort_by("id", OrderBy::Ascending)
+        .limit(20)
+        .offset(40)
+        .with_genre_names(&["Shooter"]);
+
+    let json = serde_json::to_string(&QuerySpec::from(&request)).unwrap();
+    let spec: QuerySpec = serde_json::from_str(&json).unwrap();
+    let restored = RequestBuilder::try_from(spec).unwrap();
+
+    assert_eq!(request.build_body(), restored.build_body());
+    assert_eq!(request.names, restored.names);
+}
+
+#[test]
+fn query_spec_with_unknown_sort_order_is_rejected() {
+    let spec: QuerySpec =
+        serde_json::from_str(r#"{"fields": ["name"], "sort": [{"field": "name", "order": "up"}]}"#)
+            .unwrap();
+
+    assert_eq!(10, spec.limit);
+    assert!(RequestBuilder::try_from(spec).is_err());
+}
+
+#[test]
+fn request_builder_serializes_like_its_spec() {
+    use crate::request_builder::Equality;
+
+    let mut request = RequestBuilder::new();
+    request
+        .add_field("name")
+        .add_where("rating", Equality::Greater, 80)
+        .with_theme_names(&["Horror"]);
+
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        serde_json::to_value(QuerySpec::from(&request)).unwrap(),
+        json
+    );
+    let restored: RequestBuilder = serde_json::from_value(json).unwrap();
+    assert_eq!(request.build_body(), restored.build_body());
+    assert_eq!(request.names, restored.names);
+
+    let invalid = r#"{"sort": [{"field": "name", "order": "up"}]}"#;
+    assert!(serde_json::from_str::<RequestBuilder>(invalid).is_err());
+}
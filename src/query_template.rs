@@ -0,0 +1,140 @@
+use crate::request_builder::{RequestBuilder, Value};
+use crate::Error;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+///Values of the placeholders of a QueryTemplate by name, see the params! macro
+pub type Params = HashMap<String, Value>;
+
+///An Apicalypse query with named placeholders such as `{platform}`, meant to be stored
+/// in configuration files and instantiated at runtime.
+/// Placeholders are replaced by the Apicalypse syntax of their Value, so strings are
+/// quoted and escaped while a Value::Raw is inserted as is.
+/// Lists written between braces, e.g. `{6,48}`, are not placeholders.
+/// # Examples
+/// ```
+/// use igdb::params;
+/// use igdb::query_template::QueryTemplate;
+/// use igdb::request_builder::Value;
+///
+/// let template: QueryTemplate = "fields name; where platforms = {platform} & rating > {min}; limit 5;"
+///     .parse()
+///     .unwrap();
+/// let request = template
+///     .instantiate(&params! { "platform" => 6, "min" => 80.5 })
+///     .unwrap();
+///
+/// assert_eq!(
+///     "fields name; where platforms = 6 & rating > 80.5; limit 5;",
+///     request.to_query()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct QueryTemplate {
+    query: String,
+}
+
+impl QueryTemplate {
+    ///Creates a template, failing when a placeholder is not closed
+    pub fn new<S: Into<String>>(query: S) -> Result<QueryTemplate, Error> {
+        let template = QueryTemplate {
+            query: query.into(),
+        };
+        template.render(|_| Some(String::new()))?;
+        Ok(template)
+    }
+
+    ///The names of the placeholders, in the order they appear
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        let _ = self.render(|name| {
+            names.push(name.to_owned());
+            Some(String::new())
+        });
+        names
+    }
+
+    ///Replaces the placeholders by the given params and parses the query,
+    /// failing when a param is missing or the resulting query is invalid
+    pub fn instantiate(&self, params: &Params) -> Result<RequestBuilder, Error> {
+        let query = self.render(|name| params.get(name).map(ToString::to_string))?;
+        let request: RequestBuilder = query.parse()?;
+        request.validate()?;
+        Ok(request)
+    }
+
+    fn render<F>(&self, mut value: F) -> Result<String, Error>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        let mut rendered = String::new();
+        let mut rest = self.query.as_str();
+
+        while let Some(start) = rest.find('{') {
+            rendered.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let is_placeholder = after.starts_with(|c: char| c.is_alphabetic() || c == '_');
+            if !is_placeholder {
+                rendered.push('{');
+                rest = after;
+                continue;
+            }
+
+            let end = after.find('}').ok_or_else(|| {
+                Error::InvalidQuery(format!("unclosed placeholder in: {}", self.query))
+            })?;
+            let name = &after[..end];
+            let param = value(name)
+                .ok_or_else(|| Error::InvalidQuery(format!("missing query param: {}", name)))?;
+            rendered.push_str(&param);
+            rest = &after[end + 1..];
+        }
+        rendered.push_str(rest);
+
+        Ok(rendered)
+    }
+}
+
+impl FromStr for QueryTemplate {
+    type Err = Error;
+
+    fn from_str(query: &str) -> Result<Self, Self::Err> {
+        QueryTemplate::new(query)
+    }
+}
+
+impl TryFrom<String> for QueryTemplate {
+    type Error = Error;
+
+    fn try_from(query: String) -> Result<Self, Self::Error> {
+        QueryTemplate::new(query)
+    }
+}
+
+impl fmt::Display for QueryTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.query)
+    }
+}
+
+#[test]
+fn query_templates_are_instantiated() {
+    let template = QueryTemplate::new(
+        "fields name; search {term}; where platforms = {6,48} & first_release_date > {since};",
+    )
+    .unwrap();
+    assert_eq!(vec!["term", "since"], template.placeholders());
+
+    let request = template
+        .instantiate(&crate::params! { "term" => Value::string("Zelda \"64\""), "since" => 0 })
+        .unwrap();
+    assert_eq!(
+        "fields name; search \"Zelda \\\"64\\\"\"; where platforms = {6,48} & first_release_date > 0; limit 10;",
+        request.to_query()
+    );
+
+    assert!(template.instantiate(&Params::new()).is_err());
+    assert!(QueryTemplate::new("fields name; where id = {id;").is_err());
+}
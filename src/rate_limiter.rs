@@ -0,0 +1,328 @@
+//! Client side rate limiting, see IGDBClient::with_rate_limit and IGDBClient::throttle_state.
+//!
+//! The rate adapts to IGDB's answers: each 429 Too Many Requests halves it, and once
+//! no request was rejected for a while, it grows back to the configured rate.
+//!
+//! Interactive requests are served first: background ones wait while an interactive request
+//! waits for the rate limit, so that they absorb the throttling, see IGDBClient::with_priority.
+
+use crate::time::Instant;
+use http::StatusCode;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+///Number of requests per second IGDB accepts before answering 429 Too Many Requests
+pub(crate) const DEFAULT_REQUESTS_PER_SECOND: u32 = 4;
+///Period over which the rejected requests are counted
+const WINDOW: Duration = Duration::from_secs(60);
+///Time without rejection after which the rate starts growing back
+const RECOVERY_DELAY: Duration = Duration::from_secs(10);
+///Lowest rate the throttling slows down to
+const MIN_REQUESTS_PER_SECOND: f64 = 0.25;
+///Share of the configured rate regained by each accepted request once recovering
+const RECOVERY_STEP: f64 = 0.1;
+
+///Which requests the rate limiter serves first, see IGDBClient::with_priority
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    ///Requests a user waits for, e.g. from a UI
+    #[default]
+    Interactive,
+    ///Requests which can wait, e.g. from a crawler or a sync
+    Background,
+}
+
+///How fast requests are currently sent, see IGDBClient::throttle_state
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThrottleState {
+    ///Rate requests are currently sent at
+    pub requests_per_second: f64,
+    ///Rate set on the client, reached again once IGDB stops rejecting requests
+    pub configured_requests_per_second: f64,
+    ///Requests answered with 429 Too Many Requests over the last minute
+    pub rate_limited_last_minute: usize,
+}
+
+impl ThrottleState {
+    ///Whether requests are sent slower than configured because IGDB rejected some
+    pub fn is_throttled(&self) -> bool {
+        self.requests_per_second < self.configured_requests_per_second
+    }
+}
+
+///Token bucket limiting the number of requests sent per second.
+/// It is shared by every endpoint client created from the same IGDBClient.
+pub(crate) struct RateLimiter {
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    configured: f64,
+    capacity: f64,
+    per_second: f64,
+    tokens: f64,
+    refilled_at: Instant,
+    ///Times of the requests rejected within the window, oldest first
+    rejected: VecDeque<Instant>,
+    ///Interactive requests waiting for a token
+    interactive_waiting: usize,
+}
+
+impl Bucket {
+    fn new(requests_per_second: u32, now: Instant) -> Bucket {
+        let capacity = f64::from(requests_per_second.max(1));
+        Bucket {
+            configured: capacity,
+            capacity,
+            per_second: capacity,
+            tokens: capacity,
+            refilled_at: now,
+            rejected: VecDeque::new(),
+            interactive_waiting: 0,
+        }
+    }
+
+    ///Takes a token if one is available, else returns how long to wait for the next one
+    fn take(&mut self, now: Instant) -> Result<(), Duration> {
+        self.refill(now);
+        match self.tokens >= 1.0 {
+            true => {
+                self.tokens -= 1.0;
+                Ok(())
+            }
+            false => Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.per_second,
+            )),
+        }
+    }
+
+    ///Adds the tokens earned since the last refill
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.refilled_at)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.capacity);
+        self.refilled_at = now;
+    }
+
+    ///Takes a token like take, background requests leaving it to the waiting
+    /// interactive ones until the next token
+    fn take_for(&mut self, priority: Priority, now: Instant) -> Result<(), Duration> {
+        match priority {
+            Priority::Background if self.interactive_waiting > 0 => {
+                Err(Duration::from_secs_f64(1.0 / self.per_second))
+            }
+            _ => self.take(now),
+        }
+    }
+
+    ///Halves the rate when the request was rejected, and grows it back by steps
+    /// once none was for RECOVERY_DELAY
+    fn record(&mut self, status: StatusCode, now: Instant) {
+        self.forget(now);
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.rejected.push_back(now);
+            self.set_rate((self.per_second / 2.0).max(MIN_REQUESTS_PER_SECOND));
+            return;
+        }
+
+        let recovering = self
+            .rejected
+            .back()
+            .is_none_or(|last| now.saturating_duration_since(*last) >= RECOVERY_DELAY);
+        if recovering && self.per_second < self.configured {
+            self.set_rate((self.per_second + self.configured * RECOVERY_STEP).min(self.configured));
+        }
+    }
+
+    ///Bursts are limited to the current rate, so that a throttled bucket does not
+    /// send its saved up tokens at once
+    fn set_rate(&mut self, per_second: f64) {
+        self.per_second = per_second;
+        self.capacity = per_second.clamp(1.0, self.configured);
+        self.tokens = self.tokens.min(self.capacity);
+    }
+
+    ///Drops the rejections older than the window
+    fn forget(&mut self, now: Instant) {
+        while let Some(oldest) = self.rejected.front() {
+            match now.saturating_duration_since(*oldest) > WINDOW {
+                true => self.rejected.pop_front(),
+                false => break,
+            };
+        }
+    }
+
+    fn state(&mut self, now: Instant) -> ThrottleState {
+        self.forget(now);
+        ThrottleState {
+            requests_per_second: self.per_second,
+            configured_requests_per_second: self.configured,
+            rate_limited_last_minute: self.rejected.len(),
+        }
+    }
+}
+
+impl RateLimiter {
+    pub(crate) fn new(requests_per_second: u32) -> RateLimiter {
+        RateLimiter {
+            bucket: Mutex::new(Bucket::new(requests_per_second, Instant::now())),
+        }
+    }
+
+    ///Waits until a request can be sent without exceeding the rate limit,
+    /// after the interactive requests waiting for it when in the background
+    pub(crate) async fn acquire(&self, priority: Priority) {
+        let mut waiting = None;
+        loop {
+            let wait = match self
+                .bucket
+                .lock()
+                .unwrap()
+                .take_for(priority, Instant::now())
+            {
+                Ok(()) => return,
+                Err(wait) => wait,
+            };
+            if priority == Priority::Interactive && waiting.is_none() {
+                waiting = Some(Waiting::new(self));
+            }
+            log::debug!("Rate limit reached, waiting {:?}", wait);
+            async_std::task::sleep(wait).await;
+        }
+    }
+
+    ///Adapts the rate to the status IGDB answered a request with
+    pub(crate) fn record(&self, status: StatusCode) {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.record(status, Instant::now());
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            log::warn!(
+                "IGDB rejected a request, throttling to {:.2} requests per second",
+                bucket.per_second
+            );
+        }
+    }
+
+    ///Whether a request could be sent right away, without waiting for a token
+    pub(crate) fn has_token(&self) -> bool {
+        let mut bucket = self.bucket.lock().unwrap();
+        bucket.refill(Instant::now());
+        bucket.tokens >= 1.0 && bucket.interactive_waiting == 0
+    }
+
+    pub(crate) fn state(&self) -> ThrottleState {
+        self.bucket.lock().unwrap().state(Instant::now())
+    }
+
+    ///A new rate limiter at the rate this one was configured with
+    fn like(&self) -> RateLimiter {
+        let configured = self.bucket.lock().unwrap().configured;
+        RateLimiter::new(configured as u32)
+    }
+}
+
+///Rate limiters of the tenants of a client, by client_id, see IGDBClient::as_tenant.
+/// IGDB limits the rate of each Twitch application on its own.
+#[derive(Default)]
+pub(crate) struct TenantLimiters {
+    limiters: Mutex<HashMap<String, Arc<RateLimiter>>>,
+}
+
+impl TenantLimiters {
+    ///The rate limiter of the tenant, created at the rate of the given one on first use
+    pub(crate) fn get(&self, client_id: &str, like: &RateLimiter) -> Arc<RateLimiter> {
+        self.limiters
+            .lock()
+            .unwrap()
+            .entry(client_id.to_owned())
+            .or_insert_with(|| Arc::new(like.like()))
+            .clone()
+    }
+}
+
+///Counts an interactive request among the waiting ones until it is dropped,
+/// whether it got its token or was cancelled
+struct Waiting<'a>(&'a RateLimiter);
+
+impl<'a> Waiting<'a> {
+    fn new(limiter: &'a RateLimiter) -> Waiting<'a> {
+        limiter.bucket.lock().unwrap().interactive_waiting += 1;
+        Waiting(limiter)
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.bucket.lock().unwrap().interactive_waiting -= 1;
+    }
+}
+
+#[test]
+fn bucket_allows_bursts_up_to_its_capacity_then_refills() {
+    let now = Instant::now();
+    let mut bucket = Bucket::new(4, now);
+
+    for _ in 0..4 {
+        assert_eq!(Ok(()), bucket.take(now));
+    }
+    assert_eq!(Err(Duration::from_millis(250)), bucket.take(now));
+
+    let later = now + Duration::from_millis(250);
+    assert_eq!(Ok(()), bucket.take(later));
+    assert!(bucket.take(later).is_err());
+
+    let much_later = later + Duration::from_secs(10);
+    for _ in 0..4 {
+        assert_eq!(Ok(()), bucket.take(much_later));
+    }
+    assert!(bucket.take(much_later).is_err());
+}
+
+#[test]
+fn rejections_throttle_the_rate_until_recovered() {
+    let now = Instant::now();
+    let mut bucket = Bucket::new(4, now);
+
+    bucket.record(StatusCode::TOO_MANY_REQUESTS, now);
+    bucket.record(StatusCode::TOO_MANY_REQUESTS, now);
+    let state = bucket.state(now);
+    assert_eq!(1.0, state.requests_per_second);
+    assert_eq!(2, state.rate_limited_last_minute);
+    assert!(state.is_throttled());
+
+    // Accepted requests only speed up again after the recovery delay
+    bucket.record(StatusCode::OK, now + Duration::from_secs(1));
+    assert_eq!(1.0, bucket.state(now).requests_per_second);
+
+    let recovered = now + RECOVERY_DELAY;
+    for _ in 0..10 {
+        bucket.record(StatusCode::OK, recovered);
+    }
+    assert!(!bucket.state(recovered).is_throttled());
+
+    let state = bucket.state(now + WINDOW + Duration::from_secs(1));
+    assert_eq!(0, state.rate_limited_last_minute);
+}
+
+#[test]
+fn background_requests_wait_for_the_interactive_ones() {
+    let limiter = RateLimiter::new(4);
+    let now = Instant::now();
+    let mut bucket = limiter.bucket.lock().unwrap();
+    assert_eq!(Ok(()), bucket.take_for(Priority::Background, now));
+
+    bucket.interactive_waiting = 1;
+    assert_eq!(
+        Err(Duration::from_millis(250)),
+        bucket.take_for(Priority::Background, now)
+    );
+    assert_eq!(Ok(()), bucket.take_for(Priority::Interactive, now));
+    drop(bucket);
+
+    let waiting = Waiting::new(&limiter);
+    assert_eq!(2, limiter.bucket.lock().unwrap().interactive_waiting);
+    drop(waiting);
+    assert_eq!(1, limiter.bucket.lock().unwrap().interactive_waiting);
+}
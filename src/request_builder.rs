@@ -1,30 +1,67 @@
-use crate::request_filters::Filter;
-use std::future::Future;
-use std::str::FromStr;
-use std::string::ToString;
-use url::Url;
+use crate::auth::Credentials;
+use crate::compression::{encode_body, GZIP_ENCODING, HEADER_CONTENT_ENCODING};
+#[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+use crate::compression::{ACCEPTED_ENCODINGS, HEADER_ACCEPT_ENCODING};
+use crate::endpoint_client::{ClientConfig, MAX_LIMIT};
+use crate::expr::Expr;
+use crate::model_fields::{model_fields, required_fields};
+use crate::query_spec::QuerySpec;
+use crate::request_filters::{FilterChain, NameFilter};
+use crate::Error;
+use serde::de::DeserializeOwned;
+use std::fmt;
 
 const HEADER_CLIENT_ID: &str = "Client-ID";
 const HEADER_AUTH: &str = "Authorization";
 
-#[derive(Clone)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(into = "QuerySpec", try_from = "QuerySpec")]
 /// Request Builder struct
+/// Fields, filters and sort clauses are always rendered in the order they were added,
+/// so two builders configured the same way produce the exact same query body.
+/// Repeated fields, filters and sort clauses are only rendered once, so builders can be
+/// composed from helpers adding the same clauses.
+/// Builders are serialized like their QuerySpec, e.g. to persist saved searches as json or toml.
+/// # Examples
+/// ```
+/// use igdb::request_builder::RequestBuilder;
+///
+/// let request: RequestBuilder = serde_json::from_str(
+///     r#"{"fields": ["name"], "filters": [{"field": "rating", "symbol": ">", "value": "80"}]}"#,
+/// ).unwrap();
+/// assert_eq!("fields name; where rating > 80; limit 10;", request.to_query());
+///
+/// let json = serde_json::to_string(&request).unwrap();
+/// assert!(json.starts_with(r#"{"fields":["name"],"exclude":[],"filters":"#));
+/// ```
 pub struct RequestBuilder {
     pub(crate) fields: Vec<String>,
-    pub(crate) filters: Vec<Filter>,
-    pub(crate) sort: (String, String),
+    pub(crate) exclude: Vec<String>,
+    pub(crate) filters: FilterChain,
+    pub(crate) sort: Vec<(String, String)>,
     pub(crate) limit: usize,
+    pub(crate) offset: usize,
+    ///Whether the limit and offset were set, the ones of merged builders replacing these when so
+    pub(crate) limit_set: bool,
+    pub(crate) offset_set: bool,
     pub(crate) search: String,
+    ///Filters on names resolved by the client, see with_genre_names
+    pub(crate) names: Vec<NameFilter>,
 }
 
 impl Default for RequestBuilder {
     fn default() -> Self {
         RequestBuilder {
             fields: Vec::new(),
-            filters: vec![],
-            sort: (String::new(), String::new()),
+            exclude: Vec::new(),
+            filters: FilterChain::default(),
+            sort: Vec::new(),
             limit: 10,
+            offset: 0,
+            limit_set: false,
+            offset_set: false,
             search: String::new(),
+            names: Vec::new(),
         }
     }
 }
@@ -34,16 +71,34 @@ pub enum OrderBy {
     Ascending,
 }
 
-impl ToString for OrderBy {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
             OrderBy::Ascending => "asc",
             OrderBy::Descending => "desc",
-        }
-        .into()
+        })
     }
 }
 
+///Http method used to send the queries. IGDB expects POST with the query in the body,
+/// GET is only useful for proxies that still accept it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HttpMethod {
+    #[default]
+    Post,
+    Get,
+}
+
+///Format of the responses. Protobuf responses are smaller and faster to read,
+/// endpoints without a protobuf response (multiquery, search) are still read as json.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    #[cfg(feature = "protobuf")]
+    Protobuf,
+}
+
 pub enum Equality {
     Less,
     LessOrEqual,
@@ -53,18 +108,248 @@ pub enum Equality {
     NotEqual,
 }
 
-impl ToString for Equality {
-    fn to_string(&self) -> String {
-        match self {
+impl fmt::Display for Equality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
             Equality::Equal => "=",
             Equality::NotEqual => "!=",
             Equality::Greater => ">",
             Equality::Less => "<",
             Equality::GreaterOrEqual => ">=",
             Equality::LessOrEqual => "<=",
+        })
+    }
+}
+
+///How a string filter value is matched against the field.
+/// Used with RequestBuilder::add_where_like, matching is case-insensitive (`~`).
+pub enum Match {
+    ///The field equals the value: `name ~ "zelda"`
+    Exact,
+    ///The field starts with the value: `name ~ "zelda"*`
+    StartsWith,
+    ///The field ends with the value: `name ~ *"zelda"`
+    EndsWith,
+    ///The field contains the value: `name ~ *"zelda"*`
+    Contains,
+}
+
+impl Match {
+    pub(crate) fn pattern(&self, value: &str) -> String {
+        let value = escape(value);
+        match self {
+            Match::Exact => format!("\"{}\"", value),
+            Match::StartsWith => format!("\"{}\"*", value),
+            Match::EndsWith => format!("*\"{}\"", value),
+            Match::Contains => format!("*\"{}\"*", value),
+        }
+    }
+}
+
+///Value of a `where` condition, rendered with the Apicalypse syntax of its type.
+/// Strings are quoted and escaped, use Value::Raw to send an expression as is.
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use igdb::request_builder::{Equality, Value};
+///
+/// // where name = "Conan \"The Barbarian\"" & rating > 80.5 & id = (5,8) & category = (0,8);
+/// let mut request = IGDBClient::create_request();
+/// request
+/// .add_where("name", Equality::Equal, "Conan \"The Barbarian\"")
+/// .add_where("rating", Equality::Greater, 80.5)
+/// .add_where("id", Equality::Equal, vec![5, 8])
+/// .add_where("category", Equality::Equal, Value::Raw("(0,8)".to_owned()));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    ///A string literal, quoted and escaped
+    Str(String),
+    ///A list of ids: `(1,2,3)`
+    Ids(Vec<usize>),
+    Null,
+    ///Sent without any quoting or escaping, only for trusted expressions
+    Raw(String),
+}
+
+impl Value {
+    ///Creates a quoted and escaped string value
+    pub fn string<S: Into<String>>(value: S) -> Value {
+        Value::Str(value.into())
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Str(value) => write!(f, "\"{}\"", escape(value)),
+            Value::Ids(ids) => {
+                let ids: Vec<String> = ids.iter().map(ToString::to_string).collect();
+                write!(f, "({})", ids.join(","))
+            }
+            Value::Null => f.write_str("null"),
+            Value::Raw(value) => f.write_str(value),
+        }
+    }
+}
+
+macro_rules! value_from {
+    ($variant: ident, $as: ty, $($t: ty),+) => {
+        $(impl From<$t> for Value {
+            fn from(value: $t) -> Self {
+                Value::$variant(value as $as)
+            }
+        })+
+    };
+}
+
+value_from!(Int, i64, i8, i16, i32, i64, u8, u16, u32, u64, usize);
+value_from!(Float, f64, f32, f64);
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Str(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Str(value)
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Value::Str(value.clone())
+    }
+}
+
+impl From<Vec<usize>> for Value {
+    fn from(ids: Vec<usize>) -> Self {
+        Value::Ids(ids)
+    }
+}
+
+impl From<&[usize]> for Value {
+    fn from(ids: &[usize]) -> Self {
+        Value::Ids(ids.to_vec())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Value {
+    fn from(time: chrono::DateTime<Tz>) -> Self {
+        Value::Int(time.timestamp())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for Value {
+    fn from(time: chrono::NaiveDateTime) -> Self {
+        Value::Int(time.and_utc().timestamp())
+    }
+}
+
+///The start of the day in UTC
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Value {
+    fn from(date: chrono::NaiveDate) -> Self {
+        Value::from(date.and_time(chrono::NaiveTime::MIN))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<::time::OffsetDateTime> for Value {
+    fn from(time: ::time::OffsetDateTime) -> Self {
+        Value::Int(time.unix_timestamp())
+    }
+}
+
+///Literal of an apicalypse! query, strings being quoted as in RequestBuilder::add_where
+#[doc(hidden)]
+pub trait QueryLiteral {
+    fn into_value(self) -> Value;
+}
+
+impl QueryLiteral for &str {
+    fn into_value(self) -> Value {
+        Value::string(self)
+    }
+}
+
+macro_rules! query_literal {
+    ($($t: ty),+) => {
+        $(impl QueryLiteral for $t {
+            fn into_value(self) -> Value {
+                Value::from(self)
+            }
+        })+
+    };
+}
+
+query_literal!(bool, i32, i64, u64, f64);
+
+///Finds a field required to equal a value and to differ from it, when the conditions are
+/// all joined with `&`
+fn contradicting_filters(expr: &Expr) -> Option<(&Expr, &Expr)> {
+    let conditions = match expr {
+        Expr::And(exprs) => &exprs[..],
+        Expr::Or(_) => return None,
+        condition => std::slice::from_ref(condition),
+    };
+    let parts: Vec<(&Expr, (String, String, String))> = conditions
+        .iter()
+        .filter_map(|condition| condition.parts().map(|parts| (condition, parts)))
+        .collect();
+    parts
+        .iter()
+        .filter(|(_, (_, op, _))| op == "=")
+        .find_map(|(equal, (field, _, value))| {
+            parts
+                .iter()
+                .find(|(_, other)| (&other.0, &other.1[..], &other.2) == (field, "!=", value))
+                .map(|(not_equal, _)| (*equal, *not_equal))
+        })
+}
+
+///The given fields without the repeated ones, in the order they were first added
+pub(crate) fn distinct(fields: &[String]) -> Vec<&str> {
+    let mut distinct: Vec<&str> = Vec::with_capacity(fields.len());
+    for field in fields {
+        if !distinct.contains(&field.as_str()) {
+            distinct.push(field);
+        }
+    }
+    distinct
+}
+
+///Escapes the backslashes and double quotes of a string literal and drops its control
+/// characters, so that a user input cannot end the literal and add clauses to the query
+pub(crate) fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | '"' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c if c.is_control() => {}
+            c => escaped.push(c),
         }
-        .into()
     }
+    escaped
 }
 
 impl RequestBuilder {
@@ -85,90 +370,466 @@ impl RequestBuilder {
         RequestBuilder::default()
     }
 
-    pub(crate) fn build(
-        &self,
-        client_id: &str,
-        token: &str,
-        url: &str,
-    ) -> impl Future<Output = Result<reqwest::Response, reqwest::Error>> {
-        let body = self.build_body();
+    /// Clears every clause, as if the builder was just created
+    pub fn reset(&mut self) -> &mut Self {
+        *self = RequestBuilder::default();
+        self
+    }
+
+    /// Adds the fields, excluded fields, filters and sort clauses of other to this builder.
+    /// Filters are joined with `&`, each side being parenthesized when it uses `|`.
+    /// The search of other replaces this builder one when not empty, and its limit and offset
+    /// replace this builder ones when they were set, even to their default values.
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let mut base = IGDBClient::create_request();
+    /// base.add_fields(vec!["name", "rating"])
+    ///     .add_where_null("version_parent");
+    ///
+    /// let mut popular = IGDBClient::create_request();
+    /// popular.add_where("rating", Equality::Greater, 80).limit(50);
+    ///
+    /// let mut request = base.clone();
+    /// request.merge(&popular);
+    /// assert_eq!(
+    ///     "fields name,rating; where version_parent = null & rating > 80; limit 50;",
+    ///     request.to_query()
+    /// );
+    /// ```
+    pub fn merge(&mut self, other: &RequestBuilder) -> &mut Self {
+        for field in &other.fields {
+            if !self.fields.contains(field) {
+                self.fields.push(field.clone());
+            }
+        }
+        for field in &other.exclude {
+            if !self.exclude.contains(field) {
+                self.exclude.push(field.clone());
+            }
+        }
+
+        match (&other.filters.expr, self.filters.is_empty()) {
+            (None, _) => {}
+            (Some(_), true) => self.filters = other.filters.clone(),
+            (Some(Expr::And(exprs)), false) => {
+                for expr in exprs {
+                    self.filters.constrain(expr.clone());
+                }
+            }
+            (Some(expr), false) => self.filters.constrain(expr.clone()),
+        }
+        self.sort.extend(other.sort.iter().cloned());
+        self.names.extend(other.names.iter().cloned());
+
+        if !other.search.is_empty() {
+            self.search = other.search.clone();
+        }
+        if other.limit_set {
+            self.limit = other.limit;
+            self.limit_set = true;
+        }
+        if other.offset_set {
+            self.offset = other.offset;
+            self.offset_set = true;
+        }
+        self
+    }
 
-        log::debug!("url: {}, body: {}", url, body);
-        let client = reqwest::Client::new();
-        let req = client
-            .post(Url::from_str(url).unwrap())
-            .body(body)
-            .header(HEADER_CLIENT_ID, client_id)
-            .header(HEADER_AUTH, format!("Bearer {}", token))
-            .header("content-type", "application/json")
-            .send();
-        req
+    /// Returns the Apicalypse query sent as body of the request
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .add_where("id", Equality::Equal, 1942);
+    ///
+    /// assert_eq!("fields name; where id = 1942; limit 10;", request.to_query());
+    /// assert_eq!(request.to_query(), request.to_string());
+    /// ```
+    pub fn to_query(&self) -> String {
+        self.build_body()
     }
 
     pub(crate) fn build_body(&self) -> String {
-        let fields = self
+        let fields = match self.fields.is_empty() {
+            true => String::new(),
+            false => format!("{};", distinct(&self.fields).join(",")),
+        };
+
+        let filter_clause = match &self.filters.expr {
+            Some(expr) => format!("where {};", expr),
+            None => String::new(),
+        };
+
+        self.format_body_parts(fields, filter_clause)
+    }
+
+    ///Checks the query for clauses IGDB would reject with an opaque 400 Bad Request,
+    /// which every query sent is checked for:
+    /// empty field names, a limit of 0 or above 500 (IGDBClient::query_all pages through
+    /// bigger ones), a sorted search, unknown sort orders and filters contradicting each other
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::OrderBy;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request.search("zelda").sort_by("rating", OrderBy::Descending);
+    ///
+    /// assert!(request.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        let invalid = |message: String| Err(Error::InvalidQuery(message));
+        if let Some(field) = self
             .fields
             .iter()
-            .enumerate()
-            .fold(String::new(), |mut acc, (i, v)| {
-                acc.push_str(v);
-                let separator = match i {
-                    _ if i == (self.fields.len() - 1) => ";",
-                    _ => ",",
-                };
-                acc.push_str(separator);
-                acc
-            });
-
-        let filter_clause =
-            self.filters
-                .iter()
-                .enumerate()
-                .fold(String::new(), |mut acc, (i, filter)| {
-                    if i == 0 {
-                        acc.push_str("where ")
-                    }
-                    if i != 0 {
-                        acc.push_str(" & ")
-                    };
+            .chain(&self.exclude)
+            .find(|field| field.trim().is_empty() || field.contains([',', ';']))
+        {
+            return invalid(format!("invalid field name {:?}", field));
+        }
+        if self.limit == 0 || self.limit > MAX_LIMIT {
+            return invalid(format!(
+                "limit {} is not between 1 and {}, see IGDBClient::query_all for bigger ones",
+                self.limit, MAX_LIMIT
+            ));
+        }
+        if !self.search.is_empty() && !self.sort.is_empty() {
+            return invalid("search cannot be sorted, use search_field instead".to_owned());
+        }
+        if let Some((field, order)) = self
+            .sort
+            .iter()
+            .find(|(field, order)| field.trim().is_empty() || !matches!(&order[..], "asc" | "desc"))
+        {
+            return invalid(format!("invalid sort {:?} {:?}", field, order));
+        }
+        if let Some((equal, not_equal)) = self.filters.expr.as_ref().and_then(contradicting_filters)
+        {
+            return invalid(format!(
+                "filters {} and {} never match together",
+                equal, not_equal
+            ));
+        }
+        if !self.names.is_empty() {
+            return invalid(
+                "genre and theme names are only resolved by the queries of IGDBClient".to_owned(),
+            );
+        }
+        Ok(())
+    }
 
-                    acc.push_str(&format!(
-                        "{} {} {}",
-                        filter.key, filter.symbol, filter.value
-                    ));
+    ///Returns this request filtering on the ids the names of its name filters resolved to,
+    /// given in the same order
+    pub(crate) fn with_resolved_names(&self, ids: Vec<Vec<u64>>) -> RequestBuilder {
+        let mut request = self.clone();
+        for (filter, ids) in self.names.iter().zip(ids) {
+            request.filters.constrain(Expr::is_in(filter.field, ids));
+        }
+        request.names.clear();
+        request
+    }
 
-                    if i == (self.filters.len() - 1) {
-                        acc.push(';');
-                    }
-                    acc
-                });
+    ///Builds the body of a query on registries read into T,
+    /// requesting every field of T when no field was added
+    pub(crate) fn build_body_for<T: DeserializeOwned>(&self) -> Result<String, Error> {
+        self.validate()?;
+        if !self.fields.is_empty() {
+            self.check_required_fields::<T>()?;
+            return Ok(self.build_body());
+        }
+        match model_fields::<T>() {
+            Some(fields) if !fields.is_empty() => {
+                let mut request = self.clone();
+                request.add_fields(fields.iter().copied());
+                Ok(request.build_body())
+            }
+            _ => Err(Error::InvalidQuery(
+                "no field requested, see RequestBuilder::add_fields".to_owned(),
+            )),
+        }
+    }
 
-        self.format_body_parts(fields, filter_clause)
+    ///Fails when a field the registries cannot be read into T without is not requested,
+    /// rather than failing to read the response
+    fn check_required_fields<T: DeserializeOwned>(&self) -> Result<(), Error> {
+        let (model, required) = required_fields::<T>();
+        let requested = |field: &str| {
+            self.fields.iter().any(|requested| {
+                let requested = requested.split('.').next().unwrap_or_default().trim();
+                requested == field || requested == "*"
+            }) && !self.exclude.iter().any(|excluded| excluded.trim() == field)
+        };
+        match required.into_iter().find(|field| !requested(field)) {
+            Some(field) => Err(Error::InvalidQuery(format!(
+                "field `{}` required by model {} was not selected",
+                field, model
+            ))),
+            None => Ok(()),
+        }
     }
 
-    fn format_body_parts(&self, fields: String, filters: String) -> String {
-        let mut order = String::new();
+    ///Builds the body sent to the count endpoints,
+    /// only the search and filters are relevant to count registries
+    pub(crate) fn build_count_body(&self) -> String {
+        let mut parts = Vec::new();
+
+        if !str::is_empty(&self.search) {
+            parts.push(format!("search \"{}\";", escape(&self.search)));
+        }
+        if let Some(expr) = &self.filters.expr {
+            parts.push(format!("where {};", expr));
+        }
+        parts.join(" ")
+    }
 
+    fn format_body_parts(&self, fields: String, filters: String) -> String {
         let mut body = format!("fields {}", fields);
 
+        if !self.exclude.is_empty() {
+            body = format!("{} exclude {};", body, distinct(&self.exclude).join(","));
+        }
+
         if !str::is_empty(&self.search) {
-            body = format!("{} search \"{}\";", body, self.search);
+            body = format!("{} search \"{}\";", body, escape(&self.search));
         }
 
         if !self.filters.is_empty() {
             body = format!("{} {}", body, filters);
         }
 
-        if !str::is_empty(&self.sort.0) {
-            order.push_str(&format!("sort {} {}", self.sort.0, self.sort.1));
-            body = format!("{} {};", body, order);
+        if !self.sort.is_empty() {
+            let mut sorted: Vec<&str> = Vec::new();
+            let order = self
+                .sort
+                .iter()
+                .filter(|(field, _)| match sorted.contains(&field.as_str()) {
+                    true => false,
+                    false => {
+                        sorted.push(field);
+                        true
+                    }
+                })
+                .map(|(field, order)| format!("{} {}", field, order))
+                .collect::<Vec<String>>()
+                .join(", ");
+            body = format!("{} sort {};", body, order);
         }
 
         body = format!("{} limit {};", body, self.limit);
+
+        if self.offset != 0 {
+            body = format!("{} offset {};", body, self.offset);
+        }
         body
     }
 }
 
+///Consuming variants of the builder methods, to build a query in a single expression,
+/// e.g. to initialize a static. The `&mut self` methods remain the way to build a query
+/// incrementally.
+/// # Examples
+/// ```
+/// use igdb::request_builder::{Equality, OrderBy, RequestBuilder};
+/// use std::sync::LazyLock;
+///
+/// static TOP_RATED: LazyLock<RequestBuilder> = LazyLock::new(|| {
+///     RequestBuilder::new()
+///         .fields(["name", "rating"])
+///         .wher("rating_count", Equality::Greater, 100)
+///         .sorted("rating", OrderBy::Descending)
+///         .with_limit(20)
+/// });
+///
+/// assert_eq!(
+///     "fields name,rating; where rating_count > 100; sort rating desc; limit 20;",
+///     TOP_RATED.to_query()
+/// );
+/// ```
+impl RequestBuilder {
+    /// Consuming variant of add_field
+    pub fn field<S: Into<String>>(mut self, field: S) -> Self {
+        self.add_field(field);
+        self
+    }
+
+    /// Consuming variant of add_fields
+    pub fn fields<I, T>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.add_fields(fields);
+        self
+    }
+
+    /// Consuming variant of exclude_field
+    pub fn excluding<S: Into<String>>(mut self, field: S) -> Self {
+        self.exclude_field(field);
+        self
+    }
+
+    /// Consuming variant of add_where, named after the `where` keyword it cannot use
+    pub fn wher<L: Into<String>, R: Into<Value>>(
+        mut self,
+        field: L,
+        equality: Equality,
+        value: R,
+    ) -> Self {
+        self.add_where(field, equality, value);
+        self
+    }
+
+    /// Consuming variant of add_where_or
+    pub fn or_wher<L: Into<String>, R: Into<Value>>(
+        mut self,
+        field: L,
+        equality: Equality,
+        value: R,
+    ) -> Self {
+        self.add_where_or(field, equality, value);
+        self
+    }
+
+    /// Consuming variant of sort_by
+    pub fn sorted<S: Into<String>>(mut self, field: S, order: OrderBy) -> Self {
+        self.sort_by(field, order);
+        self
+    }
+
+    /// Consuming variant of search
+    pub fn with_search<S: Into<String>>(mut self, search: S) -> Self {
+        self.search(search);
+        self
+    }
+
+    /// Consuming variant of limit
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit(limit);
+        self
+    }
+
+    /// Consuming variant of offset
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset(offset);
+        self
+    }
+
+    /// Applies any of the `&mut self` methods within a consuming chain
+    /// # Examples
+    /// ```
+    /// use igdb::model::ids::PlatformId;
+    /// use igdb::request_builder::RequestBuilder;
+    ///
+    /// let request = RequestBuilder::new()
+    ///     .field("name")
+    ///     .with(|request| {
+    ///         request.on_platforms(&[PlatformId::Linux]).min_rating(80.0);
+    ///     });
+    /// assert_eq!(
+    ///     "fields name; where platforms = (3) & total_rating >= 80; limit 10;",
+    ///     request.to_query()
+    /// );
+    /// ```
+    pub fn with<F: FnOnce(&mut Self)>(mut self, build: F) -> Self {
+        build(&mut self);
+        self
+    }
+
+    /// Checks the query like validate, then returns it
+    /// # Examples
+    /// ```
+    /// use igdb::request_builder::{Equality, RequestBuilder};
+    ///
+    /// let query = RequestBuilder::new()
+    ///     .field("name")
+    ///     .wher("id", Equality::Equal, 1942)
+    ///     .build_query()
+    ///     .unwrap();
+    /// assert_eq!("fields name; where id = 1942; limit 10;", query);
+    /// ```
+    pub fn build_query(self) -> Result<String, Error> {
+        self.validate()?;
+        Ok(self.to_query())
+    }
+}
+
+impl fmt::Display for RequestBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.build_body())
+    }
+}
+
+///Builds the http request sending the given body to the given url
+pub(crate) fn build_request(
+    body: String,
+    credentials: &Credentials,
+    url: &str,
+    config: &ClientConfig,
+) -> Result<http::Request<Vec<u8>>, Error> {
+    log::debug!("url: {}, body: {}", url, body);
+    let (body, compressed) = encode_body(body, config.compress_above);
+
+    let method = match config.method {
+        HttpMethod::Post => http::Method::POST,
+        HttpMethod::Get => http::Method::GET,
+    };
+
+    let mut req = http::Request::builder()
+        .method(method)
+        .uri(url)
+        .header(HEADER_CLIENT_ID, &credentials.client_id)
+        .header(HEADER_AUTH, format!("Bearer {}", credentials.access_token))
+        .header("content-type", "application/json");
+
+    if compressed {
+        req = req.header(HEADER_CONTENT_ENCODING, GZIP_ENCODING);
+    }
+    #[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+    {
+        req = req.header(HEADER_ACCEPT_ENCODING, ACCEPTED_ENCODINGS);
+    }
+    for (name, value) in &config.headers {
+        req = req.header(name, value);
+    }
+    Ok(req.body(body)?)
+}
+
+///Builds an http request outside of the query language, e.g. to manage webhooks,
+/// sending the given form values if any
+pub(crate) fn build_form_request(
+    method: http::Method,
+    url: &str,
+    form: Option<String>,
+    credentials: &Credentials,
+    config: &ClientConfig,
+) -> Result<http::Request<Vec<u8>>, Error> {
+    log::debug!("{} {}", method, url);
+    let mut req = http::Request::builder()
+        .method(method)
+        .uri(url)
+        .header(HEADER_CLIENT_ID, &credentials.client_id)
+        .header(HEADER_AUTH, format!("Bearer {}", credentials.access_token));
+
+    if form.is_some() {
+        req = req.header("content-type", "application/x-www-form-urlencoded");
+    }
+    #[cfg(all(feature = "gzip", not(target_arch = "wasm32")))]
+    {
+        req = req.header(HEADER_ACCEPT_ENCODING, ACCEPTED_ENCODINGS);
+    }
+    for (name, value) in &config.headers {
+        req = req.header(name, value);
+    }
+    Ok(req.body(form.unwrap_or_default().into_bytes())?)
+}
+
 #[test]
 fn request_builder_with_all_fields() {
     let mut builder = RequestBuilder::new();
@@ -188,12 +849,12 @@ fn request_builder_with_fields_and_where_clause_body_build() {
         .add_field("name")
         .add_field("involved_companies")
         .add_where("name", Equality::Equal, "Conan")
-        .add_where("id", Equality::Less, 39047.to_string());
+        .add_where("id", Equality::Less, 39047);
 
     let body = builder.build_body();
 
     assert_eq!(
-        "fields name,involved_companies; where name = Conan & id < 39047; limit 10;",
+        "fields name,involved_companies; where name = \"Conan\" & id < 39047; limit 10;",
         &body
     );
 }
@@ -205,7 +866,7 @@ fn request_builder_with_fields_where_clause_and_sort_asc_body_build() {
     builder
         .add_field("name")
         .add_field("involved_companies")
-        .add_where("id", Equality::Equal, 39047.to_string())
+        .add_where("id", Equality::Equal, 39047)
         .add_where("name", Equality::Equal, "Conan")
         .sort_by("name", OrderBy::Ascending)
         .limit(2);
@@ -213,7 +874,796 @@ fn request_builder_with_fields_where_clause_and_sort_asc_body_build() {
     let body = builder.build_body();
 
     assert_eq!(
-        "fields name,involved_companies; where id = 39047 & name = Conan; sort name asc; limit 2;",
+        "fields name,involved_companies; where id = 39047 & name = \"Conan\"; sort name asc; limit 2;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_with_search_fields_where_and_limit_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_fields(vec!["name", "rating"])
+        .search("Zelda")
+        .add_where("rating", Equality::GreaterOrEqual, 80)
+        .add_where("platforms", Equality::NotEqual, 6)
+        .limit(5);
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields name,rating; search \"Zelda\"; where rating >= 80 & platforms != 6; limit 5;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_with_where_in_contains_and_sort_desc_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where_in("id", vec![5, 8])
+        .contains("name", "Ast")
+        .add_where("category", Equality::Equal, 0)
+        .sort_by("first_release_date", OrderBy::Descending)
+        .limit(3);
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields name; where id = (5,8) & name ~ *\"Ast\"* & category = 0; sort first_release_date desc; limit 3;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_all_fields_replaces_previous_fields_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_fields(vec!["name", "summary"])
+        .all_fields()
+        .add_where("id", Equality::Greater, 340)
+        .sort_by("name", OrderBy::Descending)
+        .sort_by("id", OrderBy::Ascending);
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields *; where id > 340; sort name desc, id asc; limit 10;",
         &body
     );
 }
+
+#[test]
+fn request_builder_with_typed_enum_filters_body_build() {
+    use crate::model::enums::{GameCategory, Region, Status};
+
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where_category(Equality::NotEqual, GameCategory::DlcAddon)
+        .add_where_status(Equality::Equal, Status::EarlyAccess)
+        .add_where_region(Equality::Equal, Region::Japan);
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields name; where category != 1 & status = 4 & region = 5; limit 10;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_with_game_filters_body_build() {
+    use crate::model::ids::PlatformId;
+
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .on_platforms(&[PlatformId::Linux, PlatformId::Windows])
+        .min_rating(80.5)
+        .min_rating_count(50);
+
+    assert_eq!(
+        "fields name; where platforms = (3,6) & total_rating >= 80.5 & total_rating_count >= 50; limit 10;",
+        &builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_build_sends_post_with_query_body() {
+    let mut builder = RequestBuilder::new();
+    builder.add_field("name").limit(1);
+
+    let credentials = Credentials::new("client_id", "token");
+    let request = build_request(
+        builder.build_body(),
+        &credentials,
+        "https://api.igdb.com/v4/games",
+        &ClientConfig::default(),
+    )
+    .unwrap();
+
+    assert_eq!(http::Method::POST, request.method());
+    assert_eq!("client_id", request.headers()[HEADER_CLIENT_ID]);
+    assert_eq!("Bearer token", request.headers()[HEADER_AUTH]);
+    assert_eq!(b"fields name; limit 1;", &request.body()[..]);
+}
+
+#[test]
+fn request_builder_build_uses_configured_method() {
+    let config = ClientConfig {
+        method: HttpMethod::Get,
+        ..ClientConfig::default()
+    };
+
+    let request = build_request(
+        RequestBuilder::new().all_fields().build_body(),
+        &Credentials::new("client_id", "token"),
+        "https://api.igdb.com/v4/games",
+        &config,
+    )
+    .unwrap();
+
+    assert_eq!(http::Method::GET, request.method());
+    assert_eq!(b"fields *; limit 10;", &request.body()[..]);
+}
+
+#[test]
+fn request_builder_with_or_and_grouped_filters_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .group(|g| {
+            g.add_where("platforms", Equality::Equal, 6).add_where_or(
+                "platforms",
+                Equality::Equal,
+                14,
+            )
+        })
+        .add_where("rating", Equality::Greater, 80)
+        .add_where_or("rating_count", Equality::Greater, 1000)
+        .group_or(|g| {
+            g.add_where("category", Equality::Equal, 0).group(|g| {
+                g.add_where("status", Equality::Equal, 2)
+                    .add_where_or("status", Equality::Equal, 3)
+            })
+        })
+        .group(|g| g)
+        .sort_by("rating", OrderBy::Descending);
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields name; where ((platforms = 6 | platforms = 14) & rating > 80) | rating_count > 1000 | (category = 0 & (status = 2 | status = 3)); sort rating desc; limit 10;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_with_null_checks_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where_null("cover")
+        .add_where_not_null("first_release_date")
+        .add_where("rating", Equality::LessOrEqual, 50);
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields name; where cover = null & first_release_date != null & rating <= 50; limit 10;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_with_like_filters_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where_like("name", Match::StartsWith, "zelda")
+        .add_where_like("slug", Match::EndsWith, "remastered")
+        .add_where_like("summary", Match::Contains, "link")
+        .add_where_like("version_title", Match::Exact, "GOTY");
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields name; where name ~ \"zelda\"* & slug ~ *\"remastered\" & summary ~ *\"link\"* & version_title ~ \"GOTY\"; limit 10;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_with_offset_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .sort_by("id", OrderBy::Ascending)
+        .limit(50)
+        .offset(100);
+
+    let body = builder.build_body();
+
+    assert_eq!("fields name; sort id asc; limit 50; offset 100;", &body);
+}
+
+#[test]
+fn request_builder_with_excluded_fields_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .all_fields()
+        .exclude_field("summary")
+        .exclude_fields(vec!["storyline", "url"])
+        .search("Zelda");
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        "fields *; exclude summary,storyline,url; search \"Zelda\"; limit 10;",
+        &body
+    );
+}
+
+#[test]
+fn request_builder_with_typed_values_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where(
+            "name",
+            Equality::Equal,
+            Value::string("Say \"hi\"; limit 500"),
+        )
+        .add_where("rating", Equality::Greater, 80.5)
+        .add_where("id", Equality::NotEqual, 42usize)
+        .add_where("hidden", Equality::Equal, false)
+        .add_where("platforms", Equality::Equal, vec![6, 48])
+        .add_where("cover", Equality::NotEqual, Value::Null)
+        .add_where_in("id", vec![1, 2, 3])
+        .add_where_like("slug", Match::Contains, "a\\b\"c");
+
+    let body = builder.build_body();
+
+    assert_eq!(
+        r#"fields name; where name = "Say \"hi\"; limit 500" & rating > 80.5 & id != 42 & hidden = false & platforms = (6,48) & cover != null & id = (1,2,3) & slug ~ *"a\\b\"c"*; limit 10;"#,
+        &body
+    );
+}
+
+#[test]
+fn request_builder_escapes_hostile_strings() {
+    let hostile = "Zelda\"; fields *; limit 500; where id = 1 | \"\\";
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .search(hostile)
+        .add_where("name", Equality::Equal, Value::string("a\nb\\"))
+        .add_where_like("slug", Match::StartsWith, hostile);
+
+    assert_eq!(
+        r#"fields name; search "Zelda\"; fields *; limit 500; where id = 1 | \"\\"; where name = "ab\\" & slug ~ "Zelda\"; fields *; limit 500; where id = 1 | \"\\"*; limit 10;"#,
+        builder.build_body()
+    );
+    assert_eq!(
+        r#"search "Zelda\"; fields *; limit 500; where id = 1 | \"\\"; where name = "ab\\" & slug ~ "Zelda\"; fields *; limit 500; where id = 1 | \"\\"*;"#,
+        builder.build_count_body()
+    );
+
+    let parsed: RequestBuilder = builder.to_query().parse().unwrap();
+    assert_eq!(hostile, parsed.search);
+    assert_eq!(builder.to_query(), parsed.to_query());
+}
+
+#[test]
+fn request_builder_escapes_hostile_strings_in_where_clauses() {
+    let hostile = "Zelda\"; fields *; limit 500; where id = 1 | \"\\";
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .group(|g| {
+            g.add_where("name", Equality::Equal, hostile).add_where_or(
+                "slug",
+                Equality::Equal,
+                hostile.to_owned(),
+            )
+        })
+        .add_where("summary", Equality::NotEqual, hostile);
+
+    let escaped = r#""Zelda\"; fields *; limit 500; where id = 1 | \"\\""#;
+    assert_eq!(
+        format!(
+            "fields name; where (name = {0} | slug = {0}) & summary != {0}; limit 10;",
+            escaped
+        ),
+        builder.build_body()
+    );
+
+    let parsed: RequestBuilder = builder.to_query().parse().unwrap();
+    assert_eq!(builder.to_query(), parsed.to_query());
+}
+
+#[test]
+fn request_builder_count_body_keeps_search_and_filters() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .all_fields()
+        .search("Zelda")
+        .add_where("rating", Equality::Greater, 80)
+        .sort_by("rating", OrderBy::Descending)
+        .limit(50);
+
+    assert_eq!(
+        "search \"Zelda\"; where rating > 80;",
+        builder.build_count_body()
+    );
+    assert_eq!("", RequestBuilder::new().build_count_body());
+}
+
+#[test]
+fn request_builder_renders_clauses_in_insertion_order() {
+    let build = || {
+        let mut builder = RequestBuilder::new();
+        builder
+            .add_fields(vec!["url", "name", "id"])
+            .add_where("name", Equality::Equal, Value::string("Conan"))
+            .add_where("id", Equality::Less, 39047)
+            .add_where("category", Equality::Equal, 0)
+            .sort_by("rating", OrderBy::Descending)
+            .sort_by("id", OrderBy::Ascending);
+        builder.build_body()
+    };
+
+    assert_eq!(
+        "fields url,name,id; where name = \"Conan\" & id < 39047 & category = 0; sort rating desc, id asc; limit 10;",
+        build()
+    );
+    assert_eq!(build(), build());
+}
+
+#[test]
+fn request_builder_with_platform_ids_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where(
+            "platforms",
+            Equality::Equal,
+            crate::model::ids::PlatformId::Linux,
+        )
+        .add_where_or(
+            "platforms",
+            Equality::Equal,
+            crate::model::ids::PlatformId::PlayStation5,
+        );
+
+    assert_eq!(
+        "fields name; where platforms = 3 | platforms = 167; limit 10;",
+        builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_with_release_window_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .released_between(1577836800, 1609459199);
+
+    assert_eq!(
+        "fields name; where first_release_date >= 1577836800 & first_release_date <= 1609459199; limit 10;",
+        builder.build_body()
+    );
+
+    #[cfg(feature = "chrono")]
+    {
+        let mut dates = RequestBuilder::new();
+        dates.add_field("name").released_between_dates(
+            chrono::NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            chrono::NaiveDate::from_ymd_opt(2020, 12, 31).unwrap(),
+        );
+        assert_eq!(builder.build_body(), dates.build_body());
+    }
+}
+
+#[test]
+fn request_builder_with_language_support_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .supports_language("de", crate::model::enums::SupportType::Subtitles);
+
+    assert_eq!(
+        "fields name; where language_supports.language.locale ~ \"de\"* & language_supports.language_support_type = 2; limit 10;",
+        builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_with_expanded_fields_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_expanded_field("cover", &["image_id", "url"])
+        .add_expanded_field("genres", &["name"]);
+
+    assert_eq!(
+        "fields name,cover.image_id,cover.url,genres.name; limit 10;",
+        builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_with_typed_ids_body_build() {
+    use crate::model::ids::{GameId, GenreId};
+
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where_id_in("genres", vec![GenreId(12), GenreId(31)])
+        .add_where("id", Equality::NotEqual, GameId(1942));
+
+    assert_eq!(
+        "fields name; where genres = (12,31) & id != 1942; limit 10;",
+        builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_without_fields_requests_the_model_fields() {
+    use crate::model::genre::Genre;
+
+    let mut builder = RequestBuilder::new();
+    builder.search("Shooter");
+
+    assert_eq!(
+        "fields id,created_at,name,slug,updated_at,url; search \"Shooter\"; limit 10;",
+        builder.build_body_for::<Genre>().unwrap()
+    );
+    assert!(matches!(
+        builder.build_body_for::<serde_json::Value>(),
+        Err(Error::InvalidQuery(_))
+    ));
+
+    builder.add_field("name");
+    assert_eq!(
+        builder.build_body(),
+        builder.build_body_for::<Genre>().unwrap()
+    );
+}
+
+#[test]
+fn request_builder_requires_the_fields_the_model_cannot_be_read_without() {
+    use crate::model::age_rating::AgeRating;
+
+    let error = |builder: &RequestBuilder| match builder.build_body_for::<AgeRating>() {
+        Err(e) => e.to_string(),
+        Ok(body) => body,
+    };
+    let mut builder = RequestBuilder::new();
+    builder.add_field("category");
+    assert_eq!(
+        "invalid query: field `rating` required by model AgeRating was not selected",
+        error(&builder)
+    );
+
+    builder.add_field("*").exclude_field("rating");
+    assert!(builder.build_body_for::<AgeRating>().is_err());
+
+    let mut builder = RequestBuilder::new();
+    builder.add_fields(["category", "rating"]);
+    assert_eq!("fields category,rating; limit 10;", error(&builder));
+}
+
+#[test]
+fn request_builder_with_array_filters_body_build() {
+    let mut builder = RequestBuilder::new();
+
+    builder
+        .add_field("name")
+        .add_where_in("genres", vec![12, 31])
+        .add_where_not_in("themes", vec![42])
+        .add_where_all("platforms", vec![6, 48])
+        .add_where_exact("game_modes", vec![1, 2])
+        .add_where_in("slug", vec![Value::string("celeste")]);
+
+    assert_eq!(
+        "fields name; where genres = (12,31) & themes != (42) & platforms = [6,48] & game_modes = {1,2} & slug = (\"celeste\"); limit 10;",
+        builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_with_range_body_build() {
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .add_where_between("rating", 70, 90);
+
+    assert_eq!(
+        "fields name; where rating >= 70 & rating <= 90; limit 10;",
+        builder.build_body()
+    );
+
+    #[cfg(feature = "chrono")]
+    {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let mut builder = RequestBuilder::new();
+        builder.add_field("name").add_where_between(
+            "first_release_date",
+            Value::from(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()),
+            Value::from(Utc.with_ymd_and_hms(2020, 12, 31, 23, 59, 59).unwrap()),
+        );
+
+        assert_eq!(
+            "fields name; where first_release_date >= 1577836800 & first_release_date <= 1609459199; limit 10;",
+            builder.build_body()
+        );
+    }
+}
+
+#[test]
+fn request_builder_filters_on_the_update_and_creation_times() {
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .updated_since(1_700_000_000u64)
+        .created_since(1_600_000_000u64);
+
+    assert_eq!(
+        "fields name; where updated_at >= 1700000000 & created_at >= 1600000000; limit 10;",
+        builder.build_body()
+    );
+
+    #[cfg(feature = "chrono")]
+    {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let mut dates = RequestBuilder::new();
+        dates
+            .add_field("name")
+            .updated_since(Utc.timestamp_opt(1_700_000_000, 0).unwrap())
+            .created_since(NaiveDate::from_ymd_opt(2020, 9, 13).unwrap());
+        assert_eq!(
+            "fields name; where updated_at >= 1700000000 & created_at >= 1599955200; limit 10;",
+            dates.build_body()
+        );
+    }
+}
+
+#[test]
+fn game_presets_expand_to_categories_and_statuses() {
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .add_where("rating", Equality::Greater, 80)
+        .include_dlc();
+    assert_eq!(
+        "fields name; where rating > 80 & category = (0,1,2,4); limit 10;",
+        builder.build_body()
+    );
+
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .add_where_in("category", [0, 8, 1])
+        .include_dlc()
+        .released_only();
+    assert_eq!(
+        "fields name; where category = (0,8,1,2,4) & first_release_date != null & (status = null | status = 0); limit 10;",
+        builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_rejects_sorted_searches() {
+    use crate::model::genre::Genre;
+
+    let mut builder = RequestBuilder::new();
+    builder.add_field("name").search("zelda");
+    assert!(builder.build_body_for::<Genre>().is_ok());
+
+    builder.sort_by("rating", OrderBy::Descending);
+    assert!(matches!(
+        builder.build_body_for::<Genre>(),
+        Err(Error::InvalidQuery(_))
+    ));
+
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .search_field("name", "zelda")
+        .sort_by("rating", OrderBy::Descending);
+    assert_eq!(
+        "fields name; where name ~ *\"zelda\"*; sort rating desc; limit 10;",
+        builder.build_body_for::<Genre>().unwrap()
+    );
+}
+
+#[test]
+fn request_builder_rejects_invalid_clauses() {
+    let rejected = |configure: fn(&mut RequestBuilder)| {
+        let mut builder = RequestBuilder::new();
+        builder.add_field("name");
+        configure(&mut builder);
+        match builder.validate() {
+            Err(Error::InvalidQuery(message)) => message,
+            other => panic!("{:?} accepted: {:?}", builder.to_query(), other),
+        }
+    };
+
+    assert_eq!(
+        "invalid field name \" \"",
+        rejected(|builder| {
+            builder.add_field(" ");
+        })
+    );
+    assert_eq!(
+        "invalid field name \"summary; limit 500\"",
+        rejected(|builder| {
+            builder.exclude_field("summary; limit 500");
+        })
+    );
+    assert_eq!(
+        "limit 0 is not between 1 and 500, see IGDBClient::query_all for bigger ones",
+        rejected(|builder| {
+            builder.limit(0);
+        })
+    );
+    assert!(rejected(|builder| {
+        builder.limit(501);
+    })
+    .starts_with("limit 501"));
+    assert_eq!(
+        "invalid sort \"rating\" \"up\"",
+        rejected(|builder| builder.sort.push(("rating".to_owned(), "up".to_owned())))
+    );
+    assert_eq!(
+        "filters platforms = 6 and platforms != 6 never match together",
+        rejected(|builder| {
+            builder
+                .add_where("platforms", Equality::Equal, 6)
+                .add_where("rating", Equality::Greater, 80)
+                .add_where("platforms", Equality::NotEqual, 6);
+        })
+    );
+
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .add_where("platforms", Equality::Equal, 6)
+        .add_where("platforms", Equality::Equal, 48)
+        .add_where_or("platforms", Equality::NotEqual, 6)
+        .limit(500);
+    assert!(builder.validate().is_ok());
+}
+
+#[test]
+fn request_builder_renders_repeated_clauses_once() {
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_fields(vec!["name", "rating"])
+        .add_field("name")
+        .exclude_fields(vec!["summary", "summary"])
+        .add_where("rating", Equality::Greater, 80)
+        .add_where("platforms", Equality::Equal, 6)
+        .add_where("rating", Equality::Greater, 80)
+        .sort_by("rating", OrderBy::Descending)
+        .sort_by("rating", OrderBy::Ascending);
+
+    assert_eq!(
+        "fields name,rating; exclude summary; where rating > 80 & platforms = 6; sort rating desc; limit 10;",
+        builder.build_body()
+    );
+    assert_eq!(
+        "where rating > 80 & platforms = 6;",
+        builder.build_count_body()
+    );
+
+    let mut builder = RequestBuilder::new();
+    builder
+        .add_field("name")
+        .add_where("rating", Equality::Greater, 80)
+        .add_where_or("platforms", Equality::Equal, 6)
+        .add_where("rating", Equality::Greater, 80);
+    assert_eq!(
+        "fields name; where rating > 80 | (platforms = 6 & rating > 80); limit 10;",
+        builder.build_body()
+    );
+}
+
+#[test]
+fn request_builder_merge_keeps_the_filters_precedence() {
+    let mut base = RequestBuilder::new();
+    base.add_field("name")
+        .add_where("category", Equality::Equal, 0)
+        .add_where_or("category", Equality::Equal, 8);
+
+    let mut rated = RequestBuilder::new();
+    rated
+        .add_fields(vec!["name", "rating"])
+        .add_where("rating", Equality::Greater, 80)
+        .sort_by("rating", OrderBy::Descending)
+        .offset(20);
+
+    base.merge(&rated);
+    assert_eq!(
+        "fields name,rating; where (category = 0 | category = 8) & rating > 80; sort rating desc; limit 10; offset 20;",
+        base.build_body()
+    );
+
+    base.reset();
+    assert_eq!(RequestBuilder::new().build_body(), base.build_body());
+}
+
+#[test]
+fn request_builder_merge_applies_the_limit_and_offset_set_even_to_their_defaults() {
+    let mut base = RequestBuilder::new();
+    base.add_field("name").limit(50).offset(100);
+
+    let mut variant = RequestBuilder::new();
+    variant.limit(10).offset(0);
+    let mut request = base.clone();
+    request.merge(&variant);
+    assert_eq!("fields name; limit 10;", request.build_body());
+
+    let parsed: RequestBuilder = "limit 10;".parse().unwrap();
+    let mut request = base.clone();
+    request.merge(&parsed);
+    assert_eq!("fields name; limit 10; offset 100;", request.build_body());
+
+    // Builders not setting them keep the ones of the template
+    base.merge(&RequestBuilder::new());
+    assert_eq!("fields name; limit 50; offset 100;", base.build_body());
+}
+
+#[test]
+fn consuming_builder_matches_the_mutable_one() {
+    let mut mutable = RequestBuilder::new();
+    mutable
+        .add_fields(["name", "cover.*"])
+        .exclude_field("checksum")
+        .add_where("rating", Equality::Greater, 80)
+        .add_where_or("rating_count", Equality::Greater, 1000)
+        .sort_by("rating", OrderBy::Descending)
+        .limit(50)
+        .offset(100);
+
+    let consuming = RequestBuilder::new()
+        .field("name")
+        .field("cover.*")
+        .excluding("checksum")
+        .wher("rating", Equality::Greater, 80)
+        .or_wher("rating_count", Equality::Greater, 1000)
+        .sorted("rating", OrderBy::Descending)
+        .with_limit(50)
+        .with_offset(100);
+
+    assert_eq!(mutable.to_query(), consuming.to_query());
+    assert!(RequestBuilder::new()
+        .field("name")
+        .with_search("zelda")
+        .sorted("rating", OrderBy::Ascending)
+        .build_query()
+        .is_err());
+}
@@ -1,16 +1,154 @@
-use crate::request_builder::{Equality, OrderBy, RequestBuilder};
+use crate::expr::{Expr, Operator};
+use crate::model::enums::{GameCategory, Region, Status, SupportType};
+use crate::model::ids::EntityId;
+use crate::model::ids::PlatformId;
+use crate::request_builder::{Equality, Match, OrderBy, RequestBuilder, Value};
+use crate::timestamp::IntoTimestamp;
 
 const ALL_FIELDS: &str = "*";
 
-#[derive(Clone)]
-///Filter struct
-/// It's values represents any kind of filter, like:
-/// id >= 5
-/// name = "Conan"
-pub(crate) struct Filter {
-    pub(crate) key: String,
-    pub(crate) symbol: String,
-    pub(crate) value: String,
+///The game categories filter matching the given categories and the added ones
+fn with_categories(mut categories: Vec<Value>, added: &[GameCategory]) -> Expr {
+    for category in added {
+        let category = Value::from(category.value());
+        if !categories.contains(&category) {
+            categories.push(category);
+        }
+    }
+    Expr::In {
+        field: "category".to_owned(),
+        values: categories,
+        negated: false,
+    }
+}
+
+///Names of the registries of an endpoint a field must reference, e.g. genre names,
+/// resolved to their ids by the client sending the request
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct NameFilter {
+    pub(crate) endpoint: &'static str,
+    pub(crate) field: &'static str,
+    pub(crate) names: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Conjunction {
+    #[default]
+    And,
+    Or,
+}
+
+///The where clause being built, each filter being joined to the previous ones
+/// with `&` or `|`, `&` binding tighter
+#[derive(Clone, Debug, Default)]
+pub(crate) struct FilterChain {
+    pub(crate) expr: Option<Expr>,
+    ///Whether expr is an Or of filters joined with `|`, whose last one the next filter
+    /// joined with `&` applies to, rather than a parenthesized group
+    chained: bool,
+}
+
+impl FilterChain {
+    pub(crate) fn push(&mut self, conjunction: Conjunction, filter: Expr) {
+        let (expr, chained) = match (self.expr.take(), conjunction) {
+            (None, _) => (filter, false),
+            (Some(Expr::Or(mut exprs)), Conjunction::And) if self.chained => {
+                if let Some(last) = exprs.pop() {
+                    exprs.push(last.and(filter));
+                }
+                (Expr::Or(exprs), true)
+            }
+            (Some(Expr::Or(mut exprs)), Conjunction::Or) if self.chained => {
+                exprs.push(filter);
+                (Expr::Or(exprs), true)
+            }
+            (Some(expr), Conjunction::And) => (expr.and(filter), false),
+            (Some(expr), Conjunction::Or) => (Expr::Or(vec![expr, filter]), true),
+        };
+        self.expr = Some(expr);
+        self.chained = chained;
+    }
+
+    ///Requires the whole clause and the given filter to match
+    pub(crate) fn constrain(&mut self, filter: Expr) {
+        self.expr = Some(match self.expr.take() {
+            Some(expr) => expr.and(filter),
+            None => filter,
+        });
+        self.chained = false;
+    }
+
+    pub(crate) fn set(&mut self, expr: Option<Expr>) {
+        self.expr = expr;
+        self.chained = false;
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.expr.is_none()
+    }
+}
+
+///A parenthesized group of filters, created with RequestBuilder::group and RequestBuilder::group_or
+#[derive(Clone, Debug, Default)]
+pub struct FilterGroup {
+    filters: FilterChain,
+}
+
+impl FilterGroup {
+    /// Adds a condition joined with `&` to the previous one of the group
+    pub fn add_where<L: Into<String>, R: Into<Value>>(
+        &mut self,
+        field: L,
+        equality: Equality,
+        clause: R,
+    ) -> &mut Self {
+        self.filters
+            .push(Conjunction::And, Expr::cmp(field, equality, clause));
+        self
+    }
+
+    /// Adds a condition joined with `|` to the previous one of the group
+    pub fn add_where_or<L: Into<String>, R: Into<Value>>(
+        &mut self,
+        field: L,
+        equality: Equality,
+        clause: R,
+    ) -> &mut Self {
+        self.filters
+            .push(Conjunction::Or, Expr::cmp(field, equality, clause));
+        self
+    }
+
+    /// Adds a nested group joined with `&` to the previous condition of the group
+    pub fn group<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut FilterGroup) -> &mut FilterGroup,
+    {
+        if let Some(filter) = FilterGroup::build_filter(build) {
+            self.filters.push(Conjunction::And, filter);
+        }
+        self
+    }
+
+    /// Adds a nested group joined with `|` to the previous condition of the group
+    pub fn group_or<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut FilterGroup) -> &mut FilterGroup,
+    {
+        if let Some(filter) = FilterGroup::build_filter(build) {
+            self.filters.push(Conjunction::Or, filter);
+        }
+        self
+    }
+
+    fn build_filter<F>(build: F) -> Option<Expr>
+    where
+        F: FnOnce(&mut FilterGroup) -> &mut FilterGroup,
+    {
+        let mut group = FilterGroup::default();
+        build(&mut group);
+        group.filters.expr
+    }
 }
 ///This struct allows creating a custom and parameterized request to IGDB endpoints by using it's filter methods
 impl RequestBuilder {
@@ -31,15 +169,18 @@ impl RequestBuilder {
         self
     }
     /// Adds one field to be retrieved for this request
-    ///Requests all field for the given query
+    ///Requests all field for the given query.
+    /// The fields module of each model names its fields, e.g. model::games::fields
     /// # Examples
     /// ```
     /// use igdb::client::IGDBClient;
+    /// use igdb::model::games::fields::{self, GameField};
     ///
     /// let mut request = IGDBClient::create_request();
     /// request
     /// .add_field("description")
-    /// .add_field("name")
+    /// .add_field(fields::NAME)
+    /// .add_field(GameField::FirstReleaseDate)
     /// .search("Borderlands");
     /// ```
     pub fn add_field<S: Into<String>>(&mut self, field: S) -> &mut Self {
@@ -67,6 +208,82 @@ impl RequestBuilder {
         self
     }
 
+    /// Adds the given fields of a referenced registry, expanding the reference inline,
+    /// e.g. `cover.image_id`. See IdOr for deserializing the expanded references
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // fields name,cover.image_id,cover.url;
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .add_expanded_field("cover", &["image_id", "url"])
+    /// .search("Borderlands");
+    /// ```
+    pub fn add_expanded_field<S: Into<String>>(&mut self, field: S, nested: &[&str]) -> &mut Self {
+        let field = field.into();
+        self.add_fields(nested.iter().map(|nested| format!("{}.{}", field, nested)))
+    }
+
+    /// Excludes one field from the retrieved ones, mostly useful along with all_fields
+    /// to skip heavy fields
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .all_fields()
+    /// .exclude_field("storyline")
+    /// .search("Borderlands");
+    /// ```
+    pub fn exclude_field<S: Into<String>>(&mut self, field: S) -> &mut Self {
+        self.exclude.push(field.into());
+        self
+    }
+
+    /// Excludes several fields from the retrieved ones by using an Iterator object
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .all_fields()
+    /// .exclude_fields(vec!["summary", "storyline"])
+    /// .search("Borderlands");
+    /// ```
+    pub fn exclude_fields<I, T>(&mut self, iter: I) -> &mut Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.exclude.extend(iter.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a filter matching any of the given typed ids, like add_where_in.
+    /// The ids being typed, a genre id cannot be passed where a platform id is expected
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::ids::GenreId;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .add_where_id_in("genres", vec![GenreId(12), GenreId(31)]);
+    /// ```
+    pub fn add_where_id_in<S, I, T>(&mut self, field: S, ids: I) -> &mut Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = T>,
+        T: EntityId,
+    {
+        self.add_where_in(field, ids)
+    }
+
     /// Adds a filter representing a group of elements like WHERE IN statement in SQL
     /// # Examples
     /// ```
@@ -75,20 +292,106 @@ impl RequestBuilder {
     /// let mut request = IGDBClient::create_request();
     /// request
     /// .all_fields()
-    /// .add_where_in("name".to_owned(), vec!["5".to_owned(), "8".to_owned(), "10".to_owned()])
+    /// .add_where_in("id", vec![5, 8, 10])
     /// .limit(5);
     /// ```
-    pub fn add_where_in(&mut self, field: String, values: Vec<String>) -> &mut Self {
-        self.filters.push(Filter {
-            key: field,
-            symbol: String::new(),
-            value: format!("= ({})", values.join(",")),
-        });
+    pub fn add_where_in<S, I, V>(&mut self, field: S, values: I) -> &mut Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        self.add_where_list(field, Equality::Equal, ('(', ')'), values)
+    }
+
+    /// Adds a filter excluding the registries whose field matches any of the given values:
+    /// `field != (a,b)`
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // where genres != (12,31);
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").add_where_not_in("genres", vec![12, 31]);
+    /// ```
+    pub fn add_where_not_in<S, I, V>(&mut self, field: S, values: I) -> &mut Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        self.add_where_list(field, Equality::NotEqual, ('(', ')'), values)
+    }
+
+    /// Adds a filter on an array field containing all the given values, and possibly others:
+    /// `field = [a,b]`
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // Games released on both PC and PlayStation 4: where platforms = [6,48];
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").add_where_all("platforms", vec![6, 48]);
+    /// ```
+    pub fn add_where_all<S, I, V>(&mut self, field: S, values: I) -> &mut Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        self.add_where_list(field, Equality::Equal, ('[', ']'), values)
+    }
+
+    /// Adds a filter on an array field containing exactly the given values: `field = {a,b}`
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // Games released on PC and PlayStation 4 only: where platforms = {6,48};
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").add_where_exact("platforms", vec![6, 48]);
+    /// ```
+    pub fn add_where_exact<S, I, V>(&mut self, field: S, values: I) -> &mut Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        self.add_where_list(field, Equality::Equal, ('{', '}'), values)
+    }
 
+    fn add_where_list<S, I, V>(
+        &mut self,
+        field: S,
+        equality: Equality,
+        (open, close): (char, char),
+        values: I,
+    ) -> &mut Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        let filter = match (open, Operator::from(equality)) {
+            ('(', Operator::Equal) => Expr::is_in(field, values),
+            ('(', _) => Expr::not_in(field, values),
+            (_, op) => {
+                let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+                Expr::Cmp {
+                    field: field.into(),
+                    op,
+                    value: Value::Raw(format!("{}{}{}", open, values.join(","), close)),
+                }
+            }
+        };
+        self.filters.push(Conjunction::And, filter);
         self
     }
 
-    /// Adds a filter under using given condition
+    /// Adds a filter under using given condition.
+    /// The clause can be any Value: numbers, booleans, id lists, null, strings which are
+    /// quoted and escaped, or trusted expressions sent as is with Value::Raw
     /// # Examples
     /// ```
     /// use igdb::client::IGDBClient;
@@ -96,24 +399,554 @@ impl RequestBuilder {
     ///
     /// let mut request = IGDBClient::create_request();
     /// request
-    /// .add_where("id", Equality::GreaterOrEqual, "20")
-    /// .add_where("onlinemax", Equality::LessOrEqual, "12")
+    /// .add_where("id", Equality::GreaterOrEqual, 20)
+    /// .add_where("onlinemax", Equality::LessOrEqual, 12)
     /// .contains("name", "Fighter");
     /// ```
-    pub fn add_where<L: Into<String>, R: Into<String>>(
+    pub fn add_where<L: Into<String>, R: Into<Value>>(
         &mut self,
         field: L,
         equality: Equality,
         clause: R,
     ) -> &mut Self {
-        self.filters.push(Filter {
-            key: field.into(),
-            symbol: equality.to_string(),
-            value: clause.into(),
+        self.filters
+            .push(Conjunction::And, Expr::cmp(field, equality, clause));
+        self
+    }
+
+    /// Adds a filter matching the registries where the field has no value (`field = null`)
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // Games without cover
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .add_where_null("cover");
+    /// ```
+    pub fn add_where_null<S: Into<String>>(&mut self, field: S) -> &mut Self {
+        self.add_where(field, Equality::Equal, Value::Null)
+    }
+
+    /// Adds a filter matching the registries where the field has a value (`field != null`)
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // Games with a known release date
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .add_where_not_null("first_release_date");
+    /// ```
+    pub fn add_where_not_null<S: Into<String>>(&mut self, field: S) -> &mut Self {
+        self.add_where(field, Equality::NotEqual, Value::Null)
+    }
+
+    /// Adds a filter joined with `|` (or) to the previous one
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_where("platforms", Equality::Equal, 6)
+    /// .add_where_or("platforms", Equality::Equal, 14);
+    /// ```
+    pub fn add_where_or<L: Into<String>, R: Into<Value>>(
+        &mut self,
+        field: L,
+        equality: Equality,
+        clause: R,
+    ) -> &mut Self {
+        self.filters
+            .push(Conjunction::Or, Expr::cmp(field, equality, clause));
+        self
+    }
+
+    /// Adds a parenthesized group of filters joined with `&` to the previous filter
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// // where (platforms = 6 | platforms = 14) & rating > 80;
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .group(|g| {
+    ///     g.add_where("platforms", Equality::Equal, 6)
+    ///         .add_where_or("platforms", Equality::Equal, 14)
+    /// })
+    /// .add_where("rating", Equality::Greater, 80);
+    /// ```
+    pub fn group<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut FilterGroup) -> &mut FilterGroup,
+    {
+        if let Some(filter) = FilterGroup::build_filter(build) {
+            self.filters.push(Conjunction::And, filter);
+        }
+        self
+    }
+
+    /// Adds a parenthesized group of filters joined with `|` to the previous filter
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// // where rating > 90 | (rating > 80 & rating_count > 100);
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_where("rating", Equality::Greater, 90)
+    /// .group_or(|g| {
+    ///     g.add_where("rating", Equality::Greater, 80)
+    ///         .add_where("rating_count", Equality::Greater, 100)
+    /// });
+    /// ```
+    pub fn group_or<F>(&mut self, build: F) -> &mut Self
+    where
+        F: FnOnce(&mut FilterGroup) -> &mut FilterGroup,
+    {
+        if let Some(filter) = FilterGroup::build_filter(build) {
+            self.filters.push(Conjunction::Or, filter);
+        }
+        self
+    }
+
+    /// Adds an expression joined with `&` to the previous filter, like add_where
+    /// # Examples
+    /// ```
+    /// use igdb::expr::Expr;
+    /// use igdb::request_builder::{Equality, RequestBuilder};
+    ///
+    /// let mut request = RequestBuilder::new();
+    /// request
+    /// .add_field("name")
+    /// .add_expr(Expr::is_in("platforms", [6, 48]).or(Expr::null("platforms")));
+    /// assert_eq!(
+    ///     "fields name; where platforms = (6,48) | platforms = null; limit 10;",
+    ///     request.to_query()
+    /// );
+    /// ```
+    pub fn add_expr(&mut self, expr: Expr) -> &mut Self {
+        self.filters.push(Conjunction::And, expr);
+        self
+    }
+
+    /// Adds an expression joined with `|` to the previous filter, like add_where_or
+    pub fn add_expr_or(&mut self, expr: Expr) -> &mut Self {
+        self.filters.push(Conjunction::Or, expr);
+        self
+    }
+
+    /// Requires the given expression to match along every filter added so far, whatever
+    /// they are joined with, e.g. to restrict the queries of a tenant
+    /// # Examples
+    /// ```
+    /// use igdb::expr::Expr;
+    /// use igdb::request_builder::{Equality, RequestBuilder};
+    ///
+    /// let mut request = RequestBuilder::new();
+    /// request
+    /// .add_field("name")
+    /// .add_where("rating", Equality::Greater, 80)
+    /// .add_where_or("rating_count", Equality::Greater, 1000)
+    /// .constrain(Expr::is_in("platforms", [6]));
+    /// assert_eq!(
+    ///     "fields name; where (rating > 80 | rating_count > 1000) & platforms = (6); limit 10;",
+    ///     request.to_query()
+    /// );
+    /// ```
+    pub fn constrain(&mut self, expr: Expr) -> &mut Self {
+        self.filters.constrain(expr);
+        self
+    }
+
+    /// Returns the where clause as an expression tree, None without filters
+    pub fn filter_expr(&self) -> Option<&Expr> {
+        self.filters.expr.as_ref()
+    }
+
+    /// Replaces the where clause, None removing every filter
+    pub fn set_filter_expr(&mut self, expr: Option<Expr>) -> &mut Self {
+        self.filters.set(expr);
+        self
+    }
+
+    /// Rewrites the where clause with Expr::rewrite
+    /// # Examples
+    /// ```
+    /// use igdb::expr::Expr;
+    /// use igdb::request_builder::{Equality, RequestBuilder};
+    ///
+    /// // Reads the legacy rating field from the total rating
+    /// let mut request = RequestBuilder::new();
+    /// request
+    /// .add_field("name")
+    /// .add_where("rating", Equality::Greater, 80)
+    /// .rewrite_filters(|expr| match expr {
+    ///     Expr::Cmp { field, op, value } if field == "rating" => Expr::Cmp {
+    ///         field: "total_rating".to_owned(),
+    ///         op,
+    ///         value,
+    ///     },
+    ///     expr => expr,
+    /// });
+    /// assert_eq!("fields name; where total_rating > 80; limit 10;", request.to_query());
+    /// ```
+    pub fn rewrite_filters<F: FnMut(Expr) -> Expr>(&mut self, mut rewrite: F) -> &mut Self {
+        let expr = self.filters.expr.take();
+        self.filters
+            .set(expr.map(|expr| expr.rewrite(&mut rewrite)));
+        self
+    }
+
+    /// Adds a filter on the game category using its numeric IGDB code
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::enums::GameCategory;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .all_fields()
+    /// .add_where_category(Equality::NotEqual, GameCategory::DlcAddon);
+    /// ```
+    pub fn add_where_category(&mut self, equality: Equality, category: GameCategory) -> &mut Self {
+        self.add_where("category", equality, category.value())
+    }
+
+    /// Adds a filter on the game status using its numeric IGDB code
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::enums::Status;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .all_fields()
+    /// .add_where_status(Equality::Equal, Status::EarlyAccess);
+    /// ```
+    pub fn add_where_status(&mut self, equality: Equality, status: Status) -> &mut Self {
+        self.add_where("status", equality, status.value())
+    }
+
+    /// Adds a filter on the release region using its numeric IGDB code
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::enums::Region;
+    /// use igdb::request_builder::Equality;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .all_fields()
+    /// .add_where_region(Equality::Equal, Region::Europe);
+    /// ```
+    pub fn add_where_region(&mut self, equality: Equality, region: Region) -> &mut Self {
+        self.add_where("region", equality, region.value())
+    }
+
+    /// Adds a filter matching the main games only, leaving out their DLCs, expansions,
+    /// bundles, mods, episodes, remakes, ports and updates: `category = 0`
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").only_main_games();
+    /// assert_eq!("fields name; where category = 0; limit 10;", request.to_query());
+    /// ```
+    pub fn only_main_games(&mut self) -> &mut Self {
+        self.add_where_category(Equality::Equal, GameCategory::MainGame)
+    }
+
+    /// Adds the DLCs and expansions, standalone ones included, to the game categories the
+    /// request matches, the main games when it matches none yet
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").only_main_games().include_dlc();
+    /// assert_eq!("fields name; where category = (0,1,2,4); limit 10;", request.to_query());
+    /// ```
+    pub fn include_dlc(&mut self) -> &mut Self {
+        let dlc = [
+            GameCategory::DlcAddon,
+            GameCategory::Expansion,
+            GameCategory::StandaloneExpansion,
+        ];
+        let mut widened = false;
+        self.rewrite_filters(|expr| {
+            let categories = match expr {
+                Expr::Cmp {
+                    field,
+                    op: Operator::Equal,
+                    value: value @ Value::Int(_),
+                } if field == "category" => vec![value],
+                Expr::In {
+                    field,
+                    values,
+                    negated: false,
+                } if field == "category" => values,
+                expr => return expr,
+            };
+            widened = true;
+            with_categories(categories, &dlc)
         });
+        match widened {
+            true => self,
+            false => self.add_expr(with_categories(
+                vec![Value::from(GameCategory::MainGame.value())],
+                &dlc,
+            )),
+        }
+    }
+
+    /// Adds filters matching the released games: with a known first release date, and
+    /// without a status or with the released one, IGDB leaving the status of most released
+    /// games empty. The date is not compared to the current time so that the query stays the
+    /// same from one call to the next, add a `first_release_date` bound to exclude the games
+    /// announced for a later date
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").released_only();
+    /// assert_eq!(
+    ///     "fields name; where first_release_date != null & (status = null | status = 0); limit 10;",
+    ///     request.to_query()
+    /// );
+    /// ```
+    pub fn released_only(&mut self) -> &mut Self {
+        self.add_where_not_null("first_release_date")
+            .add_expr(Expr::null("status").or(Expr::cmp(
+                "status",
+                Equality::Equal,
+                Status::Released.value(),
+            )))
+    }
+
+    /// Adds a filter matching the games released on any of the given platforms
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::ids::PlatformId;
+    ///
+    /// // where platforms = (3,6);
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .on_platforms(&[PlatformId::Linux, PlatformId::Windows]);
+    /// ```
+    pub fn on_platforms(&mut self, platforms: &[PlatformId]) -> &mut Self {
+        self.add_where_id_in("platforms", platforms.iter().copied())
+    }
+
+    /// Adds a filter matching the games of any of the given genres, named as on IGDB
+    /// (case insensitive). The names are resolved to ids with the genres endpoint when
+    /// the request is sent, the genres being retrieved once per client
+    /// # Examples
+    /// ```no_run
+    /// use async_std::task;
+    /// use igdb::client::{GamesClient, IGDBClient};
+    ///
+    /// task::block_on(async {
+    ///     let igdb = IGDBClient::new("client_id", "token");
+    ///     let mut request = IGDBClient::create_request();
+    ///     request
+    ///     .add_field("name")
+    ///     .with_genre_names(&["Shooter", "Indie"]);
+    ///
+    ///     let games = igdb.query::<GamesClient>(&request).await.unwrap();
+    /// })
+    /// ```
+    pub fn with_genre_names<S: AsRef<str>>(&mut self, names: &[S]) -> &mut Self {
+        self.add_name_filter("genres", "genres", names)
+    }
+
+    /// Adds a filter matching the games of any of the given themes, named as on IGDB,
+    /// resolved like with_genre_names
+    pub fn with_theme_names<S: AsRef<str>>(&mut self, names: &[S]) -> &mut Self {
+        self.add_name_filter("themes", "themes", names)
+    }
+
+    fn add_name_filter<S: AsRef<str>>(
+        &mut self,
+        endpoint: &'static str,
+        field: &'static str,
+        names: &[S],
+    ) -> &mut Self {
+        if !names.is_empty() {
+            self.names.push(NameFilter {
+                endpoint,
+                field,
+                names: names.iter().map(|name| name.as_ref().to_owned()).collect(),
+            });
+        }
         self
     }
 
+    /// Adds a filter matching the games whose total rating, the average of the user and
+    /// critic ratings from 0 to 100, is at least the given one
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // Well-reviewed games
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .min_rating(80.0)
+    /// .min_rating_count(50);
+    /// ```
+    pub fn min_rating(&mut self, rating: f32) -> &mut Self {
+        self.add_where("total_rating", Equality::GreaterOrEqual, rating)
+    }
+
+    /// Adds a filter matching the games rated by at least the given number of users
+    /// and critics, see min_rating
+    pub fn min_rating_count(&mut self, count: usize) -> &mut Self {
+        self.add_where("total_rating_count", Equality::GreaterOrEqual, count)
+    }
+
+    /// Adds filters matching the games supporting a language whose locale starts with
+    /// the given one, e.g. "de" or "pt-BR", with the given support type.
+    /// Both filters apply to any of the game language supports, not necessarily the same one
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::model::enums::SupportType;
+    ///
+    /// // Games with german subtitles
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .supports_language("de", SupportType::Subtitles);
+    /// ```
+    pub fn supports_language<S: Into<String>>(
+        &mut self,
+        locale: S,
+        support_type: SupportType,
+    ) -> &mut Self {
+        self.add_where_like(
+            "language_supports.language.locale",
+            Match::StartsWith,
+            locale,
+        )
+        .add_where(
+            "language_supports.language_support_type",
+            Equality::Equal,
+            support_type.value(),
+        )
+    }
+
+    /// Adds filters matching the games first released between the given unix timestamps,
+    /// both included
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // Games released in 2020
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .released_between(1577836800, 1609459199);
+    /// ```
+    pub fn released_between(&mut self, from: u64, to: u64) -> &mut Self {
+        self.add_where_between("first_release_date", from, to)
+    }
+
+    /// Adds filters matching the registries whose field is between the given values,
+    /// both included: `field >= from & field <= to`.
+    /// With the chrono feature, dates and times are accepted and sent as unix timestamps,
+    /// a NaiveDate being the start of the day in UTC
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // where rating >= 70 & rating <= 90;
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").add_where_between("rating", 70, 90);
+    /// ```
+    pub fn add_where_between<S, V>(&mut self, field: S, from: V, to: V) -> &mut Self
+    where
+        S: Into<String>,
+        V: Into<Value>,
+    {
+        let field = field.into();
+        self.add_where(field.clone(), Equality::GreaterOrEqual, from)
+            .add_where(field, Equality::LessOrEqual, to)
+    }
+
+    /// Adds filters matching the games first released between the given days, both included
+    /// # Examples
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").released_between_dates(
+    ///     NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2020, 12, 31).unwrap(),
+    /// );
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn released_between_dates(
+        &mut self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> &mut Self {
+        let start_of_day = |date: chrono::NaiveDate| {
+            date.and_hms_opt(0, 0, 0)
+                .map(|date| date.and_utc().timestamp().max(0) as u64)
+                .unwrap_or_default()
+        };
+        self.released_between(start_of_day(from), start_of_day(to) + 86399)
+    }
+
+    /// Adds a filter matching the registries updated at or after the given time:
+    /// `updated_at >= since`, see IntoTimestamp for the accepted times
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").updated_since(1_700_000_000);
+    /// ```
+    pub fn updated_since<T: IntoTimestamp>(&mut self, since: T) -> &mut Self {
+        self.add_where(
+            "updated_at",
+            Equality::GreaterOrEqual,
+            since.into_timestamp(),
+        )
+    }
+
+    /// Adds a filter matching the registries created at or after the given time:
+    /// `created_at >= since`, see RequestBuilder::updated_since
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    ///
+    /// // Games added to IGDB since September 13, 2020
+    /// let mut request = IGDBClient::create_request();
+    /// request.add_field("name").created_since(1_600_000_000);
+    /// ```
+    pub fn created_since<T: IntoTimestamp>(&mut self, since: T) -> &mut Self {
+        self.add_where(
+            "created_at",
+            Equality::GreaterOrEqual,
+            since.into_timestamp(),
+        )
+    }
+
     /// Limits the registries obtained from the server
     /// # Examples
     /// ```
@@ -127,6 +960,27 @@ impl RequestBuilder {
     /// ```
     pub fn limit(&mut self, limit: usize) -> &mut Self {
         self.limit = limit;
+        self.limit_set = true;
+        self
+    }
+
+    /// Skips the given number of registries, used along with limit to page through results
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::OrderBy;
+    ///
+    /// // Third page of 50 games
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .sort_by("id", OrderBy::Ascending)
+    /// .limit(50)
+    /// .offset(100);
+    /// ```
+    pub fn offset(&mut self, offset: usize) -> &mut Self {
+        self.offset = offset;
+        self.offset_set = true;
         self
     }
 
@@ -143,16 +997,38 @@ impl RequestBuilder {
     /// .limit(8);
     /// ```
     pub fn contains<S: Into<String>>(&mut self, field: S, value: S) -> &mut Self {
-        self.filters.push(Filter {
-            key: field.into(),
-            symbol: String::new(),
-            value: format!("~ *\"{}\"*", value.into()),
-        });
+        self.add_where_like(field, Match::Contains, value)
+    }
+
+    /// Adds a case-insensitive (`~`) string filter, the value is matched
+    /// as a whole, as a prefix, as a suffix or anywhere in the field
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::Match;
+    ///
+    /// // where name ~ "zelda"*;
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .add_where_like("name", Match::StartsWith, "zelda");
+    /// ```
+    pub fn add_where_like<L: Into<String>, R: Into<String>>(
+        &mut self,
+        field: L,
+        matching: Match,
+        value: R,
+    ) -> &mut Self {
+        self.filters
+            .push(Conjunction::And, Expr::like(field, matching, value));
         self
     }
 
     /// Search based on name, results are sorted by similarity to the given search string.
     /// Searchable endpoints: - Characters - Collections - Games - People - Platforms - Themes
+    ///
+    /// IGDB rejects searches along with a sort clause, see search_field to sort the results.
+    /// The term is quoted and escaped, so user input can be searched as is.
     /// # Examples
     /// ```
     /// use igdb::client::IGDBClient;
@@ -168,7 +1044,30 @@ impl RequestBuilder {
         self
     }
 
-    /// Sorts the query by the given field
+    /// Searches the given term anywhere in the given field, case-insensitively:
+    /// `where field ~ *"term"*`. Unlike search, the results can be sorted
+    /// # Examples
+    /// ```
+    /// use igdb::client::IGDBClient;
+    /// use igdb::request_builder::OrderBy;
+    ///
+    /// // fields name; where name ~ *"zelda"*; sort rating desc;
+    /// let mut request = IGDBClient::create_request();
+    /// request
+    /// .add_field("name")
+    /// .search_field("name", "zelda")
+    /// .sort_by("rating", OrderBy::Descending);
+    /// ```
+    pub fn search_field<L: Into<String>, R: Into<String>>(
+        &mut self,
+        field: L,
+        term: R,
+    ) -> &mut Self {
+        self.add_where_like(field, Match::Contains, term)
+    }
+
+    /// Sorts the query by the given field.
+    /// Calling it several times sorts by each field in turn, e.g. `sort rating desc, id asc`
     /// # Examples
     /// ```
     /// use igdb::client::IGDBClient;
@@ -177,11 +1076,12 @@ impl RequestBuilder {
     /// let mut request = IGDBClient::create_request();
     /// request
     /// .add_field("name")
-    /// .sort_by("name", OrderBy::Descending)
+    /// .sort_by("rating", OrderBy::Descending)
+    /// .sort_by("name", OrderBy::Ascending)
     /// .limit(8);
     /// ```
     pub fn sort_by<S: Into<String>>(&mut self, field: S, order: OrderBy) -> &mut Self {
-        self.sort = (field.into(), order.to_string());
+        self.sort.push((field.into(), order.to_string()));
         self
     }
 }
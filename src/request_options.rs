@@ -0,0 +1,204 @@
+//! Overrides of the client settings for some of its requests, see IGDBClient::with_options.
+//!
+//! A client serving both interactive and background traffic can e.g. fail the interactive
+//! requests fast without retrying them, and refresh the cached responses in the background.
+
+use crate::endpoint_client::ClientConfig;
+use crate::rate_limiter::Priority;
+use crate::retry::RetryPolicy;
+use crate::schema::SchemaMode;
+use std::time::Duration;
+
+///How a request uses the response cache of the client, see IGDBClient::with_cache
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CacheMode {
+    ///Answers from the cache when possible and caches the response
+    #[default]
+    Use,
+    ///Neither reads nor writes the cache
+    Bypass,
+    ///Always sends the request and caches the fresh response
+    Refresh,
+}
+
+///Settings overriding the ones of the client, unset ones being kept
+/// # Examples
+/// ```
+/// use igdb::request_options::{CacheMode, RequestOptions};
+/// use std::time::Duration;
+///
+/// let interactive = RequestOptions::new()
+///     .timeout(Duration::from_secs(2))
+///     .without_retries();
+/// let background = RequestOptions::new().cache(CacheMode::Refresh);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+    total_timeout: Option<Duration>,
+    retries: Option<RetryPolicy>,
+    cache: Option<CacheMode>,
+    schema_mode: Option<SchemaMode>,
+    priority: Option<Priority>,
+}
+
+impl RequestOptions {
+    pub fn new() -> RequestOptions {
+        RequestOptions::default()
+    }
+
+    ///Fails each attempt not answered within the given duration
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    ///Fails the requests not answered within the given duration, retries included
+    pub fn total_timeout(mut self, timeout: Duration) -> Self {
+        self.total_timeout = Some(timeout);
+        self
+    }
+
+    ///Retries like IGDBClient::with_retries
+    pub fn retries(mut self, max_attempts: u32, base_delay: Duration) -> Self {
+        self.retries = Some(RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+        });
+        self
+    }
+
+    ///Sends each request once, whatever IGDB answers
+    pub fn without_retries(self) -> Self {
+        self.retries(1, Duration::ZERO)
+    }
+
+    ///Sets how the response cache is used
+    pub fn cache(mut self, mode: CacheMode) -> Self {
+        self.cache = Some(mode);
+        self
+    }
+
+    ///Sets how the fields of the responses missing from the models are handled,
+    /// see IGDBClient::with_schema_mode
+    pub fn schema_mode(mut self, mode: SchemaMode) -> Self {
+        self.schema_mode = Some(mode);
+        self
+    }
+
+    ///Sets which requests the rate limiter serves first, see IGDBClient::with_priority
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    pub(crate) fn apply(&self, config: &mut ClientConfig) {
+        if let Some(timeout) = self.timeout {
+            config.timeout = Some(timeout);
+        }
+        if let Some(timeout) = self.total_timeout {
+            config.total_timeout = Some(timeout);
+        }
+        if let Some(retry) = self.retries {
+            config.retry = retry;
+        }
+        if let Some(mode) = self.cache {
+            config.cache_mode = mode;
+        }
+        if let Some(mode) = self.schema_mode {
+            config.schema_mode = mode;
+        }
+        if let Some(priority) = self.priority {
+            config.priority = Some(priority);
+        }
+    }
+}
+
+#[test]
+fn request_options_override_the_retries_and_cache() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+
+    let genres = r#"[{"id":5,"name":"Shooter"}]"#;
+    let scripted = ScriptedTransport::new().then(503, genres).then(200, genres);
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(scripted.clone())
+        .without_rate_limit()
+        .with_retries(3, Duration::from_millis(1))
+        .with_cache(Duration::from_secs(600), 10);
+    let request = IGDBClient::create_request();
+    let sent = || scripted.sent();
+
+    let once = igdb.with_options(&RequestOptions::new().without_retries());
+    let failed = async_std::task::block_on(once.query::<GenresClient>(&request));
+    assert!(matches!(failed, Err(crate::Error::Api { status: 503, .. })));
+    assert_eq!(1, sent());
+
+    let bypass = igdb.with_options(&RequestOptions::new().cache(CacheMode::Bypass));
+    async_std::task::block_on(bypass.query::<GenresClient>(&request)).unwrap();
+    assert_eq!(2, sent());
+    assert_eq!(0, igdb.cache_stats().unwrap().entries);
+
+    for _ in 0..2 {
+        async_std::task::block_on(igdb.query::<GenresClient>(&request)).unwrap();
+    }
+    assert_eq!(3, sent());
+
+    let refresh = igdb.with_options(&RequestOptions::new().cache(CacheMode::Refresh));
+    async_std::task::block_on(refresh.query::<GenresClient>(&request)).unwrap();
+    assert_eq!(4, sent());
+    assert_eq!(1, igdb.cache_stats().unwrap().entries);
+}
+
+#[test]
+fn priorities_follow_the_options_then_the_endpoints() {
+    use crate::client::{ExternalGameClient, GenresClient, IGDBClient};
+    use crate::middleware::{Middleware, Next};
+    use crate::mock::MockTransport;
+    use crate::rate_limiter::Priority;
+    use crate::transport::TransportFuture;
+    use std::sync::{Arc, Mutex};
+
+    ///Records the priority of each request
+    struct Record(Arc<Mutex<Vec<Option<Priority>>>>);
+
+    impl Middleware for Record {
+        fn handle<'a>(
+            &'a self,
+            request: http::Request<Vec<u8>>,
+            next: Next<'a>,
+        ) -> TransportFuture<'a> {
+            let priority = request.extensions().get::<Priority>().copied();
+            self.0.lock().unwrap().push(priority);
+            next.run(request)
+        }
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mock = MockTransport::new()
+        .with_fixture("genres", "fields name; limit 10;", "[]")
+        .with_fixture("external_games", "fields name; limit 10;", "[]");
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock)
+        .with_middleware(Record(seen.clone()))
+        .with_endpoint_priority::<ExternalGameClient>(Priority::Background);
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+
+    async_std::task::block_on(async {
+        igdb.query::<GenresClient>(&request).await.unwrap();
+        igdb.query::<ExternalGameClient>(&request).await.unwrap();
+        let ui = igdb.with_options(&RequestOptions::new().priority(Priority::Interactive));
+        ui.query::<ExternalGameClient>(&request).await.unwrap();
+        let crawler = igdb.with_priority(Priority::Background);
+        crawler.query::<GenresClient>(&request).await.unwrap();
+    });
+
+    let expected = [
+        Priority::Interactive,
+        Priority::Background,
+        Priority::Interactive,
+        Priority::Background,
+    ];
+    assert_eq!(expected.map(Some).to_vec(), *seen.lock().unwrap());
+}
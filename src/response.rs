@@ -0,0 +1,97 @@
+use crate::error::{from_json_value, DeserializeError};
+use http::header::HeaderMap;
+use http::StatusCode;
+use serde::de::DeserializeOwned;
+
+///Header carrying the total number of registries matching a query, whatever its limit
+const HEADER_COUNT: &str = "x-count";
+
+///Registries returned by a query along with the metadata of the response,
+/// see IGDBClient::query_with_meta
+#[derive(Debug)]
+pub struct Response<T> {
+    pub items: Vec<T>,
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub(crate) body: Vec<u8>,
+}
+
+///Registries returned by a query, the ones which could not be read into the model being
+/// set aside, see IGDBClient::query_lenient
+#[derive(Debug)]
+pub struct Lenient<T> {
+    pub registries: Vec<T>,
+    ///Why each registry set aside could not be read, with an excerpt of its json
+    pub invalid: Vec<DeserializeError>,
+}
+
+impl<T: DeserializeOwned> Lenient<T> {
+    ///Reads the registries returned by the given endpoint, setting aside the invalid ones
+    pub(crate) fn read(endpoint: &str, registries: &[serde_json::Value]) -> Lenient<T> {
+        let mut results = Lenient {
+            registries: Vec::with_capacity(registries.len()),
+            invalid: Vec::new(),
+        };
+        for (index, registry) in registries.iter().enumerate() {
+            match from_json_value(registry) {
+                Ok(registry) => results.registries.push(registry),
+                Err(mut e) => {
+                    e.endpoint = endpoint.to_owned();
+                    e.field = match e.field.is_empty() {
+                        true => format!("[{}]", index),
+                        false => format!("[{}].{}", index, e.field),
+                    };
+                    log::warn!("Skipping an invalid registry: {}", e);
+                    results.invalid.push(e);
+                }
+            }
+        }
+        results
+    }
+}
+
+impl<T> Response<T> {
+    ///Returns the total number of registries matching the query from the `x-count` header,
+    /// useful to paginate without an additional count request
+    pub fn total_count(&self) -> Option<u64> {
+        self.headers
+            .get(HEADER_COUNT)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    ///Returns the raw body of the response, as sent by IGDB
+    pub fn raw_body(&self) -> &[u8] {
+        &self.body
+    }
+
+    ///Consumes the response, keeping its registries only
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+#[test]
+fn total_count_is_read_from_the_x_count_header() {
+    let mut response = Response::<u64> {
+        items: vec![1, 2],
+        status: StatusCode::OK,
+        headers: HeaderMap::new(),
+        body: b"[1, 2]".to_vec(),
+    };
+    assert_eq!(None, response.total_count());
+    assert_eq!(b"[1, 2]", response.raw_body());
+
+    response
+        .headers
+        .insert(HEADER_COUNT, "1234".parse().unwrap());
+    assert_eq!(Some(1234), response.total_count());
+
+    response
+        .headers
+        .insert(HEADER_COUNT, "many".parse().unwrap());
+    assert_eq!(None, response.total_count());
+}
@@ -0,0 +1,445 @@
+//! Caching of the successful responses, see IGDBClient::with_cache.
+//!
+//! Responses are kept in memory by default, FileStore keeps them across runs
+//! and any other storage can be plugged by implementing CacheStore.
+//!
+//! Expired responses that came with an ETag are revalidated with If-None-Match,
+//! a 304 Not Modified answer keeps them for another time to live and counts as a hit.
+
+use crate::time::SystemTime;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+///Hit and miss counters of the response cache, see IGDBClient::with_cache
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    ///Responses currently cached, expired ones included until evicted
+    pub entries: usize,
+}
+
+///Identifies a cached response: the endpoint path it was requested from
+/// (e.g. `games` or `games/count`) and a hash of its url and query body
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub endpoint: String,
+    pub hash: u64,
+}
+
+impl CacheKey {
+    pub(crate) fn new(endpoint: &str, url: &str, query: &str) -> CacheKey {
+        CacheKey {
+            endpoint: endpoint.to_owned(),
+            hash: fnv1a(fnv1a(FNV_OFFSET, url.as_bytes()), query.as_bytes()),
+        }
+    }
+
+    ///The key of the same response requested by the given tenant, see IGDBClient::as_tenant
+    pub(crate) fn for_tenant(mut self, tenant: &str) -> CacheKey {
+        self.hash = fnv1a(fnv1a(self.hash, b"\n"), tenant.as_bytes());
+        self
+    }
+
+    ///Whether the response was requested from the given endpoint, its count included
+    fn is_from(&self, endpoint: &str) -> bool {
+        match self.endpoint.strip_prefix(endpoint) {
+            Some(rest) => rest.is_empty() || rest.starts_with('/'),
+            None => false,
+        }
+    }
+}
+
+pub(crate) const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+///Hash that stays the same across runs and rust versions, keys are persisted by FileStore
+/// and recorded fixtures are named after it
+pub(crate) fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+///A cached response body along with the validator IGDB answered with it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CachedResponse {
+    pub body: Vec<u8>,
+    ///ETag header of the response, sent back as If-None-Match once the response expired
+    pub etag: Option<String>,
+    pub stored_at: SystemTime,
+}
+
+///Stores the cached responses. Caching is best effort,
+/// a store failing to read or write a response just misses it.
+pub trait CacheStore: Send + Sync {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse>;
+    ///Stores the response, replacing the previous one
+    fn insert(&self, key: &CacheKey, response: &CachedResponse);
+    fn remove(&self, key: &CacheKey);
+    ///Removes the responses for which keep returns false
+    fn retain(&self, keep: &dyn Fn(&CacheKey, SystemTime) -> bool);
+    ///Returns the number of stored responses
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+///Keeps at most max_entries responses in memory, evicting the oldest one when full
+pub struct MemoryStore {
+    max_entries: usize,
+    entries: Mutex<HashMap<CacheKey, CachedResponse>>,
+}
+
+impl MemoryStore {
+    pub fn new(max_entries: usize) -> MemoryStore {
+        MemoryStore {
+            max_entries: max_entries.max(1),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn entries(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, CachedResponse>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        self.entries().get(key).cloned()
+    }
+
+    fn insert(&self, key: &CacheKey, response: &CachedResponse) {
+        let mut entries = self.entries();
+
+        if entries.len() >= self.max_entries && !entries.contains_key(key) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, cached)| cached.stored_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(key.clone(), response.clone());
+    }
+
+    fn remove(&self, key: &CacheKey) {
+        self.entries().remove(key);
+    }
+
+    fn retain(&self, keep: &dyn Fn(&CacheKey, SystemTime) -> bool) {
+        self.entries()
+            .retain(|key, cached| keep(key, cached.stored_at));
+    }
+
+    fn len(&self) -> usize {
+        self.entries().len()
+    }
+}
+
+///Keeps the responses in files under the given directory, one per response
+/// in a directory per endpoint, so that they are reused across runs.
+/// Their ETag is kept aside in a `.etag` file.
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use igdb::response_cache::FileStore;
+/// use std::time::Duration;
+///
+/// let igdb = IGDBClient::new("client_id", "token")
+///     .with_cache_store(Duration::from_secs(24 * 3600), FileStore::new(".igdb-cache"));
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileStore {
+    dir: std::path::PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileStore {
+    pub fn new<P: Into<std::path::PathBuf>>(dir: P) -> FileStore {
+        FileStore { dir: dir.into() }
+    }
+
+    fn path(&self, key: &CacheKey) -> std::path::PathBuf {
+        self.dir
+            .join(&key.endpoint)
+            .join(format!("{:016x}", key.hash))
+    }
+
+    ///Lists the stored responses, the endpoint of each being its directory
+    fn files(&self) -> Vec<(CacheKey, std::path::PathBuf)> {
+        let mut files = Vec::new();
+        let mut dirs = vec![self.dir.clone()];
+
+        while let Some(dir) = dirs.pop() {
+            for entry in std::fs::read_dir(&dir).into_iter().flatten().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                    continue;
+                }
+                let hash = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .and_then(|name| u64::from_str_radix(name, 16).ok());
+                let endpoint = dir
+                    .strip_prefix(&self.dir)
+                    .ok()
+                    .and_then(|endpoint| endpoint.to_str())
+                    .map(|endpoint| endpoint.replace(std::path::MAIN_SEPARATOR, "/"));
+                if let (Some(hash), Some(endpoint)) = (hash, endpoint) {
+                    files.push((CacheKey { endpoint, hash }, path));
+                }
+            }
+        }
+        files
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl CacheStore for FileStore {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let path = self.path(key);
+        let stored_at = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+        Some(CachedResponse {
+            body: std::fs::read(&path).ok()?,
+            etag: std::fs::read_to_string(path.with_extension("etag")).ok(),
+            stored_at,
+        })
+    }
+
+    ///The response is stored with the time it is written at
+    fn insert(&self, key: &CacheKey, response: &CachedResponse) {
+        let path = self.path(key);
+        let etag = path.with_extension("etag");
+        // Written aside then renamed, so that a concurrent get never reads a partial body
+        let partial = path.with_extension("partial");
+        let written = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| match &response.etag {
+                Some(value) => std::fs::write(&etag, value),
+                None => std::fs::remove_file(&etag).or(Ok(())),
+            })
+            .and_then(|_| std::fs::write(&partial, &response.body))
+            .and_then(|_| std::fs::rename(&partial, &path));
+
+        if let Err(e) = written {
+            log::warn!("Could not cache the response in {}: {}", path.display(), e);
+        }
+    }
+
+    fn remove(&self, key: &CacheKey) {
+        let path = self.path(key);
+        let _ = std::fs::remove_file(path.with_extension("etag"));
+        let _ = std::fs::remove_file(path);
+    }
+
+    fn retain(&self, keep: &dyn Fn(&CacheKey, SystemTime) -> bool) {
+        for (key, path) in self.files() {
+            let stored_at = std::fs::metadata(&path).and_then(|m| m.modified());
+            if !stored_at.is_ok_and(|stored_at| keep(&key, stored_at)) {
+                self.remove(&key);
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.files().len()
+    }
+}
+
+///Outcome of a ResponseCache lookup
+pub(crate) enum Lookup {
+    Fresh(Vec<u8>),
+    ///Expired but revalidable with its ETag
+    Stale(CachedResponse),
+    Missing,
+}
+
+///Answers the queries from the store while their response is younger than ttl
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    store: Box<dyn CacheStore>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ResponseCache {
+    pub(crate) fn new<S: CacheStore + 'static>(ttl: Duration, store: S) -> ResponseCache {
+        ResponseCache {
+            ttl,
+            store: Box::new(store),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    ///Looks the response to the given query up. Expired responses with an ETag
+    /// are returned to be revalidated, the others are discarded.
+    /// Hits and misses are counted once known, see record
+    pub(crate) fn lookup(&self, key: &CacheKey) -> Lookup {
+        match self.store.get(key) {
+            Some(cached) if age(cached.stored_at) < self.ttl => {
+                self.record(true);
+                Lookup::Fresh(cached.body)
+            }
+            Some(cached) if cached.etag.is_some() => Lookup::Stale(cached),
+            Some(_) => {
+                self.store.remove(key);
+                self.record(false);
+                Lookup::Missing
+            }
+            None => {
+                self.record(false);
+                Lookup::Missing
+            }
+        }
+    }
+
+    ///Counts whether a stale response was revalidated by IGDB or replaced
+    pub(crate) fn record(&self, hit: bool) {
+        match hit {
+            true => self.hits.fetch_add(1, Ordering::Relaxed),
+            false => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+
+    ///Caches a successful response along with its ETag
+    pub(crate) fn insert(&self, key: &CacheKey, response: &http::Response<Vec<u8>>) {
+        let etag = response
+            .headers()
+            .get(http::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_owned);
+        let cached = CachedResponse {
+            body: response.body().clone(),
+            etag,
+            stored_at: SystemTime::now(),
+        };
+        self.store.insert(key, &cached);
+    }
+
+    ///Keeps a stale response IGDB answered 304 Not Modified for, for another ttl
+    pub(crate) fn revalidated(&self, key: &CacheKey, mut cached: CachedResponse) -> Vec<u8> {
+        self.record(true);
+        cached.stored_at = SystemTime::now();
+        self.store.insert(key, &cached);
+        cached.body
+    }
+
+    ///Removes the responses requested from the given endpoint, or all of them when None,
+    /// and only those older than the given age when set
+    pub(crate) fn invalidate(&self, endpoint: Option<&str>, older_than: Option<Duration>) {
+        self.store.retain(&|key, stored_at| {
+            let matches = endpoint.is_none_or(|endpoint| key.is_from(endpoint))
+                && older_than.is_none_or(|older_than| age(stored_at) >= older_than);
+            !matches
+        });
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.store.len(),
+        }
+    }
+}
+
+fn age(stored_at: SystemTime) -> Duration {
+    SystemTime::now()
+        .duration_since(stored_at)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+fn fresh(cache: &ResponseCache, key: &CacheKey) -> Option<Vec<u8>> {
+    match cache.lookup(key) {
+        Lookup::Fresh(body) => Some(body),
+        _ => None,
+    }
+}
+
+#[test]
+fn response_cache_expires_and_evicts_entries() {
+    let cache = ResponseCache::new(Duration::from_secs(60), MemoryStore::new(2));
+    let response = |body: &[u8]| http::Response::new(body.to_vec());
+    let games = "https://api.igdb.com/v4/games";
+    let key = |query: &str| CacheKey::new("games", games, query);
+
+    assert_eq!(None, fresh(&cache, &key("fields name; limit 1;")));
+    cache.insert(&key("fields name; limit 1;"), &response(b"[1]"));
+    cache.insert(&key("fields name; limit 2;"), &response(b"[2]"));
+    assert_eq!(
+        Some(b"[1]".to_vec()),
+        fresh(&cache, &key("fields name; limit 1;"))
+    );
+    assert_eq!(
+        None,
+        fresh(
+            &cache,
+            &CacheKey::new(
+                "genres",
+                "https://api.igdb.com/v4/genres",
+                "fields name; limit 1;"
+            )
+        )
+    );
+
+    // The oldest response is evicted once full
+    cache.insert(&key("fields name; limit 3;"), &response(b"[3]"));
+    assert_eq!(None, fresh(&cache, &key("fields name; limit 1;")));
+    assert_eq!(
+        Some(b"[3]".to_vec()),
+        fresh(&cache, &key("fields name; limit 3;"))
+    );
+    assert_eq!(
+        CacheStats {
+            hits: 2,
+            misses: 3,
+            entries: 2
+        },
+        cache.stats()
+    );
+
+    let expired = ResponseCache::new(Duration::ZERO, MemoryStore::new(2));
+    expired.insert(&key("fields name;"), &response(b"[]"));
+    assert_eq!(None, fresh(&expired, &key("fields name;")));
+    assert_eq!(0, expired.stats().entries);
+}
+
+#[test]
+fn file_store_keeps_responses_across_clients_and_invalidates_by_endpoint() {
+    let dir = std::env::temp_dir().join(format!("igdb-cache-test-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let games = CacheKey::new("games", "https://api.igdb.com/v4/games", "fields name;");
+    let count = CacheKey::new("games/count", "https://api.igdb.com/v4/games/count", "");
+    let genres = CacheKey::new("genres", "https://api.igdb.com/v4/genres", "fields name;");
+
+    let cache = ResponseCache::new(Duration::from_secs(60), FileStore::new(&dir));
+    cache.insert(&games, &http::Response::new(b"[1942]".to_vec()));
+    cache.insert(&count, &http::Response::new(br#"{"count":1}"#.to_vec()));
+    cache.insert(&genres, &http::Response::new(b"[5]".to_vec()));
+
+    let reopened = ResponseCache::new(Duration::from_secs(60), FileStore::new(&dir));
+    assert_eq!(3, reopened.stats().entries);
+    assert_eq!(Some(b"[1942]".to_vec()), fresh(&reopened, &games));
+
+    reopened.invalidate(Some("games"), None);
+    assert_eq!(None, fresh(&reopened, &games));
+    assert_eq!(None, fresh(&reopened, &count));
+    assert_eq!(Some(b"[5]".to_vec()), fresh(&reopened, &genres));
+
+    reopened.invalidate(None, Some(Duration::from_secs(3600)));
+    assert_eq!(1, reopened.stats().entries);
+    reopened.invalidate(None, None);
+    assert_eq!(0, reopened.stats().entries);
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,120 @@
+use crate::time::SystemTime;
+use http::header::{HeaderMap, RETRY_AFTER};
+use http::StatusCode;
+use std::time::Duration;
+
+///Longest delay waited between two attempts, whatever the attempt number
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+///How requests answered with 429 Too Many Requests or a transient 5xx status are retried
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RetryPolicy {
+    ///Total number of attempts, 1 disables retries
+    pub(crate) max_attempts: u32,
+    ///Delay before the first retry, doubled for each following one
+    pub(crate) base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    ///Returns how long to wait before the next attempt if the request should be retried
+    pub(crate) fn delay(
+        &self,
+        attempt: u32,
+        status: StatusCode,
+        headers: &HeaderMap,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !is_transient(status) {
+            return None;
+        }
+
+        match retry_after(headers) {
+            Some(delay) => Some(delay.min(MAX_DELAY)),
+            None => Some(self.backoff(attempt, jitter())),
+        }
+    }
+
+    ///Exponential backoff for the given attempt (starting at 1), the jitter between 0 and 1
+    /// spreads the delay between its half and its full value
+    fn backoff(&self, attempt: u32, jitter: f64) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(MAX_DELAY);
+
+        exponential / 2 + exponential.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+///Reads the Retry-After header when given in seconds
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+///Cheap pseudo random number between 0 and 1, good enough to spread retries
+fn jitter() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|now| now.subsec_nanos())
+        .unwrap_or_default();
+    f64::from(nanos % 1000) / 1000.0
+}
+
+#[test]
+fn backoff_doubles_each_attempt_and_is_capped() {
+    let policy = RetryPolicy {
+        max_attempts: 10,
+        base_delay: Duration::from_millis(100),
+    };
+
+    assert_eq!(Duration::from_millis(50), policy.backoff(1, 0.0));
+    assert_eq!(Duration::from_millis(100), policy.backoff(1, 1.0));
+    assert_eq!(Duration::from_millis(400), policy.backoff(3, 1.0));
+    assert_eq!(MAX_DELAY, policy.backoff(20, 1.0));
+}
+
+#[test]
+fn only_transient_statuses_are_retried_within_max_attempts() {
+    let policy = RetryPolicy::default();
+    let mut headers = HeaderMap::new();
+
+    assert!(policy
+        .delay(1, StatusCode::TOO_MANY_REQUESTS, &headers)
+        .is_some());
+    assert!(policy.delay(2, StatusCode::BAD_GATEWAY, &headers).is_some());
+    assert!(policy.delay(3, StatusCode::BAD_GATEWAY, &headers).is_none());
+    assert!(policy.delay(1, StatusCode::BAD_REQUEST, &headers).is_none());
+    assert!(policy.delay(1, StatusCode::OK, &headers).is_none());
+
+    headers.insert(RETRY_AFTER, "7".parse().unwrap());
+    assert_eq!(
+        Some(Duration::from_secs(7)),
+        policy.delay(1, StatusCode::TOO_MANY_REQUESTS, &headers)
+    );
+}
@@ -0,0 +1,166 @@
+//! Detection of the fields IGDB answers but the models of this crate do not know,
+//! see IGDBClient::with_schema_mode.
+//!
+//! IGDB adds and renames fields from time to time. The models ignore the unknown ones, which
+//! keeps the queries working but hides the new fields. Reporting them, or failing on them in
+//! the tests of an application, tells when the models need to follow.
+
+use crate::error::{from_json_slice, DeserializeError};
+use crate::Error;
+use serde::de::DeserializeOwned;
+use serde_ignored::Path;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+///How the fields of the responses missing from the models are handled
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SchemaMode {
+    ///Ignores them silently
+    #[default]
+    Ignore,
+    ///Records them, see IGDBClient::schema_report
+    Report,
+    ///Records them and fails the query, like `#[serde(deny_unknown_fields)]` would
+    Strict,
+}
+
+///The fields of the responses missing from the models, by endpoint, see IGDBClient::schema_report
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SchemaReport {
+    ///Paths of the unknown fields within the registries, e.g. `cover.new_field`
+    pub unknown_fields: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl SchemaReport {
+    ///Whether every field answered is known to the models
+    pub fn is_empty(&self) -> bool {
+        self.unknown_fields.is_empty()
+    }
+}
+
+///Unknown fields recorded by every endpoint client of an IGDBClient
+#[derive(Default)]
+pub(crate) struct UnknownFields(Mutex<SchemaReport>);
+
+impl UnknownFields {
+    pub(crate) fn report(&self) -> SchemaReport {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+///Reads a json response body like from_json_slice, recording the fields T does not know
+/// and failing on them in strict mode
+pub(crate) fn from_json_slice_checked<T: DeserializeOwned>(
+    body: &[u8],
+    endpoint: &str,
+    mode: SchemaMode,
+    unknown_fields: &UnknownFields,
+) -> Result<T, Error> {
+    let mut unknown = Vec::new();
+    let mut record = |path: Path<'_>| {
+        let mut index = None;
+        unknown.push((field(&path, &mut index), index));
+    };
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let value = serde_ignored::deserialize(&mut deserializer, &mut record)
+        .and_then(|value: T| deserializer.end().map(|()| value));
+    let value = match value {
+        Ok(value) => value,
+        // Parsed again, to tell where it failed
+        Err(_) => return from_json_slice(body),
+    };
+
+    if let Some((first, index)) = unknown.first().cloned() {
+        log::warn!("Unknown fields in the {} response: {:?}", endpoint, unknown);
+        let mut report = unknown_fields.0.lock().unwrap();
+        let fields = report
+            .unknown_fields
+            .entry(endpoint.to_owned())
+            .or_default();
+        fields.extend(unknown.into_iter().map(|(field, _)| field));
+
+        if mode == SchemaMode::Strict {
+            let registry = serde_json::from_slice::<serde_json::Value>(body).ok();
+            let registry = match (&registry, index) {
+                (Some(body), Some(index)) => body.get(index),
+                (body, _) => body.as_ref(),
+            };
+            return Err(DeserializeError {
+                endpoint: endpoint.to_owned(),
+                field: first.clone(),
+                excerpt: registry.map(crate::error::excerpt).unwrap_or_default(),
+                source: serde::de::Error::custom(format!("unknown field `{}`", first)),
+            }
+            .into());
+        }
+    }
+    Ok(value)
+}
+
+///The path of the field within its registry, the index of the registry within the
+/// response being set aside
+fn field(path: &Path<'_>, index: &mut Option<usize>) -> String {
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, index: i } => {
+            let parent = field(parent, index);
+            if parent.is_empty() && index.is_none() {
+                *index = Some(*i);
+            }
+            parent
+        }
+        Path::Map { parent, key } => match field(parent, index) {
+            parent if parent.is_empty() => key.clone(),
+            parent => format!("{}.{}", parent, key),
+        },
+        Path::Some { parent }
+        | Path::NewtypeStruct { parent }
+        | Path::NewtypeVariant { parent } => field(parent, index),
+    }
+}
+
+#[test]
+fn unknown_fields_are_reported_or_rejected() {
+    use crate::client::{GamesClient, IGDBClient};
+    use crate::mock::MockTransport;
+    use crate::request_options::RequestOptions;
+
+    let mock = MockTransport::new().with_fixture(
+        "games",
+        "fields name,cover.*; limit 10;",
+        r#"[
+            {"id": 1, "name": "Celeste"},
+            {"id": 2, "name": "Hades", "new_field": 1, "cover": {"id": 3, "new_cover_field": true}}
+        ]"#,
+    );
+    let mut request = IGDBClient::create_request();
+    request.add_fields(["name", "cover.*"]);
+
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock.clone())
+        .without_rate_limit();
+    async_std::task::block_on(igdb.query::<GamesClient>(&request)).unwrap();
+    assert!(igdb.schema_report().is_empty());
+
+    let reporting = igdb.with_options(&RequestOptions::new().schema_mode(SchemaMode::Report));
+    let games = async_std::task::block_on(reporting.query::<GamesClient>(&request)).unwrap();
+    assert_eq!(2, games.len());
+    let expected: BTreeSet<String> = ["cover.new_cover_field", "new_field"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    assert_eq!(
+        Some(&expected),
+        reporting.schema_report().unknown_fields.get("games")
+    );
+
+    let strict = igdb.with_schema_mode(SchemaMode::Strict);
+    assert!(!strict.schema_report().is_empty());
+    match async_std::task::block_on(strict.query::<GamesClient>(&request)) {
+        Err(Error::Deserialize(e)) => {
+            assert_eq!("new_field", e.field);
+            assert!(e.excerpt.starts_with(r#"{"cover":{"id":3"#));
+        }
+        other => panic!("unexpected result: {:?}", other.map(|games| games.len())),
+    }
+}
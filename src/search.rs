@@ -0,0 +1,130 @@
+use crate::model::character::Character;
+use crate::model::collection::Collection;
+use crate::model::company::Company;
+use crate::model::games::Game;
+use crate::model::platform::Platform;
+use crate::model::theme::Theme;
+use crate::request_builder::RequestBuilder;
+
+pub(crate) const SEARCH_PATH: &str = "search";
+
+///The entities referenced by a search result, expanded with all their fields
+const SEARCH_FIELDS: [&str; 7] = [
+    "*",
+    "character.*",
+    "collection.*",
+    "company.*",
+    "game.*",
+    "platform.*",
+    "theme.*",
+];
+
+///A result of the search endpoint, which searches several entity types at once
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use igdb::client::IGDBClient;
+/// use igdb::search::SearchResult;
+///
+/// task::block_on(async {
+///     let igdb = IGDBClient::new("client_id", "token");
+///     for result in igdb.search_all("Witcher").await.unwrap() {
+///         match result {
+///             SearchResult::Game(game) => println!("Game: {}", game.name),
+///             SearchResult::Character(character) => println!("Character: {}", character.name),
+///             other => println!("{}", other.name()),
+///         }
+///     }
+/// })
+/// ```
+#[derive(Debug, Clone)]
+pub enum SearchResult {
+    Character(Character),
+    Collection(Collection),
+    Company(Company),
+    Game(Box<Game>),
+    Platform(Platform),
+    Theme(Theme),
+    ///A result of an entity type this crate does not know, only its name is available
+    Other(String),
+}
+
+impl SearchResult {
+    ///The name of the found entity
+    pub fn name(&self) -> &str {
+        match self {
+            SearchResult::Character(character) => &character.name,
+            SearchResult::Collection(collection) => &collection.name,
+            SearchResult::Company(company) => &company.name,
+            SearchResult::Game(game) => &game.name,
+            SearchResult::Platform(platform) => &platform.name,
+            SearchResult::Theme(theme) => &theme.name,
+            SearchResult::Other(name) => name,
+        }
+    }
+}
+
+///A registry of the search endpoint, at most one of the entities is set
+#[derive(Deserialize, Debug)]
+pub(crate) struct Search {
+    #[serde(default)]
+    name: String,
+    character: Option<Character>,
+    collection: Option<Collection>,
+    company: Option<Company>,
+    game: Option<Game>,
+    platform: Option<Platform>,
+    theme: Option<Theme>,
+}
+
+impl From<Search> for SearchResult {
+    fn from(search: Search) -> Self {
+        if let Some(character) = search.character {
+            SearchResult::Character(character)
+        } else if let Some(collection) = search.collection {
+            SearchResult::Collection(collection)
+        } else if let Some(company) = search.company {
+            SearchResult::Company(company)
+        } else if let Some(game) = search.game {
+            SearchResult::Game(Box::new(game))
+        } else if let Some(platform) = search.platform {
+            SearchResult::Platform(platform)
+        } else if let Some(theme) = search.theme {
+            SearchResult::Theme(theme)
+        } else {
+            SearchResult::Other(search.name)
+        }
+    }
+}
+
+///Builds the request searching the given term in every entity type
+pub(crate) fn search_request(term: &str, limit: usize) -> RequestBuilder {
+    let mut request = RequestBuilder::new();
+    request.add_fields(SEARCH_FIELDS).search(term).limit(limit);
+    request
+}
+
+#[test]
+fn search_request_expands_every_entity() {
+    assert_eq!(
+        "fields *,character.*,collection.*,company.*,game.*,platform.*,theme.*; search \"Witcher\"; limit 50;",
+        search_request("Witcher", 50).build_body()
+    );
+}
+
+#[test]
+fn search_results_are_tagged_by_entity() {
+    let results: Vec<Search> = serde_json::from_str(
+        r#"[
+            {"id": 1, "name": "The Witcher 3", "game": {"id": 1942, "name": "The Witcher 3"}},
+            {"id": 2, "name": "Geralt", "character": {"id": 3, "name": "Geralt"}},
+            {"id": 3, "name": "Unknown"}
+        ]"#,
+    )
+    .unwrap();
+
+    let results: Vec<SearchResult> = results.into_iter().map(SearchResult::from).collect();
+    assert!(matches!(&results[0], SearchResult::Game(game) if game.id.0 == 1942));
+    assert!(matches!(&results[1], SearchResult::Character(character) if character.id.0 == 3));
+    assert_eq!("Unknown", results[2].name());
+}
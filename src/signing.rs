@@ -0,0 +1,136 @@
+//! Signing the requests for gateways fronting IGDB, see IGDBClient::with_request_signer.
+//!
+//! The signer runs on each attempt right before it is handed to the transport, after the
+//! middlewares, retries and rate limiter, so every retry is signed with a fresh timestamp
+//! and the body signed is the one sent, compressed or not.
+
+use crate::time::SystemTime;
+use crate::transport::TransportError;
+use http::{HeaderMap, Method};
+use std::time::Duration;
+
+///What a signature may cover
+#[derive(Debug, Clone, Copy)]
+pub struct SigningRequest<'a> {
+    pub method: &'a Method,
+    ///Path of the request, e.g. `/v4/games`
+    pub path: &'a str,
+    ///Query of the url, empty without one
+    pub query: &'a str,
+    pub body: &'a [u8],
+    ///Time since the unix epoch the request is sent at
+    pub timestamp: Duration,
+}
+
+///Adds headers signing the requests, e.g. an HMAC of the body and timestamp
+/// # Examples
+/// ```
+/// use http::HeaderMap;
+/// use igdb::client::IGDBClient;
+/// use igdb::signing::{RequestSigner, SigningRequest};
+/// use igdb::transport::TransportError;
+///
+/// struct Gateway {
+///     key_id: String,
+/// }
+///
+/// impl RequestSigner for Gateway {
+///     fn sign(&self, request: &SigningRequest<'_>, headers: &mut HeaderMap) -> Result<(), TransportError> {
+///         let timestamp = request.timestamp.as_secs().to_string();
+///         headers.insert("x-key-id", self.key_id.parse()?);
+///         headers.insert("x-timestamp", timestamp.parse()?);
+///         // e.g. an hmac of the method, path, timestamp and body
+///         Ok(())
+///     }
+/// }
+///
+/// let igdb = IGDBClient::new("client_id", "token")
+///     .with_base_url("https://gateway.internal/igdb")
+///     .with_request_signer(Gateway { key_id: "importer".to_owned() });
+/// ```
+pub trait RequestSigner: Send + Sync {
+    ///Inserts the signature headers, failing the attempt on error
+    fn sign(
+        &self,
+        request: &SigningRequest<'_>,
+        headers: &mut HeaderMap,
+    ) -> Result<(), TransportError>;
+}
+
+///Functions taking the request and its headers are signers
+impl<F> RequestSigner for F
+where
+    F: Fn(&SigningRequest<'_>, &mut HeaderMap) -> Result<(), TransportError> + Send + Sync,
+{
+    fn sign(
+        &self,
+        request: &SigningRequest<'_>,
+        headers: &mut HeaderMap,
+    ) -> Result<(), TransportError> {
+        self(request, headers)
+    }
+}
+
+///Signs the request as it is about to be sent
+pub(crate) fn sign(
+    signer: &dyn RequestSigner,
+    request: &mut http::Request<Vec<u8>>,
+) -> Result<(), TransportError> {
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default();
+    let mut headers = HeaderMap::new();
+    signer.sign(
+        &SigningRequest {
+            method: request.method(),
+            path: request.uri().path(),
+            query: request.uri().query().unwrap_or_default(),
+            body: request.body(),
+            timestamp,
+        },
+        &mut headers,
+    )?;
+    request.headers_mut().extend(headers);
+    Ok(())
+}
+
+#[test]
+fn every_attempt_is_signed_right_before_it_is_sent() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+    use crate::transport::TransportFuture;
+
+    ///Changes the body after the signer would have seen it, were it a middleware
+    fn pad(
+        mut request: http::Request<Vec<u8>>,
+        next: crate::middleware::Next<'_>,
+    ) -> TransportFuture<'_> {
+        request.body_mut().extend_from_slice(b" ");
+        next.run(request)
+    }
+
+    let gateway = ScriptedTransport::new().then(503, "[]").then(200, "[]");
+    let signer =
+        |request: &SigningRequest<'_>, headers: &mut HeaderMap| -> Result<(), TransportError> {
+            let signature = format!("{} {} {}", request.method, request.path, request.body.len());
+            headers.insert("x-signature", signature.parse()?);
+            Ok(())
+        };
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(gateway.clone())
+        .without_rate_limit()
+        .with_retries(2, Duration::from_millis(1))
+        .with_middleware(pad)
+        .with_request_signer(signer);
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+
+    async_std::task::block_on(igdb.query::<GenresClient>(&request)).unwrap();
+    assert_eq!(
+        vec!["POST /v4/genres 23", "POST /v4/genres 23"],
+        gateway.map(|request| request.headers()["x-signature"]
+            .to_str()
+            .unwrap()
+            .to_owned())
+    );
+}
@@ -0,0 +1,102 @@
+//! Coalescing of identical queries sent concurrently, see IGDBClient::with_single_flight.
+
+use futures::channel::oneshot;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+type Waiters = Vec<oneshot::Sender<Vec<u8>>>;
+
+///The queries in flight, by tenant, url and body, along with the callers waiting for their response
+#[derive(Default)]
+pub(crate) struct SingleFlight {
+    in_flight: Mutex<HashMap<String, Waiters>>,
+}
+
+///Whether the caller sends the query or waits for the identical one in flight
+pub(crate) enum Role<'a> {
+    Leader(Flight<'a>),
+    Follower(oneshot::Receiver<Vec<u8>>),
+}
+
+impl SingleFlight {
+    ///Joins the identical query in flight for the same tenant, if any
+    pub(crate) fn join(&self, tenant: Option<&str>, url: &str, body: &str) -> Role<'_> {
+        let key = format!("{}\n{}\n{}", tenant.unwrap_or_default(), url, body);
+        let mut in_flight = self.in_flight.lock().unwrap();
+        match in_flight.get_mut(&key) {
+            Some(waiters) => {
+                let (sender, receiver) = oneshot::channel();
+                waiters.push(sender);
+                Role::Follower(receiver)
+            }
+            None => {
+                in_flight.insert(key.clone(), Vec::new());
+                Role::Leader(Flight { flights: self, key })
+            }
+        }
+    }
+}
+
+///A query sent on behalf of the callers waiting for it. Dropping it without a response,
+/// on failure or cancellation, lets them send the query on their own.
+pub(crate) struct Flight<'a> {
+    flights: &'a SingleFlight,
+    key: String,
+}
+
+impl Flight<'_> {
+    ///Hands the body of the successful response to the waiting callers, and returns it
+    pub(crate) fn land(self, body: Vec<u8>) -> Vec<u8> {
+        for waiter in self.waiters() {
+            let _ = waiter.send(body.clone());
+        }
+        body
+    }
+
+    fn waiters(&self) -> Waiters {
+        let mut in_flight = self.flights.in_flight.lock().unwrap();
+        in_flight.remove(&self.key).unwrap_or_default()
+    }
+}
+
+impl Drop for Flight<'_> {
+    fn drop(&mut self) {
+        // Already removed once landed
+        drop(self.waiters());
+    }
+}
+
+#[test]
+fn identical_queries_in_flight_are_sent_once() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+    use std::time::Duration;
+
+    // Answers slowly, failing the first request
+    let genres = r#"[{"id": 1, "name": "Shooter"}]"#;
+    let slow = Duration::from_millis(50);
+    let scripted = ScriptedTransport::new()
+        .then(400, genres)
+        .after(slow)
+        .then(200, genres)
+        .after(slow);
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(scripted.clone())
+        .without_rate_limit()
+        .with_single_flight();
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+    let query = || igdb.query::<GenresClient>(&request);
+
+    // The failure of the first query is not shared, the next caller sends the query again
+    let (first, second, third) =
+        async_std::task::block_on(async { futures::join!(query(), query(), query()) });
+    assert!(first.is_err());
+    assert_eq!("Shooter", second.unwrap()[0].name);
+    assert_eq!("Shooter", third.unwrap()[0].name);
+    assert_eq!(2, scripted.sent());
+
+    let (first, second) = async_std::task::block_on(async { futures::join!(query(), query()) });
+    assert!(first.is_ok() && second.is_ok());
+    assert_eq!(3, scripted.sent());
+}
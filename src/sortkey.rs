@@ -0,0 +1,147 @@
+//! Sorting of game titles in the order people expect, which IGDB cannot sort by,
+//! see title_key and Pipeline::sort_by_title.
+//!
+//! Sorted as strings, `Doom 10` comes before `Doom 2`, `Final Fantasy IX` before
+//! `Final Fantasy V`, every title starting with `The` is grouped under T, and the editions of
+//! a game are scattered among the games sharing the start of its title.
+
+use crate::title_match::{split_edition, NUMERALS};
+use std::cmp::Ordering;
+
+///Articles ignored at the start of a title
+const ARTICLES: [&str; 3] = ["the", "a", "an"];
+///Sorts Final Fantasy X after IX, unlike title matching where it may be a letter
+const TEN: [(&str, &str); 1] = [("x", "10")];
+
+///A word, or the part of a word made of digits or of letters only
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Part {
+    ///Numbers sort before words, by value
+    Number(u64),
+    Word(String),
+}
+
+///Sort key of a title, see title_key
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TitleKey {
+    parts: Vec<Part>,
+    ///Edition names, sorting the editions of a game right after it
+    edition: Vec<Part>,
+    ///The title as given, ordering the titles read the same
+    title: String,
+}
+
+///Returns the key sorting titles in the expected order: case and punctuation ignored
+/// along with a leading `The`, `A` or `An`, numbers compared by value, roman numerals
+/// following the first word read as numbers, and editions, e.g. `Game of the Year Edition`,
+/// right after the game
+/// # Examples
+/// ```
+/// use igdb::sortkey::title_key;
+///
+/// let mut titles = vec![
+///     "Final Fantasy X",
+///     "The Final Fantasy Legend",
+///     "Final Fantasy IX",
+///     "Final Fantasy VII: Remake",
+///     "Final Fantasy VII - Deluxe Edition",
+///     "Final Fantasy VII",
+/// ];
+/// titles.sort_by_cached_key(|title| title_key(title));
+/// assert_eq!(
+///     vec![
+///         "Final Fantasy VII",
+///         "Final Fantasy VII - Deluxe Edition",
+///         "Final Fantasy VII: Remake",
+///         "Final Fantasy IX",
+///         "Final Fantasy X",
+///         "The Final Fantasy Legend",
+///     ],
+///     titles
+/// );
+/// ```
+pub fn title_key(title: &str) -> TitleKey {
+    let (mut words, edition) = split_edition(title);
+    if words.len() > 1 && ARTICLES.contains(&words[0].as_str()) {
+        words.remove(0);
+    }
+    TitleKey {
+        parts: parts(&words, 0),
+        edition: parts(&edition, words.len()),
+        title: title.to_owned(),
+    }
+}
+
+///Compares two titles by their title_key
+pub fn compare_titles(a: &str, b: &str) -> Ordering {
+    title_key(a).cmp(&title_key(b))
+}
+
+///Splits the words at the boundaries of digits and letters, `first` being the position
+/// of the first word in the title
+fn parts(words: &[String], first: usize) -> Vec<Part> {
+    let mut parts = Vec::with_capacity(words.len());
+    for (position, word) in (first..).zip(words) {
+        let numeral = NUMERALS
+            .iter()
+            .chain(&TEN)
+            .find(|(numeral, _)| numeral == word);
+        match numeral {
+            // A first word like `X` or `V` is a title rather than a number
+            Some((_, digits)) if position > 0 => parts.push(Part::Number(digits.parse().unwrap())),
+            _ => {
+                let mut run = String::new();
+                for c in word.chars() {
+                    if run
+                        .ends_with(|previous: char| previous.is_ascii_digit() != c.is_ascii_digit())
+                    {
+                        parts.push(part(&run));
+                        run.clear();
+                    }
+                    run.push(c);
+                }
+                parts.push(part(&run));
+            }
+        }
+    }
+    parts
+}
+
+fn part(text: &str) -> Part {
+    match text.parse() {
+        Ok(number) => Part::Number(number),
+        Err(_) => Part::Word(text.to_owned()),
+    }
+}
+
+#[test]
+fn titles_sort_by_number_value_and_without_article() {
+    let mut titles = vec![
+        "Doom 64",
+        "The Doom 3: BFG Edition",
+        "DOOM II",
+        "Doom Eternal",
+        "Doom 3",
+        "Doom",
+        "Doom 10",
+        "quake2",
+        "Quake",
+        "X",
+    ];
+    titles.sort_by(|a, b| compare_titles(a, b));
+    assert_eq!(
+        vec![
+            "Doom",
+            "DOOM II",
+            "Doom 3",
+            "The Doom 3: BFG Edition",
+            "Doom 10",
+            "Doom 64",
+            "Doom Eternal",
+            "Quake",
+            "quake2",
+            "X",
+        ],
+        titles
+    );
+}
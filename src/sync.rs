@@ -0,0 +1,344 @@
+//! Walks a whole endpoint, or the registries updated since a previous walk, to build
+//! and refresh local mirrors of IGDB, see IGDBClient::sync and IGDBClient::changes_since.
+
+use crate::endpoint_client::{EndpointClient, MAX_LIMIT};
+use crate::model::id_or::Identifiable;
+use crate::model::ids::EntityId;
+use crate::request_builder::{Equality, OrderBy, RequestBuilder};
+use crate::time::SystemTime;
+use crate::Error;
+use futures::{stream, Stream};
+use serde::de::DeserializeOwned;
+
+///Position of a sync, stored after each page so an interrupted sync resumes where it stopped.
+/// Registries updated at or after `since` are walked by ascending id, `last_id` being the
+/// last one already handed over.
+/// # Examples
+/// ```
+/// use igdb::sync::Checkpoint;
+///
+/// let saved = serde_json::to_string(&Checkpoint::since(1_600_000_000)).unwrap();
+/// let resumed: Checkpoint = serde_json::from_str(&saved).unwrap();
+/// assert_eq!(Checkpoint::since(1_600_000_000), resumed);
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Checkpoint {
+    pub since: u64,
+    pub last_id: u64,
+    ///Unix timestamp the sync started at, 0 until its first page is requested
+    pub started_at: u64,
+}
+
+impl Checkpoint {
+    ///Walks the registries updated at or after the given unix timestamp,
+    /// the default checkpoint walks the whole endpoint
+    pub fn since(updated_at: u64) -> Checkpoint {
+        Checkpoint {
+            since: updated_at,
+            ..Checkpoint::default()
+        }
+    }
+
+    ///The checkpoint of the next sync once this one is complete, walking the
+    /// registries updated since this one started
+    fn next(&self) -> Checkpoint {
+        Checkpoint::since(self.started_at)
+    }
+}
+
+///A page of registries of a sync and the checkpoint to store once it is handled
+#[derive(Debug, Clone)]
+pub struct SyncPage<T> {
+    pub registries: Vec<T>,
+    pub checkpoint: Checkpoint,
+    ///Whether this is the last page, its checkpoint then starts the next sync
+    pub complete: bool,
+}
+
+///Requests the pages of MAX_LIMIT registries following the checkpoint until exhausted.
+/// The last page, possibly empty, carries the checkpoint of the next sync.
+pub(crate) fn sync<T: DeserializeOwned + Identifiable>(
+    client: EndpointClient,
+    request: RequestBuilder,
+    checkpoint: Checkpoint,
+) -> impl Stream<Item = Result<SyncPage<T>, Error>> {
+    let checkpoint = match checkpoint.started_at {
+        0 => Checkpoint {
+            started_at: now(),
+            ..checkpoint
+        },
+        _ => checkpoint,
+    };
+
+    stream::try_unfold(
+        (client, request, Some(checkpoint)),
+        |(client, request, checkpoint)| async move {
+            let checkpoint = match checkpoint {
+                Some(checkpoint) => checkpoint,
+                None => return Ok::<_, Error>(None),
+            };
+
+            let registries = client
+                .get::<T>(&page_request(&request, &checkpoint))
+                .await?;
+            let page = match registries.last() {
+                Some(last) if registries.len() == MAX_LIMIT => SyncPage {
+                    checkpoint: Checkpoint {
+                        last_id: last.id().value(),
+                        ..checkpoint
+                    },
+                    registries,
+                    complete: false,
+                },
+                _ => SyncPage {
+                    checkpoint: checkpoint.next(),
+                    registries,
+                    complete: true,
+                },
+            };
+            let next = match page.complete {
+                true => None,
+                false => Some(page.checkpoint),
+            };
+
+            Ok(Some((page, (client, request, next))))
+        },
+    )
+}
+
+///The request filters, restricted to the registries following the checkpoint
+fn page_request(request: &RequestBuilder, checkpoint: &Checkpoint) -> RequestBuilder {
+    let mut page = RequestBuilder::new();
+    page.add_where("updated_at", Equality::GreaterOrEqual, checkpoint.since)
+        .add_where("id", Equality::Greater, checkpoint.last_id)
+        .merge(request);
+    page.sort.clear();
+    page.sort_by("id", OrderBy::Ascending)
+        .limit(MAX_LIMIT)
+        .offset(0);
+    page
+}
+
+///Position of a delta sync: every registry updated before `updated_at` was returned,
+/// as well as the ones updated at `updated_at` with an id up to `id`.
+/// Store it along the mirror, the default state returns the whole endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncState {
+    pub updated_at: u64,
+    pub id: u64,
+}
+
+///Registries changed since a SyncState and the state following them,
+/// see IGDBClient::changes_since
+#[derive(Debug, Clone)]
+pub struct Changes<T> {
+    pub registries: Vec<T>,
+    pub state: SyncState,
+}
+
+impl<T> Changes<T> {
+    ///Whether no registry changed since the previous state, the mirror is then up to date
+    pub fn is_empty(&self) -> bool {
+        self.registries.is_empty()
+    }
+}
+
+///A registry with the fields its position is made of
+struct Row {
+    updated_at: u64,
+    id: u64,
+    registry: serde_json::Value,
+}
+
+///Returns up to MAX_LIMIT registries updated after the state, sorted by updated_at.
+/// The registries updated at the time of the last one of a full page are left to the
+/// next call since the page may not hold all of them, unless every registry of the page
+/// was updated at that time: these are then walked by id.
+pub(crate) async fn changes_since<T: DeserializeOwned>(
+    client: &EndpointClient,
+    request: &RequestBuilder,
+    state: SyncState,
+) -> Result<Changes<T>, Error> {
+    let mut changed = RequestBuilder::new();
+    changed
+        .add_where("updated_at", Equality::Greater, state.updated_at)
+        .group_or(|g| {
+            g.add_where("updated_at", Equality::Equal, state.updated_at)
+                .add_where("id", Equality::Greater, state.id)
+        });
+    let mut rows = request_rows::<T>(client, request, &changed, "updated_at").await?;
+
+    if rows.len() == MAX_LIMIT {
+        let last = rows[MAX_LIMIT - 1].updated_at;
+        match rows[0].updated_at == last {
+            true => {
+                let after = match last == state.updated_at {
+                    true => state.id,
+                    false => 0,
+                };
+                let mut same = RequestBuilder::new();
+                same.add_where("updated_at", Equality::Equal, last)
+                    .add_where("id", Equality::Greater, after);
+                rows = request_rows::<T>(client, request, &same, "id").await?;
+            }
+            false => rows.retain(|row| row.updated_at != last),
+        }
+    }
+
+    let state = match rows.last() {
+        Some(last) => SyncState {
+            updated_at: last.updated_at,
+            id: rows
+                .iter()
+                .filter(|row| row.updated_at == last.updated_at)
+                .map(|row| row.id)
+                .max()
+                .unwrap_or_default(),
+        },
+        None => state,
+    };
+    let registries = rows
+        .into_iter()
+        .map(|row| Ok(T::deserialize(row.registry)?))
+        .collect::<Result<_, Error>>()?;
+
+    Ok(Changes { registries, state })
+}
+
+///Requests a page of the registries matching both requests sorted by the given field,
+/// reading the position of each one
+async fn request_rows<T: DeserializeOwned>(
+    client: &EndpointClient,
+    request: &RequestBuilder,
+    cursor: &RequestBuilder,
+    sort: &str,
+) -> Result<Vec<Row>, Error> {
+    let mut page = cursor.clone();
+    page.merge(request);
+    for field in ["id", "updated_at"] {
+        if !page.fields.is_empty() && !page.fields.iter().any(|f| f == field || f == "*") {
+            page.add_field(field);
+        }
+    }
+    page.sort.clear();
+    page.sort_by(sort, OrderBy::Ascending)
+        .limit(MAX_LIMIT)
+        .offset(0);
+
+    let page = client.resolve_names(&page).await?;
+    let registries = client
+        .post::<Vec<serde_json::Value>>(client.path, page.build_body_for::<T>()?)
+        .await?;
+    registries
+        .into_iter()
+        .map(|registry| {
+            let field = |name: &str| registry.get(name).and_then(serde_json::Value::as_u64);
+            match (field("updated_at"), field("id")) {
+                (Some(updated_at), Some(id)) => Ok(Row {
+                    updated_at,
+                    id,
+                    registry,
+                }),
+                _ => Err(Error::InvalidQuery(format!(
+                    "{} registries have no updated_at to sync from",
+                    client.path
+                ))),
+            }
+        })
+        .collect()
+}
+
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+#[test]
+fn sync_resumes_after_the_last_id_and_starts_the_next_sync() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::MockTransport;
+    use futures::TryStreamExt;
+
+    let genres = |ids: std::ops::Range<usize>| {
+        let genres: Vec<String> = ids.map(|id| format!("{{\"id\":{}}}", id)).collect();
+        format!("[{}]", genres.join(","))
+    };
+    // Answers a full page to the first request and 3 genres to the second one
+    let mock = MockTransport::new()
+        .with_fixture(
+            "genres",
+            "fields name; where updated_at >= 1600000000 & id > 0; sort id asc; limit 500;",
+            genres(1..1 + MAX_LIMIT),
+        )
+        .with_fixture(
+            "genres",
+            "fields name; where updated_at >= 1600000000 & id > 500; sort id asc; limit 500;",
+            genres(1001..1004),
+        );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock.clone())
+        .without_rate_limit();
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+
+    let checkpoint = Checkpoint {
+        started_at: 1_700_000_000,
+        ..Checkpoint::since(1_600_000_000)
+    };
+    let synced: Vec<SyncPage<crate::model::genre::Genre>> = async_std::task::block_on(
+        igdb.sync::<GenresClient>(&request, checkpoint)
+            .try_collect(),
+    )
+    .unwrap();
+
+    assert_eq!(2, synced.len());
+    assert_eq!(500, synced[0].checkpoint.last_id);
+    assert!(!synced[0].complete);
+    assert!(synced[1].complete);
+    assert_eq!(Checkpoint::since(1_700_000_000), synced[1].checkpoint);
+    assert_eq!(2, mock.requests().len());
+}
+
+#[test]
+fn changes_leave_the_last_time_of_a_full_page_to_the_next_call() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::MockTransport;
+    use crate::model::genre::Genre;
+
+    // Answers a full page whose last 2 genres were updated a second later
+    let genres: Vec<String> = (1..=MAX_LIMIT)
+        .map(|id| {
+            let updated_at = if id > 498 { 101 } else { 100 };
+            format!("{{\"id\":{},\"updated_at\":{}}}", id, updated_at)
+        })
+        .collect();
+    let mock = MockTransport::new().with_fixture(
+        "genres",
+        "fields name,id,updated_at; where updated_at > 90 | (updated_at = 90 & id > 7); sort updated_at asc; limit 500;",
+        format!("[{}]", genres.join(",")),
+    );
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(mock.clone())
+        .without_rate_limit();
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+    let state = SyncState {
+        updated_at: 90,
+        id: 7,
+    };
+
+    let changes: Changes<Genre> =
+        async_std::task::block_on(igdb.changes_since::<GenresClient>(&request, state)).unwrap();
+
+    assert_eq!(498, changes.registries.len());
+    assert_eq!(
+        SyncState {
+            updated_at: 100,
+            id: 498
+        },
+        changes.state
+    );
+    assert_eq!(1, mock.requests().len());
+}
@@ -0,0 +1,7 @@
+//! Clocks used by the client. std's are not implemented on wasm32-unknown-unknown
+//! and panic when read, the browser clocks are used instead.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::{Instant, SystemTime};
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::{Instant, SystemTime};
@@ -0,0 +1,400 @@
+//! Conversions of the IGDB timestamps, e.g. `created_at`, `updated_at` or `first_release_date`,
+//! which are unix timestamps in seconds.
+//!
+//! The models keep the raw seconds. With the `chrono` or `time` feature, Timestamped converts
+//! them to dates, and the serde adapters read them straight into dates in your own structs,
+//! see IGDBClient::query_into.
+
+use crate::model::character::Character;
+use crate::model::collection::Collection;
+use crate::model::company::Company;
+use crate::model::event::Event;
+use crate::model::event_logo::EventLogo;
+use crate::model::event_network::EventNetwork;
+use crate::model::external_game::ExternalGame;
+use crate::model::franchise::Franchise;
+use crate::model::game_engine::GameEngine;
+use crate::model::game_localization::GameLocalization;
+use crate::model::game_mode::GameMode;
+use crate::model::game_time_to_beat::GameTimeToBeat;
+use crate::model::game_version::GameVersion;
+use crate::model::games::Game;
+use crate::model::genre::Genre;
+use crate::model::involved_company::InvolvedCompany;
+use crate::model::keyword::Keyword;
+use crate::model::language::Language;
+use crate::model::language_support::LanguageSupport;
+use crate::model::network_type::NetworkType;
+use crate::model::popularity_primitive::PopularityPrimitive;
+use crate::model::popularity_type::PopularityType;
+use crate::model::region::Region;
+use crate::model::release_date::ReleaseDate;
+use crate::model::theme::Theme;
+use crate::model::webhook::Webhook;
+
+///Registries recording when they were created and last updated
+/// # Examples
+/// ```
+/// use igdb::model::games::Game;
+/// use igdb::timestamp::Timestamped;
+///
+/// let game: Game = serde_json::from_str(r#"{"id": 1942, "updated_at": 1700000000}"#).unwrap();
+/// assert_eq!(1700000000, game.updated_at());
+/// ```
+pub trait Timestamped {
+    ///Creation time in seconds since the unix epoch, 0 when not requested
+    fn created_at(&self) -> i64;
+    ///Last update time in seconds since the unix epoch, 0 when not requested
+    fn updated_at(&self) -> i64;
+
+    ///Creation time, None when not requested
+    #[cfg(feature = "chrono")]
+    fn created_at_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        to_chrono(self.created_at())
+    }
+
+    ///Last update time, None when not requested
+    #[cfg(feature = "chrono")]
+    fn updated_at_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        to_chrono(self.updated_at())
+    }
+
+    ///Creation time, None when not requested
+    #[cfg(feature = "time")]
+    fn created_at_time(&self) -> Option<::time::OffsetDateTime> {
+        to_offset_date_time(self.created_at())
+    }
+
+    ///Last update time, None when not requested
+    #[cfg(feature = "time")]
+    fn updated_at_time(&self) -> Option<::time::OffsetDateTime> {
+        to_offset_date_time(self.updated_at())
+    }
+}
+
+macro_rules! timestamped {
+    ($($model:ty),* $(,)?) => {
+        $(
+            impl Timestamped for $model {
+                fn created_at(&self) -> i64 {
+                    self.created_at
+                }
+
+                fn updated_at(&self) -> i64 {
+                    self.updated_at
+                }
+            }
+        )*
+    };
+}
+
+timestamped!(
+    Character,
+    Collection,
+    Company,
+    Event,
+    EventLogo,
+    EventNetwork,
+    ExternalGame,
+    Franchise,
+    GameEngine,
+    GameLocalization,
+    GameMode,
+    GameTimeToBeat,
+    GameVersion,
+    Game,
+    Genre,
+    InvolvedCompany,
+    Keyword,
+    Language,
+    LanguageSupport,
+    NetworkType,
+    PopularityPrimitive,
+    PopularityType,
+    Region,
+    ReleaseDate,
+    Theme,
+    Webhook,
+);
+
+///Times accepted by the filters on timestamps, e.g. RequestBuilder::updated_since: unix
+/// timestamps in seconds, and with the chrono or time feature dates and times, a NaiveDate
+/// being the start of the day in UTC
+pub trait IntoTimestamp {
+    ///Seconds since the unix epoch
+    fn into_timestamp(self) -> i64;
+}
+
+macro_rules! timestamp_seconds {
+    ($($t: ty),+) => {
+        $(impl IntoTimestamp for $t {
+            fn into_timestamp(self) -> i64 {
+                self as i64
+            }
+        })+
+    };
+}
+
+timestamp_seconds!(i32, i64, u32, u64);
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> IntoTimestamp for chrono::DateTime<Tz> {
+    fn into_timestamp(self) -> i64 {
+        self.timestamp()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl IntoTimestamp for chrono::NaiveDateTime {
+    fn into_timestamp(self) -> i64 {
+        self.and_utc().timestamp()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl IntoTimestamp for chrono::NaiveDate {
+    fn into_timestamp(self) -> i64 {
+        self.and_time(chrono::NaiveTime::MIN).into_timestamp()
+    }
+}
+
+#[cfg(feature = "time")]
+impl IntoTimestamp for ::time::OffsetDateTime {
+    fn into_timestamp(self) -> i64 {
+        self.unix_timestamp()
+    }
+}
+
+///Converts a timestamp of a model, None for the 0 of the fields not requested
+#[cfg(feature = "chrono")]
+pub fn to_chrono(seconds: i64) -> Option<chrono::DateTime<chrono::Utc>> {
+    match seconds {
+        0 => None,
+        seconds => chrono::DateTime::from_timestamp(seconds, 0),
+    }
+}
+
+///Converts a timestamp of a model, None for the 0 of the fields not requested
+#[cfg(feature = "time")]
+pub fn to_offset_date_time(seconds: i64) -> Option<::time::OffsetDateTime> {
+    match seconds {
+        0 => None,
+        seconds => ::time::OffsetDateTime::from_unix_timestamp(seconds).ok(),
+    }
+}
+
+///Reads and writes a timestamp as a `chrono::DateTime<Utc>`, with `#[serde(with = ...)]`
+/// # Examples
+/// ```
+/// use chrono::{DateTime, Utc};
+/// use serde_derive::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Game {
+///     name: String,
+///     #[serde(with = "igdb::timestamp::chrono_seconds")]
+///     updated_at: DateTime<Utc>,
+/// }
+///
+/// let game: Game = serde_json::from_str(r#"{"name": "Celeste", "updated_at": 1700000000}"#).unwrap();
+/// assert_eq!("2023-11-14T22:13:20Z", game.updated_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+/// ```
+#[cfg(feature = "chrono")]
+pub mod chrono_seconds {
+    use chrono::{DateTime, Utc};
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+
+    pub fn serialize<S: Serializer>(
+        time: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(time.timestamp())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        let seconds = i64::deserialize(deserializer)?;
+        DateTime::from_timestamp(seconds, 0)
+            .ok_or_else(|| D::Error::custom(format!("timestamp {} out of range", seconds)))
+    }
+}
+
+///Reads and writes an optional timestamp as a `chrono::DateTime<Utc>`, missing, null and 0
+/// timestamps being None. Use it along `#[serde(default)]`.
+#[cfg(feature = "chrono")]
+pub mod chrono_seconds_option {
+    use chrono::{DateTime, Utc};
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    pub fn serialize<S: Serializer>(
+        time: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match time {
+            Some(time) => serializer.serialize_some(&time.timestamp()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        match Option::<i64>::deserialize(deserializer)? {
+            None | Some(0) => Ok(None),
+            Some(seconds) => {
+                super::chrono_seconds::deserialize(serde::de::value::I64Deserializer::new(seconds))
+                    .map(Some)
+            }
+        }
+    }
+}
+
+///Reads and writes a timestamp as a `time::OffsetDateTime`, with `#[serde(with = ...)]`
+/// # Examples
+/// ```
+/// use serde_derive::Deserialize;
+/// use time::OffsetDateTime;
+///
+/// #[derive(Deserialize)]
+/// struct Game {
+///     name: String,
+///     #[serde(with = "igdb::timestamp::time_seconds")]
+///     updated_at: OffsetDateTime,
+/// }
+///
+/// let game: Game = serde_json::from_str(r#"{"name": "Celeste", "updated_at": 1700000000}"#).unwrap();
+/// assert_eq!(2023, game.updated_at.year());
+/// ```
+#[cfg(feature = "time")]
+pub mod time_seconds {
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+    use time::OffsetDateTime;
+
+    pub fn serialize<S: Serializer>(
+        time: &OffsetDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(time.unix_timestamp())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        let seconds = i64::deserialize(deserializer)?;
+        OffsetDateTime::from_unix_timestamp(seconds).map_err(D::Error::custom)
+    }
+}
+
+///Reads and writes an optional timestamp as a `time::OffsetDateTime`, missing, null and 0
+/// timestamps being None. Use it along `#[serde(default)]`.
+#[cfg(feature = "time")]
+pub mod time_seconds_option {
+    use serde::de::{Deserialize, Deserializer};
+    use serde::ser::Serializer;
+    use time::OffsetDateTime;
+
+    pub fn serialize<S: Serializer>(
+        time: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match time {
+            Some(time) => serializer.serialize_some(&time.unix_timestamp()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        match Option::<i64>::deserialize(deserializer)? {
+            None | Some(0) => Ok(None),
+            Some(seconds) => {
+                super::time_seconds::deserialize(serde::de::value::I64Deserializer::new(seconds))
+                    .map(Some)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn timestamps_convert_to_chrono_dates() {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[derive(Deserialize, Serialize)]
+    struct Release {
+        #[serde(with = "chrono_seconds")]
+        updated_at: DateTime<Utc>,
+        #[serde(default, with = "chrono_seconds_option")]
+        first_release_date: Option<DateTime<Utc>>,
+    }
+
+    let release: Release = serde_json::from_str(r#"{"updated_at": 1431993600}"#).unwrap();
+    let expected = Utc.with_ymd_and_hms(2015, 5, 19, 0, 0, 0).unwrap();
+    assert_eq!(expected, release.updated_at);
+    assert_eq!(None, release.first_release_date);
+    assert_eq!(
+        r#"{"updated_at":1431993600,"first_release_date":null}"#,
+        serde_json::to_string(&release).unwrap()
+    );
+
+    let game: Game =
+        serde_json::from_str(r#"{"id": 1942, "first_release_date": 1431993600}"#).unwrap();
+    assert_eq!(Some(expected), game.first_release_date_utc());
+    assert_eq!(None, game.created_at_utc());
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn timestamps_convert_to_time_dates() {
+    use ::time::OffsetDateTime;
+
+    #[derive(Deserialize)]
+    struct Release {
+        #[serde(with = "time_seconds")]
+        updated_at: OffsetDateTime,
+        #[serde(default, with = "time_seconds_option")]
+        first_release_date: Option<OffsetDateTime>,
+    }
+
+    let release: Release =
+        serde_json::from_str(r#"{"updated_at": 1431993600, "first_release_date": 0}"#).unwrap();
+    assert_eq!(1431993600, release.updated_at.unix_timestamp());
+    assert_eq!(None, release.first_release_date);
+
+    let game: Game = serde_json::from_str(r#"{"id": 1942, "updated_at": 1431993600}"#).unwrap();
+    assert_eq!(Some(release.updated_at), game.updated_at_time());
+}
+
+#[test]
+fn timestamps_before_1970_are_negative() {
+    let game: Game = serde_json::from_str(
+        r#"{"id": 1068, "first_release_date": -31536000, "created_at": 1297956069}"#,
+    )
+    .unwrap();
+    assert_eq!(-31536000, game.first_release_date);
+    assert_eq!(1297956069, game.created_at());
+
+    let release: ReleaseDate =
+        serde_json::from_str(r#"{"id": 1, "date": -86400, "y": 1969}"#).unwrap();
+    assert_eq!(-86400, release.date);
+
+    #[cfg(feature = "chrono")]
+    {
+        use chrono::{TimeZone, Utc};
+
+        assert_eq!(
+            Utc.with_ymd_and_hms(1969, 1, 1, 0, 0, 0).single(),
+            game.first_release_date_utc()
+        );
+    }
+    #[cfg(feature = "time")]
+    assert_eq!(
+        Some(1969),
+        to_offset_date_time(release.date).map(|date| date.year())
+    );
+}
@@ -0,0 +1,284 @@
+//! Maps free-form game titles, e.g. from package or ports names, to IGDB games,
+//! see IGDBClient::match_title.
+
+use crate::model::alternative_name::AlternativeName;
+use crate::model::games::Game;
+
+///Roman numerals commonly ending sequel titles, compared as digits. `X` is left out, being
+/// a letter as often as a tenth episode, e.g. Mega Man X is not Mega Man 10
+pub(crate) const NUMERALS: [(&str, &str); 8] = [
+    ("ii", "2"),
+    ("iii", "3"),
+    ("iv", "4"),
+    ("v", "5"),
+    ("vi", "6"),
+    ("vii", "7"),
+    ("viii", "8"),
+    ("ix", "9"),
+];
+
+///Tunes IGDBClient::match_title
+/// # Examples
+/// ```
+/// use igdb::title_match::MatchOptions;
+///
+/// let options = MatchOptions {
+///     min_confidence: 0.8,
+///     ..MatchOptions::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchOptions {
+    ///Maximum number of matches returned, 5 by default
+    pub limit: usize,
+    ///Number of search results compared to the title, 20 by default
+    pub candidates: usize,
+    ///Confidence below which candidates are dropped, from 0 to 1, 0.5 by default
+    pub min_confidence: f32,
+}
+
+impl Default for MatchOptions {
+    fn default() -> Self {
+        MatchOptions {
+            limit: 5,
+            candidates: 20,
+            min_confidence: 0.5,
+        }
+    }
+}
+
+///A game matching a title, with the confidence it is the one meant from 0 to 1
+#[derive(Debug, Clone)]
+pub struct TitleMatch {
+    pub game: Game,
+    pub confidence: f32,
+}
+
+///Words qualifying an edition before the trailing `edition`, e.g. `Game of the Year Edition`
+const EDITION_QUALIFIERS: [&str; 16] = [
+    "anniversary",
+    "collectors",
+    "complete",
+    "definitive",
+    "deluxe",
+    "digital",
+    "enhanced",
+    "game",
+    "gold",
+    "of",
+    "premium",
+    "special",
+    "standard",
+    "the",
+    "ultimate",
+    "year",
+];
+
+///Lowercased words of the title, without trademark symbols, apostrophes
+/// and trailing edition names, `&` read as `and`
+fn words(title: &str) -> Vec<String> {
+    split_edition(title).0
+}
+
+///Lowercased words of the title like words, and apart the trailing edition names
+pub(crate) fn split_edition(title: &str) -> (Vec<String>, Vec<String>) {
+    let cleaned: String = title
+        .to_lowercase()
+        .replace('&', " and ")
+        .chars()
+        .filter(|c| !matches!(c, '®' | '™' | '©' | '\'' | '’'))
+        .map(|c| match c.is_alphanumeric() {
+            true => c,
+            false => ' ',
+        })
+        .collect();
+    let mut words: Vec<String> = cleaned.split_whitespace().map(str::to_owned).collect();
+
+    let mut length = words.len();
+    while length > 1 {
+        match words[length - 1].as_str() {
+            "goty" => length -= 1,
+            "edition" => {
+                length -= 1;
+                while length > 1 && EDITION_QUALIFIERS.contains(&words[length - 1].as_str()) {
+                    length -= 1;
+                }
+            }
+            "cut" if length > 2 && words[length - 2] == "directors" => length -= 2,
+            _ => break,
+        }
+    }
+    let edition = words.split_off(length);
+    (words, edition)
+}
+
+///The slug IGDB would give a game of the given title, e.g. `the-witcher-3-wild-hunt`
+pub(crate) fn slug(title: &str) -> String {
+    words(title).join("-")
+}
+
+///Reduces a title to the words identifying the game, so the different spellings of a
+/// title compare equal: lowercased, without punctuation, trademark symbols, leading article
+/// and edition names, with `&` read as `and`, roman numerals as digits and digits split
+/// from letters
+/// # Examples
+/// ```
+/// use igdb::title_match::normalize_title;
+///
+/// assert_eq!("witcher 3 wild hunt", normalize_title("The Witcher® 3: Wild Hunt – GOTY"));
+/// assert_eq!(normalize_title("quake2"), normalize_title("Quake II"));
+/// ```
+pub fn normalize_title(title: &str) -> String {
+    let mut spaced = String::new();
+    for word in words(title) {
+        let mut previous: Option<char> = None;
+        for c in word.chars() {
+            if let Some(previous) = previous {
+                if previous.is_alphabetic() && c.is_ascii_digit()
+                    || previous.is_ascii_digit() && c.is_alphabetic()
+                {
+                    spaced.push(' ');
+                }
+            }
+            spaced.push(c);
+            previous = Some(c);
+        }
+        spaced.push(' ');
+    }
+
+    let words: Vec<&str> = spaced
+        .split_whitespace()
+        .enumerate()
+        .filter(|(i, word)| !(*i == 0 && *word == "the"))
+        .map(|(_, word)| {
+            NUMERALS
+                .iter()
+                .find(|(numeral, _)| *numeral == word)
+                .map_or(word, |(_, digit)| *digit)
+        })
+        .collect();
+    words.join(" ")
+}
+
+///Similarity of two titles from 0 to 1, the levenshtein distance of their normalized forms
+/// relative to the longest one
+pub(crate) fn similarity(a: &str, b: &str) -> f32 {
+    let a: Vec<char> = normalize_title(a).chars().collect();
+    let b: Vec<char> = normalize_title(b).chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut distances: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    1.0 - distances[b.len()] as f32 / longest as f32
+}
+
+///Ranks the game found by slug, fully confident, and the search candidates
+/// by similarity of their name or of one of their alternative names to the title,
+/// keeping each game once
+pub(crate) fn rank(
+    title: &str,
+    by_slug: Option<Game>,
+    candidates: Vec<Game>,
+    alternative_names: &[AlternativeName],
+    options: &MatchOptions,
+) -> Vec<TitleMatch> {
+    let mut matches: Vec<TitleMatch> = by_slug
+        .map(|game| TitleMatch {
+            game,
+            confidence: 1.0,
+        })
+        .into_iter()
+        .collect();
+    for game in candidates {
+        if matches.iter().all(|found| found.game.id != game.id) {
+            let confidence = alternative_names
+                .iter()
+                .filter(|alternative| alternative.game == game.id)
+                .map(|alternative| similarity(title, &alternative.name))
+                .fold(similarity(title, &game.name), f32::max);
+            matches.push(TitleMatch { game, confidence });
+        }
+    }
+
+    matches.retain(|found| found.confidence >= options.min_confidence);
+    matches.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    matches.truncate(options.limit);
+    matches
+}
+
+#[test]
+fn titles_are_ranked_by_similarity() {
+    use crate::model::ids::GameId;
+
+    let game = |id: u64, name: &str| {
+        serde_json::from_str::<Game>(&format!("{{\"id\":{},\"name\":\"{}\"}}", id, name)).unwrap()
+    };
+
+    assert_eq!("the-witcher-3-wild-hunt", slug("The Witcher 3: Wild Hunt"));
+    assert_eq!("quake 2", normalize_title("The Quake II"));
+    assert_eq!(
+        "baldurs gate",
+        normalize_title("Baldur's Gate: Enhanced Edition")
+    );
+    assert_eq!(
+        "batman arkham city",
+        normalize_title("Batman: Arkham City - Game of the Year Edition")
+    );
+    assert_eq!(1.0, similarity("Doom & Destiny", "doom and destiny"));
+    assert_ne!(
+        normalize_title("Mega Man X"),
+        normalize_title("Mega Man 10")
+    );
+
+    let matches = rank(
+        "quake2",
+        None,
+        vec![
+            game(1, "Quake"),
+            game(2, "Quake II"),
+            game(3, "Enemy Territory: Quake Wars"),
+        ],
+        &[],
+        &MatchOptions::default(),
+    );
+    let names: Vec<&str> = matches
+        .iter()
+        .map(|found| found.game.name.as_str())
+        .collect();
+    assert_eq!(vec!["Quake II", "Quake"], names);
+    assert_eq!(1.0, matches[0].confidence);
+
+    let matches = rank(
+        "Mega Man X",
+        None,
+        vec![game(5, "Mega Man 10"), game(6, "Mega Man X")],
+        &[],
+        &MatchOptions::default(),
+    );
+    assert_eq!(GameId(6), matches[0].game.id);
+    assert_eq!(1.0, matches[0].confidence);
+    assert!(matches[1].confidence < 1.0);
+
+    let persona: AlternativeName =
+        serde_json::from_str("{\"id\":1,\"game\":4,\"name\":\"Persona 3\"}").unwrap();
+    let matches = rank(
+        "persona3",
+        None,
+        vec![game(4, "Shin Megami Tensei: Persona 3")],
+        &[persona],
+        &MatchOptions::default(),
+    );
+    assert_eq!(1.0, matches[0].confidence);
+}
@@ -0,0 +1,287 @@
+//! Storage of the Twitch app access tokens, see TwitchAuthenticator::with_token_store.
+//!
+//! A stored token is reused until it expires instead of requesting a new one, e.g. by the
+//! next run of a command line tool, or by the other workers of a server sharing the store.
+//! Storing is best effort, a store failing to read or write a token just misses it.
+
+use crate::time::SystemTime;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+///An app access token and when Twitch said it expires
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoredToken {
+    pub access_token: String,
+    pub expires_at: SystemTime,
+}
+
+///Stores the tokens by Twitch application client_id
+pub trait TokenStore: Send + Sync {
+    fn get(&self, client_id: &str) -> Option<StoredToken>;
+    ///Stores the token, replacing the previous one
+    fn put(&self, client_id: &str, token: &StoredToken);
+    ///Removes the token, e.g. once IGDB rejected it
+    fn remove(&self, client_id: &str);
+}
+
+///Stores shared between several authenticators
+impl<S: TokenStore + ?Sized> TokenStore for Arc<S> {
+    fn get(&self, client_id: &str) -> Option<StoredToken> {
+        self.as_ref().get(client_id)
+    }
+
+    fn put(&self, client_id: &str, token: &StoredToken) {
+        self.as_ref().put(client_id, token)
+    }
+
+    fn remove(&self, client_id: &str) {
+        self.as_ref().remove(client_id)
+    }
+}
+
+///Keeps the tokens in memory, e.g. shared in an Arc by the clients of a process
+#[derive(Default)]
+pub struct MemoryTokenStore {
+    tokens: Mutex<HashMap<String, StoredToken>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> MemoryTokenStore {
+        MemoryTokenStore::default()
+    }
+
+    fn tokens(&self) -> std::sync::MutexGuard<'_, HashMap<String, StoredToken>> {
+        self.tokens.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn get(&self, client_id: &str) -> Option<StoredToken> {
+        self.tokens().get(client_id).cloned()
+    }
+
+    fn put(&self, client_id: &str, token: &StoredToken) {
+        self.tokens().insert(client_id.to_owned(), token.clone());
+    }
+
+    fn remove(&self, client_id: &str) {
+        self.tokens().remove(client_id);
+    }
+}
+
+///A token as written by FileTokenStore and KeyringTokenStore
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Serialize, Deserialize)]
+struct Record {
+    access_token: String,
+    ///Seconds since the unix epoch
+    expires_at: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<&StoredToken> for Record {
+    fn from(token: &StoredToken) -> Self {
+        Record {
+            access_token: token.access_token.clone(),
+            expires_at: token
+                .expires_at
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|expires_at| expires_at.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<Record> for StoredToken {
+    fn from(record: Record) -> Self {
+        StoredToken {
+            access_token: record.access_token,
+            expires_at: SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(record.expires_at),
+        }
+    }
+}
+
+///Keeps the tokens in a json file readable by the current user only,
+/// so that they are reused across runs
+/// # Examples
+/// ```
+/// use igdb::auth::TwitchAuthenticator;
+/// use igdb::client::IGDBClient;
+/// use igdb::token_store::FileTokenStore;
+///
+/// let twitch = TwitchAuthenticator::new("client_id", "client_secret")
+///     .with_token_store(FileTokenStore::new(".igdb-tokens.json"));
+/// let igdb = IGDBClient::with_authenticator(twitch);
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileTokenStore {
+    path: std::path::PathBuf,
+    ///Serializes the read-modify-write of the file within the process
+    lock: Mutex<()>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileTokenStore {
+    pub fn new<P: Into<std::path::PathBuf>>(path: P) -> FileTokenStore {
+        FileTokenStore {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read(&self) -> HashMap<String, Record> {
+        std::fs::read(&self.path)
+            .ok()
+            .and_then(|file| serde_json::from_slice(&file).ok())
+            .unwrap_or_default()
+    }
+
+    fn update(&self, update: impl FnOnce(&mut HashMap<String, Record>)) {
+        let _lock = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+        let mut records = self.read();
+        update(&mut records);
+
+        // Written aside then renamed, so that a concurrent read never sees a partial file
+        let partial = self.path.with_extension("partial");
+        let written = self
+            .path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| write_private(&partial, &serde_json::to_vec(&records)?))
+            .and_then(|_| std::fs::rename(&partial, &self.path));
+
+        if let Err(e) = written {
+            log::warn!(
+                "Could not store the token in {}: {}",
+                self.path.display(),
+                e
+            );
+        }
+    }
+}
+
+///Writes the file readable by the current user only, from its creation on so that the
+/// tokens are never readable by others
+#[cfg(not(target_arch = "wasm32"))]
+fn write_private(path: &std::path::Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    // A file left over by an interrupted write keeps the permissions it was created with
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(contents)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl TokenStore for FileTokenStore {
+    fn get(&self, client_id: &str) -> Option<StoredToken> {
+        self.read().remove(client_id).map(StoredToken::from)
+    }
+
+    fn put(&self, client_id: &str, token: &StoredToken) {
+        self.update(|records| {
+            records.insert(client_id.to_owned(), Record::from(token));
+        });
+    }
+
+    fn remove(&self, client_id: &str) {
+        self.update(|records| {
+            records.remove(client_id);
+        });
+    }
+}
+
+///Keeps the tokens in the keyring of the operating system, e.g. the macOS Keychain,
+/// the Windows Credential Manager or the Linux kernel keyring, with the `keyring` feature
+/// # Examples
+/// ```no_run
+/// use igdb::auth::TwitchAuthenticator;
+/// use igdb::token_store::KeyringTokenStore;
+///
+/// let twitch = TwitchAuthenticator::new("client_id", "client_secret")
+///     .with_token_store(KeyringTokenStore::new("my-importer"));
+/// ```
+#[cfg(all(feature = "keyring", not(target_arch = "wasm32")))]
+pub struct KeyringTokenStore {
+    service: String,
+}
+
+#[cfg(all(feature = "keyring", not(target_arch = "wasm32")))]
+impl KeyringTokenStore {
+    ///Stores the tokens under the given service name, the client_id being the user
+    pub fn new<S: Into<String>>(service: S) -> KeyringTokenStore {
+        KeyringTokenStore {
+            service: service.into(),
+        }
+    }
+
+    fn entry(&self, client_id: &str) -> Option<keyring::Entry> {
+        keyring::Entry::new(&self.service, client_id)
+            .map_err(|e| log::warn!("Could not open the keyring: {}", e))
+            .ok()
+    }
+}
+
+#[cfg(all(feature = "keyring", not(target_arch = "wasm32")))]
+impl TokenStore for KeyringTokenStore {
+    fn get(&self, client_id: &str) -> Option<StoredToken> {
+        let secret = self.entry(client_id)?.get_password().ok()?;
+        serde_json::from_str::<Record>(&secret)
+            .ok()
+            .map(StoredToken::from)
+    }
+
+    fn put(&self, client_id: &str, token: &StoredToken) {
+        let stored = serde_json::to_string(&Record::from(token))
+            .map_err(|e| e.to_string())
+            .and_then(|secret| match self.entry(client_id) {
+                Some(entry) => entry.set_password(&secret).map_err(|e| e.to_string()),
+                None => Ok(()),
+            });
+        if let Err(e) = stored {
+            log::warn!("Could not store the token in the keyring: {}", e);
+        }
+    }
+
+    fn remove(&self, client_id: &str) {
+        if let Some(entry) = self.entry(client_id) {
+            let _ = entry.delete_credential();
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+fn file_store_keeps_the_tokens_of_each_application() {
+    let path = std::env::temp_dir().join(format!("igdb-tokens-{}.json", std::process::id()));
+    let token = |access_token: &str| StoredToken {
+        access_token: access_token.to_owned(),
+        expires_at: SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_900_000_000),
+    };
+
+    let store = FileTokenStore::new(&path);
+    store.put("first", &token("abc"));
+    store.put("second", &token("def"));
+    store.remove("second");
+
+    let reopened = FileTokenStore::new(&path);
+    assert_eq!(Some(token("abc")), reopened.get("first"));
+    assert_eq!(None, reopened.get("second"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(0o600, mode & 0o777);
+    }
+    let _ = std::fs::remove_file(path);
+}
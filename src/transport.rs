@@ -0,0 +1,144 @@
+//! The HTTP client sending the requests, see IGDBClient::with_transport.
+//!
+//! `backend-reqwest` (enabled by default) and `backend-surf` provide ready to use transports,
+//! any other client can be plugged by implementing HttpTransport.
+//!
+//! On wasm32-unknown-unknown the reqwest backend sends the requests with the browser fetch api.
+//! IGDB does not answer CORS requests, so browsers have to go through a proxy,
+//! see IGDBClient::with_base_url.
+
+use std::sync::Arc;
+
+///Error raised by a transport when a request cannot be sent or its response cannot be read
+pub type TransportError = Box<dyn std::error::Error + Send + Sync>;
+
+///Future answered by HttpTransport::send. Browser futures are not Send,
+/// so it is only required to be Send on native targets.
+#[cfg(not(target_arch = "wasm32"))]
+pub type TransportFuture<'a> =
+    futures::future::BoxFuture<'a, Result<http::Response<Vec<u8>>, TransportError>>;
+#[cfg(target_arch = "wasm32")]
+pub type TransportFuture<'a> =
+    futures::future::LocalBoxFuture<'a, Result<http::Response<Vec<u8>>, TransportError>>;
+
+///Sends the requests of an IGDBClient, including the Twitch token requests and media downloads.
+/// # Examples
+/// ```
+/// use igdb::client::IGDBClient;
+/// use igdb::transport::{HttpTransport, TransportFuture};
+///
+/// struct Offline;
+///
+/// impl HttpTransport for Offline {
+///     fn send(&self, _request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+///         Box::pin(async { Ok(http::Response::new(b"[]".to_vec())) })
+///     }
+/// }
+///
+/// let igdb = IGDBClient::new("client_id", "token").with_transport(Offline);
+/// ```
+pub trait HttpTransport: Send + Sync {
+    ///Sends the request and returns its response whatever its status
+    fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_>;
+}
+
+///Transport of the enabled backend, reqwest when both are enabled
+pub(crate) fn default_transport() -> Arc<dyn HttpTransport> {
+    #[cfg(feature = "backend-reqwest")]
+    return Arc::new(ReqwestTransport::default());
+
+    #[cfg(all(feature = "backend-surf", not(feature = "backend-reqwest")))]
+    return Arc::new(SurfTransport::default());
+
+    #[cfg(not(any(feature = "backend-reqwest", feature = "backend-surf")))]
+    return Arc::new(MissingTransport);
+}
+
+///Sends the requests with reqwest, for applications running on tokio or in browsers
+#[cfg(feature = "backend-reqwest")]
+#[derive(Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "backend-reqwest")]
+impl From<reqwest::Client> for ReqwestTransport {
+    fn from(client: reqwest::Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+#[cfg(feature = "backend-reqwest")]
+impl HttpTransport for ReqwestTransport {
+    fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let request = reqwest::Request::try_from(request)?;
+            let response = self.client.execute(request).await?;
+
+            let mut builder = http::Response::builder().status(response.status());
+            for (name, value) in response.headers() {
+                builder = builder.header(name, value);
+            }
+            let body = response.bytes().await?;
+
+            Ok(builder.body(body.to_vec())?)
+        })
+    }
+}
+
+///Sends the requests with surf, for applications running on async-std
+#[cfg(feature = "backend-surf")]
+#[derive(Clone, Default)]
+pub struct SurfTransport {
+    client: surf::Client,
+}
+
+#[cfg(feature = "backend-surf")]
+impl From<surf::Client> for SurfTransport {
+    fn from(client: surf::Client) -> Self {
+        SurfTransport { client }
+    }
+}
+
+#[cfg(feature = "backend-surf")]
+impl HttpTransport for SurfTransport {
+    fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            let method = parts.method.as_str().parse().map_err(surf_error)?;
+            let url = surf::Url::parse(&parts.uri.to_string())?;
+
+            let mut request = surf::Request::builder(method, url).body(body);
+            for (name, value) in &parts.headers {
+                request = request.header(name.as_str(), value.to_str()?);
+            }
+            let mut response = self.client.send(request).await.map_err(surf_error)?;
+
+            let mut builder = http::Response::builder().status(u16::from(response.status()));
+            for (name, values) in response.iter() {
+                for value in values {
+                    builder = builder.header(name.as_str(), value.as_str());
+                }
+            }
+            let body = response.body_bytes().await.map_err(surf_error)?;
+
+            Ok(builder.body(body)?)
+        })
+    }
+}
+
+#[cfg(feature = "backend-surf")]
+fn surf_error(e: surf::Error) -> TransportError {
+    e.into_inner().into()
+}
+
+///Fails every request, used when no backend is enabled and no transport was given
+#[cfg(not(any(feature = "backend-reqwest", feature = "backend-surf")))]
+struct MissingTransport;
+
+#[cfg(not(any(feature = "backend-reqwest", feature = "backend-surf")))]
+impl HttpTransport for MissingTransport {
+    fn send(&self, _request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        Box::pin(async { Err("no HTTP backend enabled, see IGDBClient::with_transport".into()) })
+    }
+}
@@ -0,0 +1,306 @@
+//! Records the requests sent by a client and their responses to a cassette file, and
+//! replays them later without network access, e.g. to run end-to-end tests in CI.
+//! Credentials are scrubbed from the cassette: the auth headers, the Twitch client secret
+//! and the access tokens it was exchanged for. Requires the `vcr` feature.
+
+use crate::compression::{decode_body, HEADER_CONTENT_ENCODING};
+use crate::transport::{HttpTransport, TransportError, TransportFuture};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+///Value replacing the scrubbed secrets
+const REDACTED: &str = "REDACTED";
+///Headers scrubbed from the recorded requests, in lowercase
+const SCRUBBED_HEADERS: [&str; 2] = ["authorization", "client-id"];
+///Form and query string parameters scrubbed from the recorded requests
+const SCRUBBED_PARAMS: [&str; 1] = ["client_secret"];
+///Fields scrubbed from the recorded json responses
+const SCRUBBED_FIELDS: [&str; 1] = ["access_token"];
+
+///Whether a VcrTransport sends and records the requests or replays a cassette
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VcrMode {
+    ///Sends every request and records it, overwriting the cassette
+    Record,
+    ///Answers every request from the cassette, failing the ones it does not hold
+    Replay,
+    ///Replays the cassette when it exists, records it otherwise
+    Auto,
+}
+
+///A recorded request and its response, bodies decompressed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Interaction {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub request_headers: Vec<(String, String)>,
+    pub request_body: String,
+    pub status: u16,
+    #[serde(default)]
+    pub response_headers: Vec<(String, String)>,
+    pub response_body: String,
+}
+
+impl Interaction {
+    fn answers(&self, request: &Interaction) -> bool {
+        self.method == request.method
+            && self.url == request.url
+            && self.request_body == request.request_body
+    }
+}
+
+///The interactions of a cassette and whether each one was replayed yet
+#[derive(Default)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+    replayed: Vec<bool>,
+}
+
+///Records the requests sent by another transport to a cassette file, or replays it.
+/// Replayed requests are answered by the first interaction recorded for the same method,
+/// url and body that was not replayed yet, then by the last one recorded for them.
+/// Responses are stored as text, so only json responses can be replayed.
+/// See ClientBuilder::cassette to record the requests of a client.
+/// # Examples
+/// ```no_run
+/// use igdb::client::IGDBClient;
+/// use igdb::transport::ReqwestTransport;
+/// use igdb::vcr::{VcrMode, VcrTransport};
+/// use std::sync::Arc;
+///
+/// let vcr = VcrTransport::new(
+///     Arc::new(ReqwestTransport::default()),
+///     "tests/cassettes/games.json",
+///     VcrMode::Auto,
+/// )
+/// .unwrap();
+/// let igdb = IGDBClient::new("client_id", "token").with_transport(vcr);
+/// ```
+pub struct VcrTransport {
+    inner: Arc<dyn HttpTransport>,
+    path: PathBuf,
+    recording: bool,
+    cassette: Mutex<Cassette>,
+}
+
+impl VcrTransport {
+    ///Records the requests sent with the given transport to the cassette at the given path,
+    /// or replays it depending on the mode. Fails when the cassette to replay cannot be read.
+    pub fn new<P: Into<PathBuf>>(
+        inner: Arc<dyn HttpTransport>,
+        path: P,
+        mode: VcrMode,
+    ) -> std::io::Result<VcrTransport> {
+        let path = path.into();
+        let recording = match mode {
+            VcrMode::Record => true,
+            VcrMode::Replay => false,
+            VcrMode::Auto => !path.exists(),
+        };
+
+        let mut cassette = Cassette::default();
+        if !recording {
+            cassette.interactions = serde_json::from_slice(&std::fs::read(&path)?)?;
+            cassette.replayed = vec![false; cassette.interactions.len()];
+        }
+
+        Ok(VcrTransport {
+            inner,
+            path,
+            recording,
+            cassette: Mutex::new(cassette),
+        })
+    }
+
+    ///Whether the requests are sent and recorded rather than replayed
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    async fn record(
+        &self,
+        request: http::Request<Vec<u8>>,
+        mut interaction: Interaction,
+    ) -> Result<http::Response<Vec<u8>>, TransportError> {
+        let response = self.inner.send(request).await?;
+        let (mut parts, body) = response.into_parts();
+        let body = decode_body(content_encoding(&parts.headers).as_deref(), body)?;
+        parts.headers.remove(HEADER_CONTENT_ENCODING);
+        parts.headers.remove(http::header::CONTENT_LENGTH);
+
+        interaction.status = parts.status.as_u16();
+        interaction.response_headers = headers(&parts.headers);
+        interaction.response_body = scrub_response(&String::from_utf8_lossy(&body));
+
+        let mut cassette = self.cassette.lock().unwrap();
+        cassette.interactions.push(interaction);
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(
+            &self.path,
+            serde_json::to_vec_pretty(&cassette.interactions)?,
+        )?;
+
+        Ok(http::Response::from_parts(parts, body))
+    }
+
+    fn replay(&self, request: &Interaction) -> Result<http::Response<Vec<u8>>, TransportError> {
+        let mut cassette = self.cassette.lock().unwrap();
+        let Cassette {
+            interactions,
+            replayed,
+        } = &mut *cassette;
+        let position = interactions
+            .iter()
+            .zip(replayed.iter())
+            .position(|(interaction, replayed)| !replayed && interaction.answers(request))
+            .or_else(|| interactions.iter().rposition(|i| i.answers(request)))
+            .ok_or_else(|| {
+                format!(
+                    "{} holds no interaction for {} {} `{}`",
+                    self.path.display(),
+                    request.method,
+                    request.url,
+                    request.request_body
+                )
+            })?;
+        replayed[position] = true;
+
+        let interaction = &interactions[position];
+        let mut response = http::Response::builder().status(interaction.status);
+        for (name, value) in &interaction.response_headers {
+            response = response.header(name, value);
+        }
+        Ok(response.body(interaction.response_body.clone().into_bytes())?)
+    }
+}
+
+impl HttpTransport for VcrTransport {
+    fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let body = decode_body(
+                content_encoding(request.headers()).as_deref(),
+                request.body().clone(),
+            )?;
+            let interaction = Interaction {
+                method: request.method().to_string(),
+                url: scrub_params(&request.uri().to_string()),
+                request_headers: headers(request.headers()),
+                request_body: scrub_params(&String::from_utf8_lossy(&body)),
+                status: 0,
+                response_headers: Vec::new(),
+                response_body: String::new(),
+            };
+
+            match self.recording {
+                true => self.record(request, interaction).await,
+                false => self.replay(&interaction),
+            }
+        })
+    }
+}
+
+///The text headers, the credentials being scrubbed
+fn headers(headers: &http::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = match SCRUBBED_HEADERS.contains(&name.as_str()) {
+                true => REDACTED,
+                false => value.to_str().ok()?,
+            };
+            Some((name.as_str().to_owned(), value.to_owned()))
+        })
+        .collect()
+}
+
+///Scrubs the secret parameters of a form or an url, e.g. `client_secret=REDACTED`
+fn scrub_params(text: &str) -> String {
+    let mut scrubbed = text.to_owned();
+    for param in SCRUBBED_PARAMS {
+        let key = format!("{}=", param);
+        let mut from = 0;
+        while let Some(start) = scrubbed[from..].find(&key).map(|i| from + i + key.len()) {
+            let end = scrubbed[start..]
+                .find('&')
+                .map_or(scrubbed.len(), |end| start + end);
+            scrubbed.replace_range(start..end, REDACTED);
+            from = start + REDACTED.len();
+        }
+    }
+    scrubbed
+}
+
+///Scrubs the tokens of a json response
+fn scrub_response(body: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(serde_json::Value::Object(mut object))
+            if SCRUBBED_FIELDS.iter().any(|f| object.contains_key(*f)) =>
+        {
+            for field in SCRUBBED_FIELDS {
+                if let Some(value) = object.get_mut(field) {
+                    *value = REDACTED.into();
+                }
+            }
+            serde_json::Value::Object(object).to_string()
+        }
+        _ => body.to_owned(),
+    }
+}
+
+fn content_encoding(headers: &http::HeaderMap) -> Option<String> {
+    headers
+        .get(HEADER_CONTENT_ENCODING)
+        .and_then(|encoding| encoding.to_str().ok())
+        .map(|encoding| encoding.trim().to_ascii_lowercase())
+}
+
+#[test]
+fn cassettes_are_replayed_without_credentials() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::MockTransport;
+
+    let path = std::env::temp_dir().join(format!("igdb-cassette-{}.json", std::process::id()));
+    let live = MockTransport::new().with_fixture(
+        "genres",
+        "fields name; limit 10;",
+        r#"[{"id": 12, "name": "Role-playing (RPG)"}]"#,
+    );
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+
+    let recorder = IGDBClient::builder()
+        .credentials("my_client", "my_secret_token")
+        .transport(live)
+        .cassette(&path, VcrMode::Record)
+        .build()
+        .unwrap()
+        .without_rate_limit();
+    async_std::task::block_on(recorder.query::<GenresClient>(&request)).unwrap();
+
+    let cassette = std::fs::read_to_string(&path).unwrap();
+    assert!(!cassette.contains("my_secret_token"));
+    assert!(!cassette.contains("my_client"));
+    assert_eq!(
+        "client_id=id&client_secret=REDACTED&grant_type=client_credentials",
+        scrub_params("client_id=id&client_secret=secret&grant_type=client_credentials")
+    );
+
+    let replayer = IGDBClient::builder()
+        .credentials("other_client", "other_token")
+        .transport(MockTransport::new())
+        .cassette(&path, VcrMode::Replay)
+        .build()
+        .unwrap()
+        .without_rate_limit();
+    let genres = async_std::task::block_on(replayer.query::<GenresClient>(&request)).unwrap();
+    assert_eq!("Role-playing (RPG)", genres[0].name);
+
+    request.add_field("slug");
+    let missing = async_std::task::block_on(replayer.query::<GenresClient>(&request));
+    assert!(matches!(missing, Err(crate::Error::Http(_))));
+
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,133 @@
+//! Saved searches polled for new and changed registries, see IGDBClient::watch.
+//!
+//! A subscription requests the registries matching its filters updated since the previous
+//! poll, tracked by updated_at and id like IGDBClient::changes_since, so that e.g. the games
+//! added on a platform are noticed without running a webhook server.
+
+use crate::endpoint_client::EndpointClient;
+use crate::request_builder::RequestBuilder;
+use crate::sync::{self, Changes, SyncState};
+use crate::Error;
+use futures::{stream, Stream};
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+
+///A request polled every interval, from now on by default
+/// # Examples
+/// ```
+/// use igdb::request_builder::{Equality, RequestBuilder};
+/// use igdb::sync::SyncState;
+/// use igdb::watch::Subscription;
+/// use std::time::Duration;
+///
+/// let mut request = RequestBuilder::new();
+/// request.add_field("name").add_where("platforms", Equality::Equal, 6);
+///
+/// let subscription = Subscription::new(request, Duration::from_secs(600))
+///     .since(SyncState { updated_at: 1_700_000_000, id: 0 });
+/// ```
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    request: RequestBuilder,
+    interval: Duration,
+    state: Option<SyncState>,
+}
+
+impl Subscription {
+    pub fn new(request: RequestBuilder, interval: Duration) -> Subscription {
+        Subscription {
+            request,
+            interval,
+            state: None,
+        }
+    }
+
+    ///Delivers the registries changed after the state, e.g. the last one delivered before
+    /// a restart, instead of the ones changed from now on
+    pub fn since(mut self, state: SyncState) -> Subscription {
+        self.state = Some(state);
+        self
+    }
+}
+
+///Polls the subscription forever. Changes are delivered as soon as requested, and the next
+/// poll follows right away since a change may be cut at MAX_LIMIT registries; the interval is
+/// only waited once nothing changed or a poll failed, the failed poll being retried.
+pub(crate) fn watch<T: DeserializeOwned>(
+    client: EndpointClient,
+    subscription: Subscription,
+) -> impl Stream<Item = Result<Changes<T>, Error>> {
+    let state = subscription.state.unwrap_or(SyncState {
+        updated_at: sync::now(),
+        id: 0,
+    });
+
+    stream::unfold(
+        (client, subscription, state, false),
+        |(client, subscription, mut state, mut wait)| async move {
+            loop {
+                if wait {
+                    async_std::task::sleep(subscription.interval).await;
+                }
+                match sync::changes_since::<T>(&client, &subscription.request, state).await {
+                    Ok(changes) if changes.is_empty() => wait = true,
+                    Ok(changes) => {
+                        state = changes.state;
+                        return Some((Ok(changes), (client, subscription, state, false)));
+                    }
+                    Err(e) => return Some((Err(e), (client, subscription, state, true))),
+                }
+            }
+        },
+    )
+}
+
+#[test]
+fn watch_delivers_the_registries_changed_since_the_previous_poll() {
+    use crate::client::{GenresClient, IGDBClient};
+    use crate::mock::ScriptedTransport;
+    use crate::model::genre::Genre;
+    use crate::model::ids::EntityId;
+    use futures::StreamExt;
+
+    // Answers nothing to the first poll, then a new genre, then nothing again
+    let polls = ScriptedTransport::new()
+        .then(200, "[]")
+        .then(
+            200,
+            r#"[{"id":33,"name":"Arcade","updated_at":1700000100}]"#,
+        )
+        .then(200, "[]");
+    let igdb = IGDBClient::new("client_id", "token")
+        .with_transport(polls.clone())
+        .without_rate_limit();
+    let mut request = IGDBClient::create_request();
+    request.add_field("name");
+    let subscription = Subscription::new(request, Duration::from_millis(1)).since(SyncState {
+        updated_at: 1_700_000_000,
+        id: 0,
+    });
+
+    let mut changes = Box::pin(igdb.watch::<GenresClient>(subscription));
+    let first: Changes<Genre> = async_std::task::block_on(changes.next()).unwrap().unwrap();
+
+    assert_eq!(
+        vec![33],
+        first
+            .registries
+            .iter()
+            .map(|g| g.id.value())
+            .collect::<Vec<u64>>()
+    );
+    assert_eq!(
+        SyncState {
+            updated_at: 1_700_000_100,
+            id: 33
+        },
+        first.state
+    );
+    let bodies = polls.bodies();
+    assert_eq!(2, bodies.len());
+    assert_eq!(bodies[0], bodies[1]);
+    assert!(bodies[0].contains("updated_at > 1700000000"));
+}
@@ -0,0 +1,253 @@
+//! Management of the webhooks IGDB calls when registries are created, updated or deleted,
+//! see IGDBClient::webhooks. Deliveries carry the registry as json and the secret given
+//! at registration in the X-Secret header, see verify_and_parse.
+
+use crate::auth::{form_encode, Authenticator};
+use crate::endpoint_client::{ClientConfig, EndpointClient};
+use crate::endpoints::Endpoint;
+use crate::model::webhook::{Webhook, WebhookMethod};
+use crate::Error;
+use std::sync::Arc;
+
+const WEBHOOKS_PATH: &str = "webhooks";
+const HEADER_SECRET: &str = "X-Secret";
+
+///Registers, lists, tests and deletes the webhooks of the client id
+/// # Examples
+/// ```no_run
+/// use async_std::task;
+/// use igdb::client::{GamesClient, IGDBClient};
+/// use igdb::model::webhook::WebhookMethod;
+///
+/// task::block_on(async {
+///     let webhooks = IGDBClient::new("client_id", "token").webhooks();
+///     let webhook = webhooks
+///         .register::<GamesClient>("https://example.com/games", "s3cr3t", WebhookMethod::Update)
+///         .await
+///         .unwrap();
+///     webhooks.test::<GamesClient>(webhook.id, 1942).await.unwrap();
+///
+///     for webhook in webhooks.list().await.unwrap() {
+///         println!("{} {}", webhook.id, webhook.url);
+///     }
+/// })
+/// ```
+pub struct WebhooksClient {
+    endpoint_client: EndpointClient,
+}
+
+impl WebhooksClient {
+    pub(crate) fn new(auth: Arc<Authenticator>, config: ClientConfig) -> WebhooksClient {
+        WebhooksClient {
+            endpoint_client: EndpointClient::new(auth, WEBHOOKS_PATH, config),
+        }
+    }
+
+    ///Asks IGDB to post the registries of the given endpoint to url on the given event
+    pub async fn register<E: Endpoint>(
+        &self,
+        url: &str,
+        secret: &str,
+        method: WebhookMethod,
+    ) -> Result<Webhook, Error> {
+        let form = match method.as_str() {
+            Some(method) => format!(
+                "url={}&secret={}&method={}",
+                form_encode(url),
+                form_encode(secret),
+                method
+            ),
+            None => {
+                return Err(Error::InvalidQuery(format!(
+                    "Cannot register a webhook for the unknown method {}",
+                    method.value()
+                )))
+            }
+        };
+        let path = format!("{}/{}/", E::PATH, WEBHOOKS_PATH);
+        let response = self
+            .endpoint_client
+            .call(http::Method::POST, &path, Some(form))
+            .await?;
+        Ok(serde_json::from_slice(response.body())?)
+    }
+
+    ///Returns every webhook registered for the client id
+    pub async fn list(&self) -> Result<Vec<Webhook>, Error> {
+        let path = format!("{}/", WEBHOOKS_PATH);
+        let response = self
+            .endpoint_client
+            .call(http::Method::GET, &path, None)
+            .await?;
+        Ok(serde_json::from_slice(response.body())?)
+    }
+
+    ///Asks IGDB to deliver the registry with the given id to the webhook,
+    /// which is registered for the given endpoint
+    pub async fn test<E: Endpoint>(&self, webhook_id: u64, entity_id: u64) -> Result<(), Error> {
+        let path = format!(
+            "{}/{}/test/{}?entityId={}",
+            E::PATH,
+            WEBHOOKS_PATH,
+            webhook_id,
+            entity_id
+        );
+        self.endpoint_client
+            .call(http::Method::POST, &path, None)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn delete(&self, webhook_id: u64) -> Result<(), Error> {
+        let path = format!("{}/{}", WEBHOOKS_PATH, webhook_id);
+        self.endpoint_client
+            .call(http::Method::DELETE, &path, None)
+            .await?;
+        Ok(())
+    }
+}
+
+///A webhook delivery: the registry that changed and how
+#[derive(Debug, Clone)]
+pub struct WebhookEvent<T> {
+    pub method: WebhookMethod,
+    ///Deleted registries only have their id set
+    pub entity: T,
+}
+
+///Checks that a webhook delivery carries the given secret and deserializes the registry it
+/// delivers. IGDB calls a webhook for a single endpoint, so the model to deserialize is the one
+/// of the endpoint the url was registered for.
+///
+///IGDB does not tell the event apart: deliveries with only an id are deletions, the others are
+/// creations while their created_at and updated_at match, and updates afterwards.
+/// # Examples
+/// ```
+/// use igdb::client::GamesClient;
+/// use igdb::model::webhook::WebhookMethod;
+/// use igdb::webhooks::verify_and_parse;
+///
+/// let mut headers = http::HeaderMap::new();
+/// headers.insert("X-Secret", "s3cr3t".parse().unwrap());
+/// let body = br#"{"id": 1942, "name": "The Witcher 3", "created_at": 1, "updated_at": 2}"#;
+///
+/// let event = verify_and_parse::<GamesClient>(&headers, body, "s3cr3t").unwrap();
+/// assert_eq!(WebhookMethod::Update, event.method);
+/// assert_eq!("The Witcher 3", event.entity.name);
+/// ```
+pub fn verify_and_parse<E: Endpoint>(
+    headers: &http::HeaderMap,
+    body: &[u8],
+    secret: &str,
+) -> Result<WebhookEvent<E::Model>, Error> {
+    let delivered = headers
+        .get(HEADER_SECRET)
+        .map_or(&[][..], |secret| secret.as_bytes());
+    if !constant_time_eq(delivered, secret.as_bytes()) {
+        return Err(Error::WebhookSecret);
+    }
+
+    let fields: serde_json::Map<String, serde_json::Value> = serde_json::from_slice(body)?;
+    let method = event_method(&fields);
+    Ok(WebhookEvent {
+        method,
+        entity: serde_json::from_value(serde_json::Value::Object(fields))?,
+    })
+}
+
+fn event_method(fields: &serde_json::Map<String, serde_json::Value>) -> WebhookMethod {
+    if fields.keys().all(|field| field == "id") {
+        return WebhookMethod::Delete;
+    }
+    match (fields.get("created_at"), fields.get("updated_at")) {
+        (Some(created_at), Some(updated_at)) if created_at != updated_at => WebhookMethod::Update,
+        _ => WebhookMethod::Create,
+    }
+}
+
+///Compares the secrets without returning early, so their content cannot be guessed by timing
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+#[test]
+fn webhook_deliveries_are_verified_and_parsed() {
+    use crate::client::GenresClient;
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert(HEADER_SECRET, http::HeaderValue::from_static("s3cr3t"));
+    let parse = |body: &[u8], secret| verify_and_parse::<GenresClient>(&headers, body, secret);
+
+    let created = parse(
+        br#"{"id":5,"name":"Shooter","created_at":1,"updated_at":1}"#,
+        "s3cr3t",
+    );
+    assert_eq!(WebhookMethod::Create, created.unwrap().method);
+    let deleted = parse(br#"{"id":5}"#, "s3cr3t").unwrap();
+    assert_eq!(
+        (WebhookMethod::Delete, 5),
+        (deleted.method, deleted.entity.id.0)
+    );
+    assert!(matches!(
+        parse(br#"{"id":5}"#, "s3cr3"),
+        Err(Error::WebhookSecret)
+    ));
+}
+
+#[test]
+fn webhooks_are_registered_with_a_form() {
+    use crate::client::{GamesClient, IGDBClient};
+    use crate::transport::{HttpTransport, TransportFuture};
+    use std::sync::Mutex;
+
+    type Requests = Arc<Mutex<Vec<(http::Method, String, String)>>>;
+
+    ///Records the requests and answers an empty webhook
+    struct Recorder(Requests);
+
+    impl HttpTransport for Recorder {
+        fn send(&self, request: http::Request<Vec<u8>>) -> TransportFuture<'_> {
+            self.0.lock().unwrap().push((
+                request.method().clone(),
+                request.uri().to_string(),
+                String::from_utf8_lossy(request.body()).into_owned(),
+            ));
+            Box::pin(async { Ok(http::Response::new(b"{}".to_vec())) })
+        }
+    }
+
+    let requests = Requests::default();
+    let webhooks = IGDBClient::new("client_id", "token")
+        .with_transport(Recorder(requests.clone()))
+        .without_rate_limit()
+        .webhooks();
+    async_std::task::block_on(async {
+        webhooks
+            .register::<GamesClient>(
+                "https://example.com/games?a=b",
+                "s3cr3t",
+                WebhookMethod::Create,
+            )
+            .await
+            .unwrap();
+        webhooks.delete(42).await.unwrap();
+    });
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(
+        (
+            http::Method::POST,
+            "https://api.igdb.com/v4/games/webhooks/".to_owned(),
+            "url=https%3A%2F%2Fexample.com%2Fgames%3Fa%3Db&secret=s3cr3t&method=create".to_owned()
+        ),
+        requests[0]
+    );
+    assert_eq!(
+        (
+            http::Method::DELETE,
+            "https://api.igdb.com/v4/webhooks/42".to_owned(),
+            String::new()
+        ),
+        requests[1]
+    );
+}